@@ -13,7 +13,10 @@ use nexus_api::ApiCache;
 // For async mutex if needed later
 
 mod utils;
-use crate::utils::tempermission::ModOperationEvent;
+use crate::utils::tempermission::{ModOperationEvent, ModOperationGuard};
+
+#[cfg(test)]
+mod tests;
 use utils::config::{
     nuke_settings_and_relaunch,
     load_game_config,
@@ -36,6 +39,18 @@ struct GitHubRelease {
     tag_name: String, // Useful for logging/display
     prerelease: bool, // Nightly might be marked as prerelease
 }
+
+#[derive(Deserialize, Debug)]
+struct GitHubOwner {
+    login: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubRepo {
+    name: String,
+    description: Option<String>,
+    owner: GitHubOwner,
+}
 // --- End GitHub Structs ---
 
 // --- Abstraction for an installable package (like REFramework) ---
@@ -74,10 +89,12 @@ impl Package {
     }
 
     // Ensures the package is installed (downloads/extracts if needed)
+    // `on_progress` receives (bytes downloaded, total bytes if known) while the archive downloads
     async fn ensure_installed(
         &self,
+        app_handle: &AppHandle,
         game_root_path: &str,
-        // app_handle: &AppHandle // Might need app_handle later for config paths etc.
+        on_progress: &(dyn Fn(u64, Option<u64>) + Sync),
     ) -> Result<(), String> {
         log::info!("Ensuring {} is installed in: {}", self.name, game_root_path);
 
@@ -100,7 +117,8 @@ impl Package {
 
             // 1. Fetch release info (using a new helper)
             log::info!("Fetching latest {} release info...", self.name);
-            let release_info = fetch_latest_release("praydog", "REFramework-nightly").await?;
+            let github = GitHubClient::new(app_handle);
+            let release_info = fetch_latest_release(&github, REFRAMEWORK_REPO.0, REFRAMEWORK_REPO.1).await?;
             log::info!(
                 "Latest release tag: {}, Prerelease: {}",
                 release_info.tag_name,
@@ -122,18 +140,28 @@ impl Package {
                 })?;
             log::info!("Found asset URL: {}", asset.browser_download_url);
 
-            // 3. Download the asset (using a new helper)
+            // 3. Download the asset to a temp file; nightly zips are 20+ MB
             log::info!("Downloading {}...", asset.name);
-            let zip_data = download_bytes(&asset.browser_download_url).await?;
-            log::info!("Download complete ({} bytes)", zip_data.len());
+            let staging_root = utils::staging::get_staging_root(app_handle, &target_dir);
+            let scratch = utils::tempdirs::OperationTempDir::new(&staging_root, "download")?;
+            let zip_path = scratch
+                .path()
+                .join(format!("REFramework-{}-{}", release_info.tag_name, asset.name));
+            let downloaded =
+                download_to_file(&github.identity, &asset.browser_download_url, &zip_path, scratch.path(), on_progress).await?;
+            log::info!("Download complete ({} bytes)", downloaded);
 
             // 4. Extract (using the existing helper)
-            let mut archive = zip::ZipArchive::new(std::io::Cursor::new(zip_data))
-                .map_err(|e| format!("Failed to open zip archive: {}", e))?;
-
-            let extracted_count = extract_reframework_files(&mut archive, &target_dir)?;
-
-            if extracted_count == 0 {
+            let extracted = fs::File::open(&zip_path)
+                .map_err(|e| format!("Failed to open {}: {}", zip_path.display(), e))
+                .and_then(|file| {
+                    ZipArchive::new(file).map_err(|e| format!("Failed to open zip archive: {}", e))
+                })
+                .and_then(|mut archive| extract_reframework_files(&mut archive, &target_dir));
+            drop(scratch);
+            let extracted_files = extracted?;
+
+            if extracted_files.is_empty() {
                 log::error!(
                     "{} installation failed: No relevant files found in zip.",
                     self.name
@@ -147,8 +175,19 @@ impl Package {
             log::info!(
                 "{} installation successful. Extracted {} items.",
                 self.name,
-                extracted_count
+                extracted_files.len()
             );
+
+            // Remember what was installed so verify_reframework can spot tampering/quarantine
+            // and remove_reframework knows what to delete
+            if let Err(e) = utils::reframework::record_reframework_install(
+                app_handle,
+                &target_dir,
+                &release_info.tag_name,
+                &extracted_files,
+            ) {
+                log::warn!("Failed to record {} install manifest: {}", self.name, e);
+            }
             Ok(())
         } else {
             log::error!(
@@ -165,16 +204,38 @@ impl Package {
 // --- End Package Abstraction ---
 
 // --- Placeholder Helper Functions ---
+const GITHUB_API_BASE: &str = "https://api.github.com";
+/// (owner, repo) of the REFramework builds we install
+pub(crate) const REFRAMEWORK_REPO: (&str, &str) = ("praydog", "REFramework-nightly");
+
+/// Where GitHub requests go and who they identify as; tests point it at a mock server
+#[derive(Debug, Clone)]
+pub(crate) struct GitHubClient {
+    pub identity: utils::httpidentity::HttpIdentity,
+    pub api_base: String,
+}
+
+impl GitHubClient {
+    /// The public GitHub API with the identity currently configured
+    pub fn new(app_handle: &AppHandle) -> Self {
+        GitHubClient {
+            identity: utils::httpidentity::http_identity(app_handle),
+            api_base: GITHUB_API_BASE.to_string(),
+        }
+    }
+}
+
 // TODO: Implement fetch_latest_release using reqwest and GitHub API
-async fn fetch_latest_release(owner: &str, repo: &str) -> Result<GitHubRelease, String> {
+pub(crate) async fn fetch_latest_release(
+    github: &GitHubClient,
+    owner: &str,
+    repo: &str,
+) -> Result<GitHubRelease, String> {
     log::info!("Fetching latest release for {}/{}...", owner, repo);
     // Adapted from get_latest_reframework_url
-    let client = reqwest::Client::builder()
-        .user_agent("FossModManager/0.1.0") // GitHub requires a User-Agent
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+    let client = utils::httpidentity::http_client(&github.identity, utils::httpidentity::Integration::GitHub)?; // GitHub requires a User-Agent
 
-    let url = format!("https://api.github.com/repos/{}/{}/releases", owner, repo);
+    let url = format!("{}/repos/{}/{}/releases", github.api_base, owner, repo);
     log::debug!("Fetching releases from URL: {}", url);
 
     let response = client
@@ -204,10 +265,10 @@ async fn fetch_latest_release(owner: &str, repo: &str) -> Result<GitHubRelease,
 
     // Find the latest release (prefer non-prerelease, but take first if none)
     // This logic might need refinement depending on tagging conventions
-    let mut releases_iter = releases.into_iter();
-    let latest_release = releases_iter
-        .find(|r| !r.prerelease)
-        .or_else(|| releases_iter.next()) // Fallback to first if no non-prerelease
+    let stable_index = releases.iter().position(|r| !r.prerelease).unwrap_or(0); // Fallback to first if no non-prerelease
+    let latest_release = releases
+        .into_iter()
+        .nth(stable_index)
         .ok_or_else(|| format!("No releases found for {}/{}", owner, repo))?;
 
     log::info!(
@@ -220,11 +281,49 @@ async fn fetch_latest_release(owner: &str, repo: &str) -> Result<GitHubRelease,
     Ok(latest_release)
 }
 
-// TODO: Implement download_bytes using reqwest
-async fn download_bytes(url: &str) -> Result<bytes::Bytes, String> {
-    log::info!("Downloading bytes from: {}", url);
-    let client = reqwest::Client::new();
+/// Name, description and owner of a GitHub repository
+pub(crate) async fn fetch_github_repo(
+    github: &GitHubClient,
+    owner: &str,
+    repo: &str,
+) -> Result<GitHubRepo, String> {
+    let client = utils::httpidentity::http_client(&github.identity, utils::httpidentity::Integration::GitHub)?;
+    let url = format!("{}/repos/{}/{}", github.api_base, owner, repo);
     let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error body".to_string());
+        return Err(format!(
+            "GitHub API request failed for {}: Status {} - {}",
+            url, status, text
+        ));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse GitHub repository JSON from {}: {}", url, e))
+}
+
+/// Stream `url` into `dest` through a ".part" file in `temp_dir`, so an interrupted download
+/// never leaves a truncated archive behind. `on_progress` receives (bytes downloaded, total
+/// bytes if known). Returns the number of bytes written.
+pub(crate) async fn download_to_file(
+    identity: &utils::httpidentity::HttpIdentity,
+    url: &str,
+    dest: &Path,
+    temp_dir: &Path,
+    on_progress: &(dyn Fn(u64, Option<u64>) + Sync),
+) -> Result<u64, String> {
+    log::info!("Downloading {} to {}", url, dest.display());
+    let client = utils::httpidentity::http_client(identity, utils::httpidentity::Integration::Downloads)?;
+    let mut response = client
         .get(url)
         .send()
         .await
@@ -238,27 +337,85 @@ async fn download_bytes(url: &str) -> Result<bytes::Bytes, String> {
         ));
     }
 
-    let data = response
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read download bytes from {}: {}", url, e))?;
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let file_name = dest
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let partial = temp_dir.join(format!("{}.part", file_name));
+    let total = response.content_length();
+    let result = async {
+        let mut out = fs::File::create(&partial)
+            .map_err(|e| format!("Failed to create {}: {}", partial.display(), e))?;
+        let mut downloaded = 0u64;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Download of {} was interrupted: {}", file_name, e))?
+        {
+            io::Write::write_all(&mut out, &chunk)
+                .map_err(|e| format!("Failed to write {}: {}", partial.display(), e))?;
+            downloaded += chunk.len() as u64;
+            on_progress(downloaded, total);
+        }
+        out.sync_all()
+            .map_err(|e| format!("Failed to write {}: {}", partial.display(), e))?;
+        drop(out);
+        utils::fsops::move_path(&partial, dest)
+            .map_err(|e| format!("Failed to move download into place: {}", e))?;
+        Ok(downloaded)
+    }
+    .await;
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+
+    if let Ok(bytes) = &result {
+        log::info!("Successfully downloaded {} bytes from {}", bytes, url);
+    }
+    result
+}
 
-    log::info!("Successfully downloaded {} bytes from {}", data.len(), url);
-    Ok(data)
+/// Progress callback for `download_to_file` that reports to the frontend over `on_event`
+fn channel_download_progress<'a>(
+    on_event: &'a Channel<ModOperationEvent>,
+    label: &'a str,
+) -> impl Fn(u64, Option<u64>) + Sync + 'a {
+    let throttle = utils::throttle::ProgressThrottle::for_ui();
+    move |done, total| {
+        let Some(total) = total.filter(|t| *t > 0) else {
+            return;
+        };
+        if throttle.should_emit(done, total) {
+            let _ = on_event.send(ModOperationEvent::Progress {
+                operation: "download".to_string(),
+                mod_name: label.to_string(),
+                progress: done as f32 / total as f32,
+                message: format!(
+                    "Downloaded {:.1} / {:.1} MB",
+                    done as f64 / 1_048_576.0,
+                    total as f64 / 1_048_576.0
+                ),
+            });
+        }
+    }
 }
 // --- End Placeholder Helpers ---
 
 // --- Existing Helper: REFramework Selective Extraction ---
-fn extract_reframework_files(
-    archive: &mut zip::ZipArchive<std::io::Cursor<bytes::Bytes>>, // Take archive by mutable ref
+fn extract_reframework_files<R: io::Read + io::Seek>(
+    archive: &mut zip::ZipArchive<R>, // Take archive by mutable ref
     target_dir: &Path,
-) -> Result<usize, String> {
-    // Return count of extracted files/dirs
+) -> Result<Vec<PathBuf>, String> {
+    // Return the extracted files, relative to target_dir
     log::info!(
         "Starting REFramework selective extraction to {}",
         target_dir.display()
     );
-    let mut extracted_count = 0;
+    let mut extracted_files = Vec::new();
 
     for i in 0..archive.len() {
         let mut file = match archive.by_index(i) {
@@ -278,7 +435,7 @@ fn extract_reframework_files(
         };
 
         // Filter logic: Must be dinput8.dll at root OR inside reframework/ directory
-        let is_dinput = entry_path == PathBuf::from("dinput8.dll");
+        let is_dinput = entry_path == Path::new("dinput8.dll");
         let is_in_reframework_dir = entry_path.starts_with("reframework/");
 
         if !is_dinput && !is_in_reframework_dir {
@@ -335,7 +492,7 @@ fn extract_reframework_files(
             })?;
             std::io::copy(&mut file, &mut outfile)
                 .map_err(|e| format!("Failed to copy content to {}: {}", outpath.display(), e))?;
-            extracted_count += 1;
+            extracted_files.push(entry_path.clone());
         }
 
         // Set permissions (optional)
@@ -351,10 +508,10 @@ fn extract_reframework_files(
     }
 
     log::info!(
-        "REFramework selective extraction complete. {} files/dirs extracted.",
-        extracted_count
+        "REFramework selective extraction complete. {} files extracted.",
+        extracted_files.len()
     );
-    Ok(extracted_count)
+    Ok(extracted_files)
 }
 
 #[tauri::command]
@@ -366,11 +523,34 @@ async fn check_reframework_installed(game_root_path: String) -> Result<bool, Str
 
 // Rename this command to match todo.md and its behaviour
 #[tauri::command]
-async fn ensure_reframework(_app_handle: AppHandle, game_root_path: String) -> Result<(), String> {
+async fn ensure_reframework(
+    app_handle: AppHandle,
+    game_root_path: String,
+    on_event: Channel<ModOperationEvent>,
+) -> Result<(), String> {
     // Use the Package abstraction
     let reframework_pkg = Package::reframework();
-    // Pass app_handle if needed by ensure_installed later (currently not needed)
-    reframework_pkg.ensure_installed(&game_root_path).await
+    let _ = on_event.send(ModOperationEvent::Started {
+        operation: "install".to_string(),
+        mod_name: reframework_pkg.name.clone(),
+    });
+    let result = reframework_pkg
+        .ensure_installed(
+            &app_handle,
+            &game_root_path,
+            &channel_download_progress(&on_event, &reframework_pkg.name),
+        )
+        .await;
+    let _ = on_event.send(ModOperationEvent::Finished {
+        operation: "install".to_string(),
+        mod_name: reframework_pkg.name.clone(),
+        success: result.is_ok(),
+        message: match &result {
+            Ok(()) => format!("{} is installed", reframework_pkg.name),
+            Err(e) => e.clone(),
+        },
+    });
+    result
 }
 
 // Command to ensure the fossmodmanager/mods directory exists AND open it
@@ -383,9 +563,8 @@ async fn open_mods_folder(app_handle: AppHandle, game_root_path: String) -> Resu
     );
 
     // Construct the mod directory path
-    let mut mod_manager_dir = PathBuf::from(&game_root_path);
-    mod_manager_dir.push("fossmodmanager");
-    mod_manager_dir.push("mods"); // Ensure we target the 'mods' subdirectory
+    let staging_root = utils::staging::get_staging_root(&app_handle, Path::new(&game_root_path));
+    let mod_manager_dir = utils::staging::get_mods_dir(&staging_root);
 
     let mods_path_str = mod_manager_dir
         .to_str()
@@ -437,17 +616,6 @@ struct ModMetadata {
     version: Option<String>,     // Optional: Maybe parsed from filename later
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct SkinMetadata {
-    name: String,
-    path: String,
-    enabled: bool,
-    thumbnail_path: Option<String>,
-    author: Option<String>,
-    version: Option<String>,
-    description: Option<String>,
-}
-
 // #[derive(Debug, Serialize, Deserialize, Clone)]
 // struct ModListContainer {
 //     mods: Vec<ModMetadata>,
@@ -475,15 +643,269 @@ struct SkinMetadata {
 //     Ok(mods_info)
 // }
 
+/// Where an archive came from when it was downloaded through the Nexus API
+#[derive(Debug, Clone, Deserialize)]
+struct NexusInstallSource {
+    game_domain_name: String,
+    mod_id: i64,
+    file_id: i64,
+}
+
+/// Where `extract_reframework_archive` put a mod
+struct ExtractedReframeworkMod {
+    mod_type: utils::modregistry::ModType,
+    mod_dir: PathBuf,
+    installed_directory: String, // Relative to the game root, as stored in the registry
+    preserved_configs: Vec<utils::userconfigs::PreservedConfig>, // Edited config files kept from the previous copy
+    restored_data: Vec<String>, // Script data files put back after the previous copy was replaced
+}
+
+/// Unpack a REFramework plugin/autorun archive into its install target, replacing any previous
+/// copy. `on_progress` receives (entries processed, total entries).
+fn extract_reframework_archive(
+    zip_path: &Path,
+    game_root: &Path,
+    staging_root: &Path,
+    settings: &utils::settings::AppSettings,
+    mod_name: &str,
+    on_progress: &dyn Fn(usize, usize),
+) -> Result<ExtractedReframeworkMod, String> {
+    // Open the zip
+    let file = fs::File::open(zip_path).map_err(|e| format!("Failed to open zip: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Invalid zip archive: {}", e))?;
+
+    // Work out from the layout whether it's a plugins or autorun mod
+    let mod_type_enum = match utils::archives::classify_entry_names(&archive.file_names().collect::<Vec<_>>())? {
+        utils::modregistry::ModType::SkinMod => {
+            return Err("[E_ARCHIVE_IS_SKIN] This archive holds a skin mod (natives/ or .pak files); drop it onto the window or into the mods folder to add it as a skin mod".to_string());
+        }
+        other => other,
+    };
+    let is_autorun = mod_type_enum == utils::modregistry::ModType::REFrameworkAutorun;
+    let mod_type = if is_autorun { "autorun" } else { "plugins" };
+
+    // Destination comes from the configurable per-type install targets
+    let install_targets = &settings.install_targets;
+    let target_rel = install_targets.target_for(&mod_type_enum).to_string();
+    let mod_dir = install_targets.resolve(game_root, &mod_type_enum).join(mod_name);
+    let installed_directory = if target_rel.is_empty() {
+        mod_name.to_string()
+    } else {
+        format!("{}/{}", target_rel.trim_end_matches('/'), mod_name)
+    };
+
+    // Extract into a temp folder first so a bad archive never leaves a half-written mod (or
+    // no mod at all) in the game directory
+    let scratch = utils::tempdirs::OperationTempDir::new(staging_root, "extract")?;
+    let staged_dir = scratch.path().join(mod_name);
+    fs::create_dir_all(&staged_dir)
+        .map_err(|e| format!("Failed to create mod directory: {}", e))?;
+    let mut written: Vec<PathBuf> = Vec::new();
+
+    // Plugins often ship data folders next to the DLL. Archives without a
+    // reframework/<type>/ layout are taken as the mod folder itself (minus a shared root)
+    let entry_paths: Vec<PathBuf> = (0..archive.len())
+        .filter_map(|i| {
+            let f = archive.by_index(i).ok()?;
+            if f.is_dir() { None } else { f.enclosed_name() }
+        })
+        .collect();
+    let has_type_dir = entry_paths
+        .iter()
+        .any(|p| p.components().any(|c| c.as_os_str() == mod_type));
+    let shared_root = entry_paths
+        .first()
+        .and_then(|p| p.components().next())
+        .filter(|root| {
+            entry_paths
+                .iter()
+                .all(|p| p.components().count() > 1 && p.components().next() == Some(*root))
+        })
+        .map(|root| PathBuf::from(root.as_os_str()));
+
+    // Track if we extracted anything
+    let mut extracted = 0;
+    let entry_count = archive.len();
+
+    // Extract files - this part remains largely the same
+    for i in 0..archive.len() {
+        on_progress(i + 1, entry_count);
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+
+        // Skip directories
+        if file.is_dir() {
+            continue;
+        }
+
+        // enclosed_name rejects entries that would escape the mod directory
+        let Some(path) = file.enclosed_name() else {
+            log::warn!("Skipping unsafe zip entry: {}", file.name());
+            continue;
+        };
+        let name = file.name();
+
+        // Root fallback - single lua or dll files
+        if path.components().count() == 1 {
+            if name.ends_with(".lua") && mod_type == "autorun" {
+                let target = staged_dir.join(name);
+                let mut outfile = fs::File::create(&target)
+                    .map_err(|e| format!("Failed to create file: {}", e))?;
+                io::copy(&mut file, &mut outfile)
+                    .map_err(|e| format!("Failed to write file: {}", e))?;
+                written.push(target);
+                extracted += 1;
+            } else if name.ends_with(".dll")
+                && name != "dinput8.dll"
+                && mod_type == "plugins"
+            {
+                let target = staged_dir.join(name);
+                let mut outfile = fs::File::create(&target)
+                    .map_err(|e| format!("Failed to create file: {}", e))?;
+                io::copy(&mut file, &mut outfile)
+                    .map_err(|e| format!("Failed to write file: {}", e))?;
+                written.push(target);
+                extracted += 1;
+            }
+            continue;
+        }
+
+        // Extract files from reframework/plugins or reframework/autorun, keeping any
+        // nested folders; otherwise keep the archive's own layout
+        let rel_path: PathBuf = if has_type_dir {
+            path.components()
+                .skip_while(|c| c.as_os_str() != mod_type)
+                .skip(1) // Skip the mod_type component itself
+                .collect()
+        } else {
+            match &shared_root {
+                Some(root) => path.strip_prefix(root).map(Path::to_path_buf).unwrap_or_default(),
+                None => path.clone(),
+            }
+        };
+        if !rel_path.as_os_str().is_empty() {
+            let target = staged_dir.join(&rel_path);
+
+            // Create parent directories
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+
+            // Extract the file
+            let mut outfile = fs::File::create(&target)
+                .map_err(|e| format!("Failed to create file: {}", e))?;
+            io::copy(&mut file, &mut outfile)
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+            written.push(target);
+            extracted += 1;
+        }
+    }
+
+    if extracted == 0 {
+        return Err("No valid mod files found in zip".to_string());
+    }
+
+    // Config files the user edited since the last install survive the update
+    let preserved_configs = utils::userconfigs::carry_over_user_edits(game_root, &mod_dir, &staged_dir)?;
+    // So is data the scripts saved for themselves, which replacing the folder would wipe
+    let script_data = utils::scriptdata::find_script_data(
+        game_root,
+        &settings.script_data_patterns,
+        mod_name,
+        &installed_directory,
+    )?;
+    let script_data_dir = scratch.path().join("script-data");
+    utils::scriptdata::snapshot_script_data(game_root, &script_data, &script_data_dir)?;
+
+    // Replace any existing copy with the staged one
+    let ledger = utils::auditlog::LedgerOperation::begin(game_root, "install_reframework_mod");
+    if mod_dir.exists() {
+        fs::remove_dir_all(&mod_dir)
+            .map_err(|e| format!("Failed to remove existing mod: {}", e))?;
+        ledger.deleted(&mod_dir, true);
+    }
+    utils::fsops::move_path(&staged_dir, &mod_dir)?;
+    for target in written.iter().filter_map(|t| t.strip_prefix(&staged_dir).ok()) {
+        let target = mod_dir.join(target);
+        let rel = target.strip_prefix(game_root).unwrap_or(&target).to_string_lossy().replace('\\', "/");
+        match preserved_configs.iter().find(|p| p.path == rel) {
+            Some(p) => {
+                // Record what the mod shipped, not the user's copy now in its place
+                if let Some(sha256) = &p.shipped_sha256 {
+                    ledger.wrote_hashed(&target, sha256.clone());
+                }
+            }
+            None => ledger.wrote(&target, false),
+        }
+    }
+    for sidecar in preserved_configs.iter().filter_map(|p| p.new_version_path.as_ref()) {
+        ledger.wrote(&game_root.join(sidecar), false);
+    }
+    let restored_data = utils::scriptdata::restore_script_data(game_root, &script_data, &script_data_dir)?;
+    for rel in &restored_data {
+        ledger.wrote(&game_root.join(rel), false);
+    }
+
+    Ok(ExtractedReframeworkMod {
+        mod_type: mod_type_enum,
+        mod_dir,
+        installed_directory,
+        preserved_configs,
+        restored_data,
+    })
+}
+
 #[tauri::command]
 async fn install_mod_from_zip(
     app_handle: AppHandle,
     game_root_path: String,
     zip_path_str: String,
+    nexus_source: Option<NexusInstallSource>,
     on_event: Channel<ModOperationEvent>,
 ) -> Result<(), String> {
+    let _in_flight = ModOperationGuard::begin(&app_handle);
     let game_root = PathBuf::from(&game_root_path);
-    let zip_path = PathBuf::from(&zip_path_str);
+
+    // Remember exactly which Nexus file this was so updates compare against the same variant
+    let nexus_file = match &nexus_source {
+        Some(src) => Some(
+            match nexus_api::fetch_file_details(
+                &nexus_api::NexusClient::new(&app_handle)?,
+                &src.game_domain_name,
+                src.mod_id,
+                src.file_id,
+            )
+            .await
+            {
+                Ok(file) => utils::modregistry::NexusFileInfo {
+                    file_id: file.file_id,
+                    file_name: file.file_name,
+                    category: file.category_name,
+                    version: file.version,
+                },
+                Err(e) => {
+                    log::warn!("Failed to fetch Nexus file {} details: {}", src.file_id, e);
+                    utils::modregistry::NexusFileInfo {
+                        file_id: src.file_id,
+                        file_name: Path::new(&zip_path_str)
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_default(),
+                        category: None,
+                        version: None,
+                    }
+                }
+            },
+        ),
+        None => None,
+    };
+
+    // Multi-part archives get joined into a single zip first
+    let staging_root = utils::staging::get_staging_root(&app_handle, &game_root);
+    let scratch = utils::tempdirs::OperationTempDir::new(&staging_root, "install")?;
+    let zip_path = utils::archives::resolve_split_archive(Path::new(&zip_path_str), scratch.path())?;
 
     // Get mod name from zip filename
     let _original_zip_name = zip_path
@@ -499,143 +921,81 @@ async fn install_mod_from_zip(
         .ok_or_else(|| "Couldn't determine mod name".to_string())?;
 
     // Use secure access wrapper
-    with_game_dir_write_access(
+    let result = with_game_dir_write_access(
         &app_handle,
         &game_root,
         &on_event,
         "install",
         &parsed_name,
-        |_channel| {
-            // Open the zip
-            let file =
-                fs::File::open(&zip_path).map_err(|e| format!("Failed to open zip: {}", e))?;
-            let mut archive =
-                ZipArchive::new(file).map_err(|e| format!("Invalid zip archive: {}", e))?;
-
-            // Scan once to detect if it's a plugins or autorun mod
-            let mut is_autorun = false;
-            for i in 0..archive.len() {
-                if let Ok(entry) = archive.by_index(i) {
-                    if entry.name().contains("autorun/") {
-                        is_autorun = true;
-                        break;
-                    }
-                }
-            }
-
-            // Create the mod directory
-            let mod_type = if is_autorun { "autorun" } else { "plugins" };
-
-            let mod_type_enum = if is_autorun {
-                utils::modregistry::ModType::REFrameworkAutorun
-            } else {
-                utils::modregistry::ModType::REFrameworkPlugin
-            };
-
-            let rf_path = game_root.join("reframework");
-            let mod_dir = rf_path.join(mod_type).join(&parsed_name);
-
-            // Clean up existing mod
-            if mod_dir.exists() {
-                fs::remove_dir_all(&mod_dir)
-                    .map_err(|e| format!("Failed to remove existing mod: {}", e))?;
-            }
-            fs::create_dir_all(&mod_dir)
-                .map_err(|e| format!("Failed to create mod directory: {}", e))?;
-
-            // Track if we extracted anything
-            let mut extracted = 0;
-
-            // Extract files - this part remains largely the same
-            for i in 0..archive.len() {
-                let mut file = archive
-                    .by_index(i)
-                    .map_err(|e| format!("Failed to read zip entry: {}", e))?;
-
-                // Skip directories
-                if file.is_dir() {
-                    continue;
-                }
-
-                let name = file.name();
-
-                // Root fallback - single lua or dll files
-                if !name.contains('/') {
-                    if name.ends_with(".lua") && mod_type == "autorun" {
-                        let target = mod_dir.join(name);
-                        let mut outfile = fs::File::create(&target)
-                            .map_err(|e| format!("Failed to create file: {}", e))?;
-                        io::copy(&mut file, &mut outfile)
-                            .map_err(|e| format!("Failed to write file: {}", e))?;
-                        extracted += 1;
-                    } else if name.ends_with(".dll")
-                        && name != "dinput8.dll"
-                        && mod_type == "plugins"
-                    {
-                        let target = mod_dir.join(name);
-                        let mut outfile = fs::File::create(&target)
-                            .map_err(|e| format!("Failed to create file: {}", e))?;
-                        io::copy(&mut file, &mut outfile)
-                            .map_err(|e| format!("Failed to write file: {}", e))?;
-                        extracted += 1;
-                    }
-                    continue;
-                }
-
-                // Extract files from reframework/plugins or reframework/autorun
-                let path = PathBuf::from(name);
-                if let Some(rel_path) = path
-                    .components()
-                    .skip_while(|c| c.as_os_str() != mod_type)
-                    .skip(1) // Skip the mod_type component itself
-                    .collect::<PathBuf>()
-                    .to_str()
-                {
-                    let target = mod_dir.join(rel_path);
-
-                    // Create parent directories
-                    if let Some(parent) = target.parent() {
-                        fs::create_dir_all(parent)
-                            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        |channel| {
+            let settings = utils::settings::AppSettings::load(&app_handle)?;
+            let throttle = utils::throttle::ProgressThrottle::for_ui();
+            let ExtractedReframeworkMod {
+                mod_type: mod_type_enum,
+                mod_dir,
+                installed_directory: rel_path,
+                preserved_configs,
+                restored_data,
+            } = extract_reframework_archive(
+                &zip_path,
+                &game_root,
+                &staging_root,
+                &settings,
+                &parsed_name,
+                &|done, total| {
+                    if throttle.should_emit(done as u64, total as u64) {
+                        let _ = channel.send(ModOperationEvent::Progress {
+                            operation: "install".to_string(),
+                            mod_name: parsed_name.clone(),
+                            progress: done as f32 / total as f32,
+                            message: format!("Extracting {}/{}", done, total),
+                        });
                     }
+                },
+            )?;
 
-                    // Extract the file
-                    let mut outfile = fs::File::create(&target)
-                        .map_err(|e| format!("Failed to create file: {}", e))?;
-                    io::copy(&mut file, &mut outfile)
-                        .map_err(|e| format!("Failed to write file: {}", e))?;
-                    extracted += 1;
-                }
-            }
-
-            if extracted == 0 {
-                return Err("No valid mod files found in zip".to_string());
+            utils::userconfigs::notify_preserved_configs(&app_handle, &parsed_name, &preserved_configs);
+            if !restored_data.is_empty() {
+                log::info!("Kept {} script data file(s) for '{}'", restored_data.len(), parsed_name);
             }
 
-            // This part changes to use ModRegistry
-            let rel_path = format!("reframework/{}/{}", mod_type, parsed_name);
-
             // Load registry instead of modlist.json
             let mut registry = utils::modregistry::ModRegistry::load(&app_handle)?;
 
             // Create new mod entry
-            let new_mod = utils::modregistry::Mod {
-                name: parsed_name.clone(),
-                directory_name: parsed_name.clone(),
+            let mut new_mod = utils::modregistry::Mod {
                 path: zip_path_str.clone(),
                 enabled: true, // Newly installed mods start enabled
-                author: None,
-                version: None,
-                description: None,
-                source: Some("local_zip".to_string()),
-                installed_timestamp: chrono::Utc::now().timestamp(),
+                version: nexus_file.as_ref().and_then(|f| f.version.clone()),
                 installed_directory: rel_path,
-                mod_type: mod_type_enum,
+                nexus_mod_id: nexus_source.as_ref().map(|src| src.mod_id),
+                nexus_file: nexus_file.clone(),
+                origin_url: nexus_source.as_ref().map(|src| {
+                    format!(
+                        "{}?tab=files&file_id={}",
+                        nexus_api::nexus_mod_page_url(&src.game_domain_name, src.mod_id),
+                        src.file_id
+                    )
+                }),
+                dependencies: utils::dependencies::read_declared_dependencies(&mod_dir),
+                ..utils::modregistry::Mod::new(
+                    parsed_name.clone(),
+                    parsed_name.clone(),
+                    mod_type_enum,
+                    if nexus_source.is_some() { "nexus" } else { "local_zip" },
+                )
             };
+            utils::modregistry::cache_reframework_mod_thumbnail(&app_handle, &game_root, &mut new_mod);
+            utils::modregistry::apply_readme_metadata(&mod_dir, &mut new_mod);
+            // Extracting replaced whatever was in that folder, so its entry is updated in place
+            if let Some(existing) = registry.mods.iter().find(|m| m.installed_directory == new_mod.installed_directory) {
+                new_mod.id = existing.id.clone();
+            }
 
             // Add to registry and save
             registry.add_mod(new_mod);
             registry.save(&app_handle)?;
+            utils::posthooks::run_post_install_hook(&app_handle, &settings, &parsed_name, &mod_dir);
 
             log::info!(
                 "Successfully installed mod '{}' and updated registry",
@@ -644,7 +1004,197 @@ async fn install_mod_from_zip(
             Ok(())
         },
     )
-    .await
+    .await;
+    drop(scratch);
+    utils::telemetry::track_result(&app_handle, "install_mod_from_zip", result)
+}
+
+/// Register skin mods imported from a Nexus download, keeping the file they came from
+fn register_nexus_skin_download(
+    app_handle: &AppHandle,
+    staging_root: &Path,
+    link: &utils::launchargs::NxmLink,
+    downloaded: &nexus_api::DownloadedModFile,
+) -> Result<usize, String> {
+    let imported = utils::archives::import_skin_archive(&downloaded.path, staging_root)?;
+    let mut registry = utils::modregistry::ModRegistry::load(app_handle)?;
+    let mut installed = Vec::new();
+    for dir in &imported {
+        let mut skin = utils::modregistry::new_skin_mod_from_dir(dir, "nexus");
+        skin.base.version = downloaded.file.version.clone();
+        skin.base.nexus_mod_id = Some(link.mod_id);
+        skin.base.nexus_file = Some(utils::modregistry::NexusFileInfo {
+            file_id: downloaded.file.file_id,
+            file_name: downloaded.file.file_name.clone(),
+            category: downloaded.file.category_name.clone(),
+            version: downloaded.file.version.clone(),
+        });
+        skin.base.origin_url = Some(format!(
+            "{}?tab=files&file_id={}",
+            nexus_api::nexus_mod_page_url(&link.game_domain, link.mod_id),
+            downloaded.file.file_id
+        ));
+        installed.push((skin.base.directory_name.clone(), dir.clone()));
+        registry.add_skin_mod(skin);
+    }
+    registry.save(app_handle)?;
+    let settings = utils::settings::AppSettings::load(app_handle)?;
+    for (mod_name, dir) in &installed {
+        utils::posthooks::run_post_install_hook(app_handle, &settings, mod_name, dir);
+    }
+    Ok(imported.len())
+}
+
+/// Download the file an nxm:// link ("Mod Manager Download" on Nexus) points at into
+/// fossmodmanager/downloads and install it: REFramework mods through install_mod_from_zip,
+/// skin mods into the mods folder.
+#[tauri::command]
+async fn install_from_nxm_link(
+    app_handle: AppHandle,
+    game_root_path: String,
+    nxm_url: String,
+    on_event: Channel<ModOperationEvent>,
+) -> Result<(), String> {
+    let link = utils::launchargs::parse_nxm_url(&nxm_url)?;
+    let file_id = link
+        .file_id
+        .ok_or_else(|| format!("nxm link doesn't name a file: {}", nxm_url))?;
+    let staging_root = utils::staging::get_staging_root(&app_handle, Path::new(&game_root_path));
+    let label = format!("Nexus mod {}", link.mod_id);
+    log::info!("Handling nxm link for mod {} file {}", link.mod_id, file_id);
+
+    let _ = on_event.send(ModOperationEvent::Started {
+        operation: "download".to_string(),
+        mod_name: label.clone(),
+    });
+    let scratch = utils::tempdirs::OperationTempDir::new(&staging_root, "download")?;
+    let downloaded = nexus_api::download_mod_file(
+        &nexus_api::NexusClient::new(&app_handle)?,
+        &link,
+        &utils::archives::get_downloads_dir(&staging_root),
+        scratch.path(),
+        &channel_download_progress(&on_event, &label),
+    )
+    .await;
+    drop(scratch);
+    let _ = on_event.send(ModOperationEvent::Finished {
+        operation: "download".to_string(),
+        mod_name: label.clone(),
+        success: downloaded.is_ok(),
+        message: match &downloaded {
+            Ok(d) => format!("Downloaded {}", d.file.file_name),
+            Err(e) => e.clone(),
+        },
+    });
+    let downloaded = utils::telemetry::track_result(&app_handle, "install_from_nxm_link", downloaded)?;
+    // Only once downloaded; the download itself doesn't touch the game folder
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+
+    if !utils::archives::is_skin_archive(&downloaded.path)? {
+        return install_mod_from_zip(
+            app_handle,
+            game_root_path,
+            downloaded.path.to_string_lossy().to_string(),
+            Some(NexusInstallSource {
+                game_domain_name: link.game_domain.clone(),
+                mod_id: link.mod_id,
+                file_id,
+            }),
+            on_event,
+        )
+        .await;
+    }
+
+    let result = register_nexus_skin_download(&app_handle, &staging_root, &link, &downloaded);
+    let _ = on_event.send(ModOperationEvent::Finished {
+        operation: "install".to_string(),
+        mod_name: downloaded.file.name.clone(),
+        success: result.is_ok(),
+        message: match &result {
+            Ok(count) => format!("Added {} skin mod(s); enable them from the skins tab", count),
+            Err(e) => e.clone(),
+        },
+    });
+    result.map(|_| ())
+}
+
+/// Add the skin mods in a local archive to the mods folder and registry, keeping a copy of the
+/// archive with the retained ones
+fn register_local_skin_archive(app_handle: &AppHandle, staging_root: &Path, zip_path: &Path) -> Result<usize, String> {
+    let imported = utils::archives::import_skin_archive(zip_path, staging_root)?;
+    if let Err(e) = utils::archives::retain_archive_copy(staging_root, zip_path, &imported) {
+        log::warn!("Imported {} but failed to keep a copy: {}", zip_path.display(), e);
+    }
+    let mut registry = utils::modregistry::ModRegistry::load(app_handle)?;
+    let mut installed = Vec::new();
+    for dir in &imported {
+        let skin = utils::modregistry::new_skin_mod_from_dir(dir, "local_zip");
+        installed.push((skin.base.directory_name.clone(), dir.clone()));
+        registry.add_skin_mod(skin);
+    }
+    registry.save(app_handle)?;
+    let settings = utils::settings::AppSettings::load(app_handle)?;
+    for (mod_name, dir) in &installed {
+        utils::posthooks::run_post_install_hook(app_handle, &settings, mod_name, dir);
+    }
+    Ok(imported.len())
+}
+
+/// Install whatever archive was dropped onto the window. The layout decides where it goes:
+/// REFramework plugins and scripts through install_mod_from_zip, skin mods into the mods
+/// folder. Returns the kind it was installed as.
+#[tauri::command]
+async fn install_mod_from_archive(
+    app_handle: AppHandle,
+    game_root_path: String,
+    archive_path: String,
+    on_event: Channel<ModOperationEvent>,
+) -> Result<utils::modregistry::ModType, String> {
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    let staging_root = utils::staging::get_staging_root(&app_handle, Path::new(&game_root_path));
+    let scratch = utils::tempdirs::OperationTempDir::new(&staging_root, "install")?;
+    let zip_path = utils::archives::resolve_split_archive(Path::new(&archive_path), scratch.path())?;
+    let is_zip = zip_path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+    let kind = if is_zip {
+        utils::archives::classify_archive(&zip_path)
+    } else {
+        Err(format!(
+            "[E_ARCHIVE_FORMAT] Only .zip archives can be installed; extract {} with 7-Zip and re-pack it as a .zip",
+            zip_path.display()
+        ))
+    };
+    log::info!("Classified dropped archive {} as {:?}", archive_path, kind);
+
+    let skin_result = matches!(kind, Ok(utils::modregistry::ModType::SkinMod)).then(|| {
+        let label = zip_path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let _ = on_event.send(ModOperationEvent::Started {
+            operation: "install".to_string(),
+            mod_name: label.clone(),
+        });
+        let result = register_local_skin_archive(&app_handle, &staging_root, &zip_path);
+        let _ = on_event.send(ModOperationEvent::Finished {
+            operation: "install".to_string(),
+            mod_name: label,
+            success: result.is_ok(),
+            message: match &result {
+                Ok(count) => format!("Added {} skin mod(s); enable them from the skins tab", count),
+                Err(e) => e.clone(),
+            },
+        });
+        result
+    });
+
+    // install_mod_from_zip joins split archives itself
+    drop(scratch);
+    let kind = utils::telemetry::track_result(&app_handle, "install_mod_from_archive", kind)?;
+    if let Some(result) = skin_result {
+        return utils::telemetry::track_result(&app_handle, "install_mod_from_archive", result.map(|_| kind));
+    }
+    install_mod_from_zip(app_handle, game_root_path, archive_path, None, on_event)
+        .await
+        .map(|_| kind)
 }
 
 // --- Helper Function ---
@@ -717,23 +1267,31 @@ async fn preload_mod_assets(app_handle: AppHandle, mods: Vec<String>) -> Result<
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct CurrentStartupInfo {
     needs_setup: bool,
+    crashed_last_session: bool,
+    crash_log_path: Option<String>, // Log written by the panic hook when the last session crashed
 }
 
 // Modify the command function
 #[tauri::command]
 async fn get_startup_state(app_handle: AppHandle) -> Result<CurrentStartupInfo, String> {
     log::info!("get_startup_state: Checking current config status...");
+    let last_crash = utils::crashreport::last_crash();
+    let startup_info = |needs_setup: bool| CurrentStartupInfo {
+        needs_setup,
+        crashed_last_session: last_crash.is_some(),
+        crash_log_path: last_crash.as_ref().map(|c| c.log_path.clone()),
+    };
     // Directly call load_game_config to get the current status
     match utils::config::load_game_config(app_handle).await {
         Ok(Some(_)) => {
             // Config exists
             log::info!("get_startup_state: Config found, setup NOT needed.");
-            Ok(CurrentStartupInfo { needs_setup: false })
+            Ok(startup_info(false))
         }
         Ok(None) => {
             // Config does not exist
             log::info!("get_startup_state: Config NOT found, setup IS needed.");
-            Ok(CurrentStartupInfo { needs_setup: true })
+            Ok(startup_info(true))
         }
         Err(e) => {
             // Error loading config, assume setup needed as a safe default
@@ -741,7 +1299,7 @@ async fn get_startup_state(app_handle: AppHandle) -> Result<CurrentStartupInfo,
                 "get_startup_state: Error loading config: {}. Assuming setup needed.",
                 e
             );
-            Ok(CurrentStartupInfo { needs_setup: true })
+            Ok(startup_info(true))
             // Alternatively, return an error: Err(format!("Failed to check startup state: {}", e))
         }
     }
@@ -751,6 +1309,7 @@ async fn get_startup_state(app_handle: AppHandle) -> Result<CurrentStartupInfo,
 pub fn run() {
     // env_logger::init();
     // log::info!("Starting Foss Mod Manager");
+    utils::startuptiming::mark_process_start();
     let env = env_logger::Env::default().filter_or("RUST_LOG", "info"); // Default to info level
 
     env_logger::Builder::from_env(env)
@@ -778,17 +1337,16 @@ pub fn run() {
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
-            println!("Another instance tried to start: {:?} in {:?}", argv, cwd);
-            // Attempt to focus the main window if another instance starts
-            if let Some(main_window) = app.get_webview_window("main") {
-                let _ = main_window.unminimize();
-                let _ = main_window.set_focus();
-            }
+            log::info!("Another instance tried to start: {:?} in {:?}", argv, cwd);
+            // Focus the main window and route whatever it was asked to open
+            utils::launchargs::route_forwarded_args(app, &argv, &cwd);
         }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![
+        // Every frontend call is checked against the permission tiers before reaching a command
+        .invoke_handler(utils::permissions::with_permission_check(tauri::generate_handler![
             // Standard commands
             save_game_config,
             load_game_config,
@@ -796,41 +1354,235 @@ pub fn run() {
             nuke_settings_and_relaunch,
             check_reframework_installed,
             ensure_reframework,
+            utils::reframework::verify_reframework,
+            utils::reframework::remove_reframework,
+            // Sample mod commands
+            utils::samplemod::install_sample_mod,
+            utils::samplemod::check_sample_mod,
+            utils::samplemod::remove_sample_mod,
+            utils::quarantine::check_antivirus_quarantine,
+            utils::sessiondiff::get_session_changes,
+            utils::healthcheck::run_health_check,
+            utils::prelaunch::pre_launch_check,
+            utils::safemode::record_game_exit,
+            utils::gamelaunch::launch_game,
+            utils::safemode::get_safe_mode_status,
+            utils::safemode::enter_safe_mode,
+            utils::safemode::exit_safe_mode,
             install_mod_from_zip,
+            install_mod_from_archive,
+            utils::userconfigs::resolve_preserved_config,
+            install_from_nxm_link,
+            utils::launchargs::take_pending_launch_actions,
             open_mods_folder,
             preload_mod_assets,
             // Add the new command to the handler list
             get_startup_state,
+            utils::backendinfo::get_backend_info,
             // Nexus API commands
             nexus_api::fetch_trending_mods,
+            nexus_api::fetch_latest_added,
+            nexus_api::fetch_latest_updated,
+            nexus_api::search_mods,
+            nexus_api::apikey::set_nexus_api_key,
+            nexus_api::apikey::get_nexus_api_key_status,
+            nexus_api::apikey::validate_nexus_api_key,
+            nexus_api::sso::begin_sso_login,
+            nexus_api::sso::cancel_sso_login,
+            nexus_api::match_mod_on_nexus,
+            nexus_api::apply_nexus_match,
+            nexus_api::updates::check_for_mod_updates,
+            utils::metadatarefresh::refresh_all_metadata,
+            nexus_api::ratelimit::get_nexus_rate_limit_status,
             // Mod registry commands
             utils::modregistry::toggle_mod_enabled_state,
             utils::modregistry::list_mods,
+            utils::modwatcher::start_mod_watcher,
+            utils::modwatcher::stop_mod_watcher,
+            utils::contentindex::search_installed_mods,
+            utils::modregistry::get_mod_readme,
+            utils::modregistry::get_mod_details,
+            utils::modregistry::set_mod_rating,
+            utils::modregistry::set_mod_tags,
+            utils::savebackups::create_save_backup,
+            utils::savebackups::list_save_backups,
+            utils::savebackups::enable_with_save_backup,
+            utils::modregistry::get_mod_stats,
+            utils::modregistry::set_skin_mod_packing,
             // Cache thumbs commands
             utils::cachethumbs::read_mod_image,
             utils::cachethumbs::cache_mod_image,
             utils::cachethumbs::get_cached_mod_images,
+            utils::thumbimport::import_thumbnails,
+            utils::posthooks::rerun_post_install_hook,
             // Skin management commands (now from modregistry)
             utils::modregistry::scan_and_update_skin_mods, // Renamed
             utils::modregistry::enable_skin_mod_via_registry, // Renamed
             utils::modregistry::disable_skin_mod_via_registry, // Renamed
+            utils::overwrites::restore_original_files,
             utils::modregistry::list_skin_mods_from_registry, // Renamed
+            utils::modregistry::add_external_skin_mod,
+            utils::conflicts::check_skin_conflicts,
+            utils::conflicts::generate_conflict_report,
+            utils::adoption::scan_unmanaged_natives,
+            utils::adoption::adopt_natives_group,
+            // Dependency commands
+            utils::dependencies::get_mod_dependents,
+            utils::dependencies::set_mod_dependencies,
+            // Launch option commands
+            utils::launchnotes::set_mod_launch_notes,
+            utils::launchnotes::get_required_launch_options,
+            // Known-broken list commands
+            utils::knownbroken::get_known_broken_list,
+            utils::knownbroken::update_known_broken_list,
+            // Checksum database commands
+            utils::checksumdb::update_checksum_db,
+            utils::checksumdb::identify_unknown_mods,
             // Add the new delete commands
             utils::modregistry::delete_reframework_mod,
             utils::modregistry::delete_skin_mod,
-        ])
+            utils::modregistry::uninstall_mod,
+            utils::modregistry::delete_mods,
+            // Trash commands
+            utils::trash::list_deleted_mods,
+            utils::trash::restore_deleted_mod,
+            // Integrity commands
+            utils::integrity::verify_mod_integrity,
+            utils::gameupdate::detect_game_update,
+            utils::gameupdate::redeploy_all_enabled_mods,
+            // Storage cleanup commands
+            utils::cleanup::suggest_cleanup,
+            utils::cleanup::run_cleanup,
+            // Restore point commands
+            utils::restorepoints::create_restore_point,
+            utils::restorepoints::list_restore_points,
+            utils::restorepoints::restore_to_point,
+            utils::restorepoints::delete_restore_point,
+            utils::restorepoints::set_restore_points_root,
+            // Profile commands
+            utils::profiles::list_profiles,
+            utils::profiles::create_profile,
+            utils::profiles::switch_profile,
+            utils::profiles::apply_desired_state,
+            utils::profiles::delete_profile,
+            // Config backup commands
+            utils::backups::create_config_backup,
+            utils::backups::list_config_backups,
+            utils::backups::restore_config_backup,
+            utils::legacyimport::import_legacy_modlist,
+            // Export commands
+            utils::archives::export_mod,
+            utils::setupcode::export_setup_code,
+            utils::setupcode::import_setup_code,
+            utils::auditlog::export_audit_ledger,
+            // Pak management commands
+            utils::pakregistry::dedupe_paks,
+            utils::pakregistry::get_pak_load_order,
+            utils::pakregistry::set_pak_load_order,
+            // Settings commands
+            utils::settings::get_app_settings,
+            utils::settings::save_app_settings,
+            utils::featureflags::set_feature_flag,
+            utils::permissions::get_command_permissions,
+            // Error reporting commands
+            utils::telemetry::get_telemetry_summary,
+            utils::telemetry::submit_telemetry,
+            utils::telemetry::clear_telemetry,
+            utils::config::relocate_game,
+            utils::staging::set_staging_root,
+            // Sandbox commands
+            utils::sandbox::get_sandbox_status,
+            utils::sandbox::enable_sandbox,
+            utils::sandbox::disable_sandbox,
+        ]))
         .setup(|app| {
             log::info!("Executing Tauri setup closure...");
             let app_handle = app.handle().clone(); // Clone handle for use
+            use utils::startuptiming::time_phase;
+            time_phase("early_init", || {
+                utils::crashreport::init_crash_reporting(&app_handle);
+                utils::launchargs::init_protocol_handler(&app_handle);
+                utils::staging::init_staging_root(&app_handle);
+                utils::restorepoints::init_restore_points_root(&app_handle);
+                utils::httpidentity::init_http_identity(&app_handle);
+                nexus_api::apikey::init_nexus_api_key(&app_handle);
+                nexus_api::sso::init_sso(&app_handle);
+                utils::sandbox::init_sandbox(&app_handle);
+                utils::confirmation::init_confirmations(&app_handle);
+                utils::modwatcher::init_mod_watcher(&app_handle);
+                utils::tempermission::init_mod_operations(&app_handle);
+                utils::sessiondiff::init_session_changes(&app_handle);
+            });
+            let lock_result = time_phase("instance_lock", || {
+                utils::instancelock::acquire_instance_lock(&app_handle)
+            });
+            let holds_instance_lock = match lock_result {
+                Ok(true) => true,
+                Ok(false) => {
+                    log::warn!(
+                        "Another instance holds the registry lock; changes are refused until it exits"
+                    );
+                    false
+                }
+                Err(e) => {
+                    log::error!("Failed to take the instance lock: {}", e);
+                    false
+                }
+            };
+            // Skin registries and profiles from older releases are migrated once, by the instance that can save
+            if holds_instance_lock {
+                time_phase("legacy_migration", || {
+                    match utils::modregistry::migrate_legacy_skin_registries(&app_handle) {
+                        Ok(0) => {}
+                        Ok(imported) => {
+                            log::info!("Imported {} skin mods from a legacy skin registry", imported)
+                        }
+                        Err(e) => log::warn!("Failed to import legacy skin registries: {}", e),
+                    }
+                });
+                time_phase("profile_migration", || {
+                    match utils::profiles::migrate_profile_keys(&app_handle) {
+                        Ok(0) => {}
+                        Ok(changed) => log::info!("Re-keyed {} profile entries by mod id", changed),
+                        Err(e) => log::warn!("Failed to re-key profiles by mod id: {}", e),
+                    }
+                });
+            }
 
             // --- Startup Validation (Determine initial window visibility) ---
-            let mut needs_setup_initially = false; // Rename variable for clarity
+            let needs_setup_initially; // Rename variable for clarity
             // Keep this initial check ONLY for deciding which window to show first
-            match tauri::async_runtime::block_on(utils::config::load_game_config(
-                app_handle.clone(),
-            )) {
-                Ok(Some(_)) => {
+            let config_result = time_phase("config_load", || {
+                tauri::async_runtime::block_on(utils::config::load_game_config(app_handle.clone()))
+            });
+            match config_result {
+                Ok(Some(game_data)) => {
                     needs_setup_initially = false;
+                    let game_root = Path::new(&game_data.game_root_path);
+                    let staging_root = utils::staging::get_staging_root(&app_handle, game_root);
+                    let layout_result = time_phase("directory_layout", || {
+                        utils::config::ensure_directory_layout(game_root, &staging_root)
+                    });
+                    if let Err(e) = layout_result {
+                        log::warn!("Failed to set up mod folders: {}", e);
+                    }
+                    // Another instance may be mid-extraction in the same folders
+                    if holds_instance_lock {
+                        utils::tempdirs::spawn_startup_sweep(staging_root);
+                    }
+                    // Keep the mod list in sync with changes made outside the manager
+                    if let Err(e) = utils::modwatcher::start_watching(
+                        &app_handle,
+                        Path::new(&game_data.game_root_path),
+                    ) {
+                        log::warn!("Mod folders won't be watched: {}", e);
+                    }
+                    // Tell the user about anything that changed while we weren't running
+                    utils::sessiondiff::spawn_startup_check(
+                        app_handle.clone(),
+                        PathBuf::from(&game_data.game_root_path),
+                        PathBuf::from(&game_data.game_executable_path),
+                    );
                 }
                 Ok(None) => {
                     log::info!("Initial check: User config not found. Setup required.");
@@ -848,6 +1600,13 @@ pub fn run() {
                 }
             }
 
+            // A damaged registry is recovered from its backup on first load; say so early
+            if let Err(e) = time_phase("registry_validation", || {
+                utils::modregistry::ModRegistry::validate_registry(&app_handle)
+            }) {
+                log::warn!("Mod registry failed validation at startup: {}", e);
+            }
+
             // Remove the management of the old StartupState
             // let startup_state = StartupState { needs_setup: needs_setup_initially };
             // app.manage(startup_state);
@@ -874,16 +1633,44 @@ pub fn run() {
                     .map_err(|e| format!("Failed to close setup window: {}", e))?;
             }
 
+            // Surface the window ourselves if the frontend never reports in
+            let startup_window = if needs_setup_initially { "setup" } else { "main" };
+            utils::watchdog::arm_frontend_watchdog(&app_handle, startup_window);
+
             // Ensure API cache system is initialized
-            let cache = ApiCache::new(app_handle.clone());
-            app.manage(cache);
-            log::info!("API Cache managed.");
+            time_phase("cache_init", || {
+                let cache = ApiCache::new(app_handle.clone());
+                app.manage(cache);
+                log::info!("API Cache managed.");
+
+                // Clean up thumbnails of removed mods in the background
+                utils::cachethumbs::start_cache_cleanup_worker(app_handle.clone());
+            });
+
+            // Periodic background jobs
+            utils::scheduler::start_scheduler(
+                app_handle.clone(),
+                vec![
+                    utils::backups::scheduled_backup_job(),
+                    utils::knownbroken::scheduled_known_broken_job(),
+                    utils::checksumdb::scheduled_checksum_db_job(),
+                ],
+            );
 
             // Attach close handler to main window (still needed)
             let close_handle = app_handle.clone();
             main_window.on_window_event(move |event| {
                 if let WindowEvent::CloseRequested { .. } = event {
                     log::info!("Main window close requested. Exiting application.");
+                    if let Ok(Some(game_data)) = tauri::async_runtime::block_on(
+                        utils::config::load_game_config(close_handle.clone()),
+                    ) {
+                        utils::sessiondiff::record_session_snapshot(
+                            &close_handle,
+                            Path::new(&game_data.game_root_path),
+                            Path::new(&game_data.game_executable_path),
+                        );
+                    }
                     close_handle.exit(0); // Exit the entire application
                 }
             });
@@ -924,6 +1711,7 @@ pub fn run() {
             });
             log::info!("Global listener for 'setup-complete' added.");
 
+            utils::startuptiming::mark_setup_done();
             Ok(())
         })
         .run(tauri::generate_context!())
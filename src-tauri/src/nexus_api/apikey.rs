@@ -0,0 +1,300 @@
+// src-tauri/src/nexus_api/apikey.rs
+// The user's personal Nexus API key, entered in settings. It lives in the OS keyring
+// (Credential Manager, Keychain, Secret Service); where no keyring is available it falls back
+// to a file in app data readable only by the user. NEXUS_API_KEY in the environment or a .env
+// file still works for development, but a stored key takes precedence.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+use super::{nexus_headers, rate_limit_error, ratelimit, NexusClient};
+use crate::utils::httpidentity::{http_client, Integration, APP_NAME};
+
+const KEYRING_USER: &str = "nexus_api_key";
+const KEY_FILE: &str = "nexus_api_key";
+const ACCOUNT_FILE: &str = "nexus_account.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyStorage {
+    Keyring,
+    File,        // No usable keyring; stored in app data
+    Environment, // NEXUS_API_KEY from the environment or .env
+}
+
+/// Who the key belongs to, as reported by /users/validate.json
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NexusAccount {
+    pub user_id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub is_premium: bool,
+    #[serde(default)]
+    pub is_supporter: bool,
+    #[serde(default)]
+    pub profile_url: Option<String>,
+    #[serde(default)]
+    pub validated_timestamp: i64, // Set by us; not part of the Nexus response
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NexusApiKeyStatus {
+    pub configured: bool,
+    pub storage: Option<ApiKeyStorage>,
+    pub key_hint: Option<String>, // Last four characters, to tell keys apart
+    pub account: Option<NexusAccount>, // From the last successful validation
+}
+
+/// The stored key, loaded at startup and replaced when the user saves a new one
+pub struct NexusApiKeyState(RwLock<Option<(String, ApiKeyStorage)>>);
+
+fn read_stored_key(app_handle: &AppHandle) -> Result<Option<(String, ApiKeyStorage)>, String> {
+    app_handle
+        .state::<NexusApiKeyState>()
+        .0
+        .read()
+        .map(|stored| stored.clone())
+        .map_err(|_| "The stored Nexus API key is unavailable; restart the app".to_string())
+}
+
+fn write_stored_key(app_handle: &AppHandle, stored: Option<(String, ApiKeyStorage)>) -> Result<(), String> {
+    *app_handle
+        .state::<NexusApiKeyState>()
+        .0
+        .write()
+        .map_err(|_| "The stored Nexus API key is unavailable; restart the app".to_string())? = stored;
+    Ok(())
+}
+
+fn keyring_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(APP_NAME, KEYRING_USER).map_err(|e| format!("Failed to open the OS keyring: {}", e))
+}
+
+fn get_key_file_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    Ok(data_dir.join(KEY_FILE))
+}
+
+fn get_account_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    Ok(get_key_file_path(app_handle)?.with_file_name(ACCOUNT_FILE))
+}
+
+/// Fallback storage, readable only by the user
+#[cfg(unix)]
+fn write_key_file(path: &Path, api_key: &str) -> Result<(), String> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .map_err(|e| format!("Failed to write API key file: {}", e))?;
+    file.write_all(api_key.as_bytes())
+        .map_err(|e| format!("Failed to write API key file: {}", e))
+}
+
+/// Fallback storage; app data is already per-user on Windows
+#[cfg(not(unix))]
+fn write_key_file(path: &Path, api_key: &str) -> Result<(), String> {
+    fs::write(path, api_key).map_err(|e| format!("Failed to write API key file: {}", e))
+}
+
+fn load_account(app_handle: &AppHandle) -> Option<NexusAccount> {
+    let content = fs::read_to_string(get_account_path(app_handle).ok()?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub(super) fn save_account(app_handle: &AppHandle, account: Option<&NexusAccount>) -> Result<(), String> {
+    let path = get_account_path(app_handle)?;
+    match account {
+        Some(account) => {
+            let content = serde_json::to_string_pretty(account)
+                .map_err(|e| format!("Failed to serialize Nexus account: {}", e))?;
+            fs::write(&path, content).map_err(|e| format!("Failed to write Nexus account: {}", e))
+        }
+        None if path.exists() => {
+            fs::remove_file(&path).map_err(|e| format!("Failed to remove Nexus account: {}", e))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Load the stored key into managed state; call once at startup
+pub fn init_nexus_api_key(app_handle: &AppHandle) {
+    let from_keyring = keyring_entry().and_then(|entry| match entry.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read the OS keyring: {}", e)),
+    });
+    let stored = match from_keyring {
+        Ok(Some(key)) => Some((key, ApiKeyStorage::Keyring)),
+        result => {
+            if let Err(e) = result {
+                warn!("{}", e);
+            }
+            get_key_file_path(app_handle)
+                .ok()
+                .and_then(|p| fs::read_to_string(p).ok())
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .map(|k| (k, ApiKeyStorage::File))
+        }
+    };
+    if let Some((_, storage)) = &stored {
+        info!("Loaded Nexus API key ({:?})", storage);
+    }
+    app_handle.manage(NexusApiKeyState(RwLock::new(stored)));
+}
+
+/// The key saved through settings, if any
+pub(crate) fn stored_api_key(app_handle: &AppHandle) -> Result<Option<String>, String> {
+    Ok(read_stored_key(app_handle)?.map(|(key, _)| key))
+}
+
+/// Store the key in the keyring, or the app data file when there is no keyring
+pub(super) fn store_api_key(app_handle: &AppHandle, api_key: &str) -> Result<ApiKeyStorage, String> {
+    let key_file = get_key_file_path(app_handle)?;
+    let storage = match keyring_entry().and_then(|entry| {
+        entry
+            .set_password(api_key)
+            .map_err(|e| format!("Failed to write to the OS keyring: {}", e))
+    }) {
+        Ok(()) => {
+            // A key left in the fallback file from before would be stale now
+            if key_file.exists() {
+                fs::remove_file(&key_file)
+                    .map_err(|e| format!("Failed to remove old API key file: {}", e))?;
+            }
+            ApiKeyStorage::Keyring
+        }
+        Err(e) => {
+            warn!("{}; storing the Nexus API key in app data instead", e);
+            write_key_file(&key_file, api_key)?;
+            ApiKeyStorage::File
+        }
+    };
+    write_stored_key(app_handle, Some((api_key.to_string(), storage)))?;
+    Ok(storage)
+}
+
+fn clear_api_key(app_handle: &AppHandle) -> Result<(), String> {
+    match keyring_entry().map(|entry| entry.delete_credential()) {
+        Ok(Ok(())) | Ok(Err(keyring::Error::NoEntry)) => {}
+        Ok(Err(e)) => warn!("Failed to remove the Nexus API key from the OS keyring: {}", e),
+        Err(e) => warn!("{}", e),
+    }
+    let key_file = get_key_file_path(app_handle)?;
+    if key_file.exists() {
+        fs::remove_file(&key_file).map_err(|e| format!("Failed to remove API key file: {}", e))?;
+    }
+    write_stored_key(app_handle, None)
+}
+
+/// Check a key against /users/validate.json
+pub(crate) async fn validate_api_key(client: &NexusClient, api_key: &str) -> Result<NexusAccount, String> {
+    let request_url = format!("{}/users/validate.json", client.v1_base_url);
+    ratelimit::acquire(&client.cache).await?;
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .get(&request_url)
+        .headers(nexus_headers(&client.identity, api_key)?)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err("[E_NEXUS_KEY_INVALID] Nexus rejected the API key".to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("Nexus API key validation failed with status {}", response.status()));
+    }
+    let mut account = response
+        .json::<NexusAccount>()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus key validation: {}", e))?;
+    account.validated_timestamp = chrono::Utc::now().timestamp();
+    Ok(account)
+}
+
+pub(super) fn key_status(app_handle: &AppHandle) -> Result<NexusApiKeyStatus, String> {
+    let (key, storage) = match read_stored_key(app_handle)? {
+        Some((key, storage)) => (Some(key), Some(storage)),
+        None => match super::env_api_key() {
+            Some(key) => (Some(key), Some(ApiKeyStorage::Environment)),
+            None => (None, None),
+        },
+    };
+    Ok(NexusApiKeyStatus {
+        configured: key.is_some(),
+        storage,
+        key_hint: key.map(|k| k.chars().rev().take(4).collect::<Vec<_>>().into_iter().rev().collect()),
+        account: storage.and_then(|_| load_account(app_handle)),
+    })
+}
+
+/// Save the user's Nexus API key, or remove it when `api_key` is empty. The key is checked
+/// against Nexus first unless `skip_validation` is set (e.g. while offline).
+#[tauri::command]
+pub async fn set_nexus_api_key(
+    app_handle: AppHandle,
+    api_key: Option<String>,
+    skip_validation: Option<bool>,
+) -> Result<NexusApiKeyStatus, String> {
+    let api_key = api_key.map(|k| k.trim().to_string()).filter(|k| !k.is_empty());
+    let Some(api_key) = api_key else {
+        clear_api_key(&app_handle)?;
+        save_account(&app_handle, None)?;
+        info!("Removed the stored Nexus API key");
+        return key_status(&app_handle);
+    };
+
+    let account = if skip_validation.unwrap_or(false) {
+        None
+    } else {
+        Some(validate_api_key(&NexusClient::new(&app_handle)?, &api_key).await?)
+    };
+    let storage = store_api_key(&app_handle, &api_key)?;
+    save_account(&app_handle, account.as_ref())?;
+    info!(
+        "Stored Nexus API key ({:?}){}",
+        storage,
+        account.as_ref().map(|a| format!(" for {}", a.name)).unwrap_or_default()
+    );
+    key_status(&app_handle)
+}
+
+#[tauri::command]
+pub async fn get_nexus_api_key_status(app_handle: AppHandle) -> Result<NexusApiKeyStatus, String> {
+    key_status(&app_handle)
+}
+
+/// Validate `api_key`, or the key currently in use when none is given. Validating the key in
+/// use refreshes the account details shown in settings.
+#[tauri::command]
+pub async fn validate_nexus_api_key(
+    app_handle: AppHandle,
+    api_key: Option<String>,
+) -> Result<NexusAccount, String> {
+    let candidate = api_key.map(|k| k.trim().to_string()).filter(|k| !k.is_empty());
+    let checking_current = candidate.is_none();
+    let api_key = match candidate {
+        Some(key) => key,
+        None => super::nexus_api_key(&app_handle)?.ok_or_else(super::missing_key_error)?,
+    };
+    let account = validate_api_key(&NexusClient::new(&app_handle)?, &api_key).await?;
+    if checking_current {
+        save_account(&app_handle, Some(&account))?;
+    }
+    Ok(account)
+}
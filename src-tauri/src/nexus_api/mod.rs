@@ -3,31 +3,132 @@ use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, USER_AGENT};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
-use std::time::{Duration, Instant};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::Manager;
+
+use crate::utils::cachethumbs::{cache_downscaled_image, get_image_cache_dir};
+use crate::utils::httpidentity::{
+    http_client, http_identity, user_agent, HttpIdentity, Integration, APP_NAME, APP_VERSION,
+};
+use crate::utils::launchargs::NxmLink;
+use crate::utils::modregistry::{Mod, ModInfo, ModRegistry, ModUpdateCheck};
+
+pub mod apikey;
+pub mod ratelimit;
+pub mod sso;
+pub mod updates;
 
 // --- Cache Structures ---
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CacheEntry {
     pub data: Vec<NexusMod>,
-    pub timestamp: Instant,
+    pub fetched_timestamp: i64, // Unix seconds, so entries keep their age across restarts
+}
+
+impl CacheEntry {
+    fn new(data: Vec<NexusMod>) -> Self {
+        Self {
+            data,
+            fetched_timestamp: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        chrono::Utc::now().timestamp() - self.fetched_timestamp < CACHE_DURATION.as_secs() as i64
+    }
 }
 
-// Wrapper struct for the cache state to be managed by Tauri
+// Wrapper struct for the cache state to be managed by Tauri. Entries are also written to
+// app_cache_dir/fossmodmanager/api so a restart doesn't cost a fresh round of API calls.
 #[derive(Default)] // Add default derive for easy initialization
 pub struct ApiCache {
     // Store entries directly in a HashMap
     pub entries: HashMap<String, CacheEntry>,
+    cache_dir: Option<PathBuf>, // None keeps the cache in memory only
+    refresh_attempts: HashMap<String, i64>, // Key -> when a background refresh last started
+    rate_limiter: ratelimit::RateLimiter,
 }
 
 // Add constructor implementation for ApiCache
 impl ApiCache {
-    pub fn new(_app_handle: tauri::AppHandle) -> std::sync::Arc<tokio::sync::Mutex<Self>> {
-        std::sync::Arc::new(tokio::sync::Mutex::new(Self::default()))
+    pub fn new(app_handle: tauri::AppHandle) -> std::sync::Arc<tokio::sync::Mutex<Self>> {
+        let cache_dir = match app_handle.path().app_cache_dir() {
+            Ok(dir) => Some(dir.join("fossmodmanager").join("api")),
+            Err(e) => {
+                log::warn!("No app cache dir, Nexus API cache will be memory-only: {}", e);
+                None
+            }
+        };
+        std::sync::Arc::new(tokio::sync::Mutex::new(Self {
+            cache_dir,
+            ..Self::default()
+        }))
+    }
+
+    /// A cache backed by `dir`, for tests
+    #[cfg(test)]
+    pub(crate) fn in_dir(dir: PathBuf) -> std::sync::Arc<tokio::sync::Mutex<Self>> {
+        std::sync::Arc::new(tokio::sync::Mutex::new(Self {
+            cache_dir: Some(dir),
+            ..Self::default()
+        }))
+    }
+
+    fn entry_path(&self, key: &str) -> Option<PathBuf> {
+        let file_name: String = key
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect();
+        Some(self.cache_dir.as_ref()?.join(format!("{}.json", file_name)))
+    }
+
+    /// The entry for `key` from memory, or from disk if an earlier run cached it
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        if let Some(entry) = self.entries.get(key) {
+            return Some(entry.clone());
+        }
+        let content = fs::read_to_string(self.entry_path(key)?).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content)
+            .map_err(|e| log::warn!("Ignoring unreadable API cache entry '{}': {}", key, e))
+            .ok()?;
+        self.entries.insert(key.to_string(), entry.clone());
+        Some(entry)
+    }
+
+    fn insert(&mut self, key: String, entry: CacheEntry) {
+        if let Some(path) = self.entry_path(&key) {
+            let written = fs::create_dir_all(path.parent().unwrap_or(&path))
+                .map_err(|e| e.to_string())
+                .and_then(|_| serde_json::to_string(&entry).map_err(|e| e.to_string()))
+                .and_then(|json| fs::write(&path, json).map_err(|e| e.to_string()));
+            if let Err(e) = written {
+                log::warn!("Failed to persist API cache entry '{}': {}", key, e);
+            }
+        }
+        self.refresh_attempts.remove(&key);
+        self.entries.insert(key, entry);
+    }
+
+    /// Claim the background refresh of a stale entry, unless one started recently (still
+    /// running, or failed -- e.g. rate limited -- and not due for another try)
+    fn start_refresh(&mut self, key: &str) -> bool {
+        let now = chrono::Utc::now().timestamp();
+        match self.refresh_attempts.get(key) {
+            Some(started) if now - started < REFRESH_RETRY_INTERVAL.as_secs() as i64 => false,
+            _ => {
+                self.refresh_attempts.insert(key.to_string(), now);
+                true
+            }
+        }
     }
 }
 
 const CACHE_DURATION: Duration = Duration::from_secs(3600);
+/// How long a failed background refresh waits before the next attempt
+const REFRESH_RETRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
 
 // --- Nexus Mods API Structures (V1 REST API) ---
 
@@ -60,109 +161,156 @@ pub struct NexusMod {
 // --- End Nexus Mods API Structures ---
 
 // Constants
-// const NEXUS_API_URL_GRAPHQL: &str = "https://api.nexusmods.com/v2/graphql"; // Keep if needed later
+const NEXUS_API_URL_GRAPHQL: &str = "https://api.nexusmods.com/v2/graphql";
 const NEXUS_API_URL_V1_BASE: &str = "https://api.nexusmods.com/v1";
-const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
-const APP_NAME: &str = "fossmodmanager";
+const MATCH_CANDIDATE_COUNT: u32 = 10; // Candidates offered when matching a mod by name
+const SEARCH_PAGE_SIZE: u32 = 20; // Results per page when the browse tab doesn't say
+const SEARCH_MAX_PAGE_SIZE: u32 = 100;
 
-// Removed execute_query as it was for GraphQL
+/// Where Nexus requests go and who they identify as, plus the cache whose quota tracker they
+/// report to. Commands talk to the public API; tests point the URLs at a mock server.
+#[derive(Clone)]
+pub struct NexusClient {
+    pub identity: HttpIdentity,
+    pub api_key: Option<String>, // None until the user adds one
+    pub v1_base_url: String,
+    pub graphql_url: String,
+    pub cache: std::sync::Arc<tokio::sync::Mutex<ApiCache>>,
+}
 
-#[tauri::command]
-pub async fn fetch_trending_mods(
-    game_domain_name: String,
-    state: tauri::State<'_, std::sync::Arc<tokio::sync::Mutex<ApiCache>>>,
-    // count: Option<u32>, // V1 trending doesn't seem to support count directly
-) -> Result<Vec<NexusMod>, String> {
-    let now = Instant::now();
+impl NexusClient {
+    /// The public Nexus API with the identity, key and cache currently in use
+    pub fn new(app_handle: &tauri::AppHandle) -> Result<Self, String> {
+        let cache = app_handle
+            .state::<std::sync::Arc<tokio::sync::Mutex<ApiCache>>>()
+            .inner()
+            .clone();
+        Ok(NexusClient {
+            api_key: nexus_api_key(app_handle)?,
+            ..Self::with_identity(http_identity(app_handle), cache)
+        })
+    }
 
-    // --- Cache Check ---
-    {
-        let cache_map_lock = state.lock().await;
-        if let Some(entry) = cache_map_lock.entries.get(&game_domain_name) {
-            if now.duration_since(entry.timestamp) < CACHE_DURATION {
-                println!(
-                    "Cache hit for game: '{}'. Returning cached data.",
-                    game_domain_name
-                );
-                return Ok(entry.data.clone());
-            }
-            println!(
-                "Cache expired for game: '{}'. Fetching fresh data.",
-                game_domain_name
-            );
-        } else {
-            println!(
-                "Cache miss for game: '{}'. Fetching data.",
-                game_domain_name
-            );
+    /// The public Nexus API identifying as `identity`, without a key
+    pub fn with_identity(identity: HttpIdentity, cache: std::sync::Arc<tokio::sync::Mutex<ApiCache>>) -> Self {
+        NexusClient {
+            identity,
+            api_key: None,
+            cache,
+            v1_base_url: NEXUS_API_URL_V1_BASE.to_string(),
+            graphql_url: NEXUS_API_URL_GRAPHQL.to_string(),
         }
     }
 
-    // --- API Fetch (if cache miss or expired) ---
-    println!("Proceeding with API fetch for game: '{}'", game_domain_name);
+    /// The key requests are sent with
+    fn api_key(&self) -> Result<String, String> {
+        self.api_key.clone().ok_or_else(missing_key_error)
+    }
+}
+
+/// Record the quota headers of a Nexus response; for a 429, the error reporting them
+async fn rate_limit_error(client: &NexusClient, response: &reqwest::Response) -> Option<String> {
+    ratelimit::record(&client.cache, response).await;
+    if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("unknown")
+            .to_string()
+    };
+    Some(format!(
+        "Nexus API rate limit reached (hourly remaining: {}, daily remaining: {}); the hourly quota resets at {}",
+        header("x-rl-hourly-remaining"),
+        header("x-rl-daily-remaining"),
+        header("x-rl-hourly-reset")
+    ))
+}
 
+/// NEXUS_API_KEY from the environment (or .env file), for development
+fn env_api_key() -> Option<String> {
     // Load environment variables from .env file
     dotenv().ok(); // Ignore error if .env is not found, API key might be set elsewhere
+    env::var("NEXUS_API_KEY").ok()
+}
 
-    // Get API key from environment
-    let api_key = env::var("NEXUS_API_KEY")
-        .map_err(|_| "NEXUS_API_KEY not found in environment variables or .env file".to_string())?;
+/// The Nexus API key saved in settings, or NEXUS_API_KEY from the environment
+pub(crate) fn nexus_api_key(app_handle: &tauri::AppHandle) -> Result<Option<String>, String> {
+    Ok(apikey::stored_api_key(app_handle)?.or_else(env_api_key))
+}
 
-    let client = reqwest::Client::new();
+fn missing_key_error() -> String {
+    "[E_NEXUS_KEY_MISSING] No Nexus API key configured; add your personal API key in settings".to_string()
+}
 
-    // Construct the V1 API URL
-    let request_url = format!(
-        "{}/games/{}/mods/latest_added.json",
-        NEXUS_API_URL_V1_BASE, game_domain_name
-    );
-    println!("Fetching latest added mods from: {}", request_url);
+/// Whether a Nexus API key is available, without exposing it
+pub(crate) fn nexus_api_key_configured(app_handle: &tauri::AppHandle) -> Result<bool, String> {
+    Ok(nexus_api_key(app_handle)?.is_some())
+}
 
-    // Construct headers for V1
+/// Headers shared by every Nexus API request
+fn nexus_headers(identity: &HttpIdentity, api_key: &str) -> Result<HeaderMap, String> {
     let mut headers = HeaderMap::new();
-    let user_agent_string = format!("{}/{} (Rust; reqwest)", APP_NAME, APP_VERSION);
     headers.insert(
         USER_AGENT,
-        HeaderValue::from_str(&user_agent_string)
+        HeaderValue::from_str(&user_agent(identity, Integration::Nexus))
             .map_err(|e| format!("Invalid User-Agent header value: {}", e))?,
     );
+    // Nexus asks API clients to identify the application separately from the User-Agent
+    headers.insert(HeaderName::from_static("application-name"), HeaderValue::from_static(APP_NAME));
+    headers.insert(HeaderName::from_static("application-version"), HeaderValue::from_static(APP_VERSION));
     headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
     // Use HeaderName for the custom API key header
     headers.insert(
         HeaderName::from_static("apikey"),
-        HeaderValue::from_str(&api_key).map_err(|_| "Invalid API Key format".to_string())?,
+        HeaderValue::from_str(api_key).map_err(|_| "Invalid API Key format".to_string())?,
+    );
+    Ok(headers)
+}
+
+/// Request one of the V1 mod lists (`trending`, `latest_added`, `latest_updated`) for a game
+async fn request_mod_list(
+    client: &NexusClient,
+    game_domain_name: &str,
+    list: &str,
+) -> Result<Vec<NexusMod>, String> {
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+
+    let http = http_client(&client.identity, Integration::Nexus)?;
+
+    // Construct the V1 API URL
+    let request_url = format!(
+        "{}/games/{}/mods/{}.json",
+        client.v1_base_url, game_domain_name, list
     );
+    log::info!("Fetching {} mods from: {}", list, request_url);
+
+    // Construct headers for V1
+    let headers = nexus_headers(&client.identity, &api_key)?;
 
     // Send request
-    let response = client
+    let response = http
         .get(&request_url)
         .headers(headers)
         .send()
         .await
         .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
 
     // Check status and parse response
     if response.status().is_success() {
-        let mods = response.json::<Vec<NexusMod>>().await.map_err(|e| {
+        response.json::<Vec<NexusMod>>().await.map_err(|e| {
             format!(
                 "Failed to parse Nexus API V1 response into Vec<NexusMod>: {}. URL: {}",
                 e, request_url
             )
-        })?;
-
-        // --- Cache Update ---
-        {
-            let mut cache_map_lock = state.lock().await;
-            println!("Updating cache for game: '{}'", game_domain_name);
-            let new_entry = CacheEntry {
-                data: mods.clone(),
-                timestamp: Instant::now(),
-            };
-            cache_map_lock
-                .entries
-                .insert(game_domain_name.clone(), new_entry);
-        }
-
-        Ok(mods)
+        })
     } else {
         let status = response.status();
         let error_body = response
@@ -175,4 +323,669 @@ pub async fn fetch_trending_mods(
         ))
     }
 }
+
+/// A V1 mod list through the cache. Fresh entries are returned as is; stale ones (including
+/// those left on disk by an earlier run) are returned straight away while a background
+/// request refreshes them, so a cold start doesn't wait on -- or spend quota on -- the API.
+pub(crate) async fn fetch_mod_list(
+    client: &NexusClient,
+    game_domain_name: &str,
+    list: &str,
+) -> Result<Vec<NexusMod>, String> {
+    let cache_key = format!("{}:{}", game_domain_name, list);
+    let state = &client.cache;
+
+    // --- Cache Check ---
+    let cached = state.lock().await.get(&cache_key);
+    if let Some(entry) = cached {
+        if entry.is_fresh() {
+            log::debug!("Cache hit for '{}'", cache_key);
+            return Ok(entry.data);
+        }
+        if state.lock().await.start_refresh(&cache_key) {
+            log::info!("Serving stale '{}' while refreshing it", cache_key);
+            let state = state.clone();
+            let client = client.clone();
+            let game_domain_name = game_domain_name.to_string();
+            let list = list.to_string();
+            tauri::async_runtime::spawn(async move {
+                match request_mod_list(&client, &game_domain_name, &list).await {
+                    Ok(mods) => state.lock().await.insert(cache_key, CacheEntry::new(mods)),
+                    Err(e) => log::warn!("Background refresh of '{}' failed: {}", cache_key, e),
+                }
+            });
+        }
+        return Ok(entry.data);
+    }
+
+    // --- API Fetch (nothing cached yet) ---
+    log::info!("Cache miss for '{}'. Fetching data.", cache_key);
+    let mods = request_mod_list(client, game_domain_name, list).await?;
+    state.lock().await.insert(cache_key, CacheEntry::new(mods.clone()));
+    Ok(mods)
+}
+
+#[tauri::command]
+pub async fn fetch_trending_mods(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+) -> Result<Vec<NexusMod>, String> {
+    fetch_mod_list(&NexusClient::new(&app_handle)?, &game_domain_name, "trending").await
+}
+
+/// The mods most recently published for a game
+#[tauri::command]
+pub async fn fetch_latest_added(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+) -> Result<Vec<NexusMod>, String> {
+    fetch_mod_list(&NexusClient::new(&app_handle)?, &game_domain_name, "latest_added").await
+}
+
+/// The mods most recently updated for a game
+#[tauri::command]
+pub async fn fetch_latest_updated(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+) -> Result<Vec<NexusMod>, String> {
+    fetch_mod_list(&NexusClient::new(&app_handle)?, &game_domain_name, "latest_updated").await
+}
+
 // Removed GraphQL related TODOs
+
+// --- Matching manually installed mods ---
+
+// Shape of a mod node in the V2 GraphQL `mods` query
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlModNode {
+    mod_id: i64,
+    name: String,
+    summary: Option<String>,
+    version: Option<String>,
+    picture_url: Option<String>,
+    author: Option<String>,
+    endorsements: Option<i64>,
+    downloads: Option<i64>,
+}
+
+impl From<GraphQlModNode> for NexusMod {
+    fn from(node: GraphQlModNode) -> Self {
+        NexusMod {
+            mod_id: node.mod_id,
+            name: node.name,
+            summary: node.summary,
+            version: node.version,
+            picture_url: node.picture_url,
+            updated_timestamp: None,
+            endorsements_count: node.endorsements,
+            total_downloads: node.downloads,
+            total_unique_downloads: None,
+            author: node.author,
+            uploaded_timestamp: None,
+            external_virus_scan_url: None,
+        }
+    }
+}
+
+/// Public page of a Nexus mod
+pub fn nexus_mod_page_url(game_domain_name: &str, mod_id: i64) -> String {
+    format!("https://www.nexusmods.com/{}/mods/{}", game_domain_name, mod_id)
+}
+
+/// Fetch a single mod's details from the V1 API
+pub async fn fetch_mod_details(
+    client: &NexusClient,
+    game_domain_name: &str,
+    mod_id: i64,
+) -> Result<NexusMod, String> {
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+    let request_url = format!(
+        "{}/games/{}/mods/{}.json",
+        client.v1_base_url, game_domain_name, mod_id
+    );
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .get(&request_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Nexus API V1 request failed with status {} at URL {}",
+            response.status(),
+            request_url
+        ));
+    }
+    response
+        .json::<NexusMod>()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus mod {}: {}", mod_id, e))
+}
+
+/// A mod's details through the API cache, so repeat lookups within the hour cost no request
+pub(crate) async fn fetch_mod_details_cached(
+    client: &NexusClient,
+    game_domain_name: &str,
+    mod_id: i64,
+) -> Result<NexusMod, String> {
+    let cache_key = format!("{}:mod:{}", game_domain_name, mod_id);
+    let state = &client.cache;
+    let cached = state.lock().await.get(&cache_key);
+    if let Some(details) = cached.filter(CacheEntry::is_fresh).and_then(|e| e.data.into_iter().next()) {
+        log::debug!("Cache hit for '{}'", cache_key);
+        return Ok(details);
+    }
+    let details = fetch_mod_details(client, game_domain_name, mod_id).await?;
+    state.lock().await.insert(cache_key, CacheEntry::new(vec![details.clone()]));
+    Ok(details)
+}
+
+/// A single downloadable file of a Nexus mod (V1 files endpoint)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NexusModFile {
+    pub file_id: i64,
+    pub name: String,
+    pub file_name: String,
+    pub version: Option<String>,
+    pub category_name: Option<String>, // "MAIN", "OPTIONAL", "UPDATE", ...
+    pub size_kb: Option<i64>,
+    pub uploaded_timestamp: Option<u64>,
+}
+
+/// Fetch the details of one file of a mod from the V1 API
+pub async fn fetch_file_details(
+    client: &NexusClient,
+    game_domain_name: &str,
+    mod_id: i64,
+    file_id: i64,
+) -> Result<NexusModFile, String> {
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+    let request_url = format!(
+        "{}/games/{}/mods/{}/files/{}.json",
+        client.v1_base_url, game_domain_name, mod_id, file_id
+    );
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .get(&request_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Nexus API V1 request failed with status {} at URL {}",
+            response.status(),
+            request_url
+        ));
+    }
+    response
+        .json::<NexusModFile>()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus file {} of mod {}: {}", file_id, mod_id, e))
+}
+
+/// One mirror returned by the download_link endpoint
+#[derive(Deserialize, Debug)]
+struct NexusDownloadLink {
+    #[serde(rename = "URI")]
+    uri: String,
+    short_name: Option<String>,
+}
+
+/// A mod file downloaded from an nxm:// link
+#[derive(Debug, Clone)]
+pub struct DownloadedModFile {
+    pub path: PathBuf,
+    pub file: NexusModFile,
+}
+
+/// Resolve an nxm:// link to a CDN URL through the V1 API and download the archive into
+/// `downloads_dir`, writing the partial file in `temp_dir`. Non-premium accounts need the
+/// key/expires pair from the link. `on_progress` receives (bytes downloaded, total bytes if known).
+pub async fn download_mod_file(
+    client: &NexusClient,
+    link: &NxmLink,
+    downloads_dir: &Path,
+    temp_dir: &Path,
+    on_progress: &(dyn Fn(u64, Option<u64>) + Sync),
+) -> Result<DownloadedModFile, String> {
+    let file_id = link
+        .file_id
+        .ok_or_else(|| format!("nxm link doesn't name a file: {}", link.url))?;
+    if link.expires.is_some_and(|expires| expires < chrono::Utc::now().timestamp()) {
+        return Err("This download link has expired; click \"Mod Manager Download\" on Nexus again".to_string());
+    }
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+    let file = fetch_file_details(client, &link.game_domain, link.mod_id, file_id).await?;
+
+    let mut request_url = format!(
+        "{}/games/{}/mods/{}/files/{}/download_link.json",
+        client.v1_base_url, link.game_domain, link.mod_id, file_id
+    );
+    if let (Some(key), Some(expires)) = (&link.key, link.expires) {
+        request_url.push_str(&format!("?key={}&expires={}", key, expires));
+    }
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .get(&request_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to get a download link for file {} of mod {}: status {}",
+            file_id,
+            link.mod_id,
+            response.status()
+        ));
+    }
+    let mirrors: Vec<NexusDownloadLink> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus download links: {}", e))?;
+    let mirror = mirrors
+        .first()
+        .ok_or_else(|| format!("Nexus returned no download mirrors for file {}", file_id))?;
+    log::info!(
+        "Downloading {} from {}",
+        file.file_name,
+        mirror.short_name.as_deref().unwrap_or("Nexus CDN")
+    );
+
+    // Only the final path component of the reported name is trusted
+    let file_name = Path::new(&file.file_name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| format!("{}-{}.zip", link.mod_id, file_id));
+    let dest = downloads_dir.join(&file_name);
+    crate::download_to_file(&client.identity, &mirror.uri, &dest, temp_dir, on_progress).await?;
+
+    log::info!("Downloaded {} to {}", file_name, dest.display());
+    Ok(DownloadedModFile { path: dest, file })
+}
+
+/// Search Nexus for mods whose name matches a locally installed mod.
+/// Returns candidates for the user to confirm; nothing is written to the registry.
+#[tauri::command]
+pub async fn match_mod_on_nexus(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+    name: String,
+) -> Result<Vec<NexusMod>, String> {
+    log::info!("Searching Nexus ({}) for mods named '{}'", game_domain_name, name);
+    let client = &NexusClient::new(&app_handle)?;
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+
+    let query = r#"query MatchMods($filter: ModsFilter, $count: Int) {
+        mods(filter: $filter, count: $count) {
+            nodes { modId name summary version pictureUrl author endorsements downloads }
+        }
+    }"#;
+    let body = serde_json::json!({
+        "query": query,
+        "variables": {
+            "count": MATCH_CANDIDATE_COUNT,
+            "filter": {
+                "gameDomainName": [{ "value": game_domain_name, "op": "EQUALS" }],
+                "name": [{ "value": name, "op": "WILDCARD" }],
+            },
+        },
+    });
+
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .post(&client.graphql_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V2 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Nexus API V2 search failed with status {}",
+            response.status()
+        ));
+    }
+
+    let payload: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus search response: {}", e))?;
+    if let Some(errors) = payload.get("errors") {
+        return Err(format!("Nexus search returned errors: {}", errors));
+    }
+    let nodes = payload
+        .pointer("/data/mods/nodes")
+        .cloned()
+        .unwrap_or(serde_json::Value::Array(Vec::new()));
+    let nodes: Vec<GraphQlModNode> = serde_json::from_value(nodes)
+        .map_err(|e| format!("Unexpected Nexus search response: {}", e))?;
+
+    Ok(nodes.into_iter().map(NexusMod::from).collect())
+}
+
+/// One page of search_mods results
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NexusSearchPage {
+    pub mods: Vec<NexusMod>,
+    pub total_count: i64,
+    pub page: u32, // 1-based
+    pub page_size: u32,
+    pub has_more: bool,
+}
+
+/// One hit of the V1 md5_search endpoint; the file part isn't needed for browsing
+#[derive(Deserialize, Debug)]
+struct Md5SearchHit {
+    #[serde(rename = "mod")]
+    nexus_mod: NexusMod,
+}
+
+fn is_md5(query: &str) -> bool {
+    query.len() == 32 && query.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// GraphQL sort for the browse tab's sort options; relevance when searching by name
+fn search_sort(sort_by: Option<&str>, sort_order: Option<&str>, has_query: bool) -> Option<serde_json::Value> {
+    let field = match sort_by {
+        Some("popular") => "downloads",
+        Some("endorsed") => "endorsements",
+        Some("newest") => "createdAt",
+        Some("updated") => "updatedAt",
+        Some("name") => "name",
+        _ if has_query => "relevance",
+        _ => return None,
+    };
+    let direction = match sort_order {
+        Some("asc") => "ASC",
+        _ => "DESC",
+    };
+    Some(serde_json::json!([{ field: { "direction": direction } }]))
+}
+
+/// Find the mod a file belongs to by its MD5 (V1 md5_search). A 404 means no match.
+pub async fn search_mods_by_md5(
+    client: &NexusClient,
+    game_domain_name: &str,
+    md5: &str,
+) -> Result<Vec<NexusMod>, String> {
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+    let request_url = format!(
+        "{}/games/{}/mods/md5_search/{}.json",
+        client.v1_base_url, game_domain_name, md5
+    );
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .get(&request_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V1 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(Vec::new());
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Nexus API V1 request failed with status {} at URL {}",
+            response.status(),
+            request_url
+        ));
+    }
+    let hits: Vec<Md5SearchHit> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus MD5 search response: {}", e))?;
+    // Several files of one mod can share a hash; list each mod once
+    let mut mods: Vec<NexusMod> = Vec::new();
+    for hit in hits {
+        if !mods.iter().any(|m| m.mod_id == hit.nexus_mod.mod_id) {
+            mods.push(hit.nexus_mod);
+        }
+    }
+    Ok(mods)
+}
+
+/// Search a game's mods on Nexus by name, a page at a time. An empty query browses every mod
+/// in the chosen order; a 32-character hex query is looked up as a file MD5 instead.
+#[tauri::command]
+pub async fn search_mods(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+    query: Option<String>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+) -> Result<NexusSearchPage, String> {
+    search_mods_with(&NexusClient::new(&app_handle)?, game_domain_name, query, page, page_size, sort_by, sort_order).await
+}
+
+/// `search_mods` sending requests through `client`
+pub(crate) async fn search_mods_with(
+    client: &NexusClient,
+    game_domain_name: String,
+    query: Option<String>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+) -> Result<NexusSearchPage, String> {
+    let query = query.map(|q| q.trim().to_string()).filter(|q| !q.is_empty());
+    let page = page.unwrap_or(1).max(1);
+    let page_size = page_size.unwrap_or(SEARCH_PAGE_SIZE).clamp(1, SEARCH_MAX_PAGE_SIZE);
+
+    if let Some(md5) = query.as_deref().filter(|q| is_md5(q)) {
+        log::info!("Looking up MD5 {} on Nexus ({})", md5, game_domain_name);
+        let mods = search_mods_by_md5(client, &game_domain_name, &md5.to_lowercase()).await?;
+        return Ok(NexusSearchPage {
+            total_count: mods.len() as i64,
+            mods,
+            page: 1,
+            page_size,
+            has_more: false,
+        });
+    }
+
+    log::info!(
+        "Searching Nexus ({}) for '{}', page {}",
+        game_domain_name,
+        query.as_deref().unwrap_or(""),
+        page
+    );
+    let api_key = client.api_key()?;
+    ratelimit::acquire(&client.cache).await?;
+    let gql = r#"query SearchMods($filter: ModsFilter, $sort: [ModsSort!], $offset: Int, $count: Int) {
+        mods(filter: $filter, sort: $sort, offset: $offset, count: $count) {
+            totalCount
+            nodes { modId name summary version pictureUrl author endorsements downloads }
+        }
+    }"#;
+    let mut filter = serde_json::json!({
+        "gameDomainName": [{ "value": game_domain_name, "op": "EQUALS" }],
+    });
+    if let Some(query) = &query {
+        filter["name"] = serde_json::json!([{ "value": format!("*{}*", query), "op": "WILDCARD" }]);
+    }
+    let body = serde_json::json!({
+        "query": gql,
+        "variables": {
+            "filter": filter,
+            "sort": search_sort(sort_by.as_deref(), sort_order.as_deref(), query.is_some()),
+            "offset": (page - 1) * page_size,
+            "count": page_size,
+        },
+    });
+
+    let response = http_client(&client.identity, Integration::Nexus)?
+        .post(&client.graphql_url)
+        .headers(nexus_headers(&client.identity, &api_key)?)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Nexus API V2 request failed: {}", e))?;
+    if let Some(e) = rate_limit_error(client, &response).await {
+        return Err(e);
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Nexus API V2 search failed with status {}",
+            response.status()
+        ));
+    }
+
+    let payload: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Nexus search response: {}", e))?;
+    if let Some(errors) = payload.get("errors") {
+        return Err(format!("Nexus search returned errors: {}", errors));
+    }
+    let nodes = payload
+        .pointer("/data/mods/nodes")
+        .cloned()
+        .unwrap_or(serde_json::Value::Array(Vec::new()));
+    let nodes: Vec<GraphQlModNode> = serde_json::from_value(nodes)
+        .map_err(|e| format!("Unexpected Nexus search response: {}", e))?;
+    let total_count = payload
+        .pointer("/data/mods/totalCount")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(nodes.len() as i64);
+
+    Ok(NexusSearchPage {
+        has_more: i64::from(page * page_size) < total_count,
+        mods: nodes.into_iter().map(NexusMod::from).collect(),
+        total_count,
+        page,
+        page_size,
+    })
+}
+
+/// Download a Nexus mod picture into the image cache and return its thumbnail path
+pub(crate) async fn cache_nexus_picture(
+    app_handle: &tauri::AppHandle,
+    game_domain_name: &str,
+    mod_id: i64,
+    picture_url: &str,
+) -> Result<String, String> {
+    let response = http_client(&http_identity(app_handle), Integration::Nexus)?
+        .get(picture_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download {}: {}", picture_url, e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Picture download failed from {}: Status {}",
+            picture_url,
+            response.status()
+        ));
+    }
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read picture from {}: {}", picture_url, e))?;
+
+    // image::open picks the decoder from the extension, so keep the URL's one
+    let extension = picture_url
+        .rsplit('/')
+        .next()
+        .and_then(|file| file.rsplit_once('.'))
+        .map(|(_, ext)| ext.split('?').next().unwrap_or(ext).to_lowercase())
+        .unwrap_or_else(|| "jpg".to_string());
+    let download_path = get_image_cache_dir(app_handle)?.join(format!(
+        "nexus_{}_{}.{}",
+        game_domain_name, mod_id, extension
+    ));
+    std::fs::write(&download_path, &bytes)
+        .map_err(|e| format!("Failed to write {}: {}", download_path.display(), e))?;
+
+    let thumb_path = cache_downscaled_image(app_handle, &download_path.to_string_lossy())?;
+    Ok(thumb_path.to_string_lossy().to_string())
+}
+
+/// Link a registry mod to a confirmed Nexus match and pull its metadata and picture
+#[tauri::command]
+pub async fn apply_nexus_match(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+    mod_name: String,
+    nexus_mod_id: i64,
+) -> Result<ModInfo, String> {
+    log::info!(
+        "Applying Nexus mod {} ({}) metadata to '{}'",
+        nexus_mod_id,
+        game_domain_name,
+        mod_name
+    );
+    let details = fetch_mod_details(&NexusClient::new(&app_handle)?, &game_domain_name, nexus_mod_id).await?;
+
+    let thumbnail = match details.picture_url.as_deref() {
+        Some(url) => match cache_nexus_picture(&app_handle, &game_domain_name, nexus_mod_id, url).await {
+            Ok(path) => Some(path),
+            Err(e) => {
+                log::warn!("Failed to cache Nexus picture for '{}': {}", mod_name, e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    let apply = |m: &mut Mod| {
+        m.nexus_mod_id = Some(details.mod_id);
+        if m.origin_url.is_none() {
+            m.origin_url = Some(nexus_mod_page_url(&game_domain_name, details.mod_id));
+        }
+        if details.author.is_some() {
+            m.author = details.author.clone();
+        }
+        // Nexus's version is the latest upload, not necessarily what's installed, so it only
+        // feeds the update check
+        m.update_check = Some(ModUpdateCheck {
+            checked_timestamp: chrono::Utc::now().timestamp(),
+            latest_version: details.version.clone(),
+        });
+        if details.summary.is_some() {
+            m.description = details.summary.clone();
+        }
+    };
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let info = if let Some(mod_entry) = registry.find_mod_mut(&mod_name) {
+        apply(mod_entry);
+        if thumbnail.is_some() {
+            mod_entry.thumbnail = thumbnail;
+        }
+        ModRegistry::to_mod_info(mod_entry)
+    } else if let Some(skin_mod) = registry.find_skin_mod_mut(&mod_name) {
+        apply(&mut skin_mod.base);
+        if thumbnail.is_some() {
+            skin_mod.thumbnail_path = thumbnail;
+        }
+        ModRegistry::skin_to_mod_info(skin_mod)
+    } else {
+        return Err(format!("Mod '{}' not found in registry", mod_name));
+    };
+
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+    Ok(info)
+}
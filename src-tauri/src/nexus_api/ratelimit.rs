@@ -0,0 +1,120 @@
+// src-tauri/src/nexus_api/ratelimit.rs
+// Nexus API quota tracking. Every response reports the requests left this hour and today; we
+// keep the latest numbers so calls are held back once the quota is spent instead of being
+// refused one by one, and so settings can show why Nexus features stopped working.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use super::ApiCache;
+
+/// A call that would have to wait longer than this is refused instead
+const MAX_WAIT: Duration = Duration::from_secs(10);
+/// Pause after a 429 that didn't say when the quota resets
+const DEFAULT_BACKOFF: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RateLimitStatus {
+    pub hourly_limit: Option<u32>,
+    pub hourly_remaining: Option<u32>,
+    pub hourly_reset: Option<String>, // RFC 3339, as sent by Nexus
+    pub daily_limit: Option<u32>,
+    pub daily_remaining: Option<u32>,
+    pub daily_reset: Option<String>,
+    pub updated_timestamp: Option<i64>, // None until the first response this session
+    pub blocked_until: Option<i64>,     // Requests are refused until then
+}
+
+/// Quota tracker kept in the API cache state
+#[derive(Debug, Default)]
+pub(super) struct RateLimiter {
+    status: RateLimitStatus,
+}
+
+impl RateLimiter {
+    fn record(&mut self, response: &reqwest::Response) {
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        };
+        let number = |name: &str| header(name).and_then(|v| v.parse::<u32>().ok());
+        let now = chrono::Utc::now().timestamp();
+        let status = &mut self.status;
+
+        if header("x-rl-hourly-remaining").is_some() || header("x-rl-daily-remaining").is_some() {
+            status.hourly_limit = number("x-rl-hourly-limit").or(status.hourly_limit);
+            status.hourly_remaining = number("x-rl-hourly-remaining");
+            status.hourly_reset = header("x-rl-hourly-reset");
+            status.daily_limit = number("x-rl-daily-limit").or(status.daily_limit);
+            status.daily_remaining = number("x-rl-daily-remaining");
+            status.daily_reset = header("x-rl-daily-reset");
+            status.updated_timestamp = Some(now);
+        }
+
+        // The hourly allowance only applies once the daily one is used up
+        let exhausted = status.daily_remaining == Some(0) && status.hourly_remaining == Some(0);
+        if exhausted || response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let reset = status
+                .hourly_reset
+                .as_deref()
+                .and_then(|r| chrono::DateTime::parse_from_rfc3339(r).ok())
+                .map(|r| r.timestamp())
+                .filter(|r| *r > now)
+                .unwrap_or(now + DEFAULT_BACKOFF.as_secs() as i64);
+            if status.blocked_until != Some(reset) {
+                warn!("Nexus API quota used up; holding requests until {}", reset);
+            }
+            status.blocked_until = Some(reset);
+        } else if status.blocked_until.take().is_some() {
+            info!("Nexus API quota available again");
+        }
+    }
+}
+
+/// Note the quota headers of a Nexus response
+pub(super) async fn record(cache: &Mutex<ApiCache>, response: &reqwest::Response) {
+    cache.lock().await.rate_limiter.record(response);
+}
+
+/// Wait until the quota allows another request. Fails straight away when that would take
+/// longer than MAX_WAIT.
+pub(super) async fn acquire(cache: &Mutex<ApiCache>) -> Result<(), String> {
+    let now = chrono::Utc::now().timestamp();
+    let blocked_until = cache.lock().await.rate_limiter.status.blocked_until;
+    let Some(until) = blocked_until.filter(|u| *u > now) else {
+        return Ok(());
+    };
+    let wait = Duration::from_secs((until - now) as u64);
+    if wait > MAX_WAIT {
+        let reset = chrono::DateTime::from_timestamp(until, 0)
+            .map(|t| t.with_timezone(&chrono::Local).format("%H:%M").to_string())
+            .unwrap_or_else(|| until.to_string());
+        return Err(format!(
+            "[E_NEXUS_RATE_LIMITED] The Nexus API quota is used up; requests resume at {}",
+            reset
+        ));
+    }
+    info!("Waiting {:?} for the Nexus API quota to reset", wait);
+    tokio::time::sleep(wait).await;
+    Ok(())
+}
+
+/// The latest known quota, for the settings page
+pub async fn status(cache: &Mutex<ApiCache>) -> RateLimitStatus {
+    let mut status = cache.lock().await.rate_limiter.status.clone();
+    let now = chrono::Utc::now().timestamp();
+    status.blocked_until = status.blocked_until.filter(|u| *u > now);
+    status
+}
+
+#[tauri::command]
+pub async fn get_nexus_rate_limit_status(
+    state: tauri::State<'_, Arc<Mutex<ApiCache>>>,
+) -> Result<RateLimitStatus, String> {
+    Ok(status(state.inner()).await)
+}
@@ -0,0 +1,214 @@
+// src-tauri/src/nexus_api/sso.rs
+// Nexus Mods single sign-on: instead of pasting an API key, the user approves the manager on
+// the Nexus website and the key arrives over the SSO websocket. Progress is reported with
+// "nexus-sso" events so the settings page can follow along.
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_opener::OpenerExt;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use super::apikey::{key_status, save_account, store_api_key, validate_api_key, NexusApiKeyStatus};
+use super::NexusClient;
+
+const SSO_WEBSOCKET_URL: &str = "wss://sso.nexusmods.com";
+const SSO_AUTHORIZE_URL: &str = "https://www.nexusmods.com/sso";
+/// Application slug the manager is registered under for Nexus SSO
+const SSO_APPLICATION_SLUG: &str = "fossmodmanager";
+/// How long the user gets to approve the login in the browser
+const SSO_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// Reconnects (with the connection token) before giving up on a dropped socket
+const SSO_MAX_RECONNECTS: u32 = 3;
+
+/// The running login, if any; only one runs at a time
+#[derive(Default)]
+pub struct SsoState {
+    active: AtomicBool,
+    cancelled: AtomicBool, // Set by cancel_sso_login, checked by the running login
+}
+
+/// Register the login state. Called once during setup.
+pub fn init_sso(app_handle: &AppHandle) {
+    app_handle.manage(SsoState::default());
+}
+
+/// Marks a login as running until dropped, so one that fails or panics can't block the next
+struct SsoActiveGuard<'a>(&'a AtomicBool);
+
+impl Drop for SsoActiveGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "event", content = "data")]
+pub enum NexusSsoEvent {
+    Connecting,
+    #[serde(rename_all = "camelCase")]
+    WaitingForBrowser { authorize_url: String },
+    Validating,
+    #[serde(rename_all = "camelCase")]
+    Finished { status: NexusApiKeyStatus },
+    #[serde(rename_all = "camelCase")]
+    Failed { error: String },
+}
+
+#[derive(Serialize)]
+struct SsoRequest<'a> {
+    id: &'a str,
+    token: Option<&'a str>,
+    protocol: u8,
+}
+
+#[derive(Deserialize)]
+struct SsoResponse {
+    success: bool,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+fn emit(app_handle: &AppHandle, event: NexusSsoEvent) {
+    if let Err(e) = app_handle.emit("nexus-sso", &event) {
+        warn!("Failed to emit Nexus SSO event: {}", e);
+    }
+}
+
+/// Browser page where the user approves the login request `id`
+fn authorize_url(id: &str) -> String {
+    format!("{}?id={}&application={}", SSO_AUTHORIZE_URL, id, SSO_APPLICATION_SLUG)
+}
+
+/// Talk to the SSO websocket until it hands over an API key. Opens the browser once the
+/// server acknowledges the request; reconnects with the connection token if the socket drops.
+async fn receive_api_key(app_handle: &AppHandle, id: &str, cancelled: &AtomicBool) -> Result<String, String> {
+    let deadline = Instant::now() + SSO_TIMEOUT;
+    let mut connection_token: Option<String> = None;
+    let mut reconnects = 0;
+    loop {
+        let (mut socket, _) = connect_async(SSO_WEBSOCKET_URL)
+            .await
+            .map_err(|e| format!("Failed to connect to Nexus SSO: {}", e))?;
+        let request = serde_json::to_string(&SsoRequest {
+            id,
+            token: connection_token.as_deref(),
+            protocol: 2,
+        })
+        .map_err(|e| format!("Failed to serialize SSO request: {}", e))?;
+        socket
+            .send(Message::Text(request.into()))
+            .await
+            .map_err(|e| format!("Failed to send SSO request: {}", e))?;
+
+        loop {
+            if cancelled.load(Ordering::SeqCst) {
+                let _ = socket.close(None).await;
+                return Err("[E_SSO_CANCELLED] Nexus login was cancelled".to_string());
+            }
+            if Instant::now() >= deadline {
+                let _ = socket.close(None).await;
+                return Err("[E_SSO_TIMEOUT] The Nexus login wasn't approved in time".to_string());
+            }
+            // Short reads so cancellation and the deadline are noticed promptly
+            let message = match tokio::time::timeout(Duration::from_secs(1), socket.next()).await {
+                Err(_) => continue,
+                Ok(None) => break,
+                Ok(Some(Err(e))) => {
+                    warn!("Nexus SSO socket error: {}", e);
+                    break;
+                }
+                Ok(Some(Ok(message))) => message,
+            };
+            let text = match message {
+                Message::Text(text) => text.to_string(),
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            let response: SsoResponse = serde_json::from_str(&text)
+                .map_err(|e| format!("Failed to parse Nexus SSO response: {}", e))?;
+            if !response.success {
+                return Err(format!(
+                    "Nexus SSO refused the login: {}",
+                    response.error.unwrap_or_else(|| "no reason given".to_string())
+                ));
+            }
+            let data = response.data.unwrap_or_default();
+            if let Some(api_key) = data.get("api_key").and_then(|v| v.as_str()) {
+                let _ = socket.close(None).await;
+                return Ok(api_key.to_string());
+            }
+            if let Some(token) = data.get("connection_token").and_then(|v| v.as_str()) {
+                // First acknowledgement; a reconnect reuses the token and needs no new tab
+                if connection_token.is_none() {
+                    let url = authorize_url(id);
+                    app_handle
+                        .opener()
+                        .open_url(&url, None::<&str>)
+                        .map_err(|e| format!("Failed to open the browser for Nexus login: {}", e))?;
+                    emit(app_handle, NexusSsoEvent::WaitingForBrowser { authorize_url: url });
+                }
+                connection_token = Some(token.to_string());
+            }
+        }
+
+        reconnects += 1;
+        if connection_token.is_none() || reconnects > SSO_MAX_RECONNECTS {
+            return Err("The Nexus SSO connection closed before the login finished".to_string());
+        }
+        info!("Nexus SSO socket closed, reconnecting ({}/{})", reconnects, SSO_MAX_RECONNECTS);
+    }
+}
+
+/// Log in to Nexus through the browser and store the API key it grants. Resolves once the
+/// user approves (or the login fails, times out or is cancelled); "nexus-sso" events report
+/// each step.
+#[tauri::command]
+pub async fn begin_sso_login(
+    app_handle: AppHandle,
+    sso: State<'_, SsoState>,
+) -> Result<NexusApiKeyStatus, String> {
+    if sso.active.swap(true, Ordering::SeqCst) {
+        return Err("A Nexus login is already in progress".to_string());
+    }
+    let active = SsoActiveGuard(&sso.active);
+    sso.cancelled.store(false, Ordering::SeqCst);
+    emit(&app_handle, NexusSsoEvent::Connecting);
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let result = async {
+        let api_key = receive_api_key(&app_handle, &id, &sso.cancelled).await?;
+        emit(&app_handle, NexusSsoEvent::Validating);
+        let account = validate_api_key(&NexusClient::new(&app_handle)?, &api_key).await?;
+        let storage = store_api_key(&app_handle, &api_key)?;
+        save_account(&app_handle, Some(&account))?;
+        info!("Logged in to Nexus as {} via SSO ({:?})", account.name, storage);
+        key_status(&app_handle)
+    }
+    .await;
+    drop(active);
+
+    match &result {
+        Ok(status) => emit(&app_handle, NexusSsoEvent::Finished { status: status.clone() }),
+        Err(e) => {
+            warn!("Nexus SSO login failed: {}", e);
+            emit(&app_handle, NexusSsoEvent::Failed { error: e.clone() });
+        }
+    }
+    result
+}
+
+/// Abandon a login that is waiting for the browser
+#[tauri::command]
+pub async fn cancel_sso_login(sso: State<'_, SsoState>) -> Result<bool, String> {
+    if !sso.active.load(Ordering::SeqCst) {
+        return Ok(false);
+    }
+    sso.cancelled.store(true, Ordering::SeqCst);
+    info!("Cancelling Nexus SSO login");
+    Ok(true)
+}
@@ -0,0 +1,171 @@
+// src-tauri/src/nexus_api/updates.rs
+// Update checks for registry mods linked to a Nexus page. Each mod remembers when it was last
+// checked and what Nexus reported, so repeat checks within the interval cost no API calls.
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use super::{fetch_mod_details, nexus_mod_page_url, NexusClient};
+use crate::utils::modregistry::{Mod, ModRegistry, ModUpdateCheck};
+
+/// Mods checked more recently than this reuse the stored result unless forced
+const UPDATE_CHECK_INTERVAL_SECS: i64 = 6 * 60 * 60;
+
+/// A registry mod with a newer version on Nexus
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModUpdateInfo {
+    pub mod_name: String, // Registry identifier (directory name)
+    pub display_name: String,
+    pub nexus_mod_id: i64,
+    pub installed_version: Option<String>,
+    pub latest_version: String,
+    pub changelog_url: String,
+    pub page_url: String,
+}
+
+/// Changelog tab of a Nexus mod page
+pub fn nexus_changelog_url(game_domain_name: &str, mod_id: i64) -> String {
+    format!("{}?tab=logs", nexus_mod_page_url(game_domain_name, mod_id))
+}
+
+/// Numeric parts of a version string ("v1.2.10b" -> [1, 2, 10])
+fn version_numbers(version: &str) -> Vec<u64> {
+    version
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Whether `latest` is newer than `installed`. Versions compare by their numeric parts
+/// (missing parts count as 0); when either has none, any difference counts as an update.
+pub(crate) fn is_newer_version(installed: &str, latest: &str) -> bool {
+    let installed = installed.trim();
+    let latest = latest.trim();
+    if installed.eq_ignore_ascii_case(latest) {
+        return false;
+    }
+    let (a, b) = (version_numbers(installed), version_numbers(latest));
+    if a.is_empty() || b.is_empty() {
+        return true;
+    }
+    let len = a.len().max(b.len());
+    let part = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    for i in 0..len {
+        match part(&b, i).cmp(&part(&a, i)) {
+            Ordering::Greater => return true,
+            Ordering::Less => return false,
+            Ordering::Equal => {}
+        }
+    }
+    false
+}
+
+/// Version to compare against: the installed Nexus file's when known, else the mod's own
+fn installed_version(m: &Mod) -> Option<String> {
+    m.nexus_file
+        .as_ref()
+        .and_then(|f| f.version.clone())
+        .or_else(|| m.version.clone())
+}
+
+pub(crate) fn registry_mod_mut<'a>(registry: &'a mut ModRegistry, name: &str) -> Option<&'a mut Mod> {
+    if registry.find_mod(name).is_some() {
+        return registry.find_mod_mut(name);
+    }
+    registry.find_skin_mod_mut(name).map(|sm| &mut sm.base)
+}
+
+/// Check every Nexus-linked mod for a newer version and return the outdated ones.
+/// Mods checked within the last few hours reuse their stored result unless `force` is set,
+/// and hitting the rate limit stops the run with whatever was checked so far.
+#[tauri::command]
+pub async fn check_for_mod_updates(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+    force: Option<bool>,
+) -> Result<Vec<ModUpdateInfo>, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    let client = NexusClient::new(&app_handle)?;
+    let now = chrono::Utc::now().timestamp();
+    let force = force.unwrap_or(false);
+
+    let linked: Vec<Mod> = registry
+        .mods
+        .iter()
+        .chain(registry.skin_mods.iter().map(|sm| &sm.base))
+        .filter(|m| m.nexus_mod_id.is_some())
+        .cloned()
+        .collect();
+    log::info!("Checking {} Nexus-linked mod(s) for updates", linked.len());
+
+    let mut fresh_checks: Vec<(String, ModUpdateCheck)> = Vec::new();
+    let mut rate_limited = false;
+    let mut updates = Vec::new();
+    for m in &linked {
+        let Some(nexus_mod_id) = m.nexus_mod_id else {
+            continue;
+        };
+        let recent = m
+            .update_check
+            .as_ref()
+            .filter(|c| !force && now - c.checked_timestamp < UPDATE_CHECK_INTERVAL_SECS);
+        let latest_version = match recent {
+            Some(check) => check.latest_version.clone(),
+            None if rate_limited => m.update_check.as_ref().and_then(|c| c.latest_version.clone()),
+            None => match fetch_mod_details(&client, &game_domain_name, nexus_mod_id).await {
+                Ok(details) => {
+                    fresh_checks.push((
+                        m.directory_name.clone(),
+                        ModUpdateCheck {
+                            checked_timestamp: now,
+                            latest_version: details.version.clone(),
+                        },
+                    ));
+                    details.version
+                }
+                Err(e) => {
+                    log::warn!("Update check for '{}' failed: {}", m.name, e);
+                    rate_limited = e.contains("rate limit");
+                    m.update_check.as_ref().and_then(|c| c.latest_version.clone())
+                }
+            },
+        };
+
+        let Some(latest_version) = latest_version else {
+            continue;
+        };
+        let installed = installed_version(m);
+        let outdated = match installed.as_deref() {
+            Some(installed) => is_newer_version(installed, &latest_version),
+            None => false, // Nothing to compare against
+        };
+        if outdated {
+            updates.push(ModUpdateInfo {
+                mod_name: m.directory_name.clone(),
+                display_name: m.name.clone(),
+                nexus_mod_id,
+                installed_version: installed,
+                latest_version,
+                changelog_url: nexus_changelog_url(&game_domain_name, nexus_mod_id),
+                page_url: nexus_mod_page_url(&game_domain_name, nexus_mod_id),
+            });
+        }
+    }
+
+    // Reload so changes made while the requests were in flight aren't overwritten
+    if !fresh_checks.is_empty() {
+        let mut registry = ModRegistry::load(&app_handle)?;
+        for (name, check) in fresh_checks {
+            if let Some(m) = registry_mod_mut(&mut registry, &name) {
+                m.update_check = Some(check);
+            }
+        }
+        registry.save(&app_handle)?;
+    }
+
+    if rate_limited {
+        log::warn!("Nexus rate limit reached during update check; remaining mods used stored results");
+    }
+    log::info!("{} mod(s) have updates available", updates.len());
+    Ok(updates)
+}
@@ -0,0 +1,374 @@
+// src-tauri/src/tests/api_mocks.rs
+// Nexus and GitHub clients against local mock servers: success, 404, 429, malformed JSON
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::nexus_api::apikey::validate_api_key;
+use crate::nexus_api::ratelimit;
+use crate::nexus_api::updates::is_newer_version;
+use crate::utils::httpidentity::HttpIdentity;
+use super::FakeGame;
+use crate::nexus_api::{
+    fetch_file_details, fetch_mod_details, fetch_mod_list, search_mods_with, ApiCache, NexusClient,
+};
+use crate::{fetch_latest_release, GitHubClient};
+
+const GAME: &str = "monsterhunterwilds";
+
+/// Start a mock server and a client sending Nexus V1 requests to it with a test key,
+/// identifying as the manager with no User-Agent overrides. The client gets its own API
+/// cache in `game`, so quota tracked by one test doesn't hold back another.
+async fn mock_nexus(game: &FakeGame) -> (MockServer, NexusClient) {
+    let server = MockServer::start().await;
+    let cache = ApiCache::in_dir(game.root().join("api-cache"));
+    let client = NexusClient {
+        api_key: Some("test-key".to_string()),
+        v1_base_url: server.uri(),
+        ..NexusClient::with_identity(HttpIdentity::default(), cache)
+    };
+    (server, client)
+}
+
+async fn mock_github() -> (MockServer, GitHubClient) {
+    let server = MockServer::start().await;
+    let github = GitHubClient {
+        identity: HttpIdentity::default(),
+        api_base: server.uri(),
+    };
+    (server, github)
+}
+
+fn mod_json(mod_id: i64) -> serde_json::Value {
+    json!({
+        "mod_id": mod_id,
+        "name": "Better Camera",
+        "summary": "Camera tweaks",
+        "version": "1.2.0",
+        "picture_url": null,
+        "updated_timestamp": 1_700_000_000u64,
+        "endorsements_count": 10,
+        "total_downloads": 500,
+        "total_unique_downloads": 400,
+        "author": "someone",
+        "uploaded_timestamp": 1_690_000_000u64,
+        "external_virus_scan_url": null
+    })
+}
+
+#[tokio::test]
+async fn nexus_mod_details_success_sends_api_key() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/42.json", GAME)))
+        .and(header("apikey", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mod_json(42)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let details = fetch_mod_details(&client, GAME, 42).await.expect("mod details");
+    assert_eq!(details.mod_id, 42);
+    assert_eq!(details.version.as_deref(), Some("1.2.0"));
+}
+
+#[tokio::test]
+async fn nexus_mod_details_not_found() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/7.json", GAME)))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "message": "No Mod Found" })))
+        .mount(&server)
+        .await;
+
+    let error = fetch_mod_details(&client, GAME, 7).await.unwrap_err();
+    assert!(error.contains("404"), "{}", error);
+}
+
+#[tokio::test]
+async fn nexus_rate_limit_reports_quota_headers() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/42/files/9.json", GAME)))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("x-rl-hourly-remaining", "0")
+                .insert_header("x-rl-daily-remaining", "1234")
+                .insert_header("x-rl-hourly-reset", "2026-01-01T13:00:00+00:00"),
+        )
+        .mount(&server)
+        .await;
+
+    let error = fetch_file_details(&client, GAME, 42, 9).await.unwrap_err();
+    assert!(error.contains("rate limit"), "{}", error);
+    assert!(error.contains("hourly remaining: 0"), "{}", error);
+    assert!(error.contains("daily remaining: 1234"), "{}", error);
+    assert!(error.contains("2026-01-01T13:00:00+00:00"), "{}", error);
+}
+
+#[tokio::test]
+async fn nexus_malformed_json_is_an_error() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/42/files/9.json", GAME)))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{ \"file_id\": "))
+        .mount(&server)
+        .await;
+
+    let error = fetch_file_details(&client, GAME, 42, 9).await.unwrap_err();
+    assert!(error.starts_with("Failed to parse Nexus file 9"), "{}", error);
+}
+
+#[tokio::test]
+async fn nexus_file_details_success() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/42/files/9.json", GAME)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "file_id": 9,
+            "name": "Main file",
+            "file_name": "BetterCamera-42-1-2-0.zip",
+            "version": "1.2.0",
+            "category_name": "MAIN",
+            "size_kb": 120,
+            "uploaded_timestamp": 1_690_000_000u64
+        })))
+        .mount(&server)
+        .await;
+
+    let file = fetch_file_details(&client, GAME, 42, 9).await.expect("file details");
+    assert_eq!(file.file_name, "BetterCamera-42-1-2-0.zip");
+    assert_eq!(file.category_name.as_deref(), Some("MAIN"));
+}
+
+#[tokio::test]
+async fn nexus_api_key_validation() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path("/users/validate.json"))
+        .and(header("apikey", "good-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "user_id": 1234,
+            "key": "good-key",
+            "name": "hunter",
+            "is_premium": true,
+            "is_supporter": false,
+            "email": "hunter@example.invalid",
+            "profile_url": "https://example.invalid/avatar.png"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/validate.json"))
+        .and(header("apikey", "bad-key"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({ "message": "Please provide a valid API Key" })))
+        .mount(&server)
+        .await;
+
+    let account = validate_api_key(&client, "good-key").await.expect("valid key");
+    assert_eq!(account.name, "hunter");
+    assert!(account.is_premium && account.validated_timestamp > 0);
+    let error = validate_api_key(&client, "bad-key").await.unwrap_err();
+    assert!(error.starts_with("[E_NEXUS_KEY_INVALID]"), "{}", error);
+}
+
+fn release_json(tag: &str, prerelease: bool) -> serde_json::Value {
+    json!({
+        "tag_name": tag,
+        "prerelease": prerelease,
+        "assets": [{ "name": "MHWILDS.zip", "browser_download_url": "https://example.invalid/MHWILDS.zip" }]
+    })
+}
+
+#[tokio::test]
+async fn github_latest_release_prefers_stable() {
+    let (server, github) = mock_github().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/REFramework-nightly/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            release_json("nightly-02", true),
+            release_json("v1.5", false),
+        ])))
+        .mount(&server)
+        .await;
+
+    let release = fetch_latest_release(&github, "praydog", "REFramework-nightly").await.expect("release");
+    assert_eq!(release.tag_name, "v1.5");
+}
+
+#[tokio::test]
+async fn github_latest_release_falls_back_to_prerelease() {
+    let (server, github) = mock_github().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/REFramework-nightly/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            release_json("nightly-02", true),
+            release_json("nightly-01", true),
+        ])))
+        .mount(&server)
+        .await;
+
+    let release = fetch_latest_release(&github, "praydog", "REFramework-nightly").await.expect("release");
+    assert_eq!(release.tag_name, "nightly-02");
+}
+
+#[tokio::test]
+async fn github_errors_and_malformed_json() {
+    let (server, github) = mock_github().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/missing/releases"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/limited/releases"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("x-ratelimit-remaining", "0")
+                .set_body_string("API rate limit exceeded"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/garbled/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[{\"tag_name\": 5}]"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/praydog/empty/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    let missing = fetch_latest_release(&github, "praydog", "missing").await.unwrap_err();
+    assert!(missing.contains("404"), "{}", missing);
+    let limited = fetch_latest_release(&github, "praydog", "limited").await.unwrap_err();
+    assert!(limited.contains("429") && limited.contains("rate limit exceeded"), "{}", limited);
+    let garbled = fetch_latest_release(&github, "praydog", "garbled").await.unwrap_err();
+    assert!(garbled.starts_with("Failed to parse GitHub releases JSON"), "{}", garbled);
+    let empty = fetch_latest_release(&github, "praydog", "empty").await.unwrap_err();
+    assert!(empty.starts_with("No releases found"), "{}", empty);
+}
+
+#[test]
+fn update_check_version_comparison() {
+    assert!(is_newer_version("1.2.0", "1.10.0"));
+    assert!(is_newer_version("v1.2", "1.2.1"));
+    assert!(!is_newer_version("1.2.0", "1.2"));
+    assert!(!is_newer_version("2.0", "1.9.9"));
+    assert!(!is_newer_version("Beta", "beta"));
+    // Without numbers to compare, any change is reported
+    assert!(is_newer_version("beta", "release"));
+}
+
+#[tokio::test]
+async fn nexus_search_by_md5_lists_each_mod_once() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    let md5 = "0123456789abcdef0123456789abcdef";
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/md5_search/{}.json", GAME, md5)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "mod": mod_json(42), "file_details": { "file_id": 1 } },
+            { "mod": mod_json(42), "file_details": { "file_id": 2 } },
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/md5_search/{}.json", GAME, "f".repeat(32))))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let page = search_mods_with(&client, GAME.to_string(), Some(md5.to_uppercase()), None, None, None, None)
+        .await
+        .expect("md5 search");
+    assert_eq!(page.mods.len(), 1);
+    assert_eq!(page.mods[0].mod_id, 42);
+    assert!(!page.has_more);
+
+    let page = search_mods_with(&client, GAME.to_string(), Some("F".repeat(32)), None, None, None, None)
+        .await
+        .expect("md5 search without a match");
+    assert!(page.mods.is_empty());
+}
+
+#[tokio::test]
+async fn nexus_mod_lists_survive_a_restart_through_the_disk_cache() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/trending.json", GAME)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mod_json(42)])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let first = fetch_mod_list(&client, GAME, "trending").await.expect("trending mods");
+    // A fresh cache on the same directory stands in for the next app launch
+    let relaunched = NexusClient {
+        cache: ApiCache::in_dir(game.root().join("api-cache")),
+        ..client
+    };
+    let second = fetch_mod_list(&relaunched, GAME, "trending").await.expect("trending mods from disk");
+    assert_eq!(first.len(), 1);
+    assert_eq!(second[0].mod_id, 42);
+}
+
+#[tokio::test]
+async fn nexus_requests_are_held_back_once_the_quota_is_spent() {
+    let game = FakeGame::new();
+    let (server, client) = mock_nexus(&game).await;
+    let reset = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    Mock::given(method("GET"))
+        .and(path(format!("/games/{}/mods/42.json", GAME)))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mod_json(42))
+                .insert_header("x-rl-hourly-limit", "500")
+                .insert_header("x-rl-hourly-remaining", "0")
+                .insert_header("x-rl-hourly-reset", reset.as_str())
+                .insert_header("x-rl-daily-remaining", "0"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    fetch_mod_details(&client, GAME, 42).await.expect("last request within the quota");
+    let status = ratelimit::status(&client.cache).await;
+    assert_eq!(status.hourly_limit, Some(500));
+    assert_eq!(status.hourly_remaining, Some(0));
+    assert!(status.blocked_until.is_some());
+
+    // Refused locally; the mock's expect(1) checks Nexus never saw it
+    let error = fetch_mod_details(&client, GAME, 42).await.unwrap_err();
+    assert!(error.starts_with("[E_NEXUS_RATE_LIMITED]"), "{}", error);
+}
+
+#[test]
+fn metadata_sources_are_read_from_origin_urls() {
+    use crate::utils::metadatarefresh::{parse_origin_url, MetadataSource};
+
+    assert_eq!(
+        parse_origin_url("https://www.nexusmods.com/monsterhunterwilds/mods/42?tab=files"),
+        Some(MetadataSource::Nexus {
+            game_domain: GAME.to_string(),
+            mod_id: 42
+        })
+    );
+    assert_eq!(
+        parse_origin_url("https://github.com/praydog/REFramework/releases"),
+        Some(MetadataSource::GitHub {
+            owner: "praydog".to_string(),
+            repo: "REFramework".to_string()
+        })
+    );
+    assert_eq!(parse_origin_url("https://example.com/mod.zip"), None);
+    assert_eq!(parse_origin_url("https://www.nexusmods.com/monsterhunterwilds"), None);
+}
@@ -0,0 +1,106 @@
+// src-tauri/src/tests/mod.rs
+// End-to-end harness: a throwaway fake game install plus fixture archives, driven through the
+// same filesystem functions the Tauri commands delegate to. The unit tests next to each module
+// build their fixtures with it too.
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::utils::archives::import_skin_archive;
+use crate::utils::config::ensure_directory_layout;
+use crate::utils::modregistry::{new_skin_mod_from_dir, ModRegistry};
+use crate::utils::staging::{default_staging_root, get_mods_dir};
+
+mod api_mocks;
+mod mod_flows;
+mod permissions;
+
+static NEXT_GAME_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Texture every skin from `install_skin` ships, so any two of them conflict
+pub const SHARED_TEXTURE: &str = "STM/Art/Model/Character/ch02/tex.tex.241106027";
+
+/// Progress callback for copies nobody watches
+pub fn no_progress(_: u64, _: u64) {}
+
+/// A fake game directory (exe, natives, REFramework) removed again when dropped
+pub struct FakeGame {
+    root: PathBuf,
+}
+
+impl FakeGame {
+    pub fn new() -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "fossmodmanager-test-{}-{}",
+            std::process::id(),
+            NEXT_GAME_ID.fetch_add(1, Ordering::SeqCst)
+        ));
+        if root.exists() {
+            fs::remove_dir_all(&root).expect("clear stale fake game");
+        }
+        for dir in ["natives/STM", "reframework/plugins", "reframework/autorun"] {
+            fs::create_dir_all(root.join(dir)).expect("create fake game dirs");
+        }
+        fs::write(root.join("MonsterHunterWilds.exe"), b"MZ fake game").expect("write fake exe");
+        fs::write(root.join("dinput8.dll"), b"MZ fake reframework").expect("write fake dinput8");
+        ensure_directory_layout(&root, &default_staging_root(&root)).expect("create manager layout");
+        Self { root }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn staging_root(&self) -> PathBuf {
+        default_staging_root(&self.root)
+    }
+
+    pub fn mods_dir(&self) -> PathBuf {
+        get_mods_dir(&self.staging_root())
+    }
+
+    /// Build a fixture archive next to the game from (path in zip, contents) pairs
+    pub fn fixture_zip(&self, name: &str, entries: &[(&str, &[u8])]) -> PathBuf {
+        let fixtures = self.root.join("fixtures");
+        fs::create_dir_all(&fixtures).expect("create fixtures dir");
+        let zip_path = fixtures.join(name);
+        let mut zip = ZipWriter::new(fs::File::create(&zip_path).expect("create fixture zip"));
+        for (path, contents) in entries {
+            zip.start_file(*path, SimpleFileOptions::default())
+                .expect("add fixture entry");
+            zip.write_all(contents).expect("write fixture entry");
+        }
+        zip.finish().expect("finish fixture zip");
+        zip_path
+    }
+
+    /// Import a skin fixture archive and register it the way the mods-dir scan does
+    pub fn install_skin(&self, registry: &mut ModRegistry, name: &str, texture: &[u8]) -> String {
+        let unique = format!("natives/STM/{}/only.txt", name);
+        let shared = format!("natives/{}", SHARED_TEXTURE);
+        let zip = self.fixture_zip(
+            &format!("{}.zip", name),
+            &[(shared.as_str(), texture), (unique.as_str(), name.as_bytes())],
+        );
+        let imported = import_skin_archive(&zip, &self.staging_root()).expect("import skin archive");
+        assert_eq!(imported.len(), 1);
+        let skin = new_skin_mod_from_dir(&imported[0], "local_zip");
+        let mod_path = skin.base.path.clone();
+        registry.skin_mods.push(skin);
+        mod_path
+    }
+
+    /// Where a file under natives/ ends up in the game folder
+    pub fn deployed(&self, rel: &str) -> PathBuf {
+        self.root.join("natives").join(rel)
+    }
+}
+
+impl Drop for FakeGame {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
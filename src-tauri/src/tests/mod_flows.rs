@@ -0,0 +1,513 @@
+// src-tauri/src/tests/mod_flows.rs
+// install -> toggle -> conflict -> delete, for both REFramework and skin mods
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::{no_progress, FakeGame, SHARED_TEXTURE};
+use crate::utils::archives::import_skin_archive;
+use crate::utils::auditlog::{read_ledger, LedgerAction};
+use crate::utils::cachethumbs::ThumbnailCleanup;
+use crate::utils::fsops::long_path;
+use crate::utils::gameupdate::{check_for_game_update, redeploy_enabled_skin_mods};
+use crate::utils::hashing::hash_reader;
+use crate::utils::modregistry::{
+    delete_reframework_mod_entry, delete_skin_mod_entry, disable_skin_mod_files, enable_skin_mod_files,
+    find_natives_conflicts, new_skin_mod_from_dir, plan_uninstall, scan_and_update_reframework_mods,
+    set_reframework_mod_enabled, ConflictResolution, ModRegistry, ModType,
+};
+use crate::utils::profiles::apply_desired_state_to;
+use crate::utils::reframework::{remove_reframework_files, ReframeworkStatus};
+use crate::utils::safemode::{disable_script_mods, restore_script_mods};
+use crate::utils::samplemod::{install_sample_mod_files, remove_sample_mod_files, sample_mod_status};
+use crate::utils::repak::pack_natives_to_pak;
+use crate::utils::settings::{AppSettings, DeploymentMode};
+
+#[test]
+fn reframework_mod_install_toggle_delete() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let zip = game.fixture_zip(
+        "TestPlugin-1-0.zip",
+        &[
+            ("reframework/plugins/TestPlugin/TestPlugin.dll", b"MZ plugin"),
+            ("reframework/plugins/TestPlugin/data/config.json", b"{}"),
+        ],
+    );
+
+    let installed = crate::extract_reframework_archive(
+        &zip,
+        game.root(),
+        &game.staging_root(),
+        &settings,
+        "TestPlugin",
+        &|_, _| {},
+    )
+    .expect("extract plugin archive");
+    assert_eq!(installed.mod_type, ModType::REFrameworkPlugin);
+    assert_eq!(installed.installed_directory, "reframework/plugins/TestPlugin");
+    assert!(installed.mod_dir.join("TestPlugin/TestPlugin.dll").is_file());
+    assert!(installed.mod_dir.join("TestPlugin/data/config.json").is_file());
+
+    let mut registry = ModRegistry::default();
+    scan_and_update_reframework_mods(&mut registry, game.root(), &settings.install_targets)
+        .expect("scan plugins");
+    assert!(registry.find_mod("TestPlugin").is_some_and(|m| m.enabled));
+
+    let plugin_dir = game.root().join("reframework/plugins/TestPlugin");
+    let disabled_dir = game.root().join("reframework/plugins/TestPlugin.disabled");
+    set_reframework_mod_enabled(&mut registry, game.root(), "TestPlugin", false).expect("disable");
+    assert!(!plugin_dir.exists() && disabled_dir.is_dir());
+    assert!(registry.find_mod("TestPlugin").is_some_and(|m| !m.enabled));
+
+    set_reframework_mod_enabled(&mut registry, game.root(), "TestPlugin", true).expect("enable");
+    assert!(plugin_dir.is_dir() && !disabled_dir.exists());
+
+    let errors = delete_reframework_mod_entry(&mut registry, game.root(), "TestPlugin", None, &ThumbnailCleanup::default())
+        .expect("delete plugin");
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert!(!plugin_dir.exists() && !disabled_dir.exists());
+    assert!(registry.find_mod("TestPlugin").is_none());
+
+    // Every step above left a trace in the audit ledger
+    let ledger = read_ledger(game.root()).expect("read audit ledger");
+    let dll = ledger
+        .iter()
+        .find(|e| e.path == "reframework/plugins/TestPlugin/TestPlugin/TestPlugin.dll")
+        .expect("dll creation recorded");
+    assert_eq!(dll.action, LedgerAction::Created);
+    assert_eq!(dll.sha256, Some(hash_reader(&b"MZ plugin"[..]).unwrap()));
+    let renames: Vec<_> = ledger.iter().filter(|e| e.action == LedgerAction::Renamed).collect();
+    assert_eq!(renames.len(), 2);
+    assert_eq!(renames[0].renamed_to.as_deref(), Some("reframework/plugins/TestPlugin.disabled/"));
+    assert!(ledger
+        .iter()
+        .any(|e| e.action == LedgerAction::Deleted && e.path == "reframework/plugins/TestPlugin/"));
+}
+
+#[test]
+fn skin_mod_conflict_keep_replace_delete() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+    let second = game.install_skin(&mut registry, "SecondSkin", b"second");
+    let shared = game.deployed(SHARED_TEXTURE);
+    let no_resolutions = HashMap::new();
+
+    enable_skin_mod_files(&mut registry, game.root(), &first, &settings, &no_resolutions, &no_progress)
+        .expect("enable first skin");
+    assert_eq!(fs::read(&shared).unwrap(), b"first");
+    assert!(game.deployed("STM/FirstSkin/only.txt").is_file());
+
+    // The second skin collides on the shared texture only
+    let conflicts = find_natives_conflicts(&registry, game.root(), &second, &settings);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].relative_path, SHARED_TEXTURE);
+    assert_eq!(conflicts[0].current_owner, first);
+
+    // Keep: the first skin's file stays and stays owned by it
+    let keep = HashMap::from([(SHARED_TEXTURE.to_string(), ConflictResolution::Keep)]);
+    enable_skin_mod_files(&mut registry, game.root(), &second, &settings, &keep, &no_progress)
+        .expect("enable second skin keeping conflicts");
+    assert_eq!(fs::read(&shared).unwrap(), b"first");
+    assert!(game.deployed("STM/SecondSkin/only.txt").is_file());
+    disable_skin_mod_files(&mut registry, game.root(), &second).expect("disable second skin");
+    assert!(shared.is_file());
+    assert!(!game.deployed("STM/SecondSkin/only.txt").exists());
+
+    // Replace: the second skin takes the file over, so disabling the first leaves it alone
+    let replace = HashMap::from([(SHARED_TEXTURE.to_string(), ConflictResolution::Replace)]);
+    enable_skin_mod_files(&mut registry, game.root(), &second, &settings, &replace, &no_progress)
+        .expect("enable second skin replacing conflicts");
+    assert_eq!(fs::read(&shared).unwrap(), b"second");
+    disable_skin_mod_files(&mut registry, game.root(), &first).expect("disable first skin");
+    assert_eq!(fs::read(&shared).unwrap(), b"second");
+    assert!(!game.deployed("STM/FirstSkin/only.txt").exists());
+
+    // A dry run lists the deployed files and the source folder without touching them
+    let plan = plan_uninstall(&registry, game.root(), &second, false).expect("plan uninstall");
+    assert!(plan.paths.contains(&shared.to_string_lossy().to_string()), "{:?}", plan.paths);
+    assert!(plan.paths.contains(&second), "{:?}", plan.paths);
+    assert!(shared.is_file() && Path::new(&second).is_dir());
+
+    // Deleting an enabled skin undeploys it and removes its source folder
+    let errors = delete_skin_mod_entry(&mut registry, game.root(), &second, None, &ThumbnailCleanup::default()).expect("delete second skin");
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert!(!shared.exists());
+    assert!(!Path::new(&second).exists());
+    assert_eq!(registry.skin_mods.len(), 1);
+
+    delete_skin_mod_entry(&mut registry, game.root(), &first, None, &ThumbnailCleanup::default()).expect("delete first skin");
+    assert!(registry.skin_mods.is_empty());
+    assert!(!Path::new(&first).exists());
+}
+
+#[test]
+fn skin_files_for_another_platform_deploy_under_ours() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let zip = game.fixture_zip(
+        "WrongPlatform.zip",
+        &[
+            ("natives/X64/Art/wrong.txt", b"x64".as_slice()),
+            ("natives/Art/missing.txt", b"none".as_slice()),
+            ("natives/STM/Art/right.txt", b"stm".as_slice()),
+        ],
+    );
+    let imported = import_skin_archive(&zip, &game.staging_root()).expect("import skin archive");
+    let skin = new_skin_mod_from_dir(&imported[0], "local_zip");
+    let mod_path = skin.base.path.clone();
+    registry.skin_mods.push(skin);
+
+    enable_skin_mod_files(&mut registry, game.root(), &mod_path, &settings, &HashMap::new(), &no_progress)
+        .expect("enable skin");
+    for file in ["STM/Art/wrong.txt", "STM/Art/missing.txt", "STM/Art/right.txt"] {
+        assert!(game.deployed(file).is_file(), "{} not deployed", file);
+    }
+    assert!(!game.deployed("X64").exists());
+    assert!(!game.deployed("Art").exists());
+}
+
+#[test]
+fn reframework_archive_without_mod_files_is_rejected() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let zip = game.fixture_zip("Readme-only.zip", &[("README.txt", b"nothing to install")]);
+
+    let result = crate::extract_reframework_archive(
+        &zip,
+        game.root(),
+        &game.staging_root(),
+        &settings,
+        "Readme",
+        &|_, _| {},
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn remove_reframework_keeps_user_configs() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let zip = game.fixture_zip(
+        "TestScript.zip",
+        &[("reframework/autorun/TestScript/init.lua", b"-- script")],
+    );
+    crate::extract_reframework_archive(&zip, game.root(), &game.staging_root(), &settings, "TestScript", &|_, _| {})
+        .expect("extract script archive");
+    let mut registry = ModRegistry::default();
+    scan_and_update_reframework_mods(&mut registry, game.root(), &settings.install_targets)
+        .expect("scan scripts");
+    assert!(registry.find_mod("TestScript").is_some());
+    fs::create_dir_all(game.root().join("reframework/data")).unwrap();
+    fs::write(game.root().join("reframework/data/TestScript.json"), b"{\"volume\": 3}").unwrap();
+
+    let report = remove_reframework_files(&mut registry, game.root(), None, true);
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    assert!(!game.root().join("dinput8.dll").exists());
+    assert!(!game.root().join("reframework/autorun").exists());
+    assert!(game.root().join("reframework/data/TestScript.json").is_file());
+    assert_eq!(report.preserved_files, vec!["reframework/data/TestScript.json".to_string()]);
+    assert_eq!(report.unregistered_mods, vec!["TestScript".to_string()]);
+    assert!(registry.find_mod("TestScript").is_none());
+
+    let report = remove_reframework_files(&mut registry, game.root(), None, false);
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    assert!(!game.root().join("reframework").exists());
+}
+
+#[test]
+fn disabling_a_skin_restores_the_files_it_overwrote() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+    let second = game.install_skin(&mut registry, "SecondSkin", b"second");
+    let shared = game.deployed(SHARED_TEXTURE);
+    let hand_placed = game.deployed("STM/FirstSkin/only.txt");
+    fs::create_dir_all(hand_placed.parent().unwrap()).unwrap();
+    fs::write(&hand_placed, b"placed by hand").unwrap();
+
+    // A file nobody manages is backed up and comes back on disable
+    enable_skin_mod_files(&mut registry, game.root(), &first, &settings, &HashMap::new(), &no_progress)
+        .expect("enable first skin");
+    assert_eq!(fs::read(&hand_placed).unwrap(), b"FirstSkin");
+
+    // Another mod's file goes back to it when the mod that replaced it is disabled
+    let replace = HashMap::from([(SHARED_TEXTURE.to_string(), ConflictResolution::Replace)]);
+    enable_skin_mod_files(&mut registry, game.root(), &second, &settings, &replace, &no_progress)
+        .expect("enable second skin");
+    assert_eq!(fs::read(&shared).unwrap(), b"second");
+    let errors = disable_skin_mod_files(&mut registry, game.root(), &second).expect("disable second skin");
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(fs::read(&shared).unwrap(), b"first");
+    let first_mod = registry.skin_mods.iter().find(|m| m.base.path == first).unwrap();
+    assert!(first_mod.installed_files.iter().any(|f| Path::new(&f.path) == shared));
+
+    disable_skin_mod_files(&mut registry, game.root(), &first).expect("disable first skin");
+    assert!(!shared.exists());
+    assert_eq!(fs::read(&hand_placed).unwrap(), b"placed by hand");
+    assert!(!game.root().join("fossmodmanager/backups").read_dir().unwrap().any(|_| true));
+}
+
+#[test]
+fn unpacked_paks_back_up_and_restore_the_files_they_overwrite() {
+    let game = FakeGame::new();
+    let mut registry = ModRegistry::default();
+    let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+    enable_skin_mod_files(&mut registry, game.root(), &first, &AppSettings::default(), &HashMap::new(), &no_progress)
+        .expect("enable first skin");
+
+    // A pak-only mod shipping the same texture, deployed as loose natives
+    let source = game.root().join("fixtures/PakSkin");
+    fs::create_dir_all(source.join("natives").join(Path::new(SHARED_TEXTURE).parent().unwrap())).unwrap();
+    fs::write(source.join("natives").join(SHARED_TEXTURE), b"from pak").unwrap();
+    let pak_dir = game.mods_dir().join("PakSkin");
+    fs::create_dir_all(&pak_dir).unwrap();
+    pack_natives_to_pak(&source, &pak_dir.join("PakSkin.pak"), &|rel| rel.to_path_buf(), &|_| {}).expect("pack fixture");
+    let file_list = game.root().join("fixtures/files.list");
+    fs::write(&file_list, format!("natives/{}\n", SHARED_TEXTURE)).unwrap();
+    let settings = AppSettings {
+        deployment_mode: DeploymentMode::LooseNatives,
+        pak_file_list: Some(file_list.to_string_lossy().to_string()),
+        ..AppSettings::default()
+    };
+    let skin = new_skin_mod_from_dir(&pak_dir, "local");
+    let pak_mod = skin.base.path.clone();
+    registry.skin_mods.push(skin);
+
+    // Keeping the other mod's file leaves it in place
+    let keep = HashMap::from([(SHARED_TEXTURE.to_string(), ConflictResolution::Keep)]);
+    enable_skin_mod_files(&mut registry, game.root(), &pak_mod, &settings, &keep, &no_progress)
+        .expect("enable pak skin keeping the conflict");
+    let shared = game.deployed(SHARED_TEXTURE);
+    assert_eq!(fs::read(&shared).unwrap(), b"first");
+    disable_skin_mod_files(&mut registry, game.root(), &pak_mod).expect("disable pak skin");
+
+    // Replacing it takes it over, and disabling hands it back
+    enable_skin_mod_files(&mut registry, game.root(), &pak_mod, &settings, &HashMap::new(), &no_progress)
+        .expect("enable pak skin");
+    assert_eq!(fs::read(&shared).unwrap(), b"from pak");
+    let first_mod = registry.skin_mods.iter().find(|m| m.base.path == first).unwrap();
+    assert!(!first_mod.installed_files.iter().any(|f| Path::new(&f.path) == shared));
+    let errors = disable_skin_mod_files(&mut registry, game.root(), &pak_mod).expect("disable pak skin");
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(fs::read(&shared).unwrap(), b"first");
+    let first_mod = registry.skin_mods.iter().find(|m| m.base.path == first).unwrap();
+    assert!(first_mod.installed_files.iter().any(|f| Path::new(&f.path) == shared));
+}
+
+#[test]
+fn skin_files_past_max_path_deploy_and_are_removed() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let nested: Vec<String> = (0..8).map(|i| format!("deeply_nested_folder_for_armor_variant_{:02}", i)).collect();
+    let rel = format!("STM/{}/armor.tex", nested.join("/"));
+    let zip = game.fixture_zip("DeepSkin.zip", &[(format!("natives/{}", rel).as_str(), b"deep")]);
+    let imported = import_skin_archive(&zip, &game.staging_root()).expect("import skin archive");
+    let skin = new_skin_mod_from_dir(&imported[0], "local_zip");
+    let mod_path = skin.base.path.clone();
+    registry.skin_mods.push(skin);
+
+    let target = game.deployed(&rel);
+    assert!(target.as_os_str().len() > 260, "{}", target.display());
+    enable_skin_mod_files(&mut registry, game.root(), &mod_path, &settings, &HashMap::new(), &no_progress)
+        .expect("enable deep skin");
+    assert_eq!(fs::read(long_path(&target)).unwrap(), b"deep");
+
+    let errors = disable_skin_mod_files(&mut registry, game.root(), &mod_path).expect("disable deep skin");
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert!(!long_path(&target).exists());
+}
+
+#[test]
+fn safe_mode_disables_script_mods_and_restores_them() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    for (name, entry) in [
+        ("SafePlugin", "reframework/plugins/SafePlugin/SafePlugin.dll"),
+        ("SafeScript", "reframework/autorun/SafeScript/init.lua"),
+        ("OffScript", "reframework/autorun/OffScript/init.lua"),
+    ] {
+        let zip = game.fixture_zip(&format!("{}.zip", name), &[(entry, b"mod")]);
+        crate::extract_reframework_archive(&zip, game.root(), &game.staging_root(), &settings, name, &|_, _| {})
+            .expect("extract script mod");
+    }
+    let mut registry = ModRegistry::default();
+    scan_and_update_reframework_mods(&mut registry, game.root(), &settings.install_targets)
+        .expect("scan script mods");
+    set_reframework_mod_enabled(&mut registry, game.root(), "OffScript", false).expect("disable");
+
+    let (mut disabled, errors) = disable_script_mods(&mut registry, game.root());
+    assert!(errors.is_empty(), "{:?}", errors);
+    disabled.sort();
+    assert_eq!(disabled, vec!["SafePlugin".to_string(), "SafeScript".to_string()]);
+    assert!(registry.mods.iter().all(|m| !m.enabled));
+    assert!(game.root().join("reframework/plugins/SafePlugin.disabled").is_dir());
+
+    // Only what safe mode turned off comes back; the user's own disabled mod stays off
+    let (restored, errors) = restore_script_mods(&mut registry, game.root(), &disabled);
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(restored.len(), 2);
+    assert!(registry.find_mod("SafePlugin").is_some_and(|m| m.enabled));
+    assert!(registry.find_mod("SafeScript").is_some_and(|m| m.enabled));
+    assert!(registry.find_mod("OffScript").is_some_and(|m| !m.enabled));
+    assert!(game.root().join("reframework/autorun/SafeScript").is_dir());
+}
+
+#[test]
+fn loose_lua_archive_installs_as_autorun() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let zip = game.fixture_zip("LooseScript.zip", &[("loose_script.lua", b"-- script")]);
+
+    let installed = crate::extract_reframework_archive(
+        &zip,
+        game.root(),
+        &game.staging_root(),
+        &settings,
+        "LooseScript",
+        &|_, _| {},
+    )
+    .expect("extract loose script");
+    assert_eq!(installed.mod_type, ModType::REFrameworkAutorun);
+    assert!(installed.mod_dir.join("loose_script.lua").is_file());
+}
+
+#[test]
+fn game_update_is_detected_and_mods_redeployed_over_it() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+    let second = game.install_skin(&mut registry, "SecondSkin", b"second");
+    let shared = game.deployed(SHARED_TEXTURE);
+    fs::create_dir_all(shared.parent().unwrap()).unwrap();
+    fs::write(&shared, b"original").unwrap();
+    for path in [&first, &second] {
+        let replace = HashMap::from([(SHARED_TEXTURE.to_string(), ConflictResolution::Replace)]);
+        enable_skin_mod_files(&mut registry, game.root(), path, &settings, &replace, &no_progress)
+            .expect("enable skin");
+    }
+    assert!(!check_for_game_update(game.root()).unwrap().updated);
+
+    // The patch ships a new executable and a new version of the shared texture
+    fs::write(game.root().join("MonsterHunterWilds.exe"), b"MZ fake game, patched").unwrap();
+    fs::write(&shared, b"patched original").unwrap();
+    let status = check_for_game_update(game.root()).unwrap();
+    assert!(status.updated, "{:?}", status);
+
+    let report = redeploy_enabled_skin_mods(&mut registry, game.root(), &settings);
+    assert!(report.failed.is_empty(), "{:?}", report.failed);
+    assert_eq!(report.redeployed, vec![first.clone(), second.clone()]);
+    assert_eq!(report.refreshed_originals, 1);
+    assert_eq!(fs::read(&shared).unwrap(), b"second");
+
+    // Disabling both now brings back the patched file, not the one from before the update
+    disable_skin_mod_files(&mut registry, game.root(), &second).expect("disable second skin");
+    disable_skin_mod_files(&mut registry, game.root(), &first).expect("disable first skin");
+    assert_eq!(fs::read(&shared).unwrap(), b"patched original");
+}
+
+#[test]
+fn desired_state_only_touches_mods_that_differ() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+    let second = game.install_skin(&mut registry, "SecondSkin", b"second");
+    enable_skin_mod_files(&mut registry, game.root(), &first, &settings, &HashMap::new(), &no_progress)
+        .expect("enable skin");
+    let first_id = registry.find_any_mod(&first).unwrap().id.clone();
+    let desired = std::collections::BTreeMap::from([
+        (first_id, false),
+        (second.clone(), true),
+        ("NotInstalled".to_string(), true),
+    ]);
+
+    // A dry run reports the diff without deploying anything
+    let plan = apply_desired_state_to(&mut registry, game.root(), &settings, &desired, true).unwrap();
+    assert_eq!((plan.enabled.len(), plan.disabled.len()), (1, 1));
+    assert_eq!(plan.missing, vec!["NotInstalled".to_string()]);
+    assert!(registry.find_any_mod(&first).unwrap().enabled);
+    assert!(!game.deployed("STM/SecondSkin/only.txt").exists());
+
+    let report = apply_desired_state_to(&mut registry, game.root(), &settings, &desired, false).unwrap();
+    assert_eq!(report.enabled, plan.enabled);
+    assert!(!registry.find_any_mod(&first).unwrap().enabled);
+    assert!(game.deployed("STM/SecondSkin/only.txt").exists());
+
+    // Reaching the same state again changes nothing
+    let again = apply_desired_state_to(&mut registry, game.root(), &settings, &desired, false).unwrap();
+    assert!(again.enabled.is_empty() && again.disabled.is_empty());
+    assert_eq!(again.unchanged, 2);
+}
+
+#[test]
+fn script_data_survives_a_reinstall() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let v1 = game.fixture_zip(
+        "DataPlugin-1-0.zip",
+        &[
+            ("reframework/plugins/DataPlugin/DataPlugin.dll", b"MZ v1"),
+            ("reframework/plugins/DataPlugin/data/defaults.json", b"{\"v\": 1}"),
+        ],
+    );
+    let staging_root = game.staging_root();
+    let installed = crate::extract_reframework_archive(&v1, game.root(), &staging_root, &settings, "DataPlugin", &|_, _| {})
+        .expect("install v1");
+    // What the plugin saves for itself at runtime, inside its folder and under reframework/data
+    let saved = installed.mod_dir.join("DataPlugin/data/saved_state.json");
+    fs::write(&saved, b"{\"progress\": 42}").unwrap();
+    let shared = game.root().join("reframework/data/DataPlugin.json");
+    fs::create_dir_all(shared.parent().unwrap()).unwrap();
+    fs::write(&shared, b"{\"hotkey\": \"F5\"}").unwrap();
+
+    let v2 = game.fixture_zip(
+        "DataPlugin-2-0.zip",
+        &[
+            ("reframework/plugins/DataPlugin/DataPlugin.dll", b"MZ v2"),
+            ("reframework/plugins/DataPlugin/data/defaults.json", b"{\"v\": 2}"),
+        ],
+    );
+    let reinstalled = crate::extract_reframework_archive(&v2, game.root(), &staging_root, &settings, "DataPlugin", &|_, _| {})
+        .expect("install v2");
+    assert_eq!(
+        reinstalled.restored_data,
+        vec!["reframework/plugins/DataPlugin/DataPlugin/data/saved_state.json".to_string()]
+    );
+    assert_eq!(fs::read(&saved).unwrap(), b"{\"progress\": 42}");
+    assert_eq!(fs::read(&shared).unwrap(), b"{\"hotkey\": \"F5\"}");
+    // Files the new version ships are its own
+    assert_eq!(fs::read(installed.mod_dir.join("DataPlugin/data/defaults.json")).unwrap(), b"{\"v\": 2}");
+}
+
+#[test]
+fn sample_mod_installs_into_autorun_and_is_removed_in_one_go() {
+    let game = FakeGame::new();
+    let settings = AppSettings::default();
+    let mut registry = ModRegistry::default();
+    let sample = install_sample_mod_files(&mut registry, game.root(), &settings).expect("install sample");
+    assert_eq!(sample.installed_directory, "reframework/autorun/FossModManagerSample");
+    assert!(game.root().join(&sample.installed_directory).join("fossmodmanager_sample.lua").is_file());
+
+    // The list scan sees it as the registered mod, not a manually added one
+    scan_and_update_reframework_mods(&mut registry, game.root(), &settings.install_targets).expect("scan");
+    assert_eq!(registry.mods.len(), 1);
+    let status = sample_mod_status(&registry, game.root(), ReframeworkStatus::Ok);
+    assert!(status.installed && status.enabled && !status.loaded_in_game);
+
+    // What the script writes once REFramework has run it
+    fs::create_dir_all(game.root().join("reframework/data")).unwrap();
+    fs::write(game.root().join("reframework/data/fossmodmanager_sample_loaded.txt"), b"hello").unwrap();
+    assert!(sample_mod_status(&registry, game.root(), ReframeworkStatus::Ok).loaded_in_game);
+
+    remove_sample_mod_files(&mut registry, game.root(), &ThumbnailCleanup::default()).expect("remove sample");
+    assert!(registry.mods.is_empty());
+    assert!(!game.root().join(&sample.installed_directory).exists());
+    assert!(!sample_mod_status(&registry, game.root(), ReframeworkStatus::Ok).loaded_in_game);
+}
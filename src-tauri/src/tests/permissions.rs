@@ -0,0 +1,94 @@
+// src-tauri/src/tests/permissions.rs
+// Command tiers: each command is classified once, and the policy refuses whole tiers
+use crate::utils::confirmation::{gate_confirmation, ConfirmationState};
+use crate::utils::permissions::{
+    check_command_against, command_tier, disabled_tiers, needs_write_access, CommandTier, COMMAND_TIERS,
+    POLICY_COMMANDS,
+};
+use crate::utils::settings::AppSettings;
+
+#[test]
+fn every_command_has_exactly_one_tier() {
+    assert!(COMMAND_TIERS.len() > 50, "only {} commands classified", COMMAND_TIERS.len());
+    let mut names: Vec<_> = COMMAND_TIERS.iter().map(|(name, _)| *name).collect();
+    names.sort();
+    let duplicates: Vec<_> = names.windows(2).filter(|w| w[0] == w[1]).map(|w| w[0]).collect();
+    assert!(duplicates.is_empty(), "commands classified twice: {:?}", duplicates);
+    let policy_with_tier: Vec<_> = POLICY_COMMANDS.iter().filter(|c| names.contains(c)).collect();
+    assert!(policy_with_tier.is_empty(), "policy commands with a tier: {:?}", policy_with_tier);
+}
+
+#[test]
+#[cfg(not(feature = "kiosk"))]
+fn disabled_tiers_follow_settings() {
+    let mut settings = AppSettings::default();
+    assert!(disabled_tiers(&settings).is_empty());
+
+    settings.disabled_command_tiers = vec![CommandTier::Destructive, CommandTier::ModifyGameDir, CommandTier::Destructive];
+    assert_eq!(
+        disabled_tiers(&settings),
+        vec![CommandTier::ModifyGameDir, CommandTier::Destructive]
+    );
+    assert_eq!(command_tier("delete_mods"), CommandTier::Destructive);
+    // Going back to a restore point overwrites the current state with an older one
+    assert_eq!(command_tier("restore_to_point"), CommandTier::Destructive);
+    assert_eq!(command_tier("list_mods"), CommandTier::Read);
+    // The watcher rescans and saves the registry whenever the mod folders change
+    assert_eq!(command_tier("start_mod_watcher"), CommandTier::ModifyMods);
+    assert_eq!(command_tier("stop_mod_watcher"), CommandTier::ModifyMods);
+    // Unknown commands are treated as the most dangerous kind
+    assert_eq!(command_tier("some_future_command"), CommandTier::Destructive);
+}
+
+#[test]
+#[cfg(not(feature = "kiosk"))]
+fn disabled_tiers_refuse_their_commands() {
+    let settings = AppSettings {
+        disabled_command_tiers: vec![CommandTier::Destructive],
+        ..AppSettings::default()
+    };
+    for (command, tier) in COMMAND_TIERS {
+        let result = check_command_against(&settings, command);
+        if *tier == CommandTier::Destructive {
+            let error = result.expect_err(command);
+            assert!(error.starts_with("[E_COMMAND_DISABLED]"), "{}", error);
+        } else {
+            assert!(result.is_ok(), "{} was refused", command);
+        }
+    }
+    assert!(check_command_against(&settings, "some_future_command").is_err());
+    // The user can always lift their own restrictions
+    assert!(check_command_against(&settings, "save_app_settings").is_ok());
+    assert!(check_command_against(&AppSettings::default(), "delete_mods").is_ok());
+}
+
+#[test]
+fn only_read_commands_run_without_the_instance_lock() {
+    assert!(!needs_write_access("list_mods"));
+    // Creates the mods folder when it is missing
+    assert!(needs_write_access("open_mods_folder"));
+    assert!(needs_write_access("dedupe_paks"));
+    assert!(needs_write_access("save_app_settings"));
+    assert!(needs_write_access("some_future_command"));
+}
+
+#[test]
+fn confirmation_tokens_are_single_use_and_bound_to_their_targets() {
+    let state = ConfirmationState::default();
+    let targets = vec!["target".to_string()];
+    assert!(gate_confirmation(&state, false, "delete_mods", &targets, "summary", None).unwrap().is_none());
+
+    let challenge = gate_confirmation(&state, true, "delete_mods", &targets, "summary", None)
+        .unwrap()
+        .expect("a challenge instead of running");
+    assert_eq!(challenge.action, "delete_mods");
+    let other_targets = vec!["other".to_string()];
+    assert!(gate_confirmation(&state, true, "delete_mods", &other_targets, "summary", Some(&challenge.token)).is_err());
+
+    let challenge = gate_confirmation(&state, true, "delete_mods", &targets, "summary", None).unwrap().unwrap();
+    assert!(gate_confirmation(&state, true, "delete_profile", &targets, "summary", Some(&challenge.token)).is_err());
+
+    let challenge = gate_confirmation(&state, true, "delete_mods", &targets, "summary", None).unwrap().unwrap();
+    assert!(gate_confirmation(&state, true, "delete_mods", &targets, "summary", Some(&challenge.token)).unwrap().is_none());
+    assert!(gate_confirmation(&state, true, "delete_mods", &targets, "summary", Some(&challenge.token)).is_err());
+}
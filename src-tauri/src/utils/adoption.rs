@@ -0,0 +1,169 @@
+// src-tauri/src/utils/adoption.rs
+// Natives files nobody in the registry owns (Fluffy Mod Manager or hand-copied installs),
+// grouped into likely mods and adopted as managed skin mods so they can be toggled.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::fsops::{copy_file_with_progress, long_path};
+use crate::utils::modregistry::{
+    deployed_file_owners, new_skin_mod_from_dir, owned_path_key, InstalledFile, ModRegistry, ModType,
+    SkinMod,
+};
+use crate::utils::settings::{AppSettings, NATIVES_PLATFORM_DIRS};
+use crate::utils::staging::{get_mods_dir, get_staging_root};
+use crate::utils::walk::Walk;
+
+/// Folders below natives/<platform>/ used to tell mods apart (e.g. Art/Model/Character)
+const GROUP_DEPTH: usize = 3;
+
+/// Unmanaged natives files that probably belong to the same mod
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExternalNativesGroup {
+    pub key: String,            // Grouping folder relative to natives/
+    pub suggested_name: String,
+    pub files: Vec<String>,     // Relative to natives/
+    pub total_bytes: u64,
+}
+
+/// The grouping folder for a file relative to natives/
+fn group_key(rel: &Path) -> String {
+    let parts: Vec<String> = rel
+        .parent()
+        .map(|p| p.iter().map(|c| c.to_string_lossy().to_string()).collect())
+        .unwrap_or_default();
+    // Platform folders directly under natives/ are skipped when grouping
+    let skip = usize::from(
+        parts
+            .first()
+            .is_some_and(|p| NATIVES_PLATFORM_DIRS.iter().any(|d| d.eq_ignore_ascii_case(p))),
+    );
+    let depth = (skip + GROUP_DEPTH).min(parts.len());
+    if depth == 0 {
+        return String::new();
+    }
+    parts[..depth].join("/")
+}
+
+/// Group every natives file no registered skin mod has deployed
+pub fn find_unmanaged_natives(
+    registry: &ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+) -> Vec<ExternalNativesGroup> {
+    let natives_dir = settings.install_targets.resolve(game_root, &ModType::NativesMod);
+    let owned = deployed_file_owners(registry, "");
+    let mut groups: BTreeMap<String, ExternalNativesGroup> = BTreeMap::new();
+
+    for entry in Walk::new(&natives_dir).files() {
+        if owned.contains_key(&owned_path_key(&entry.path().to_string_lossy())) {
+            continue;
+        }
+        let Ok(rel) = entry.path().strip_prefix(&natives_dir) else {
+            continue;
+        };
+        let key = group_key(rel);
+        let group = groups.entry(key.clone()).or_insert_with(|| ExternalNativesGroup {
+            suggested_name: key
+                .rsplit('/')
+                .find(|p| !p.is_empty())
+                .map(|p| format!("{} (external)", p))
+                .unwrap_or_else(|| "Loose natives (external)".to_string()),
+            key,
+            files: Vec::new(),
+            total_bytes: 0,
+        });
+        group.files.push(rel.to_string_lossy().replace('\\', "/"));
+        group.total_bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+    }
+    groups.into_values().collect()
+}
+
+#[tauri::command]
+pub async fn scan_unmanaged_natives(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<Vec<ExternalNativesGroup>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
+    let registry = ModRegistry::load(&app_handle)?;
+    let settings = AppSettings::load(&app_handle)?;
+    let groups = find_unmanaged_natives(&registry, &game_root, &settings);
+    info!("Found {} group(s) of unmanaged natives files", groups.len());
+    Ok(groups)
+}
+
+/// Register a group of unmanaged natives files as an enabled skin mod. The files are copied
+/// into staging so disabling removes them from the game and enabling puts them back.
+#[tauri::command]
+pub async fn adopt_natives_group(
+    app_handle: AppHandle,
+    game_root_path: String,
+    group_key: String,
+    name: Option<String>,
+) -> Result<SkinMod, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let settings = AppSettings::load(&app_handle)?;
+    let group = find_unmanaged_natives(&registry, &game_root, &settings)
+        .into_iter()
+        .find(|g| g.key == group_key)
+        .ok_or_else(|| format!("No unmanaged natives files left under '{}'", group_key))?;
+
+    let display_name = name
+        .filter(|n| !n.trim().is_empty())
+        .unwrap_or_else(|| group.suggested_name.clone());
+    let base_folder: String = format!("external-{}", group.key.replace('/', "-"))
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    let mods_dir = get_mods_dir(&get_staging_root(&app_handle, &game_root));
+    let mut folder = base_folder.clone();
+    let mut suffix = 1;
+    while mods_dir.join(&folder).exists() || registry.find_skin_mod(&folder).is_some() {
+        suffix += 1;
+        folder = format!("{}-{}", base_folder, suffix);
+    }
+    let staged_dir = mods_dir.join(&folder);
+
+    let natives_dir = settings.install_targets.resolve(&game_root, &ModType::NativesMod);
+    let mut installed_files = Vec::with_capacity(group.files.len());
+    for rel in &group.files {
+        let deployed = natives_dir.join(rel);
+        let staged = staged_dir.join("natives").join(rel);
+        let copied = staged
+            .parent()
+            .map(|parent| {
+                fs::create_dir_all(long_path(parent))
+                    .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))
+            })
+            .transpose()
+            .and_then(|_| copy_file_with_progress(&deployed, &staged, false, &|_| {}));
+        if let Err(e) = copied {
+            let _ = fs::remove_dir_all(long_path(&staged_dir));
+            return Err(e);
+        }
+        installed_files.push(InstalledFile::record(&deployed));
+    }
+
+    let mut skin_mod = new_skin_mod_from_dir(&staged_dir, "external");
+    skin_mod.base.name = display_name;
+    skin_mod.base.enabled = true;
+    skin_mod.installed_files = installed_files;
+    registry.skin_mods.push(skin_mod.clone());
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+
+    info!(
+        "Adopted {} unmanaged natives file(s) under '{}' as '{}'",
+        group.files.len(),
+        group.key,
+        skin_mod.base.name
+    );
+    Ok(skin_mod)
+}
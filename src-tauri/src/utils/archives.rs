@@ -0,0 +1,711 @@
+// src-tauri/src/utils/archives.rs
+use log::{info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::utils::contentindex::index_retained_archive;
+use crate::utils::fsops::move_path;
+use crate::utils::hashing::hash_file;
+use crate::utils::modregistry::{is_skin_mod_dir, resolve_reframework_mod_dir, ModRegistry, ModType};
+use crate::utils::staging::get_mods_dir;
+use crate::utils::tempdirs::OperationTempDir;
+use crate::utils::walk::Walk;
+
+/// Get the folder where imported archives are retained after extraction
+pub fn get_archives_dir(staging_root: &Path) -> PathBuf {
+    staging_root.join("archives")
+}
+
+/// Get the folder archives downloaded from Nexus land in
+pub fn get_downloads_dir(staging_root: &Path) -> PathBuf {
+    staging_root.join("downloads")
+}
+
+/// Work out what kind of mod an archive holds from its entry names (any separator or case).
+/// REFramework layouts win over skin content, so a plugin that ships a natives/ folder for
+/// its own assets still installs as a plugin.
+pub fn classify_entry_names<S: AsRef<str>>(names: &[S]) -> Result<ModType, String> {
+    let files: Vec<String> = names
+        .iter()
+        .map(|n| n.as_ref().replace('\\', "/").to_lowercase())
+        .filter(|n| !n.ends_with('/'))
+        .collect();
+    let has_dir = |dir: &str| {
+        files
+            .iter()
+            .any(|n| n.starts_with(&format!("{}/", dir)) || n.contains(&format!("/{}/", dir)))
+    };
+    let file_name = |n: &String| n.rsplit('/').next().unwrap_or(n).to_string();
+
+    if has_dir("autorun") {
+        return Ok(ModType::REFrameworkAutorun);
+    }
+    if has_dir("reframework") {
+        return Ok(ModType::REFrameworkPlugin);
+    }
+    if has_dir("natives") || files.iter().any(|n| n.ends_with(".pak")) {
+        return Ok(ModType::SkinMod);
+    }
+    if files.iter().any(|n| n.ends_with(".dll") && file_name(n) != "dinput8.dll") {
+        return Ok(ModType::REFrameworkPlugin);
+    }
+    if files.iter().any(|n| n.ends_with(".lua")) {
+        return Ok(ModType::REFrameworkAutorun);
+    }
+    if files.iter().any(|n| file_name(n) == "dinput8.dll") {
+        return Err("[E_ARCHIVE_IS_REFRAMEWORK] This archive is REFramework itself; install it from the REFramework section instead".to_string());
+    }
+    Err("[E_ARCHIVE_UNRECOGNIZED] Couldn't tell what kind of mod this archive holds (no reframework/, natives/, .pak, .dll or .lua files)".to_string())
+}
+
+/// Entry names of a zip archive, as stored
+fn zip_entry_names(zip_path: &Path) -> Result<Vec<String>, String> {
+    let file = fs::File::open(zip_path)
+        .map_err(|e| format!("Failed to open {}: {}", zip_path.display(), e))?;
+    let archive = ZipArchive::new(file)
+        .map_err(|e| format!("Invalid zip archive {}: {}", zip_path.display(), e))?;
+    Ok(archive.file_names().map(str::to_string).collect())
+}
+
+/// Classify a zip archive as a REFramework plugin, autorun script or skin mod
+pub fn classify_archive(zip_path: &Path) -> Result<ModType, String> {
+    classify_entry_names(&zip_entry_names(zip_path)?)
+        .map_err(|e| format!("{} ({})", e, zip_path.display()))
+}
+
+/// Whether an archive holds a skin mod (natives/ or .pak files) rather than a REFramework mod
+pub fn is_skin_archive(zip_path: &Path) -> Result<bool, String> {
+    let kind = classify_entry_names(&zip_entry_names(zip_path)?);
+    Ok(matches!(kind, Ok(ModType::SkinMod)))
+}
+
+/// Extract every entry of a zip file into `dest`, skipping unsafe paths
+pub fn extract_zip_to(zip_path: &Path, dest: &Path) -> Result<usize, String> {
+    let file = fs::File::open(zip_path)
+        .map_err(|e| format!("Failed to open {}: {}", zip_path.display(), e))?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| format!("Invalid zip archive {}: {}", zip_path.display(), e))?;
+
+    let mut extracted = 0;
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+        let Some(rel_path) = entry.enclosed_name() else {
+            warn!("Skipping potentially unsafe zip entry: {}", entry.name());
+            continue;
+        };
+        let outpath = dest.join(rel_path);
+
+        if entry.is_dir() {
+            fs::create_dir_all(&outpath)
+                .map_err(|e| format!("Failed to create directory {}: {}", outpath.display(), e))?;
+            continue;
+        }
+        if let Some(parent) = outpath.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+        }
+        let mut outfile = fs::File::create(&outpath)
+            .map_err(|e| format!("Failed to create file {}: {}", outpath.display(), e))?;
+        io::copy(&mut entry, &mut outfile)
+            .map_err(|e| format!("Failed to write file {}: {}", outpath.display(), e))?;
+        extracted += 1;
+    }
+    Ok(extracted)
+}
+
+/// Whether a path is the root of a skin mod itself (natives/ or a .pak at the top level)
+fn is_skin_mod_root(dir: &Path) -> bool {
+    if dir.join("natives").is_dir() {
+        return true;
+    }
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries.filter_map(Result::ok).any(|e| {
+                e.path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pak"))
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Extract a skin mod archive into the mods dir.
+/// Archives that bundle several mod folders (e.g. `_tex` + `_model`) produce one folder each.
+pub fn import_skin_archive(zip_path: &Path, staging_root: &Path) -> Result<Vec<PathBuf>, String> {
+    let mods_dir = get_mods_dir(staging_root);
+    let stem = zip_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| format!("Invalid archive name: {}", zip_path.display()))?
+        .to_string();
+    let scratch = OperationTempDir::new(staging_root, "extract")?;
+    let staging_dir = scratch.path().join(&stem);
+
+    let extracted = extract_zip_to(zip_path, &staging_dir)?;
+    if extracted == 0 {
+        return Err(format!("No files found in {}", zip_path.display()));
+    }
+
+    // Work out which extracted folders are the actual mods
+    let mut mod_roots: Vec<(PathBuf, String)> = Vec::new();
+    if is_skin_mod_root(&staging_dir) {
+        mod_roots.push((staging_dir.clone(), stem.clone()));
+    } else {
+        for entry in fs::read_dir(&staging_dir)
+            .map_err(|e| format!("Failed to read {}: {}", staging_dir.display(), e))?
+            .filter_map(Result::ok)
+        {
+            let path = entry.path();
+            if path.is_dir() && is_skin_mod_dir(&path) {
+                let name = entry.file_name().to_string_lossy().to_string();
+                mod_roots.push((path, name));
+            }
+        }
+    }
+    if mod_roots.is_empty() {
+        return Err(format!(
+            "{} doesn't contain a skin mod (no 'natives' folder or .pak file)",
+            zip_path.display()
+        ));
+    }
+    if let Some((_, name)) = mod_roots.iter().find(|(_, name)| mods_dir.join(name).exists()) {
+        return Err(format!("A mod folder named '{}' already exists", name));
+    }
+
+    let mut imported = Vec::new();
+    for (root, name) in mod_roots {
+        let target = mods_dir.join(&name);
+        move_path(&root, &target)?;
+        imported.push(target);
+    }
+    Ok(imported)
+}
+
+/// Move an imported archive into the archives dir. A byte-identical copy already there
+/// is kept instead; a different archive with the same name gets a numbered suffix.
+/// Returns where the archive is kept.
+fn retain_archive(staging_root: &Path, zip_path: &Path) -> Result<PathBuf, String> {
+    let archives_dir = get_archives_dir(staging_root);
+    let file_name = zip_path.file_name().unwrap_or_default();
+    let mut retained = archives_dir.join(file_name);
+    if retained.exists() {
+        if hash_file(&retained)? == hash_file(zip_path)? {
+            info!("{} is already in archives, discarding the duplicate", zip_path.display());
+            fs::remove_file(zip_path)
+                .map_err(|e| format!("Failed to remove {}: {}", zip_path.display(), e))?;
+            return Ok(retained);
+        }
+        let stem = zip_path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let mut suffix = 1;
+        while retained.exists() {
+            retained = archives_dir.join(format!("{} ({}).zip", stem, suffix));
+            suffix += 1;
+        }
+    }
+    move_path(zip_path, &retained)?;
+    Ok(retained)
+}
+
+/// Keep a copy of an archive installed from elsewhere (e.g. dropped onto the window) with the
+/// other retained archives and index it against the mod folders it produced. The original is
+/// left where it is.
+pub fn retain_archive_copy(staging_root: &Path, zip_path: &Path, mod_dirs: &[PathBuf]) -> Result<PathBuf, String> {
+    let scratch = OperationTempDir::new(staging_root, "retain")?;
+    let copy = scratch.path().join(zip_path.file_name().unwrap_or_default());
+    fs::copy(zip_path, &copy)
+        .map_err(|e| format!("Failed to copy {}: {}", zip_path.display(), e))?;
+    let retained = retain_archive(staging_root, &copy)?;
+    index_retained_archive(staging_root, &retained, mod_dirs)?;
+    Ok(retained)
+}
+
+/// What `import_archives_in_mods_dir` did with the archives it found
+#[derive(Debug, Default)]
+pub struct ModsDirArchives {
+    pub skin_mod_dirs: Vec<PathBuf>,        // Mod folders extracted from skin archives
+    pub reframework_archives: Vec<PathBuf>, // Moved to downloads; the caller installs them
+}
+
+/// Move an archive out of the mods dir into the downloads folder, numbering it if the name
+/// is taken. Returns where it went.
+fn move_to_downloads(staging_root: &Path, zip_path: &Path) -> Result<PathBuf, String> {
+    let downloads_dir = get_downloads_dir(staging_root);
+    let stem = zip_path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let mut target = downloads_dir.join(zip_path.file_name().unwrap_or_default());
+    let mut suffix = 1;
+    while target.exists() {
+        target = downloads_dir.join(format!("{} ({}).zip", stem, suffix));
+        suffix += 1;
+    }
+    move_path(zip_path, &target)?;
+    Ok(target)
+}
+
+/// Deal with any .zip archives sitting in the mods dir. Skin archives are extracted in place
+/// and moved to archives; REFramework archives are moved to downloads for the caller to run
+/// through the installer, and anything unrecognized is moved there too, so no archive is
+/// retried on every scan.
+pub fn import_archives_in_mods_dir(staging_root: &Path, mods_dir: &Path) -> ModsDirArchives {
+    let mut found = ModsDirArchives::default();
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return found;
+    };
+    let archives: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| {
+            p.is_file()
+                && p
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+        })
+        .collect();
+
+    for zip_path in archives {
+        info!("Found archive in mods folder, importing: {}", zip_path.display());
+        match classify_archive(&zip_path) {
+            Ok(ModType::SkinMod) => match import_skin_archive(&zip_path, staging_root) {
+                Ok(mod_dirs) => {
+                    match retain_archive(staging_root, &zip_path) {
+                        Ok(retained) => {
+                            if let Err(e) = index_retained_archive(staging_root, &retained, &mod_dirs) {
+                                warn!("Failed to index {}: {}", retained.display(), e);
+                            }
+                        }
+                        Err(e) => warn!("Imported {} but failed to move it to archives: {}", zip_path.display(), e),
+                    }
+                    info!("Imported {} mod folder(s) from {}", mod_dirs.len(), zip_path.display());
+                    found.skin_mod_dirs.extend(mod_dirs);
+                }
+                Err(e) => warn!("Failed to import archive {}: {}", zip_path.display(), e),
+            },
+            Ok(kind) => match move_to_downloads(staging_root, &zip_path) {
+                Ok(moved) => {
+                    info!("{} holds a {:?} mod, installing it from {}", zip_path.display(), kind, moved.display());
+                    found.reframework_archives.push(moved);
+                }
+                Err(e) => warn!("Failed to move {} out of the mods folder: {}", zip_path.display(), e),
+            },
+            Err(reason) => match move_to_downloads(staging_root, &zip_path) {
+                Ok(moved) => warn!(
+                    "Can't install {} from the mods folder, moved it to {}: {}",
+                    zip_path.display(),
+                    moved.display(),
+                    reason
+                ),
+                Err(e) => warn!("Failed to move unsupported archive {} aside: {}", zip_path.display(), e),
+            },
+        }
+    }
+    found
+}
+
+/// Kinds of multi-part archive sets
+#[derive(Debug, Clone, PartialEq)]
+pub enum SplitArchiveKind {
+    NumberedZip, // Foo.zip.001, Foo.zip.002, ... (byte-split by 7-Zip and friends)
+    SpannedZip,  // Foo.z01, Foo.z02, ..., Foo.zip
+    RarParts,    // Foo.part1.rar, Foo.part2.rar, ...
+}
+
+/// A multi-part archive and its parts in order
+#[derive(Debug, Clone)]
+pub struct SplitArchiveSet {
+    pub kind: SplitArchiveKind,
+    pub base_name: String, // "Foo.zip" / "Foo.rar"
+    pub parts: Vec<PathBuf>,
+    pub missing: Vec<String>, // Part file names that should exist but don't
+}
+
+/// Numbered sibling parts matching `pattern` (capture 1 = base name, 2 = part number)
+fn find_numbered_parts(dir: &Path, pattern: &Regex, base: &str) -> Vec<(u32, PathBuf)> {
+    let mut parts: Vec<(u32, PathBuf)> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    let caps = pattern.captures(&name)?;
+                    if !caps[1].eq_ignore_ascii_case(base) {
+                        return None;
+                    }
+                    Some((caps[2].parse().ok()?, e.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    parts.sort_by_key(|(n, _)| *n);
+    parts
+}
+
+/// Numbers missing from 1..=highest
+fn missing_numbers(parts: &[(u32, PathBuf)]) -> Vec<u32> {
+    let highest = parts.last().map(|(n, _)| *n).unwrap_or(0);
+    (1..=highest)
+        .filter(|n| !parts.iter().any(|(p, _)| p == n))
+        .collect()
+}
+
+/// Work out whether `path` is one part of a split archive and find its siblings
+pub fn detect_split_archive(path: &Path) -> Option<SplitArchiveSet> {
+    let dir = path.parent()?;
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let numbered = Regex::new(r"(?i)^(.+\.zip)\.(\d{3})$").unwrap();
+    let spanned = Regex::new(r"(?i)^(.+)\.z(\d{2,})$").unwrap();
+    let rar = Regex::new(r"(?i)^(.+)\.part(\d+)\.rar$").unwrap();
+
+    if let Some(caps) = numbered.captures(&file_name) {
+        let base = caps[1].to_string();
+        let parts = find_numbered_parts(dir, &numbered, &base);
+        return Some(SplitArchiveSet {
+            kind: SplitArchiveKind::NumberedZip,
+            missing: missing_numbers(&parts)
+                .into_iter()
+                .map(|n| format!("{}.{:03}", base, n))
+                .collect(),
+            parts: parts.into_iter().map(|(_, p)| p).collect(),
+            base_name: base,
+        });
+    }
+
+    // Spanned sets are recognised from any .zNN part or from the final .zip
+    let stem = match spanned.captures(&file_name) {
+        Some(caps) => Some(caps[1].to_string()),
+        None if file_name.to_lowercase().ends_with(".zip") => {
+            let stem = file_name[..file_name.len() - 4].to_string();
+            dir.join(format!("{}.z01", stem)).exists().then_some(stem)
+        }
+        None => None,
+    };
+    if let Some(stem) = stem {
+        let parts = find_numbered_parts(dir, &spanned, &stem);
+        let mut missing: Vec<String> = missing_numbers(&parts)
+            .into_iter()
+            .map(|n| format!("{}.z{:02}", stem, n))
+            .collect();
+        let final_part = dir.join(format!("{}.zip", stem));
+        if !final_part.exists() {
+            missing.push(format!("{}.zip", stem));
+        }
+        let mut ordered: Vec<PathBuf> = parts.into_iter().map(|(_, p)| p).collect();
+        ordered.push(final_part);
+        return Some(SplitArchiveSet {
+            kind: SplitArchiveKind::SpannedZip,
+            base_name: format!("{}.zip", stem),
+            parts: ordered,
+            missing,
+        });
+    }
+
+    if let Some(caps) = rar.captures(&file_name) {
+        let base = caps[1].to_string();
+        let parts = find_numbered_parts(dir, &rar, &base);
+        return Some(SplitArchiveSet {
+            kind: SplitArchiveKind::RarParts,
+            missing: missing_numbers(&parts)
+                .into_iter()
+                .map(|n| format!("{}.part{}.rar", base, n))
+                .collect(),
+            parts: parts.into_iter().map(|(_, p)| p).collect(),
+            base_name: format!("{}.rar", base),
+        });
+    }
+    None
+}
+
+/// If `path` is part of a split archive, join the parts into a single zip in `work_dir`
+/// and return that; plain archives are returned unchanged. Errors name any missing parts.
+pub fn resolve_split_archive(path: &Path, work_dir: &Path) -> Result<PathBuf, String> {
+    let Some(set) = detect_split_archive(path) else {
+        return Ok(path.to_path_buf());
+    };
+    info!(
+        "{} is part of a split archive ({:?}, {} parts found)",
+        path.display(),
+        set.kind,
+        set.parts.len()
+    );
+    if !set.missing.is_empty() {
+        return Err(format!(
+            "Split archive '{}' is incomplete. Missing part(s): {}",
+            set.base_name,
+            set.missing.join(", ")
+        ));
+    }
+    if set.kind == SplitArchiveKind::RarParts {
+        return Err(format!(
+            "'{}' is a multi-part RAR archive, which isn't supported. Extract it with 7-Zip or WinRAR and install the resulting folder or a re-packed .zip.",
+            set.base_name
+        ));
+    }
+
+    fs::create_dir_all(work_dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", work_dir.display(), e))?;
+    let joined_path = work_dir.join(&set.base_name);
+    let mut joined = fs::File::create(&joined_path)
+        .map_err(|e| format!("Failed to create {}: {}", joined_path.display(), e))?;
+    for part in &set.parts {
+        let mut input = fs::File::open(part)
+            .map_err(|e| format!("Failed to open {}: {}", part.display(), e))?;
+        io::copy(&mut input, &mut joined)
+            .map_err(|e| format!("Failed to join {}: {}", part.display(), e))?;
+    }
+    drop(joined);
+
+    // Spanned zips carry per-disk offsets; make sure the joined file actually reads
+    if let Err(e) = fs::File::open(&joined_path)
+        .map_err(|e| e.to_string())
+        .and_then(|f| ZipArchive::new(f).map(|_| ()).map_err(|e| e.to_string()))
+    {
+        let _ = fs::remove_file(&joined_path);
+        return Err(format!(
+            "Joined '{}' from {} parts but it isn't readable ({}). Re-create the archive or extract it with 7-Zip.",
+            set.base_name,
+            set.parts.len(),
+            e
+        ));
+    }
+    info!("Joined {} parts into {}", set.parts.len(), joined_path.display());
+    Ok(joined_path)
+}
+
+/// Junk that never belongs in a shared mod archive
+const DEFAULT_EXPORT_IGNORE: &[&str] = &[
+    "Thumbs.db",
+    "desktop.ini",
+    ".DS_Store",
+    "__MACOSX",
+    "*.psd",
+];
+
+/// Per-mod overrides, one gitignore-style pattern per line ("!pattern" re-includes)
+const EXPORT_IGNORE_FILE: &str = ".fmmignore";
+
+/// A single ignore rule, matched against the relative path or any of its components
+struct IgnorePattern {
+    regex: Regex,
+    negated: bool,
+    anchored: bool, // Contains a '/', so it matches the whole relative path
+}
+
+impl IgnorePattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, pattern) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let pattern = pattern.trim_matches('/');
+        let mut expr = String::from("(?i)^");
+        for c in pattern.chars() {
+            match c {
+                '*' => expr.push_str("[^/]*"),
+                '?' => expr.push_str("[^/]"),
+                _ => expr.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        expr.push('$');
+        Some(Self {
+            regex: Regex::new(&expr).ok()?,
+            negated,
+            anchored: pattern.contains('/'),
+        })
+    }
+
+    fn matches(&self, rel_path: &str) -> bool {
+        if self.anchored {
+            self.regex.is_match(rel_path)
+        } else {
+            rel_path.split('/').any(|part| self.regex.is_match(part))
+        }
+    }
+}
+
+/// Built-in ignore rules followed by the mod's own .fmmignore, later rules winning
+fn load_ignore_patterns(mod_dir: &Path) -> Vec<IgnorePattern> {
+    let mut patterns: Vec<IgnorePattern> = DEFAULT_EXPORT_IGNORE
+        .iter()
+        .filter_map(|p| IgnorePattern::parse(p))
+        .collect();
+    patterns.extend(IgnorePattern::parse(EXPORT_IGNORE_FILE));
+    if let Ok(content) = fs::read_to_string(mod_dir.join(EXPORT_IGNORE_FILE)) {
+        patterns.extend(content.lines().filter_map(IgnorePattern::parse));
+    }
+    patterns
+}
+
+fn is_ignored(patterns: &[IgnorePattern], rel_path: &str) -> bool {
+    patterns
+        .iter()
+        .rev()
+        .find(|p| p.matches(rel_path))
+        .is_some_and(|p| !p.negated)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExportReport {
+    pub archive_path: String,
+    pub file_count: usize,
+    pub skipped: Vec<String>, // Relative paths left out by ignore rules
+}
+
+/// Zip a mod folder, leaving out junk files and anything the mod's .fmmignore excludes
+pub fn package_mod_dir(mod_dir: &Path, zip_path: &Path) -> Result<ExportReport, String> {
+    let patterns = load_ignore_patterns(mod_dir);
+    let root_name = mod_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().trim_end_matches(".disabled").to_string())
+        .unwrap_or_else(|| "mod".to_string());
+
+    if let Some(parent) = zip_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let file = fs::File::create(zip_path)
+        .map_err(|e| format!("Failed to create {}: {}", zip_path.display(), e))?;
+    let mut writer = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let mut report = ExportReport {
+        archive_path: zip_path.to_string_lossy().to_string(),
+        file_count: 0,
+        skipped: Vec::new(),
+    };
+    let mut walker = Walk::new(mod_dir).min_depth(1).sorted().include_hidden().into_iter();
+    while let Some(entry) = walker.next() {
+        let rel_path = entry
+            .path()
+            .strip_prefix(mod_dir)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+
+        if is_ignored(&patterns, &rel_path) {
+            report.skipped.push(rel_path);
+            if entry.file_type().is_dir() {
+                walker.skip_current_dir();
+            }
+            continue;
+        }
+
+        let archive_name = format!("{}/{}", root_name, rel_path);
+        if entry.file_type().is_dir() {
+            writer
+                .add_directory(archive_name, options)
+                .map_err(|e| format!("Failed to add directory {}: {}", rel_path, e))?;
+            continue;
+        }
+        writer
+            .start_file(archive_name, options)
+            .map_err(|e| format!("Failed to add {}: {}", rel_path, e))?;
+        let mut source = fs::File::open(entry.path())
+            .map_err(|e| format!("Failed to open {}: {}", entry.path().display(), e))?;
+        io::copy(&mut source, &mut writer)
+            .map_err(|e| format!("Failed to write {} to archive: {}", rel_path, e))?;
+        report.file_count += 1;
+    }
+
+    writer
+        .finish()
+        .map_err(|e| format!("Failed to finalize {}: {}", zip_path.display(), e))?;
+    Ok(report)
+}
+
+/// Package an installed mod into a clean zip for sharing.
+/// Defaults to fossmodmanager/exports/<mod>.zip when no output path is given.
+#[tauri::command]
+pub async fn export_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_name: String,
+    output_path: Option<String>,
+) -> Result<ExportReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+
+    let mod_dir = if let Some(mod_entry) = registry.find_mod(&mod_name) {
+        resolve_reframework_mod_dir(&game_root, mod_entry)
+    } else {
+        registry
+            .find_skin_mod(&mod_name)
+            .map(|sm| PathBuf::from(&sm.base.path))
+            .filter(|p| p.is_dir())
+    }
+    .ok_or_else(|| format!("Could not locate files for mod '{}'", mod_name))?;
+
+    let zip_path = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => game_root
+            .join("fossmodmanager")
+            .join("exports")
+            .join(format!("{}.zip", mod_name)),
+    };
+
+    info!("Exporting '{}' from {} to {}", mod_name, mod_dir.display(), zip_path.display());
+    let report = package_mod_dir(&mod_dir, &zip_path)?;
+    info!(
+        "Exported {} files for '{}' ({} skipped by ignore rules)",
+        report.file_count,
+        mod_name,
+        report.skipped.len()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn dropped_archives_are_classified_by_layout() {
+        let kind = |names: &[&str]| classify_entry_names(names);
+        assert_eq!(kind(&["reframework/plugins/Foo/Foo.dll"]), Ok(ModType::REFrameworkPlugin));
+        assert_eq!(kind(&["Foo\\reframework\\autorun\\foo.lua"]), Ok(ModType::REFrameworkAutorun));
+        // A plugin carrying its own natives/ assets is still a plugin
+        assert_eq!(
+            kind(&["reframework/plugins/Foo.dll", "natives/STM/foo.tex"]),
+            Ok(ModType::REFrameworkPlugin)
+        );
+        assert_eq!(kind(&["Armor/natives/STM/ch03.mesh"]), Ok(ModType::SkinMod));
+        assert_eq!(kind(&["re_chunk_000.pak.patch_001.pak"]), Ok(ModType::SkinMod));
+        assert_eq!(kind(&["Foo/", "Foo/Foo.dll", "Foo/readme.txt"]), Ok(ModType::REFrameworkPlugin));
+        assert_eq!(kind(&["better_hud.lua"]), Ok(ModType::REFrameworkAutorun));
+        assert!(kind(&["dinput8.dll"]).unwrap_err().starts_with("[E_ARCHIVE_IS_REFRAMEWORK]"));
+        assert!(kind(&["README.txt"]).unwrap_err().starts_with("[E_ARCHIVE_UNRECOGNIZED]"));
+    }
+
+    #[test]
+    fn archives_in_the_mods_folder_are_sorted_by_kind() {
+        let game = FakeGame::new();
+        let dropped = [
+            game.fixture_zip("DroppedSkin.zip", &[("natives/STM/DroppedSkin/only.txt", b"skin")]),
+            game.fixture_zip("DroppedPlugin.zip", &[("reframework/plugins/DroppedPlugin.dll", b"MZ plugin")]),
+            game.fixture_zip("Readme.zip", &[("readme.txt", b"not a mod")]),
+        ];
+        for zip in &dropped {
+            fs::copy(zip, game.mods_dir().join(zip.file_name().unwrap())).unwrap();
+        }
+
+        let found = import_archives_in_mods_dir(&game.staging_root(), &game.mods_dir());
+        assert_eq!(found.skin_mod_dirs, vec![game.mods_dir().join("DroppedSkin")]);
+        assert_eq!(found.reframework_archives, vec![get_downloads_dir(&game.staging_root()).join("DroppedPlugin.zip")]);
+        // The one that isn't a mod is set aside rather than retried on every scan
+        assert!(get_downloads_dir(&game.staging_root()).join("Readme.zip").is_file());
+        let left_in_mods = Walk::new(game.mods_dir())
+            .files()
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "zip"))
+            .count();
+        assert_eq!(left_in_mods, 0);
+    }
+}
@@ -0,0 +1,310 @@
+// src-tauri/src/utils/auditlog.rs
+// Append-only ledger of every path the manager creates, modifies, renames or deletes inside
+// the game folder. Kept separate from the app log: one JSON entry per line in
+// fossmodmanager/audit_ledger.jsonl, never rewritten or trimmed.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::utils::hashing::hash_files;
+
+const LEDGER_FILE: &str = "audit_ledger.jsonl";
+const HASH_WORKERS: usize = 4;
+
+/// Serializes appends so concurrent operations can't interleave partial lines
+static LEDGER_LOCK: Mutex<()> = Mutex::new(());
+static NEXT_OPERATION: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAction {
+    Created,
+    Modified,
+    Renamed,
+    Deleted,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LedgerEntry {
+    pub timestamp: i64,
+    pub operation_id: String,
+    pub operation: String,
+    pub action: LedgerAction,
+    pub path: String, // Relative to the game root with '/' separators; directories end in '/'
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renamed_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>, // Content after the change, for created/modified files
+}
+
+pub fn get_ledger_path(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join(LEDGER_FILE)
+}
+
+struct PendingChange {
+    action: LedgerAction,
+    path: PathBuf,
+    renamed_to: Option<PathBuf>,
+    is_dir: bool,
+    sha256: Option<String>,
+}
+
+/// The game-folder changes made by one manager operation. Entries share an operation id and
+/// are appended when the operation is dropped, so one that fails halfway still records what
+/// it had already done.
+pub struct LedgerOperation {
+    game_root: PathBuf,
+    id: String,
+    name: String,
+    pending: Mutex<Vec<PendingChange>>,
+}
+
+impl LedgerOperation {
+    pub fn begin(game_root: &Path, name: &str) -> Self {
+        let id = format!(
+            "{}-{}",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S%3f"),
+            NEXT_OPERATION.fetch_add(1, Ordering::Relaxed)
+        );
+        Self {
+            game_root: game_root.to_path_buf(),
+            id,
+            name: name.to_string(),
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn push(&self, change: PendingChange) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.push(change);
+        }
+    }
+
+    /// A file or directory was written; `replaced` when something was already at that path
+    pub fn wrote(&self, path: &Path, replaced: bool) {
+        self.push(PendingChange {
+            action: if replaced {
+                LedgerAction::Modified
+            } else {
+                LedgerAction::Created
+            },
+            path: path.to_path_buf(),
+            renamed_to: None,
+            is_dir: path.is_dir(),
+            sha256: None,
+        });
+    }
+
+    /// A file was created whose hash the caller already computed
+    pub fn wrote_hashed(&self, path: &Path, sha256: String) {
+        self.push(PendingChange {
+            action: LedgerAction::Created,
+            path: path.to_path_buf(),
+            renamed_to: None,
+            is_dir: false,
+            sha256: Some(sha256),
+        });
+    }
+
+    pub fn deleted(&self, path: &Path, is_dir: bool) {
+        self.push(PendingChange {
+            action: LedgerAction::Deleted,
+            path: path.to_path_buf(),
+            renamed_to: None,
+            is_dir,
+            sha256: None,
+        });
+    }
+
+    pub fn renamed(&self, from: &Path, to: &Path) {
+        self.push(PendingChange {
+            action: LedgerAction::Renamed,
+            path: from.to_path_buf(),
+            renamed_to: Some(to.to_path_buf()),
+            is_dir: to.is_dir(),
+            sha256: None,
+        });
+    }
+
+    /// Game-relative form of a path, or None for anything outside the game folder or inside
+    /// the manager's own fossmodmanager/ bookkeeping folder
+    fn relative(&self, path: &Path, is_dir: bool) -> Option<String> {
+        let rel = path.strip_prefix(&self.game_root).ok()?;
+        if rel.as_os_str().is_empty() || rel.starts_with("fossmodmanager") {
+            return None;
+        }
+        let mut rel = rel.to_string_lossy().replace('\\', "/");
+        if is_dir {
+            rel.push('/');
+        }
+        Some(rel)
+    }
+
+    fn flush(&mut self) {
+        let pending = match self.pending.get_mut() {
+            Ok(pending) => std::mem::take(pending),
+            Err(_) => return,
+        };
+        let mut changes: Vec<(PendingChange, String)> = pending
+            .into_iter()
+            .filter_map(|c| self.relative(&c.path, c.is_dir).map(|rel| (c, rel)))
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+
+        // Hash written files now that the operation is done with them
+        let to_hash: Vec<usize> = changes
+            .iter()
+            .enumerate()
+            .filter(|(_, (c, _))| {
+                matches!(c.action, LedgerAction::Created | LedgerAction::Modified)
+                    && !c.is_dir
+                    && c.sha256.is_none()
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let paths: Vec<PathBuf> = to_hash.iter().map(|&i| changes[i].0.path.clone()).collect();
+        for (&i, hash) in to_hash.iter().zip(hash_files(&paths, HASH_WORKERS)) {
+            match hash {
+                Ok(sha256) => changes[i].0.sha256 = Some(sha256),
+                Err(e) => warn!("Audit ledger: {}", e),
+            }
+        }
+
+        let timestamp = chrono::Utc::now().timestamp();
+        let mut lines = String::new();
+        for (change, path) in changes {
+            let entry = LedgerEntry {
+                timestamp,
+                operation_id: self.id.clone(),
+                operation: self.name.clone(),
+                action: change.action,
+                renamed_to: change
+                    .renamed_to
+                    .as_deref()
+                    .and_then(|to| self.relative(to, change.is_dir)),
+                path,
+                sha256: change.sha256,
+            };
+            match serde_json::to_string(&entry) {
+                Ok(line) => {
+                    lines.push_str(&line);
+                    lines.push('\n');
+                }
+                Err(e) => warn!("Failed to serialize audit ledger entry: {}", e),
+            }
+        }
+        if let Err(e) = append_lines(&self.game_root, &lines) {
+            warn!("Failed to append to audit ledger: {}", e);
+        }
+    }
+}
+
+impl Drop for LedgerOperation {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn append_lines(game_root: &Path, lines: &str) -> Result<(), String> {
+    let ledger_path = get_ledger_path(game_root);
+    let _guard = LEDGER_LOCK.lock().map_err(|e| e.to_string())?;
+    if let Some(parent) = ledger_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&ledger_path)
+        .map_err(|e| format!("Failed to open {}: {}", ledger_path.display(), e))?;
+    file.write_all(lines.as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", ledger_path.display(), e))
+}
+
+/// Every ledger entry, oldest first. Unreadable lines are skipped rather than failing the read.
+pub fn read_ledger(game_root: &Path) -> Result<Vec<LedgerEntry>, String> {
+    let ledger_path = get_ledger_path(game_root);
+    if !ledger_path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&ledger_path)
+        .map_err(|e| format!("Failed to read {}: {}", ledger_path.display(), e))?;
+    Ok(content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| match serde_json::from_str(l) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                warn!("Skipping unreadable audit ledger line: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(entries: &[LedgerEntry]) -> String {
+    let mut out = String::from("timestamp,operation_id,operation,action,path,renamed_to,sha256\n");
+    for entry in entries {
+        let action = match entry.action {
+            LedgerAction::Created => "created",
+            LedgerAction::Modified => "modified",
+            LedgerAction::Renamed => "renamed",
+            LedgerAction::Deleted => "deleted",
+        };
+        let fields = [
+            entry.timestamp.to_string(),
+            entry.operation_id.clone(),
+            entry.operation.clone(),
+            action.to_string(),
+            entry.path.clone(),
+            entry.renamed_to.clone().unwrap_or_default(),
+            entry.sha256.clone().unwrap_or_default(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Export the ledger as CSV (when `output_path` ends in .csv) or JSON Lines.
+/// Returns the number of entries written.
+#[tauri::command]
+pub async fn export_audit_ledger(game_root_path: String, output_path: String) -> Result<usize, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let entries = read_ledger(&game_root)?;
+    let output = PathBuf::from(&output_path);
+    let is_csv = output
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    let content = if is_csv {
+        to_csv(&entries)
+    } else {
+        let mut lines = String::new();
+        for entry in &entries {
+            let line = serde_json::to_string(entry)
+                .map_err(|e| format!("Failed to serialize audit ledger entry: {}", e))?;
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+        lines
+    };
+    fs::write(&output, content)
+        .map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+    info!("Exported {} audit ledger entries to {}", entries.len(), output.display());
+    Ok(entries.len())
+}
@@ -0,0 +1,60 @@
+// src-tauri/src/utils/backendinfo.rs
+// One call telling the frontend what this backend can do, so it doesn't probe feature by feature.
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::utils::config::{load_game_config, GameData};
+use crate::utils::featureflags::{flag_states, FeatureFlagState};
+use crate::utils::httpidentity::{http_identity, user_agents, APP_VERSION};
+use crate::utils::launchnotes::proton_prefix;
+use crate::utils::settings::AppSettings;
+use crate::utils::startuptiming::{startup_timing, StartupTiming};
+
+/// Archive types install_mod_from_zip and install_mod_from_archive accept (split zips are joined first)
+const SUPPORTED_ARCHIVE_FORMATS: &[&str] = &["zip", "zip.001", "z01"];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Integrations {
+    pub nexus_api_configured: bool,
+    pub proton_detected: bool,
+    pub proton_prefix: Option<String>,
+    pub reframework_installed: bool,
+    pub user_agents: BTreeMap<String, String>, // Integration key -> User-Agent sent
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackendInfo {
+    pub app_version: String,
+    pub platform: String,
+    pub supported_archive_formats: Vec<String>,
+    pub integrations: Integrations,
+    pub feature_flags: Vec<FeatureFlagState>,
+    pub active_game: Option<GameData>,
+    pub startup_timing: StartupTiming,
+}
+
+#[tauri::command]
+pub async fn get_backend_info(app_handle: AppHandle) -> Result<BackendInfo, String> {
+    let active_game = load_game_config(app_handle.clone()).await.unwrap_or(None);
+    let game_root = active_game.as_ref().map(|g| Path::new(&g.game_root_path));
+    let prefix = game_root.and_then(proton_prefix);
+    let settings = AppSettings::load(&app_handle)?;
+
+    Ok(BackendInfo {
+        app_version: APP_VERSION.to_string(),
+        platform: std::env::consts::OS.to_string(),
+        supported_archive_formats: SUPPORTED_ARCHIVE_FORMATS.iter().map(|f| f.to_string()).collect(),
+        integrations: Integrations {
+            nexus_api_configured: crate::nexus_api::nexus_api_key_configured(&app_handle)?,
+            proton_detected: prefix.is_some(),
+            proton_prefix: prefix.map(|p| p.to_string_lossy().to_string()),
+            reframework_installed: game_root.is_some_and(|root| root.join("dinput8.dll").is_file()),
+            user_agents: user_agents(&http_identity(&app_handle)),
+        },
+        feature_flags: flag_states(&settings),
+        active_game,
+        startup_timing: startup_timing(),
+    })
+}
@@ -0,0 +1,214 @@
+// src-tauri/src/utils/backups.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::scheduler::ScheduledJob;
+use crate::utils::settings::{AppSettings, BackupSchedule};
+
+/// Files in the app config dir that make up the manager's state
+const BACKED_UP_FILES: &[&str] = &["userconfig.json", "mod_registry.json", "settings.json"];
+
+/// A snapshot archive in the backups folder
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigBackup {
+    pub id: String, // Archive file stem, e.g. "backup-20250101-120000"
+    pub created_timestamp: i64,
+    pub size_bytes: u64,
+    pub files: Vec<String>,
+}
+
+fn get_config_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get app config dir: {}", e))
+}
+
+/// Rotating backups live in app data, away from the config they protect
+pub fn get_backups_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?
+        .join("backups");
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create backups directory {}: {}", dir.display(), e))?;
+    Ok(dir)
+}
+
+fn read_backup_info(archive_path: &Path) -> Option<ConfigBackup> {
+    let id = archive_path.file_stem()?.to_string_lossy().to_string();
+    let meta = fs::metadata(archive_path).ok()?;
+    let archive = ZipArchive::new(fs::File::open(archive_path).ok()?).ok()?;
+    let created_timestamp = chrono::NaiveDateTime::parse_from_str(
+        id.trim_start_matches("backup-"),
+        "%Y%m%d-%H%M%S",
+    )
+    .map(|t| t.and_utc().timestamp())
+    .unwrap_or(0);
+    Some(ConfigBackup {
+        files: archive.file_names().map(str::to_string).collect(),
+        id,
+        created_timestamp,
+        size_bytes: meta.len(),
+    })
+}
+
+/// All backups, newest first
+pub fn list_backups(app_handle: &AppHandle) -> Result<Vec<ConfigBackup>, String> {
+    list_backups_in(&get_backups_dir(app_handle)?)
+}
+
+/// All backups in `dir`, newest first
+pub fn list_backups_in(dir: &Path) -> Result<Vec<ConfigBackup>, String> {
+    let mut backups: Vec<ConfigBackup> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "zip"))
+        .filter_map(|p| read_backup_info(&p))
+        .collect();
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_timestamp));
+    Ok(backups)
+}
+
+/// Zip userconfig.json, the mod registry and settings into a new backup, then drop the
+/// oldest backups beyond the retention count
+pub fn create_backup(app_handle: &AppHandle) -> Result<ConfigBackup, String> {
+    let config_dir = get_config_dir(app_handle)?;
+    let backups_dir = get_backups_dir(app_handle)?;
+    let id = format!("backup-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S"));
+    let archive_path = backups_dir.join(format!("{}.zip", id));
+
+    let write = || -> Result<usize, String> {
+        let file = fs::File::create(&archive_path)
+            .map_err(|e| format!("Failed to create {}: {}", archive_path.display(), e))?;
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let mut count = 0;
+        for name in BACKED_UP_FILES {
+            let Ok(content) = fs::read(config_dir.join(name)) else {
+                continue;
+            };
+            zip.start_file(*name, options)
+                .map_err(|e| format!("Failed to add {} to backup: {}", name, e))?;
+            zip.write_all(&content)
+                .map_err(|e| format!("Failed to write {} to backup: {}", name, e))?;
+            count += 1;
+        }
+        zip.finish()
+            .map_err(|e| format!("Failed to finish backup archive: {}", e))?;
+        Ok(count)
+    };
+    match write() {
+        Ok(0) => {
+            let _ = fs::remove_file(&archive_path);
+            return Err("Nothing to back up yet".to_string());
+        }
+        Ok(count) => info!("Backed up {} config file(s) to {}", count, archive_path.display()),
+        Err(e) => {
+            let _ = fs::remove_file(&archive_path);
+            return Err(e);
+        }
+    }
+
+    let retention = AppSettings::load(app_handle)?.backup_retention.max(1) as usize;
+    for old in list_backups(app_handle)?.into_iter().skip(retention) {
+        let path = backups_dir.join(format!("{}.zip", old.id));
+        match fs::remove_file(&path) {
+            Ok(_) => info!("Rotated out old backup {}", old.id),
+            Err(e) => warn!("Failed to remove old backup {}: {}", path.display(), e),
+        }
+    }
+
+    read_backup_info(&archive_path).ok_or_else(|| format!("Failed to read back {}", archive_path.display()))
+}
+
+/// The scheduler job for automatic backups, following the configured schedule
+pub fn scheduled_backup_job() -> ScheduledJob {
+    ScheduledJob {
+        name: "config_backup",
+        interval: |settings: &AppSettings| match settings.backup_schedule {
+            BackupSchedule::Off => None,
+            BackupSchedule::Daily => Some(Duration::from_secs(24 * 60 * 60)),
+            BackupSchedule::Weekly => Some(Duration::from_secs(7 * 24 * 60 * 60)),
+        },
+        run: |app_handle: &AppHandle| match create_backup(app_handle) {
+            Ok(_) => Ok(()),
+            // A fresh install with no config yet isn't a failure worth retrying every tick
+            Err(e) if e == "Nothing to back up yet" => Ok(()),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+#[tauri::command]
+pub async fn create_config_backup(app_handle: AppHandle) -> Result<ConfigBackup, String> {
+    create_backup(&app_handle)
+}
+
+#[tauri::command]
+pub async fn list_config_backups(app_handle: AppHandle) -> Result<Vec<ConfigBackup>, String> {
+    list_backups(&app_handle)
+}
+
+/// Restore config, registry and settings from a backup. The current state is backed up first
+/// so a restore can itself be undone.
+#[tauri::command]
+pub async fn restore_config_backup(
+    app_handle: AppHandle,
+    backup_id: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<Vec<String>>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "restore_config_backup",
+        std::slice::from_ref(&backup_id),
+        &format!("Replace the current config, registry and settings with backup '{}'", backup_id),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    if backup_id.contains(['/', '\\']) || backup_id.contains("..") {
+        return Err(format!("Invalid backup id: {}", backup_id));
+    }
+    let archive_path = get_backups_dir(&app_handle)?.join(format!("{}.zip", backup_id));
+    let mut archive = fs::File::open(&archive_path)
+        .map_err(|e| format!("Backup '{}' not found: {}", backup_id, e))
+        .and_then(|f| ZipArchive::new(f).map_err(|e| format!("Backup '{}' is unreadable: {}", backup_id, e)))?;
+
+    crate::utils::instancelock::ensure_write_access(&app_handle)?;
+    if let Err(e) = create_backup(&app_handle) {
+        warn!("Couldn't back up current state before restoring: {}", e);
+    }
+
+    let config_dir = get_config_dir(&app_handle)?;
+    fs::create_dir_all(&config_dir)
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    let mut restored = Vec::new();
+    for name in BACKED_UP_FILES {
+        let Ok(mut entry) = archive.by_name(name) else {
+            continue;
+        };
+        let target = config_dir.join(name);
+        let tmp_path = target.with_extension("json.tmp");
+        let mut out = fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create {}: {}", tmp_path.display(), e))?;
+        io::copy(&mut entry, &mut out)
+            .map_err(|e| format!("Failed to restore {}: {}", name, e))?;
+        drop(out);
+        fs::rename(&tmp_path, &target)
+            .map_err(|e| format!("Failed to replace {}: {}", target.display(), e))?;
+        restored.push(name.to_string());
+    }
+    info!("Restored {:?} from backup {}", restored, backup_id);
+    Ok(Confirmable::Done(restored))
+}
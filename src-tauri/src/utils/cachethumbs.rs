@@ -4,7 +4,9 @@ use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
 // Image cache entry metadata
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -40,6 +42,204 @@ pub fn get_image_cache_key(image_path: &str) -> String {
     format!("{:x}", hasher.finish())
 }
 
+/// Longest edge, in pixels, of thumbnails generated from mod folders
+const THUMBNAIL_MAX_DIMENSION: u32 = 320;
+
+/// Downscale an image found inside a mod folder and store it in the image cache.
+/// Returns the path of the cached PNG so it can be served through the asset protocol.
+pub fn cache_downscaled_image(app_handle: &AppHandle, image_path: &str) -> Result<PathBuf, String> {
+    let cache_key = get_image_cache_key(&format!("thumb:{}", image_path));
+    let cache_dir = get_image_cache_dir(app_handle)?;
+    let thumb_path = cache_dir.join(format!("{}.thumb.png", cache_key));
+
+    // Reuse an existing thumbnail unless the source image changed after it was made
+    if thumb_path.exists() {
+        let source_modified = fs::metadata(image_path).and_then(|m| m.modified()).ok();
+        let thumb_modified = fs::metadata(&thumb_path).and_then(|m| m.modified()).ok();
+        if let (Some(source), Some(thumb)) = (source_modified, thumb_modified) {
+            if thumb >= source {
+                debug!("Reusing cached thumbnail for {}", image_path);
+                return Ok(thumb_path);
+            }
+        }
+    }
+
+    let img = image::open(image_path)
+        .map_err(|e| format!("Failed to decode image {}: {}", image_path, e))?;
+    let thumbnail = img.thumbnail(THUMBNAIL_MAX_DIMENSION, THUMBNAIL_MAX_DIMENSION);
+    thumbnail
+        .save_with_format(&thumb_path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to write thumbnail {:?}: {}", thumb_path, e))?;
+
+    let cache_info = CacheEntry {
+        original_path: image_path.to_string(),
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    let cache_info_json = serde_json::to_string(&cache_info)
+        .map_err(|e| format!("Failed to serialize cache info: {}", e))?;
+    fs::write(cache_dir.join(format!("{}.json", cache_key)), cache_info_json)
+        .map_err(|e| format!("Failed to write cache info: {}", e))?;
+
+    info!("Cached thumbnail for {} at {:?}", image_path, thumb_path);
+    Ok(thumb_path)
+}
+
+/// Downscale a user-supplied preview for a mod into the image cache. The copy doesn't depend
+/// on the source staying around: its metadata points at itself, so the orphan sweep keeps it
+/// until the mod is removed. Importing again for the same mod replaces it.
+pub fn cache_custom_thumbnail(app_handle: &AppHandle, image_path: &Path, mod_key: &str) -> Result<PathBuf, String> {
+    let cache_key = get_image_cache_key(&format!("custom:{}", mod_key));
+    let cache_dir = get_image_cache_dir(app_handle)?;
+    let thumb_path = cache_dir.join(format!("{}.thumb.png", cache_key));
+
+    let img = image::open(image_path)
+        .map_err(|e| format!("Failed to decode image {}: {}", image_path.display(), e))?;
+    img.thumbnail(THUMBNAIL_MAX_DIMENSION, THUMBNAIL_MAX_DIMENSION)
+        .save_with_format(&thumb_path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to write thumbnail {:?}: {}", thumb_path, e))?;
+
+    let cache_info = CacheEntry {
+        original_path: thumb_path.to_string_lossy().to_string(),
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    let cache_info_json = serde_json::to_string(&cache_info)
+        .map_err(|e| format!("Failed to serialize cache info: {}", e))?;
+    fs::write(cache_dir.join(format!("{}.json", cache_key)), cache_info_json)
+        .map_err(|e| format!("Failed to write cache info: {}", e))?;
+    Ok(thumb_path)
+}
+
+/// How often the cache is swept for entries whose source image is gone
+const CACHE_SWEEP_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Queue of images of removed mods, waiting for the cleanup worker. Managed once the worker
+/// starts; the default queue has no worker and drops what it is given.
+#[derive(Default)]
+pub struct ThumbnailCleanup(Option<mpsc::Sender<String>>);
+
+/// Files on disk belonging to one cache key (metadata, base64 cache, thumbnail)
+pub fn cache_key_files(cache_dir: &Path, cache_key: &str) -> Vec<PathBuf> {
+    [
+        format!("{}.json", cache_key),
+        format!("{}.cache", cache_key),
+        format!("{}.thumb.png", cache_key),
+    ]
+    .iter()
+    .map(|name| cache_dir.join(name))
+    .filter(|path| path.exists())
+    .collect()
+}
+
+/// Remove every file belonging to one cache key
+fn remove_cache_key(cache_dir: &Path, cache_key: &str) -> usize {
+    cache_key_files(cache_dir, cache_key)
+        .iter()
+        .filter(|path| match fs::remove_file(path) {
+            Ok(_) => true,
+            Err(e) => {
+                warn!("Failed to remove cached image {:?}: {}", path, e);
+                false
+            }
+        })
+        .count()
+}
+
+/// Drop the cache entries for an image, given either its cached thumbnail or its source path
+fn remove_cached_image(cache_dir: &Path, image_path: &str) -> usize {
+    let path = Path::new(image_path);
+    if path.starts_with(cache_dir) {
+        let Some(cache_key) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.split('.').next())
+        else {
+            return 0;
+        };
+        return remove_cache_key(cache_dir, cache_key);
+    }
+    remove_cache_key(cache_dir, &get_image_cache_key(image_path))
+        + remove_cache_key(cache_dir, &get_image_cache_key(&format!("thumb:{}", image_path)))
+}
+
+/// Cache keys whose original image no longer exists
+pub fn find_orphaned_cache_keys(cache_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return Vec::new();
+    };
+    let mut orphaned = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(cache_key) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let is_orphaned = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheEntry>(&json).ok())
+            .map(|info| !Path::new(&info.original_path).exists())
+            .unwrap_or(true); // Unreadable metadata can't be matched to anything either
+        if is_orphaned {
+            orphaned.push(cache_key.to_string());
+        }
+    }
+    orphaned
+}
+
+/// Remove cache entries whose original image no longer exists. Returns files removed.
+pub fn sweep_orphaned_cache_entries(cache_dir: &Path) -> usize {
+    find_orphaned_cache_keys(cache_dir)
+        .iter()
+        .map(|cache_key| remove_cache_key(cache_dir, cache_key))
+        .sum()
+}
+
+impl ThumbnailCleanup {
+    /// Queue the cached images of a removed mod for deletion in the background
+    pub fn queue(&self, image_paths: Vec<String>) {
+        let Some(sender) = &self.0 else {
+            debug!("Thumbnail cleanup worker not running; skipping {} image(s)", image_paths.len());
+            return;
+        };
+        for path in image_paths {
+            let _ = sender.send(path);
+        }
+    }
+}
+
+/// Start the background worker that deletes queued thumbnails and periodically sweeps
+/// cache entries whose source image disappeared
+pub fn start_cache_cleanup_worker(app_handle: AppHandle) {
+    if app_handle.try_state::<ThumbnailCleanup>().is_some() {
+        warn!("Thumbnail cleanup worker already started");
+        return;
+    }
+    let (sender, receiver) = mpsc::channel::<String>();
+    app_handle.manage(ThumbnailCleanup(Some(sender)));
+    std::thread::spawn(move || {
+        let Ok(cache_dir) = get_image_cache_dir(&app_handle) else {
+            warn!("Image cache directory unavailable; thumbnail cleanup disabled");
+            return;
+        };
+        let removed = sweep_orphaned_cache_entries(&cache_dir);
+        info!("Startup image cache sweep removed {} file(s)", removed);
+        loop {
+            match receiver.recv_timeout(CACHE_SWEEP_INTERVAL) {
+                Ok(image_path) => {
+                    let removed = remove_cached_image(&cache_dir, &image_path);
+                    debug!("Removed {} cached file(s) for {}", removed, image_path);
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let removed = sweep_orphaned_cache_entries(&cache_dir);
+                    info!("Periodic image cache sweep removed {} file(s)", removed);
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    });
+}
+
 /// Function to read mod image files and return as base64
 #[tauri::command]
 pub fn read_mod_image(image_path: String) -> Result<String, String> {
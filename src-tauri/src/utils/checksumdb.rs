@@ -0,0 +1,274 @@
+// src-tauri/src/utils/checksumdb.rs
+// Community-maintained list of file hashes and the mods they come from, published with the
+// project. Patch paks and natives files no registered mod owns (hand-copied installs, paks
+// left behind by other tools) can be named from it without Nexus access. Syncing is opt-in;
+// lookups use whatever copy was cached last.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::adoption::find_unmanaged_natives;
+use crate::utils::hashing::hash_files;
+use crate::utils::httpidentity::{http_client, http_identity, Integration};
+use crate::utils::modregistry::{ModRegistry, ModType};
+use crate::utils::pakregistry::list_patch_paks;
+use crate::utils::scheduler::ScheduledJob;
+use crate::utils::settings::AppSettings;
+
+const CHECKSUM_DB_URL: &str =
+    "https://raw.githubusercontent.com/slbillups/fossmodmanager-MHW/main/checksums.json";
+const CHECKSUM_DB_CACHE_FILE: &str = "checksum_db.json";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// One known file
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChecksumDbEntry {
+    pub sha256: String,
+    pub mod_name: String,
+    #[serde(default)]
+    pub nexus_mod_id: Option<i64>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub file_name: Option<String>, // Name the file is shipped under, e.g. a pak before renumbering
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ChecksumDb {
+    #[serde(default)]
+    pub updated_timestamp: i64,
+    #[serde(default)]
+    pub entries: Vec<ChecksumDbEntry>,
+}
+
+/// An unowned file the database recognized
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IdentifiedFile {
+    pub path: String,
+    pub kind: ModType, // SkinMod for patch paks, NativesMod for loose natives files
+    pub entry: ChecksumDbEntry,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IdentificationReport {
+    pub checked: usize, // Unowned files hashed
+    pub identified: Vec<IdentifiedFile>,
+    pub db_entries: usize, // Size of the cached database; 0 if it was never synced
+    pub db_updated_timestamp: i64,
+}
+
+fn get_cache_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get app config dir: {}", e))?;
+    Ok(config_dir.join(CHECKSUM_DB_CACHE_FILE))
+}
+
+/// The cached database; empty if it has never been fetched
+pub fn load_checksum_db(app_handle: &AppHandle) -> ChecksumDb {
+    let Ok(path) = get_cache_path(app_handle) else {
+        return ChecksumDb::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            warn!(
+                "Ignoring unreadable checksum database {}: {}",
+                path.display(),
+                e
+            );
+            ChecksumDb::default()
+        }),
+        Err(_) => ChecksumDb::default(),
+    }
+}
+
+/// Download the latest database and replace the cached copy
+async fn fetch_checksum_db(app_handle: &AppHandle) -> Result<ChecksumDb, String> {
+    let response = http_client(&http_identity(app_handle), Integration::ChecksumDb)?
+        .get(CHECKSUM_DB_URL)
+        .timeout(FETCH_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch checksum database: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("Failed to fetch checksum database: {}", e))?;
+    let db: ChecksumDb = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse checksum database: {}", e))?;
+
+    let path = get_cache_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(&db)
+        .map_err(|e| format!("Failed to serialize checksum database: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write checksum database: {}", e))?;
+    info!("Updated checksum database ({} entries)", db.entries.len());
+    Ok(db)
+}
+
+/// Hash every patch pak and natives file no registered skin mod owns and look each up in `db`
+pub fn identify_unowned_files(
+    registry: &ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+    db: &ChecksumDb,
+) -> IdentificationReport {
+    let by_hash: HashMap<String, &ChecksumDbEntry> = db
+        .entries
+        .iter()
+        .map(|e| (e.sha256.to_lowercase(), e))
+        .collect();
+
+    let managed: HashSet<&str> = registry
+        .skin_mods
+        .iter()
+        .filter_map(|m| m.installed_pak_path.as_deref())
+        .collect();
+    let pak_dir = settings
+        .install_targets
+        .resolve(game_root, &ModType::SkinMod);
+    let mut candidates: Vec<(PathBuf, ModType)> = list_patch_paks(&pak_dir)
+        .unwrap_or_else(|e| {
+            warn!("{}; patch paks are left out of identification", e);
+            Vec::new()
+        })
+        .into_iter()
+        .filter(|p| !managed.contains(p.path.as_str()))
+        .map(|p| (PathBuf::from(p.path), ModType::SkinMod))
+        .collect();
+    let natives_dir = settings
+        .install_targets
+        .resolve(game_root, &ModType::NativesMod);
+    candidates.extend(
+        find_unmanaged_natives(registry, game_root, settings)
+            .into_iter()
+            .flat_map(|g| g.files)
+            .map(|rel| (natives_dir.join(rel), ModType::NativesMod)),
+    );
+
+    let paths: Vec<PathBuf> = candidates.iter().map(|(path, _)| path.clone()).collect();
+    let mut report = IdentificationReport {
+        checked: candidates.len(),
+        db_entries: db.entries.len(),
+        db_updated_timestamp: db.updated_timestamp,
+        ..Default::default()
+    };
+    for ((path, kind), hash) in candidates
+        .into_iter()
+        .zip(hash_files(&paths, settings.worker_count(game_root)))
+    {
+        let hash = match hash {
+            Ok(hash) => hash,
+            Err(e) => {
+                warn!("Skipping unreadable file during identification: {}", e);
+                continue;
+            }
+        };
+        if let Some(entry) = by_hash.get(&hash) {
+            report.identified.push(IdentifiedFile {
+                path: path.to_string_lossy().to_string(),
+                kind,
+                entry: (*entry).clone(),
+            });
+        }
+    }
+    report
+}
+
+/// The scheduler job keeping the cached database fresh, when syncing is switched on
+pub fn scheduled_checksum_db_job() -> ScheduledJob {
+    ScheduledJob {
+        name: "checksum_db_update",
+        interval: |settings: &AppSettings| {
+            settings
+                .checksum_db_sync
+                .then_some(Duration::from_secs(7 * 24 * 60 * 60))
+        },
+        run: |app_handle: &AppHandle| {
+            tauri::async_runtime::block_on(fetch_checksum_db(app_handle)).map(|_| ())
+        },
+    }
+}
+
+#[tauri::command]
+pub async fn update_checksum_db(app_handle: AppHandle) -> Result<ChecksumDb, String> {
+    fetch_checksum_db(&app_handle).await
+}
+
+/// Name unowned patch paks and natives files from the cached checksum database
+#[tauri::command]
+pub async fn identify_unknown_mods(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<IdentificationReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
+    let registry = ModRegistry::load(&app_handle)?;
+    let settings = AppSettings::load(&app_handle)?;
+    let report = identify_unowned_files(
+        &registry,
+        &game_root,
+        &settings,
+        &load_checksum_db(&app_handle),
+    );
+    info!(
+        "Identified {} of {} unowned file(s) by checksum",
+        report.identified.len(),
+        report.checked
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{no_progress, FakeGame};
+    use crate::utils::hashing::hash_reader;
+    use crate::utils::modregistry::enable_skin_mod_files;
+    use crate::utils::pakregistry::patch_pak_file_name;
+
+    #[test]
+    fn unowned_files_are_named_from_the_checksum_database() {
+        let game = FakeGame::new();
+        let settings = AppSettings::default();
+        let mut registry = ModRegistry::default();
+        let path = game.install_skin(&mut registry, "ManagedSkin", b"managed");
+        enable_skin_mod_files(&mut registry, game.root(), &path, &settings, &HashMap::new(), &no_progress)
+            .expect("enable skin");
+        // A pak and a natives file copied in by hand
+        let pak_dir = settings.install_targets.resolve(game.root(), &ModType::SkinMod);
+        let pak = pak_dir.join(patch_pak_file_name(40, true));
+        fs::write(&pak, b"mystery pak").unwrap();
+        let stray = game.deployed("STM/Mystery/stray.tex");
+        fs::create_dir_all(stray.parent().unwrap()).unwrap();
+        fs::write(&stray, b"unknown texture").unwrap();
+
+        let entry = |bytes: &[u8], name: &str| ChecksumDbEntry {
+            sha256: hash_reader(bytes).unwrap().to_uppercase(),
+            mod_name: name.to_string(),
+            nexus_mod_id: Some(7),
+            version: None,
+            file_name: None,
+        };
+        let db = ChecksumDb {
+            updated_timestamp: 1,
+            // The managed mod's texture is listed too, but its files are never candidates
+            entries: vec![entry(b"mystery pak", "Mystery Outfit"), entry(b"managed", "Managed Skin")],
+        };
+        let report = identify_unowned_files(&registry, game.root(), &settings, &db);
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.identified.len(), 1);
+        assert_eq!(report.identified[0].path, pak.to_string_lossy());
+        assert_eq!(report.identified[0].entry.mod_name, "Mystery Outfit");
+    }
+}
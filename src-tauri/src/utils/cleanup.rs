@@ -0,0 +1,366 @@
+// src-tauri/src/utils/cleanup.rs
+// One place to see what the manager's own storage could give back: thumbnails of images that
+// are gone, archives of mods that were removed, the trash, leftovers of interrupted operations
+// and old backups. Each category can be cleared with a single call; the report and the
+// cleanup find their candidates the same way, so what gets removed is what was shown.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::archives::get_archives_dir;
+use crate::utils::backups::{get_backups_dir, list_backups_in};
+use crate::utils::cachethumbs::{cache_key_files, find_orphaned_cache_keys, get_image_cache_dir};
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::contentindex::{archive_skin_mods, refresh_content_index};
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::modregistry::ModRegistry;
+use crate::utils::restorepoints::{get_restore_points_dir, read_restore_points};
+use crate::utils::savebackups::{get_save_backups_dir, list_save_backups_in};
+use crate::utils::staging::get_staging_root;
+use crate::utils::tempdirs::find_stale_temp_entries;
+use crate::utils::trash::{get_trash_dir, read_trash_entries};
+use crate::utils::walk::Walk;
+
+/// Backups younger than this are never suggested for cleanup
+const OLD_BACKUP_AGE_DAYS: i64 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupCategory {
+    ImageCache,       // Cached thumbnails whose source image is gone
+    RetainedArchives, // Retained archives no registered mod came from
+    Trash,            // Every deleted mod still kept in the trash
+    TempFiles,        // Temp folders, partial downloads and interrupted extractions
+    OldBackups,       // Config backups, save backups and restore points past the age limit
+}
+
+impl CleanupCategory {
+    pub const ALL: [CleanupCategory; 5] = [
+        CleanupCategory::ImageCache,
+        CleanupCategory::RetainedArchives,
+        CleanupCategory::Trash,
+        CleanupCategory::TempFiles,
+        CleanupCategory::OldBackups,
+    ];
+
+    fn description(self) -> &'static str {
+        match self {
+            CleanupCategory::ImageCache => "Cached thumbnails of images that no longer exist",
+            CleanupCategory::RetainedArchives => {
+                "Archives kept for mods that are no longer installed"
+            }
+            CleanupCategory::Trash => {
+                "Deleted mods waiting in the trash; they can't be restored afterwards"
+            }
+            CleanupCategory::TempFiles => "Leftovers of interrupted installs and downloads",
+            CleanupCategory::OldBackups => {
+                "Backups and restore points older than 30 days, keeping the newest of each"
+            }
+        }
+    }
+}
+
+/// Where each category looks; resolved once per call so the report and the cleanup agree
+pub(crate) struct CleanupLocations {
+    pub staging_root: PathBuf,
+    pub restore_points_dir: PathBuf,
+    pub image_cache_dir: PathBuf,
+    pub config_backups_dir: PathBuf,
+    pub save_backups_dir: PathBuf,
+}
+
+impl CleanupLocations {
+    fn resolve(app_handle: &AppHandle, game_root: &Path) -> Result<Self, String> {
+        Ok(CleanupLocations {
+            staging_root: get_staging_root(app_handle, game_root),
+            restore_points_dir: get_restore_points_dir(app_handle, game_root),
+            image_cache_dir: get_image_cache_dir(app_handle)?,
+            config_backups_dir: get_backups_dir(app_handle)?,
+            save_backups_dir: get_save_backups_dir(app_handle)?,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CleanupSuggestion {
+    pub category: CleanupCategory,
+    pub description: String,
+    pub item_count: usize,
+    pub reclaimable_bytes: u64,
+    pub paths: Vec<String>, // What `run_cleanup` for this category would remove
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CleanupReport {
+    pub suggestions: Vec<CleanupSuggestion>,
+    pub reclaimable_bytes: u64,      // Sum over all categories
+    pub image_cache_bytes: u64,      // Whole image cache, including thumbnails still in use
+    pub retained_archive_bytes: u64, // Every retained archive, including ones of installed mods
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CleanupResult {
+    pub category: CleanupCategory,
+    pub removed: usize,
+    pub freed_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+/// Bytes taken by a file or everything under a folder
+pub fn path_size(path: &Path) -> u64 {
+    Walk::new(path)
+        .include_hidden()
+        .no_follow()
+        .files()
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Everything but the newest item, limited to items created before `cutoff`. `items` must be
+/// sorted newest first.
+fn older_than_newest(items: Vec<(i64, PathBuf)>, cutoff: i64) -> Vec<PathBuf> {
+    items
+        .into_iter()
+        .skip(1)
+        .filter(|(created, _)| *created < cutoff)
+        .map(|(_, path)| path)
+        .collect()
+}
+
+/// Retained archives that none of the registry's skin mods was extracted from
+pub(crate) fn find_unowned_archives(
+    registry: &ModRegistry,
+    staging_root: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let archives_dir = get_archives_dir(staging_root);
+    Ok(refresh_content_index(staging_root)?
+        .archives
+        .iter()
+        .filter(|entry| archive_skin_mods(registry, entry).is_empty())
+        .map(|entry| archives_dir.join(&entry.archive))
+        .collect())
+}
+
+/// What cleaning up `category` would remove
+pub(crate) fn find_cleanup_candidates(
+    registry: &ModRegistry,
+    locations: &CleanupLocations,
+    category: CleanupCategory,
+) -> Result<Vec<PathBuf>, String> {
+    match category {
+        CleanupCategory::ImageCache => Ok(find_orphaned_cache_keys(&locations.image_cache_dir)
+            .iter()
+            .flat_map(|key| cache_key_files(&locations.image_cache_dir, key))
+            .collect()),
+        CleanupCategory::RetainedArchives => find_unowned_archives(registry, &locations.staging_root),
+        CleanupCategory::Trash => Ok(read_trash_entries(&get_trash_dir(&locations.staging_root))
+            .iter()
+            .map(|entry| get_trash_dir(&locations.staging_root).join(&entry.id))
+            .collect()),
+        CleanupCategory::TempFiles => Ok(find_stale_temp_entries(&locations.staging_root)),
+        CleanupCategory::OldBackups => {
+            let cutoff = chrono::Utc::now().timestamp() - OLD_BACKUP_AGE_DAYS * 24 * 60 * 60;
+            let config_backups = list_backups_in(&locations.config_backups_dir)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|b| {
+                    (
+                        b.created_timestamp,
+                        locations.config_backups_dir.join(format!("{}.zip", b.id)),
+                    )
+                })
+                .collect();
+            let save_backups = list_save_backups_in(&locations.save_backups_dir)
+                .into_iter()
+                .map(|b| {
+                    (
+                        b.created_timestamp,
+                        locations.save_backups_dir.join(format!("{}.zip", b.id)),
+                    )
+                })
+                .collect();
+            let restore_points = read_restore_points(&locations.restore_points_dir)
+                .into_iter()
+                .map(|p| {
+                    (
+                        p.created_timestamp,
+                        locations.restore_points_dir.join(&p.id),
+                    )
+                })
+                .collect();
+            let mut paths = older_than_newest(config_backups, cutoff);
+            paths.extend(older_than_newest(save_backups, cutoff));
+            paths.extend(older_than_newest(restore_points, cutoff));
+            Ok(paths)
+        }
+    }
+}
+
+pub(crate) fn build_cleanup_report(
+    registry: &ModRegistry,
+    locations: &CleanupLocations,
+) -> Result<CleanupReport, String> {
+    let mut suggestions = Vec::new();
+    for category in CleanupCategory::ALL {
+        let paths = find_cleanup_candidates(registry, locations, category)?;
+        suggestions.push(CleanupSuggestion {
+            category,
+            description: category.description().to_string(),
+            item_count: paths.len(),
+            reclaimable_bytes: paths.iter().map(|p| path_size(p)).sum(),
+            paths: paths
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect(),
+        });
+    }
+    Ok(CleanupReport {
+        reclaimable_bytes: suggestions.iter().map(|s| s.reclaimable_bytes).sum(),
+        suggestions,
+        image_cache_bytes: path_size(&locations.image_cache_dir),
+        retained_archive_bytes: path_size(&get_archives_dir(&locations.staging_root)),
+    })
+}
+
+/// Remove everything `find_cleanup_candidates` turns up for `category`
+pub(crate) fn clean_up_category(
+    registry: &ModRegistry,
+    locations: &CleanupLocations,
+    category: CleanupCategory,
+) -> Result<CleanupResult, String> {
+    let mut result = CleanupResult {
+        category,
+        removed: 0,
+        freed_bytes: 0,
+        errors: Vec::new(),
+    };
+    for path in find_cleanup_candidates(registry, locations, category)? {
+        let size = path_size(&path);
+        let removal = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        match removal {
+            Ok(()) => {
+                result.removed += 1;
+                result.freed_bytes += size;
+            }
+            Err(e) => {
+                warn!("Failed to clean up {}: {}", path.display(), e);
+                result
+                    .errors
+                    .push(format!("Failed to remove {}: {}", path.display(), e));
+            }
+        }
+    }
+    if category == CleanupCategory::RetainedArchives && result.removed > 0 {
+        refresh_content_index(&locations.staging_root)?;
+    }
+    info!(
+        "Cleaned up {:?}: {} item(s) removed, {} bytes freed",
+        category, result.removed, result.freed_bytes
+    );
+    Ok(result)
+}
+
+/// Everything the manager could free up, per category, with the paths each cleanup would remove
+#[tauri::command]
+pub async fn suggest_cleanup(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<CleanupReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let locations = CleanupLocations::resolve(&app_handle, &game_root)?;
+    let report = build_cleanup_report(&registry, &locations)?;
+    info!(
+        "Cleanup suggestions: {} bytes reclaimable across {} categor(ies) with candidates",
+        report.reclaimable_bytes,
+        report
+            .suggestions
+            .iter()
+            .filter(|s| s.item_count > 0)
+            .count()
+    );
+    Ok(report)
+}
+
+/// Clear one category from `suggest_cleanup`
+#[tauri::command]
+pub async fn run_cleanup(
+    app_handle: AppHandle,
+    game_root_path: String,
+    category: CleanupCategory,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<CleanupResult>, String> {
+    let category_name = format!("{:?}", category);
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "run_cleanup",
+        std::slice::from_ref(&category_name),
+        &format!("Clean up: {}", category.description()),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    // Stale temp entries are only safe to remove while no other instance is working
+    ensure_write_access(&app_handle)?;
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let locations = CleanupLocations::resolve(&app_handle, &game_root)?;
+    let result = clean_up_category(&registry, &locations, category);
+    crate::utils::telemetry::track_result(&app_handle, "run_cleanup", result).map(Confirmable::Done)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use crate::utils::archives::retain_archive_copy;
+    use crate::utils::restorepoints::default_restore_points_dir;
+    use crate::utils::tempdirs::get_temp_root;
+
+    #[test]
+    fn cleanup_report_lists_archives_of_removed_mods_and_clears_them() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        let kept = game.install_skin(&mut registry, "KeptSkin", b"kept");
+        let gone = game.install_skin(&mut registry, "GoneSkin", b"gone");
+        for (name, dir) in [("KeptSkin.zip", &kept), ("GoneSkin.zip", &gone)] {
+            let zip = game.root().join("fixtures").join(name);
+            retain_archive_copy(&game.staging_root(), &zip, &[Path::new(dir).to_path_buf()]).expect("retain archive");
+        }
+        registry.skin_mods.retain(|m| m.base.path != gone);
+        fs::create_dir_all(get_temp_root(&game.staging_root()).join("extract-1-0")).unwrap();
+
+        let app_data = game.root().join("app_data");
+        let locations = CleanupLocations {
+            staging_root: game.staging_root(),
+            restore_points_dir: default_restore_points_dir(game.root()),
+            image_cache_dir: app_data.join("images"),
+            config_backups_dir: app_data.join("backups"),
+            save_backups_dir: app_data.join("save_backups"),
+        };
+        let report = build_cleanup_report(&registry, &locations).expect("build report");
+        let archives = report
+            .suggestions
+            .iter()
+            .find(|s| s.category == CleanupCategory::RetainedArchives)
+            .unwrap();
+        assert_eq!(archives.item_count, 1);
+        assert!(archives.paths[0].ends_with("GoneSkin.zip"), "{:?}", archives.paths);
+        assert!(archives.reclaimable_bytes > 0);
+        let temp = report.suggestions.iter().find(|s| s.category == CleanupCategory::TempFiles).unwrap();
+        assert_eq!(temp.item_count, 1);
+        assert!(report.reclaimable_bytes >= archives.reclaimable_bytes);
+
+        let result = clean_up_category(&registry, &locations, CleanupCategory::RetainedArchives).expect("clean up");
+        assert_eq!((result.removed, result.freed_bytes), (1, archives.reclaimable_bytes));
+        assert!(get_archives_dir(&game.staging_root()).join("KeptSkin.zip").is_file());
+        assert!(!get_archives_dir(&game.staging_root()).join("GoneSkin.zip").exists());
+    }
+}
@@ -0,0 +1,165 @@
+// src-tauri/src/utils/cloudsync.rs
+// Cloud sync clients (OneDrive, Dropbox, ...) swap files for placeholders and lock them while
+// syncing, which shows up as odd IO errors halfway through a deployment.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::walk::Walk;
+
+/// Prefix for errors caused by sync interference, so the frontend can show specific guidance
+pub const CLOUD_SYNC_ERROR_CODE: &str = "E_CLOUD_SYNC";
+
+/// Folder names used by common sync clients
+const CLOUD_FOLDER_MARKERS: &[(&str, &str)] = &[
+    ("onedrive", "OneDrive"),
+    ("dropbox", "Dropbox"),
+    ("google drive", "Google Drive"),
+    ("googledrive", "Google Drive"),
+    ("icloud drive", "iCloud Drive"),
+    ("iclouddrive", "iCloud Drive"),
+    ("pcloud", "pCloud"),
+    ("megasync", "MEGA"),
+];
+
+/// Don't walk the whole game directory looking for placeholders
+const PLACEHOLDER_SCAN_LIMIT: usize = 2000;
+
+/// Name of the sync client whose folder contains `path`, judged by folder names
+pub fn cloud_provider_for_path(path: &Path) -> Option<&'static str> {
+    path.components().find_map(|c| {
+        let name = c.as_os_str().to_string_lossy().to_lowercase();
+        CLOUD_FOLDER_MARKERS
+            .iter()
+            .find(|(marker, _)| name == *marker || name.starts_with(&format!("{} -", marker)))
+            .map(|(_, provider)| *provider)
+    })
+}
+
+/// Whether a file is a cloud placeholder (not fully on disk) or otherwise managed by a
+/// sync provider. Only Windows exposes this; elsewhere nothing is reported.
+#[cfg(windows)]
+pub fn is_cloud_placeholder(path: &Path) -> bool {
+    use std::os::windows::fs::MetadataExt;
+    const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+    const FILE_ATTRIBUTE_OFFLINE: u32 = 0x1000;
+    const FILE_ATTRIBUTE_RECALL_ON_OPEN: u32 = 0x40000;
+    const FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS: u32 = 0x400000;
+    std::fs::symlink_metadata(path)
+        .map(|m| {
+            m.file_attributes()
+                & (FILE_ATTRIBUTE_REPARSE_POINT
+                    | FILE_ATTRIBUTE_OFFLINE
+                    | FILE_ATTRIBUTE_RECALL_ON_OPEN
+                    | FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS)
+                != 0
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(not(windows))]
+pub fn is_cloud_placeholder(_path: &Path) -> bool {
+    false
+}
+
+/// Placeholder files under `dir`, sampling at most PLACEHOLDER_SCAN_LIMIT entries
+pub fn find_cloud_placeholders(dir: &Path) -> Vec<String> {
+    Walk::new(dir)
+        .include_hidden()
+        .into_iter()
+        .take(PLACEHOLDER_SCAN_LIMIT)
+        .filter(|e| e.file_type().is_file() && is_cloud_placeholder(e.path()))
+        .map(|e| e.path().to_string_lossy().to_string())
+        .collect()
+}
+
+/// Whether an IO failure on `path` is plausibly caused by a sync client
+pub fn is_sync_affected(path: &Path) -> bool {
+    cloud_provider_for_path(path).is_some() || is_cloud_placeholder(path)
+}
+
+/// Wrap an error from a sync-affected path with the error code and guidance
+pub fn sync_interference_error(path: &Path, error: &str) -> String {
+    let provider = cloud_provider_for_path(path).unwrap_or("a cloud sync client");
+    format!(
+        "[{}] {} ({} appears to be syncing this folder; pause syncing or move the game and \
+         manager data out of the synced folder, then try again)",
+        CLOUD_SYNC_ERROR_CODE, error, provider
+    )
+}
+
+
+/// Steam installs that may hold per-user cloud data for this game: the library the game is in
+/// (the main Steam folder for the default library) plus the usual install locations
+pub(crate) fn steam_root_candidates(game_root: &Path) -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(library) = game_root.parent().and_then(Path::parent).and_then(Path::parent) {
+        roots.push(library.to_path_buf());
+    }
+    if cfg!(windows) {
+        if let Ok(program_files) = std::env::var("ProgramFiles(x86)") {
+            roots.push(Path::new(&program_files).join("Steam"));
+        }
+    } else if let Ok(home) = std::env::var("HOME") {
+        roots.push(Path::new(&home).join(".local/share/Steam"));
+        roots.push(Path::new(&home).join(".steam/steam"));
+    }
+    roots.dedup();
+    roots
+}
+
+/// Files Steam Cloud tracks for the game, from userdata/<user>/<appid>/remotecache.vdf.
+/// Names are as Steam records them: relative to their sync root, forward slashes.
+pub fn steam_cloud_files(game_root: &Path) -> Vec<String> {
+    let Some(app_id) = crate::utils::config::find_steam_app_id(game_root) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for steam_root in steam_root_candidates(game_root) {
+        let Ok(users) = fs::read_dir(steam_root.join("userdata")) else {
+            continue;
+        };
+        for user in users.filter_map(Result::ok) {
+            let Ok(content) = fs::read_to_string(user.path().join(&app_id).join("remotecache.vdf")) else {
+                continue;
+            };
+            // File entries are the quoted keys one level down that open a block
+            let mut depth = 0;
+            let mut last_key: Option<String> = None;
+            for line in content.lines().map(str::trim) {
+                match line {
+                    "{" => {
+                        if depth == 1 {
+                            if let Some(key) = last_key.take() {
+                                files.push(key.replace('\\', "/"));
+                            }
+                        }
+                        depth += 1;
+                    }
+                    "}" => depth -= 1,
+                    _ => last_key = line.strip_prefix('"').and_then(|l| l.strip_suffix('"')).map(str::to_string),
+                }
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Copies sync clients leave behind when two machines edit the same file, e.g.
+/// "config (conflicted copy 2024-01-01).json" (Dropbox) or "config-DESKTOP-1AB2C3D.json" (OneDrive)
+pub fn find_sync_conflict_copies(dir: &Path) -> Vec<String> {
+    let computer_name = std::env::var("COMPUTERNAME").ok().map(|n| n.to_lowercase());
+    Walk::new(dir)
+        .include_hidden()
+        .into_iter()
+        .take(PLACEHOLDER_SCAN_LIMIT)
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            let stem = e.path().file_stem().map(|s| s.to_string_lossy().to_lowercase()).unwrap_or_default();
+            stem.contains("conflicted copy")
+                || computer_name.as_ref().is_some_and(|n| stem.ends_with(&format!("-{}", n)))
+        })
+        .map(|e| e.path().to_string_lossy().to_string())
+        .collect()
+}
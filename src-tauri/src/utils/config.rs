@@ -1,10 +1,17 @@
-use log::{error, info};
+use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
 use std::env;
 
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::archives::{get_archives_dir, get_downloads_dir};
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::modregistry::ModRegistry;
+use crate::utils::sandbox::{active_sandbox, redirect_game_data};
+use crate::utils::staging::{get_mods_dir, get_staging_root};
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GameData {
     pub game_root_path: String,
@@ -75,9 +82,32 @@ pub fn find_game_paths_from_exe(executable_path_str: &str) -> Result<(PathBuf, P
     }
 }
 
+/// Validation result: the game paths plus anything the user should know before modding
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GameValidationReport {
+    #[serde(flatten)]
+    pub game_data: GameData,
+    pub warnings: Vec<String>,
+}
+
+/// REFramework and several native plugins build ANSI paths from the game directory and
+/// fail in confusing ways (missing scripts, plugins not loading) when it isn't plain ASCII
+pub fn non_ascii_path_warning(game_root: &str) -> Option<String> {
+    let offending: String = game_root.chars().filter(|c| !c.is_ascii()).collect();
+    if offending.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "The game folder path contains non-ASCII characters ({}). REFramework and some mods can fail \
+         to load scripts or plugins from such paths. If you run into problems, move the game to a \
+         Steam library whose path uses only English letters and digits (e.g. C:\\SteamLibrary).",
+        offending
+    ))
+}
+
 // New command to validate game path and return GameData without writing config
 #[tauri::command]
-pub async fn validate_game_installation(executable_path: String) -> Result<GameData, String> {
+pub async fn validate_game_installation(executable_path: String) -> Result<GameValidationReport, String> {
     info!(
         "Validating game installation from executable: {}",
         executable_path
@@ -95,14 +125,90 @@ pub async fn validate_game_installation(executable_path: String) -> Result<GameD
         game_executable_path: executable_path.clone(),
     };
 
+    let mut warnings = Vec::new();
+    if let Some(warning) = non_ascii_path_warning(&game_root_path_str) {
+        warn!("{}", warning);
+        warnings.push(warning);
+    }
+
     info!("Validation successful for: {}", executable_path);
-    Ok(game_data)
+    Ok(GameValidationReport {
+        game_data,
+        warnings,
+    })
+}
+
+/// Value of a top-level `"key" "value"` line in an appmanifest
+fn manifest_value(content: &str, key: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let mut parts = line.split('"').filter(|p| !p.trim().is_empty());
+        if parts.next()? != key {
+            return None;
+        }
+        parts.next().map(str::to_string)
+    })
+}
+
+/// Contents of the appmanifest_<id>.acf whose installdir matches the game folder.
+/// None for non-Steam installs.
+fn read_app_manifest(game_root: &Path) -> Option<String> {
+    let install_dir = game_root.file_name()?.to_str()?;
+    let steamapps = game_root.parent()?.parent()?;
+    fs::read_dir(steamapps)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|e| e.file_name().to_string_lossy().starts_with("appmanifest_"))
+        .find_map(|entry| {
+            let content = fs::read_to_string(entry.path()).ok()?;
+            let installdir = manifest_value(&content, "installdir")?;
+            installdir.eq_ignore_ascii_case(install_dir).then_some(content)
+        })
+}
+
+/// Steam app id of the game, from its appmanifest. None for non-Steam installs.
+pub fn find_steam_app_id(game_root: &Path) -> Option<String> {
+    manifest_value(&read_app_manifest(game_root)?, "appid")
+}
+
+/// Steam build id of the installed game version, which changes with every patch
+pub fn find_steam_build_id(game_root: &Path) -> Option<String> {
+    manifest_value(&read_app_manifest(game_root)?, "buildid")
+}
+
+/// Folders under <game_root>/fossmodmanager the rest of the app expects to exist
+const LAYOUT_DIRS: &[&str] = &[".trash", "restore_points", "exports"];
+
+/// Create the fossmodmanager folder layout (trash, ...) and the staging folders (mods,
+/// archives, downloads) under `staging_root` so scans and installs never run against missing
+/// directories. Existing folders are left alone.
+pub fn ensure_directory_layout(game_root: &Path, staging_root: &Path) -> Result<(), String> {
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root.display()));
+    }
+    let manager_dir = game_root.join("fossmodmanager");
+    let staged_dirs = [
+        get_mods_dir(staging_root),
+        get_archives_dir(staging_root),
+        get_downloads_dir(staging_root),
+    ];
+    for path in LAYOUT_DIRS.iter().map(|d| manager_dir.join(d)).chain(staged_dirs) {
+        if !path.is_dir() {
+            fs::create_dir_all(&path)
+                .map_err(|e| format!("Failed to create directory {}: {}", path.display(), e))?;
+            info!("Created {}", path.display());
+        }
+    }
+    Ok(())
 }
 
 // New function to explicitly save GameData
 #[tauri::command] // Expose saving as a separate command
 pub async fn save_game_config(app_handle: AppHandle, game_data: GameData) -> Result<(), String> {
     info!("Saving game config: {:?}", game_data);
+    ensure_write_access(&app_handle)?;
+    if active_sandbox(&app_handle).is_some() {
+        return Err("Leave sandbox mode before changing the game folder".to_string());
+    }
     let config_path = get_config_path(&app_handle)?;
     fs::create_dir_all(config_path.parent().unwrap()) // Ensure dir exists
         .map_err(|e| format!("Failed to create config directory: {}", e))?;
@@ -115,12 +221,22 @@ pub async fn save_game_config(app_handle: AppHandle, game_data: GameData) -> Res
     .map_err(|e| format!("Failed to write config to {:?}: {}", config_path, e))?;
 
     info!("Successfully saved game config to {:?}", config_path);
+    let game_root = Path::new(&game_data.game_root_path);
+    if let Err(e) = ensure_directory_layout(game_root, &get_staging_root(&app_handle, game_root)) {
+        warn!("Failed to set up mod folders: {}", e);
+    }
     Ok(())
 }
 
+/// The configured game, pointed at the sandbox copy while sandbox mode is on
 #[tauri::command]
 pub async fn load_game_config(app_handle: AppHandle) -> Result<Option<GameData>, String> {
-    let config_path = get_config_path(&app_handle)?;
+    Ok(read_game_config_file(&app_handle)?.map(|real| redirect_game_data(&app_handle, real)))
+}
+
+/// The real game from userconfig.json, ignoring sandbox mode
+pub(crate) fn read_game_config_file(app_handle: &AppHandle) -> Result<Option<GameData>, String> {
+    let config_path = get_config_path(app_handle)?;
     match fs::read_to_string(&config_path) {
         Ok(json) => {
             let data = serde_json::from_str(&json).map_err(|e| {
@@ -151,7 +267,19 @@ pub async fn load_game_config(app_handle: AppHandle) -> Result<Option<GameData>,
 }
 
 #[tauri::command]
-pub async fn nuke_settings_and_relaunch(app_handle: AppHandle) -> Result<(), String> {
+pub async fn nuke_settings_and_relaunch(
+    app_handle: AppHandle,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "nuke_settings_and_relaunch",
+        &[],
+        "Delete all settings, data and caches, then restart",
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
     info!("Attempting to delete all application configuration, data, and cache.");
 
     let config_dir = app_handle
@@ -259,3 +387,119 @@ fn get_config_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
         .map_err(|e| format!("Failed to get app config dir: {}", e))?;
     Ok(dir.join("userconfig.json"))
 }
+
+/// Summary of a game relocation
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelocationReport {
+    pub old_root: String,
+    pub new_root: String,
+    pub rewritten_paths: usize,
+}
+
+/// Re-root `path` from `old_root` to `new_root`; None if it isn't under `old_root`
+fn rebase_path(path: &str, old_root: &Path, new_root: &Path) -> Option<String> {
+    let rel = Path::new(path).strip_prefix(old_root).ok()?;
+    Some(new_root.join(rel).to_string_lossy().to_string())
+}
+
+/// Rewrite every absolute game path in the registry. Returns how many paths changed.
+pub(crate) fn rebase_registry(registry: &mut ModRegistry, old_root: &Path, new_root: &Path) -> usize {
+    let mut count = 0;
+    let mut rebase = |path: &mut String| {
+        if let Some(new_path) = rebase_path(path, old_root, new_root) {
+            *path = new_path;
+            count += 1;
+        }
+    };
+
+    for mod_entry in &mut registry.mods {
+        rebase(&mut mod_entry.path);
+    }
+    for skin_mod in &mut registry.skin_mods {
+        rebase(&mut skin_mod.base.path);
+        if let Some(thumb) = skin_mod.thumbnail_path.as_mut() {
+            rebase(thumb);
+        }
+        if let Some(pak) = skin_mod.installed_pak_path.as_mut() {
+            rebase(pak);
+        }
+        for installed in &mut skin_mod.installed_files {
+            rebase(&mut installed.path);
+        }
+        for file in &mut skin_mod.files {
+            rebase(&mut file.original_path);
+        }
+    }
+    count
+}
+
+/// Point the manager at a game installation that was moved (e.g. a new Steam library).
+/// Validates the new location, then rewrites the registry paths and userconfig.json together:
+/// if the config can't be written the previous registry is put back.
+#[tauri::command]
+pub async fn relocate_game(app_handle: AppHandle, new_root_path: String) -> Result<RelocationReport, String> {
+    let old_config = load_game_config(app_handle.clone())
+        .await?
+        .ok_or_else(|| "No game is configured yet; run setup instead".to_string())?;
+    let old_root = PathBuf::from(&old_config.game_root_path);
+    let new_root = PathBuf::from(&new_root_path);
+    info!("Relocating game from {:?} to {:?}", old_root, new_root);
+    ensure_write_access(&app_handle)?;
+    if active_sandbox(&app_handle).is_some() {
+        return Err("Leave sandbox mode before relocating the game".to_string());
+    }
+
+    if !new_root.is_dir() {
+        return Err(format!("New game folder does not exist: {}", new_root_path));
+    }
+    let exe_rel = Path::new(&old_config.game_executable_path)
+        .strip_prefix(&old_root)
+        .map(Path::to_path_buf)
+        .or_else(|_| {
+            Path::new(&old_config.game_executable_path)
+                .file_name()
+                .map(PathBuf::from)
+                .ok_or(())
+        })
+        .map_err(|_| "Configured game executable path is invalid".to_string())?;
+    let new_exe = new_root.join(&exe_rel);
+    if !new_exe.is_file() {
+        return Err(format!(
+            "{} doesn't look like the game folder: {} not found",
+            new_root_path,
+            exe_rel.display()
+        ));
+    }
+
+    let previous_registry = ModRegistry::load(&app_handle)?;
+    let mut registry = previous_registry.clone();
+    let rewritten_paths = rebase_registry(&mut registry, &old_root, &new_root);
+    registry.last_updated = chrono::Utc::now().timestamp();
+    let new_config = GameData {
+        game_root_path: new_root.to_string_lossy().to_string(),
+        game_executable_path: new_exe.to_string_lossy().to_string(),
+    };
+
+    let config_path = get_config_path(&app_handle)?;
+    let config_json = serde_json::to_string_pretty(&new_config)
+        .map_err(|e| format!("Failed to serialize GameData: {}", e))?;
+
+    registry.save(&app_handle)?;
+    if let Err(e) = crate::utils::fsops::write_file_atomic(&config_path, config_json.as_bytes()) {
+        error!("Failed to update config during relocation, restoring registry: {}", e);
+        if let Err(restore_err) = previous_registry.save(&app_handle) {
+            error!("Failed to restore registry after failed relocation: {}", restore_err);
+        }
+        return Err(e);
+    }
+
+    info!(
+        "Relocated game to {:?}, rewrote {} registry paths",
+        new_root, rewritten_paths
+    );
+    Ok(RelocationReport {
+        old_root: old_config.game_root_path,
+        new_root: new_config.game_root_path,
+        rewritten_paths,
+    })
+}
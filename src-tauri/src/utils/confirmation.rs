@@ -0,0 +1,151 @@
+// src-tauri/src/utils/confirmation.rs
+// Backend-enforced confirmation for destructive commands. With `require_confirmation` on,
+// the first call hands back a single-use token and only a second call carrying it executes.
+// Bulk disables (safe mode, profile switches, apply_desired_state) go through the same gate
+// whenever they would turn off at least one mod.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::hashing::StreamHasher;
+use crate::utils::settings::AppSettings;
+
+/// How long a confirmation token stays valid
+const CONFIRMATION_TTL_SECS: i64 = 120;
+
+struct PendingConfirmation {
+    action: String,
+    fingerprint: String,
+    expires_timestamp: i64,
+}
+
+/// Outstanding challenges, kept as managed state for the lifetime of the app
+#[derive(Default)]
+pub struct ConfirmationState {
+    pending: Mutex<HashMap<String, PendingConfirmation>>,
+    token_counter: AtomicU64,
+}
+
+/// Register the confirmation state. Called once during setup.
+pub fn init_confirmations(app_handle: &AppHandle) {
+    app_handle.manage(ConfirmationState::default());
+}
+
+/// Returned instead of executing when confirmation is required
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmationChallenge {
+    pub confirmation_required: bool, // Always true; lets the frontend tell this apart from a result
+    pub token: String,
+    pub action: String,
+    pub summary: String,
+    pub expires_timestamp: i64,
+}
+
+/// Result of a confirmable command. Serializes as the plain result when it ran, so callers
+/// that never opted into the policy see no difference.
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum Confirmable<T> {
+    ConfirmationRequired(ConfirmationChallenge),
+    Done(T),
+}
+
+/// Binds a token to the exact targets it was issued for
+fn fingerprint(action: &str, targets: &[String]) -> String {
+    let mut sorted = targets.to_vec();
+    sorted.sort();
+    let mut hasher = StreamHasher::new();
+    hasher.update(action.as_bytes());
+    for target in &sorted {
+        hasher.update(&[0]);
+        hasher.update(target.as_bytes());
+    }
+    hasher.finish()
+}
+
+fn new_token(counter: &AtomicU64) -> String {
+    let mut hasher = StreamHasher::new();
+    hasher.update(&RandomState::new().hash_one(counter.fetch_add(1, Ordering::SeqCst)).to_le_bytes());
+    hasher.update(&RandomState::new().hash_one(std::process::id()).to_le_bytes());
+    hasher.update(
+        &chrono::Utc::now()
+            .timestamp_nanos_opt()
+            .unwrap_or_default()
+            .to_le_bytes(),
+    );
+    hasher.finish()
+}
+
+/// Gate a destructive action. Returns `Ok(None)` when it may run, `Ok(Some(challenge))` when
+/// the caller must come back with the token, and an error for a bad or expired token.
+pub fn check_confirmation(
+    app_handle: &AppHandle,
+    action: &str,
+    targets: &[String],
+    summary: &str,
+    token: Option<&str>,
+) -> Result<Option<ConfirmationChallenge>, String> {
+    let required = AppSettings::load(app_handle)?.require_confirmation;
+    gate_confirmation(&app_handle.state::<ConfirmationState>(), required, action, targets, summary, token)
+}
+
+/// `check_confirmation` with the `require_confirmation` setting already read
+pub(crate) fn gate_confirmation(
+    state: &ConfirmationState,
+    required: bool,
+    action: &str,
+    targets: &[String],
+    summary: &str,
+    token: Option<&str>,
+) -> Result<Option<ConfirmationChallenge>, String> {
+    if !required {
+        return Ok(None);
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let mut pending = state
+        .pending
+        .lock()
+        .map_err(|e| format!("Failed to lock confirmation state: {}", e))?;
+    pending.retain(|_, p| p.expires_timestamp > now);
+
+    let expected = fingerprint(action, targets);
+    if let Some(token) = token {
+        // Tokens are single use whether or not they match
+        return match pending.remove(token) {
+            Some(p) if p.action == action && p.fingerprint == expected => {
+                info!("Confirmation accepted for '{}'", action);
+                Ok(None)
+            }
+            Some(p) => {
+                warn!("Confirmation token for '{}' presented for '{}'", p.action, action);
+                Err("Confirmation token doesn't match this action; request a new confirmation.".to_string())
+            }
+            None => Err("Confirmation token is invalid or expired; request a new confirmation.".to_string()),
+        };
+    }
+
+    let challenge = ConfirmationChallenge {
+        confirmation_required: true,
+        token: new_token(&state.token_counter),
+        action: action.to_string(),
+        summary: summary.to_string(),
+        expires_timestamp: now + CONFIRMATION_TTL_SECS,
+    };
+    pending.insert(
+        challenge.token.clone(),
+        PendingConfirmation {
+            action: action.to_string(),
+            fingerprint: expected,
+            expires_timestamp: challenge.expires_timestamp,
+        },
+    );
+    info!("Issued confirmation challenge for '{}': {}", action, summary);
+    Ok(Some(challenge))
+}
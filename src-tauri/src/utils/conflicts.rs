@@ -0,0 +1,521 @@
+// src-tauri/src/utils/conflicts.rs
+// Conflict engine for skin mods. Indexes every game file each skin mod writes, whether as a
+// loose natives file or as an entry inside a patch pak, and works out whose copy the game
+// ends up loading for each file more than one mod provides.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::modregistry::{ModRegistry, ModType, SkinMod};
+use crate::utils::httpidentity::APP_VERSION;
+use crate::utils::pakregistry::{list_patch_paks, patch_number_of, PatchPak};
+use crate::utils::repak::{load_file_list, pak_entry_key, read_pak_entries};
+use crate::utils::settings::{AppSettings, InstallTargets};
+use crate::utils::walk::Walk;
+
+/// How a mod delivers a file to the game
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FileSource {
+    Loose, // natives/ file in the game directory
+    Pak,   // Entry inside a numbered patch pak
+}
+
+/// One file provided by two mods, and which of them the game will load
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SkinFileConflict {
+    pub file: String, // natives/... path, or the pak entry hash when no file list names it
+    pub winning_mod: String, // Source path of the mod whose copy is loaded
+    pub winning_mod_name: String,
+    pub winning_source: FileSource,
+    pub losing_mod: String,
+    pub losing_mod_name: String,
+    pub losing_source: FileSource,
+}
+
+struct IndexedFile {
+    mod_index: usize,
+    source: FileSource,
+    load_order: u32, // Patch number for paks; later ones override earlier ones
+}
+
+/// Load order given to the mod about to be enabled: its pak gets the next patch number and
+/// its loose files replace whatever is deployed
+const INCOMING_LOAD_ORDER: u32 = u32::MAX;
+
+/// Every file each indexed skin mod writes, keyed by pak path hash so loose natives files and
+/// pak entries for the same game path land on the same key
+#[derive(Default)]
+pub struct SkinFileIndex {
+    mods: Vec<(String, String)>, // (source path, name)
+    files: HashMap<(u32, u32), Vec<IndexedFile>>,
+    names: HashMap<(u32, u32), String>,
+}
+
+impl SkinFileIndex {
+    fn add_file(&mut self, mod_index: usize, path: Option<String>, key: (u32, u32), source: FileSource, load_order: u32) {
+        if let Some(path) = path {
+            self.names.entry(key).or_insert(path);
+        }
+        self.files.entry(key).or_default().push(IndexedFile {
+            mod_index,
+            source,
+            load_order,
+        });
+    }
+
+    fn add_loose(&mut self, mod_index: usize, rel_to_natives: &Path, load_order: u32) {
+        let path = format!("natives/{}", rel_to_natives.to_string_lossy().replace('\\', "/"));
+        let key = pak_entry_key(&path);
+        self.add_file(mod_index, Some(path), key, FileSource::Loose, load_order);
+    }
+
+    fn add_pak(&mut self, mod_index: usize, pak: &Path, load_order: u32) {
+        match read_pak_entries(pak) {
+            Ok(entries) => {
+                for entry in entries {
+                    let key = (entry.hash_lower, entry.hash_upper);
+                    self.add_file(mod_index, None, key, FileSource::Pak, load_order);
+                }
+            }
+            Err(e) => warn!("Skipping pak contents in conflict check: {}", e),
+        }
+    }
+
+    /// Index a mod's files as they would be deployed from its source folder, as the next mod
+    /// to be enabled
+    pub fn add_incoming(&mut self, mod_path: &str, name: &str, install_targets: &InstallTargets) {
+        let mod_index = self.mods.len();
+        self.mods.push((mod_path.to_string(), name.to_string()));
+        let mod_dir = Path::new(mod_path);
+        let natives_prefix = mod_dir.join("natives");
+        for entry in Walk::new(&natives_prefix).files() {
+            if let Ok(rel) = entry.path().strip_prefix(&natives_prefix) {
+                let (rel, _) = install_targets.natives_platform_path(rel);
+                self.add_loose(mod_index, &rel, INCOMING_LOAD_ORDER);
+            }
+        }
+        let root_paks: Vec<PathBuf> = fs::read_dir(mod_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pak")))
+            .collect();
+        for pak in root_paks {
+            self.add_pak(mod_index, &pak, INCOMING_LOAD_ORDER);
+        }
+    }
+
+    /// Index what an enabled skin mod actually deployed to the game directory
+    pub fn add_deployed(&mut self, skin_mod: &SkinMod, game_natives_dir: &Path) {
+        let mod_index = self.mods.len();
+        self.mods.push((skin_mod.base.path.clone(), skin_mod.base.name.clone()));
+        for file in skin_mod.installed_files.iter().map(|f| &f.path) {
+            let path = Path::new(file);
+            if skin_mod.installed_pak_path.as_deref() == Some(file.as_str()) {
+                let patch_number = patch_number_of(file).unwrap_or(0);
+                self.add_pak(mod_index, path, patch_number);
+            } else if let Ok(rel) = path.strip_prefix(game_natives_dir) {
+                self.add_loose(mod_index, rel, 0);
+            }
+        }
+    }
+
+    /// Index an enabled patch pak no mod in the registry deployed
+    pub fn add_unmanaged_pak(&mut self, pak: &PatchPak) {
+        let mod_index = self.mods.len();
+        let name = Path::new(&pak.path).file_name().unwrap_or_default().to_string_lossy().to_string();
+        self.mods.push((pak.path.clone(), name));
+        self.add_pak(mod_index, Path::new(&pak.path), pak.number);
+    }
+
+    /// Every contested file, optionally limited to those involving one mod (by source path).
+    /// Loose files win over pak entries since REFramework's loose file loader reads them
+    /// first; between two paks the higher patch number wins.
+    pub fn conflicts(&self, involving: Option<&str>, file_list: &[String]) -> Vec<SkinFileConflict> {
+        let mut contested = Vec::new();
+        for (key, providers) in &self.files {
+            let Some(winner) = providers
+                .iter()
+                .max_by_key(|f| (f.source == FileSource::Loose, f.load_order))
+            else {
+                continue;
+            };
+            let mut seen = HashSet::from([winner.mod_index]);
+            for loser in providers {
+                if !seen.insert(loser.mod_index) {
+                    continue;
+                }
+                let (winning_mod, winning_mod_name) = &self.mods[winner.mod_index];
+                let (losing_mod, losing_mod_name) = &self.mods[loser.mod_index];
+                if involving.is_some_and(|m| m != winning_mod && m != losing_mod) {
+                    continue;
+                }
+                contested.push((
+                    *key,
+                    SkinFileConflict {
+                        file: String::new(),
+                        winning_mod: winning_mod.clone(),
+                        winning_mod_name: winning_mod_name.clone(),
+                        winning_source: winner.source,
+                        losing_mod: losing_mod.clone(),
+                        losing_mod_name: losing_mod_name.clone(),
+                        losing_source: loser.source,
+                    },
+                ));
+            }
+        }
+
+        // Pak-only entries are named from the community file list when one is configured
+        let mut names = self.names.clone();
+        if contested.iter().any(|(key, _)| !names.contains_key(key)) {
+            for name in file_list {
+                let key = pak_entry_key(name);
+                if self.files.contains_key(&key) {
+                    names.entry(key).or_insert_with(|| name.clone());
+                }
+            }
+        }
+
+        let mut conflicts: Vec<SkinFileConflict> = contested
+            .into_iter()
+            .map(|(key, mut conflict)| {
+                conflict.file = names
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_else(|| format!("<pak entry {:08x}{:08x}>", key.0, key.1));
+                conflict
+            })
+            .collect();
+        conflicts.sort_by(|a, b| a.file.cmp(&b.file).then_with(|| a.losing_mod.cmp(&b.losing_mod)));
+        conflicts
+    }
+}
+
+/// Conflicts between enabled skin mods, or between them and `mod_path` if it were enabled next
+pub(crate) fn find_skin_conflicts(
+    registry: &ModRegistry,
+    game_root: &Path,
+    mod_path: Option<&str>,
+    settings: &AppSettings,
+) -> Result<Vec<SkinFileConflict>, String> {
+    let game_natives_dir = settings.install_targets.resolve(game_root, &ModType::NativesMod);
+    let mut index = SkinFileIndex::default();
+    for skin_mod in registry.skin_mods.iter().filter(|m| m.base.enabled) {
+        if Some(skin_mod.base.path.as_str()) != mod_path {
+            index.add_deployed(skin_mod, &game_natives_dir);
+        }
+    }
+    if let Some(mod_path) = mod_path {
+        let skin_mod = registry
+            .skin_mods
+            .iter()
+            .find(|m| m.base.path == mod_path)
+            .ok_or_else(|| format!("SkinMod with path '{}' not found in registry", mod_path))?;
+        index.add_incoming(mod_path, &skin_mod.base.name, &settings.install_targets);
+    }
+
+    Ok(index.conflicts(mod_path, &configured_file_list(settings)))
+}
+
+/// The community pak file list from settings, used to name pak entries
+fn configured_file_list(settings: &AppSettings) -> Vec<String> {
+    match settings.pak_file_list.as_deref() {
+        Some(list_path) => load_file_list(Path::new(list_path)).unwrap_or_else(|e| {
+            warn!("{}; pak entries will be reported by hash", e);
+            Vec::new()
+        }),
+        None => Vec::new(),
+    }
+}
+
+/// Per-file conflicts a skin mod would have with the enabled ones, checked before enabling it.
+/// Without `mod_path`, reports conflicts among the currently enabled skin mods.
+#[tauri::command]
+pub async fn check_skin_conflicts(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_path: Option<String>,
+) -> Result<Vec<SkinFileConflict>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let mod_path = mod_path.map(|p| registry.resolve_skin_mod_path(&p).unwrap_or(p));
+    let settings = AppSettings::load(&app_handle)?;
+    let conflicts = find_skin_conflicts(&registry, &game_root, mod_path.as_deref(), &settings)?;
+    info!(
+        "Skin conflict check{}: {} conflicting file(s)",
+        mod_path.map(|p| format!(" for {}", p)).unwrap_or_default(),
+        conflicts.len()
+    );
+    Ok(conflicts)
+}
+
+/// Something that provides files to the game, as listed in a conflict report
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LoadOrderSlot {
+    pub name: String,
+    pub mod_path: Option<String>, // None for paks no mod in the registry deployed
+    pub source: FileSource,
+    pub pak_path: Option<String>,
+    pub patch_number: Option<u32>,
+}
+
+/// Every file-level conflict across the enabled skin mods and patch paks, for sharing when
+/// asking for help
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConflictReport {
+    pub generated_timestamp: i64,
+    pub app_version: String,
+    pub game_root: String,
+    pub load_order: Vec<LoadOrderSlot>, // Loaded first first; later slots override earlier ones
+    pub conflicts: Vec<SkinFileConflict>,
+    pub contested_files: usize,
+    pub text: String, // The same report as plain text
+}
+
+/// Build the conflict report for the current setup: enabled skin mods as deployed plus any
+/// patch paks the registry doesn't know about
+pub(crate) fn build_conflict_report(
+    registry: &ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+) -> Result<ConflictReport, String> {
+    let game_natives_dir = settings.install_targets.resolve(game_root, &ModType::NativesMod);
+    let pak_dir = settings.install_targets.resolve(game_root, &ModType::SkinMod);
+    let mut index = SkinFileIndex::default();
+    let mut paks: Vec<LoadOrderSlot> = Vec::new();
+    let mut loose: Vec<LoadOrderSlot> = Vec::new();
+
+    for skin_mod in registry.skin_mods.iter().filter(|m| m.base.enabled) {
+        index.add_deployed(skin_mod, &game_natives_dir);
+        let slot = |source, pak_path: Option<&String>| LoadOrderSlot {
+            name: skin_mod.base.name.clone(),
+            mod_path: Some(skin_mod.base.path.clone()),
+            source,
+            pak_path: pak_path.cloned(),
+            patch_number: pak_path.and_then(|p| patch_number_of(p)),
+        };
+        if let Some(pak_path) = &skin_mod.installed_pak_path {
+            paks.push(slot(FileSource::Pak, Some(pak_path)));
+        }
+        let has_loose = skin_mod
+            .installed_files
+            .iter()
+            .any(|f| Some(&f.path) != skin_mod.installed_pak_path.as_ref() && Path::new(&f.path).starts_with(&game_natives_dir));
+        if has_loose {
+            loose.push(slot(FileSource::Loose, None));
+        }
+    }
+
+    let managed: HashSet<&str> = registry
+        .skin_mods
+        .iter()
+        .filter_map(|m| m.installed_pak_path.as_deref())
+        .collect();
+    let unmanaged = list_patch_paks(&pak_dir).unwrap_or_else(|e| {
+        warn!("{}; unmanaged paks are left out of the conflict report", e);
+        Vec::new()
+    });
+    for pak in unmanaged.iter().filter(|p| p.enabled && !managed.contains(p.path.as_str())) {
+        index.add_unmanaged_pak(pak);
+        paks.push(LoadOrderSlot {
+            name: Path::new(&pak.path).file_name().unwrap_or_default().to_string_lossy().to_string(),
+            mod_path: None,
+            source: FileSource::Pak,
+            pak_path: Some(pak.path.clone()),
+            patch_number: Some(pak.number),
+        });
+    }
+    paks.sort_by_key(|slot| slot.patch_number);
+
+    let conflicts = index.conflicts(None, &configured_file_list(settings));
+    let mut report = ConflictReport {
+        generated_timestamp: chrono::Utc::now().timestamp(),
+        app_version: APP_VERSION.to_string(),
+        game_root: game_root.to_string_lossy().to_string(),
+        load_order: paks.into_iter().chain(loose).collect(),
+        contested_files: conflicts.iter().map(|c| &c.file).collect::<HashSet<_>>().len(),
+        conflicts,
+        text: String::new(),
+    };
+    report.text = conflict_report_text(&report);
+    Ok(report)
+}
+
+fn source_label(source: FileSource) -> &'static str {
+    match source {
+        FileSource::Loose => "loose",
+        FileSource::Pak => "pak",
+    }
+}
+
+/// Plain-text rendering of a conflict report, meant to be pasted into a help request
+pub fn conflict_report_text(report: &ConflictReport) -> String {
+    let mut text = String::new();
+    let generated = chrono::DateTime::from_timestamp(report.generated_timestamp, 0)
+        .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_default();
+    let _ = writeln!(text, "FossModManager conflict report ({}, v{})", generated, report.app_version);
+    let _ = writeln!(text, "Game: {}", report.game_root);
+
+    let _ = writeln!(text, "\nLoad order (later entries win; loose files win over every pak):");
+    if report.load_order.is_empty() {
+        let _ = writeln!(text, "  (no enabled skin mods or patch paks)");
+    }
+    for (i, slot) in report.load_order.iter().enumerate() {
+        let position = match slot.patch_number {
+            Some(n) => format!("pak {:03}", n),
+            None => source_label(slot.source).to_string(),
+        };
+        let unmanaged = if slot.mod_path.is_none() { " (not managed by FossModManager)" } else { "" };
+        let _ = writeln!(text, "  {:>3}. [{}] {}{}", i + 1, position, slot.name, unmanaged);
+    }
+
+    let _ = writeln!(
+        text,
+        "\nConflicts: {} file(s) provided by more than one mod",
+        report.contested_files
+    );
+    let mut current_file: Option<&str> = None;
+    for conflict in &report.conflicts {
+        if current_file != Some(conflict.file.as_str()) {
+            current_file = Some(&conflict.file);
+            let _ = writeln!(text, "  {}", conflict.file);
+            let _ = writeln!(
+                text,
+                "    wins:       {} ({})",
+                conflict.winning_mod_name,
+                source_label(conflict.winning_source)
+            );
+        }
+        let _ = writeln!(
+            text,
+            "    overridden: {} ({})",
+            conflict.losing_mod_name,
+            source_label(conflict.losing_source)
+        );
+    }
+    text
+}
+
+/// Report every file-level conflict in the current load order. With `output_path`, also
+/// writes it there: JSON for a .json path, plain text otherwise.
+#[tauri::command]
+pub async fn generate_conflict_report(
+    app_handle: AppHandle,
+    game_root_path: String,
+    output_path: Option<String>,
+) -> Result<ConflictReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let settings = AppSettings::load(&app_handle)?;
+    let report = build_conflict_report(&registry, &game_root, &settings)?;
+    info!(
+        "Conflict report: {} load order slot(s), {} contested file(s)",
+        report.load_order.len(),
+        report.contested_files
+    );
+
+    if let Some(output_path) = output_path {
+        let output = PathBuf::from(&output_path);
+        let is_json = output
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        let content = if is_json {
+            serde_json::to_string_pretty(&report)
+                .map_err(|e| format!("Failed to serialize conflict report: {}", e))?
+        } else {
+            report.text.clone()
+        };
+        fs::write(&output, content)
+            .map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+        info!("Wrote conflict report to {}", output.display());
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{no_progress, FakeGame, SHARED_TEXTURE};
+    use crate::utils::modregistry::{enable_skin_mod_files, new_skin_mod_from_dir};
+    use crate::utils::pakregistry::patch_pak_file_name;
+    use crate::utils::repak::pack_natives_to_pak;
+
+    #[test]
+    fn skin_conflicts_rank_loose_files_over_pak_entries() {
+        let game = FakeGame::new();
+        let settings = AppSettings::default();
+        let mut registry = ModRegistry::default();
+        let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+        let second = game.install_skin(&mut registry, "SecondSkin", b"second");
+
+        // A pak-only skin shipping the same texture
+        let pak_source = game.root().join("pak_source");
+        fs::create_dir_all(pak_source.join("natives").join(SHARED_TEXTURE).parent().unwrap()).unwrap();
+        fs::write(pak_source.join("natives").join(SHARED_TEXTURE), b"pak").unwrap();
+        let pak_skin_dir = game.mods_dir().join("PakSkin");
+        fs::create_dir_all(&pak_skin_dir).unwrap();
+        pack_natives_to_pak(&pak_source, &pak_skin_dir.join("PakSkin.pak"), &|rel| rel.to_path_buf(), &|_| {}).expect("pack fixture pak");
+        let pak_skin = new_skin_mod_from_dir(&pak_skin_dir, "local_zip");
+        let pak_skin_path = pak_skin.base.path.clone();
+        registry.skin_mods.push(pak_skin);
+
+        enable_skin_mod_files(&mut registry, game.root(), &first, &settings, &HashMap::new(), &no_progress)
+            .expect("enable first skin");
+        let shared_path = format!("natives/{}", SHARED_TEXTURE);
+
+        // An incoming loose file replaces the deployed one
+        let conflicts = find_skin_conflicts(&registry, game.root(), Some(&second), &settings).expect("check second");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].file, shared_path);
+        assert_eq!(conflicts[0].winning_mod, second);
+        assert_eq!(conflicts[0].losing_mod, first);
+
+        // A pak entry loses to the loose file already deployed
+        let conflicts =
+            find_skin_conflicts(&registry, game.root(), Some(&pak_skin_path), &settings).expect("check pak skin");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].file, shared_path);
+        assert_eq!(conflicts[0].winning_mod, first);
+        assert_eq!(conflicts[0].losing_mod, pak_skin_path);
+        assert_eq!(conflicts[0].losing_source, FileSource::Pak);
+
+        // Nothing else is enabled, so the enabled set has no conflicts of its own
+        assert!(find_skin_conflicts(&registry, game.root(), None, &settings).unwrap().is_empty());
+    }
+
+    #[test]
+    fn conflict_report_covers_unmanaged_paks_and_names_the_winner() {
+        let game = FakeGame::new();
+        let settings = AppSettings::default();
+        let mut registry = ModRegistry::default();
+        let first = game.install_skin(&mut registry, "FirstSkin", b"first");
+        enable_skin_mod_files(&mut registry, game.root(), &first, &settings, &HashMap::new(), &no_progress)
+            .expect("enable first skin");
+
+        // A patch pak dropped into the game folder by hand, carrying the same texture
+        let pak_source = game.root().join("pak_source");
+        fs::create_dir_all(pak_source.join("natives").join(SHARED_TEXTURE).parent().unwrap()).unwrap();
+        fs::write(pak_source.join("natives").join(SHARED_TEXTURE), b"pak").unwrap();
+        let pak_dir = settings.install_targets.resolve(game.root(), &ModType::SkinMod);
+        let manual_pak = pak_dir.join(patch_pak_file_name(5, true));
+        pack_natives_to_pak(&pak_source, &manual_pak, &|rel| rel.to_path_buf(), &|_| {}).expect("pack fixture pak");
+
+        let report = build_conflict_report(&registry, game.root(), &settings).expect("build report");
+        assert_eq!(report.load_order.len(), 2);
+        assert_eq!(report.load_order[0].patch_number, Some(5));
+        assert!(report.load_order[0].mod_path.is_none());
+        assert_eq!(report.load_order[1].mod_path.as_deref(), Some(first.as_str()));
+        assert_eq!(report.contested_files, 1);
+        assert_eq!(report.conflicts[0].winning_mod, first);
+        assert_eq!(report.conflicts[0].losing_mod, manual_pak.to_string_lossy());
+        assert!(report.text.contains(&format!("natives/{}", SHARED_TEXTURE)));
+        assert!(report.text.contains("wins:       FirstSkin (loose)"));
+    }
+}
@@ -0,0 +1,397 @@
+// src-tauri/src/utils/contentindex.rs
+// File listings of the retained archives, so a search for a game file name ("which mod ships
+// ch03_002_0011.mesh?") also finds mods that aren't deployed or were deleted since. Listings
+// are cached in archives/index.json and re-read only when an archive changes.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+use zip::ZipArchive;
+
+use crate::utils::archives::get_archives_dir;
+use crate::utils::modregistry::{resolve_reframework_mod_dir, ModRegistry};
+use crate::utils::staging::get_staging_root;
+use crate::utils::walk::Walk;
+
+const INDEX_FILE: &str = "index.json";
+/// Matched files reported per mod; the count is still exact
+const MAX_MATCHED_FILES: usize = 50;
+
+/// One retained archive's contents
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArchiveIndexEntry {
+    pub archive: String, // File name inside the archives dir
+    pub size_bytes: u64,
+    pub modified_timestamp: i64,
+    pub files: Vec<String>, // Entry paths, '/' separators
+    #[serde(default)]
+    pub mod_paths: Vec<String>, // Skin mod folders extracted from it, when recorded at import
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ContentIndex {
+    pub archives: Vec<ArchiveIndexEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModSearchSource {
+    Reframework,
+    Skin,
+    Archive, // Only known from a retained archive; not in the registry
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModSearchHit {
+    pub mod_name: String,
+    pub mod_id: Option<String>, // Directory name (REFramework) or source path (skin); None for archives
+    pub source: ModSearchSource,
+    pub enabled: bool,
+    pub name_matched: bool,
+    pub matched_file_count: usize,
+    pub matched_files: Vec<String>,
+    pub archives: Vec<String>, // Retained archives that contributed matches
+}
+
+fn get_index_path(staging_root: &Path) -> PathBuf {
+    get_archives_dir(staging_root).join(INDEX_FILE)
+}
+
+fn load_index(staging_root: &Path) -> ContentIndex {
+    let path = get_index_path(staging_root);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return ContentIndex::default();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        warn!("Rebuilding unreadable content index {}: {}", path.display(), e);
+        ContentIndex::default()
+    })
+}
+
+fn save_index(staging_root: &Path, index: &ContentIndex) -> Result<(), String> {
+    let content = serde_json::to_string(index)
+        .map_err(|e| format!("Failed to serialize content index: {}", e))?;
+    fs::write(get_index_path(staging_root), content)
+        .map_err(|e| format!("Failed to write content index: {}", e))
+}
+
+fn file_stamp(path: &Path) -> Option<(u64, i64)> {
+    let meta = fs::metadata(path).ok()?;
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    Some((meta.len(), modified))
+}
+
+/// Every file entry of a zip archive
+pub fn list_archive_files(zip_path: &Path) -> Result<Vec<String>, String> {
+    let file = fs::File::open(zip_path)
+        .map_err(|e| format!("Failed to open {}: {}", zip_path.display(), e))?;
+    let archive = ZipArchive::new(file)
+        .map_err(|e| format!("Invalid zip archive {}: {}", zip_path.display(), e))?;
+    Ok(archive
+        .file_names()
+        .filter(|n| !n.ends_with('/') && !n.ends_with('\\'))
+        .map(|n| n.replace('\\', "/"))
+        .collect())
+}
+
+/// Bring the index in line with the archives dir: list new or changed archives and drop
+/// entries for archives that are gone. Returns the index, saved if anything changed.
+pub(crate) fn refresh_content_index(staging_root: &Path) -> Result<ContentIndex, String> {
+    let archives_dir = get_archives_dir(staging_root);
+    let mut index = load_index(staging_root);
+    let Ok(entries) = fs::read_dir(&archives_dir) else {
+        return Ok(index);
+    };
+    let archives: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("zip")))
+        .collect();
+
+    let before = index.archives.len();
+    index.archives.retain(|entry| {
+        archives
+            .iter()
+            .any(|p| p.file_name().is_some_and(|n| n.to_string_lossy() == entry.archive))
+    });
+    let mut changed = index.archives.len() != before;
+    for path in archives {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let Some((size_bytes, modified_timestamp)) = file_stamp(&path) else {
+            continue;
+        };
+        let existing = index.archives.iter().position(|e| e.archive == name);
+        if let Some(i) = existing {
+            let entry = &index.archives[i];
+            if entry.size_bytes == size_bytes && entry.modified_timestamp == modified_timestamp {
+                continue;
+            }
+        }
+        let files = match list_archive_files(&path) {
+            Ok(files) => files,
+            Err(e) => {
+                warn!("Not indexing {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        let mod_paths = existing.map(|i| index.archives[i].mod_paths.clone()).unwrap_or_default();
+        let entry = ArchiveIndexEntry {
+            archive: name,
+            size_bytes,
+            modified_timestamp,
+            files,
+            mod_paths,
+        };
+        match existing {
+            Some(i) => index.archives[i] = entry,
+            None => index.archives.push(entry),
+        }
+        changed = true;
+    }
+    if changed {
+        index.archives.sort_by(|a, b| a.archive.cmp(&b.archive));
+        save_index(staging_root, &index)?;
+        info!("Content index updated: {} archive(s)", index.archives.len());
+    }
+    Ok(index)
+}
+
+/// Index a freshly retained archive and remember which mod folders it produced
+pub(crate) fn index_retained_archive(staging_root: &Path, archive_path: &Path, mod_dirs: &[PathBuf]) -> Result<(), String> {
+    let mut index = refresh_content_index(staging_root)?;
+    let name = archive_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let Some(entry) = index.archives.iter_mut().find(|e| e.archive == name) else {
+        return Ok(());
+    };
+    for dir in mod_dirs {
+        let dir = dir.to_string_lossy().to_string();
+        if !entry.mod_paths.contains(&dir) {
+            entry.mod_paths.push(dir);
+        }
+    }
+    save_index(staging_root, &index)
+}
+
+/// Registry skin mods an archive belongs to: recorded at import, or else (e.g. after the
+/// staging folder moved) guessed from the archive name and its top-level folders
+pub(crate) fn archive_skin_mods(registry: &ModRegistry, entry: &ArchiveIndexEntry) -> Vec<usize> {
+    let recorded: Vec<usize> = registry
+        .skin_mods
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| entry.mod_paths.contains(&m.base.path))
+        .map(|(i, _)| i)
+        .collect();
+    if !recorded.is_empty() {
+        return recorded;
+    }
+    let stem = Path::new(&entry.archive)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    registry
+        .skin_mods
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| {
+            let dir = m.base.directory_name.to_lowercase();
+            dir == stem
+                || entry
+                    .files
+                    .iter()
+                    .any(|f| f.split('/').next().is_some_and(|top| top.to_lowercase() == dir))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Relative paths of the files under `dir` that contain `needle`
+fn matching_files_in_dir(dir: &Path, needle: &str) -> Vec<String> {
+    Walk::new(dir)
+        .files()
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(dir).ok()?.to_string_lossy().replace('\\', "/");
+            rel.to_lowercase().contains(needle).then_some(rel)
+        })
+        .collect()
+}
+
+fn add_matches(hit: &mut ModSearchHit, files: impl IntoIterator<Item = String>) {
+    for file in files {
+        if !hit.matched_files.contains(&file) {
+            hit.matched_file_count += 1;
+            if hit.matched_files.len() < MAX_MATCHED_FILES {
+                hit.matched_files.push(file);
+            }
+        }
+    }
+}
+
+/// Mods whose name, author or files match `query` (case-insensitive substring). Files are
+/// looked up in the mods' folders on disk and in the indexed archives; archives that belong to
+/// no registered mod are reported on their own.
+pub(crate) fn search_mod_contents(
+    registry: &ModRegistry,
+    game_root: &Path,
+    index: &ContentIndex,
+    query: &str,
+) -> Vec<ModSearchHit> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let name_matches = |name: &str, dir: &str, author: Option<&String>| {
+        name.to_lowercase().contains(&needle)
+            || dir.to_lowercase().contains(&needle)
+            || author.is_some_and(|a| a.to_lowercase().contains(&needle))
+    };
+
+    let mut hits: Vec<ModSearchHit> = Vec::new();
+    for m in &registry.mods {
+        let mut hit = ModSearchHit {
+            mod_name: m.name.clone(),
+            mod_id: Some(m.directory_name.clone()),
+            source: ModSearchSource::Reframework,
+            enabled: m.enabled,
+            name_matched: name_matches(&m.name, &m.directory_name, m.author.as_ref()),
+            matched_file_count: 0,
+            matched_files: Vec::new(),
+            archives: Vec::new(),
+        };
+        if let Some(dir) = resolve_reframework_mod_dir(game_root, m) {
+            add_matches(&mut hit, matching_files_in_dir(&dir, &needle));
+        }
+        hits.push(hit);
+    }
+    let skin_start = hits.len();
+    for sm in &registry.skin_mods {
+        let mut hit = ModSearchHit {
+            mod_name: sm.base.name.clone(),
+            mod_id: Some(sm.base.path.clone()),
+            source: ModSearchSource::Skin,
+            enabled: sm.base.enabled,
+            name_matched: name_matches(&sm.base.name, &sm.base.directory_name, sm.base.author.as_ref()),
+            matched_file_count: 0,
+            matched_files: Vec::new(),
+            archives: Vec::new(),
+        };
+        let dir = Path::new(&sm.base.path);
+        if dir.is_dir() {
+            add_matches(&mut hit, matching_files_in_dir(dir, &needle));
+        }
+        hits.push(hit);
+    }
+
+    for entry in &index.archives {
+        let matched: Vec<String> = entry
+            .files
+            .iter()
+            .filter(|f| f.to_lowercase().contains(&needle))
+            .cloned()
+            .collect();
+        let archive_name_matched = entry.archive.to_lowercase().contains(&needle);
+        if matched.is_empty() && !archive_name_matched {
+            continue;
+        }
+        let owners = archive_skin_mods(registry, entry);
+        if owners.is_empty() {
+            let mut hit = ModSearchHit {
+                mod_name: Path::new(&entry.archive)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_else(|| entry.archive.clone()),
+                mod_id: None,
+                source: ModSearchSource::Archive,
+                enabled: false,
+                name_matched: archive_name_matched,
+                matched_file_count: 0,
+                matched_files: Vec::new(),
+                archives: vec![entry.archive.clone()],
+            };
+            add_matches(&mut hit, matched);
+            hits.push(hit);
+            continue;
+        }
+        for owner in owners {
+            let hit = &mut hits[skin_start + owner];
+            if !matched.is_empty() {
+                // Archive paths carry the mod folder; strip it so they line up with the disk copy
+                let dir = registry.skin_mods[owner].base.directory_name.to_lowercase();
+                add_matches(
+                    hit,
+                    matched.iter().map(|f| match f.split_once('/') {
+                        Some((top, rest)) if top.to_lowercase() == dir => rest.to_string(),
+                        _ => f.clone(),
+                    }),
+                );
+                hit.archives.push(entry.archive.clone());
+            }
+        }
+    }
+
+    hits.retain(|h| h.name_matched || h.matched_file_count > 0);
+    hits.sort_by(|a, b| {
+        b.name_matched
+            .cmp(&a.name_matched)
+            .then(b.matched_file_count.cmp(&a.matched_file_count))
+            .then(a.mod_name.to_lowercase().cmp(&b.mod_name.to_lowercase()))
+    });
+    hits
+}
+
+/// Search mods by name, author, or the files they contain -- including mods that are
+/// disabled or only kept as a retained archive
+#[tauri::command]
+pub async fn search_installed_mods(
+    app_handle: AppHandle,
+    game_root_path: String,
+    query: String,
+) -> Result<Vec<ModSearchHit>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let index = refresh_content_index(&get_staging_root(&app_handle, &game_root))?;
+    let hits = search_mod_contents(&registry, &game_root, &index, &query);
+    info!("Search for '{}' matched {} mod(s)", query, hits.len());
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn search_finds_files_in_mods_and_retained_archives() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        game.install_skin(&mut registry, "FirstSkin", b"first");
+        let archives_dir = get_archives_dir(&game.staging_root());
+        fs::create_dir_all(&archives_dir).unwrap();
+        fs::copy(game.root().join("fixtures/FirstSkin.zip"), archives_dir.join("FirstSkin.zip")).unwrap();
+        // A mod that was deleted but whose archive is still kept
+        let gone = game.fixture_zip("GoneSkin.zip", &[("GoneSkin/natives/STM/ch03_002_0011.mesh", b"mesh")]);
+        fs::copy(&gone, archives_dir.join("GoneSkin.zip")).unwrap();
+
+        let index = refresh_content_index(&game.staging_root()).expect("index archives");
+        assert_eq!(index.archives.len(), 2);
+
+        let hits = search_mod_contents(&registry, game.root(), &index, "CH03_002");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].source, ModSearchSource::Archive);
+        assert_eq!(hits[0].mod_name, "GoneSkin");
+        assert_eq!(hits[0].matched_files, vec!["GoneSkin/natives/STM/ch03_002_0011.mesh".to_string()]);
+
+        // The same file on disk and in the mod's archive counts once
+        let hits = search_mod_contents(&registry, game.root(), &index, "tex.tex");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].source, ModSearchSource::Skin);
+        assert_eq!(hits[0].matched_file_count, 1);
+        assert_eq!(hits[0].archives, vec!["FirstSkin.zip".to_string()]);
+    }
+}
@@ -0,0 +1,130 @@
+// src-tauri/src/utils/crashreport.rs
+// Panic hook that leaves a crash log behind and makes sure a panic can't leave an invisible
+// process running. The next launch picks up the marker and reports it through get_startup_state.
+use log::{error, info, warn};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+const CRASH_DIR: &str = "crashes";
+const CRASH_MARKER_FILE: &str = "last_crash.json";
+/// Exit code used when the process is taken down after a panic
+const PANIC_EXIT_CODE: i32 = 101;
+
+/// Written next to the crash logs when a panic happens, removed on the next start
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrashMarker {
+    pub timestamp: i64,
+    pub app_version: String,
+    pub message: String,
+    pub log_path: String,
+}
+
+/// The previous session's crash, taken from disk once at startup
+static LAST_CRASH: OnceCell<Option<CrashMarker>> = OnceCell::new();
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_string());
+    match info.location() {
+        Some(location) => format!("{} at {}:{}", payload, location.file(), location.line()),
+        None => payload,
+    }
+}
+
+/// Write the crash log and the marker pointing at it
+fn write_crash_report(crash_dir: &Path, info: &PanicHookInfo) -> Result<PathBuf, String> {
+    fs::create_dir_all(crash_dir)
+        .map_err(|e| format!("Failed to create crash directory: {}", e))?;
+    let now = chrono::Local::now();
+    let message = panic_message(info);
+    let thread = std::thread::current();
+    let log_path = crash_dir.join(format!("crash-{}.log", now.format("%Y%m%d-%H%M%S")));
+    let report = format!(
+        "FOSS Mod Manager {} crashed at {}\nOS: {} {}\nThread: {}\nPanic: {}\n\nBacktrace:\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        now.to_rfc3339(),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        thread.name().unwrap_or("<unnamed>"),
+        message,
+        Backtrace::force_capture()
+    );
+    fs::write(&log_path, report).map_err(|e| format!("Failed to write crash log: {}", e))?;
+
+    let marker = CrashMarker {
+        timestamp: now.timestamp(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        message,
+        log_path: log_path.to_string_lossy().into_owned(),
+    };
+    let content = serde_json::to_string_pretty(&marker)
+        .map_err(|e| format!("Failed to serialize crash marker: {}", e))?;
+    fs::write(crash_dir.join(CRASH_MARKER_FILE), content)
+        .map_err(|e| format!("Failed to write crash marker: {}", e))?;
+    Ok(log_path)
+}
+
+/// A panic on a background thread is survivable as long as the user can still see a window
+/// to quit from. Panics on the main thread, or with every window hidden, end the process.
+fn should_exit(app_handle: &AppHandle) -> bool {
+    if std::thread::current().name() == Some("main") {
+        return true;
+    }
+    !app_handle
+        .webview_windows()
+        .values()
+        .any(|w| w.is_visible().unwrap_or(false))
+}
+
+/// Pick up the previous session's crash marker and install the panic hook. Call first in setup.
+pub fn init_crash_reporting(app_handle: &AppHandle) {
+    let crash_dir = match app_handle.path().app_data_dir() {
+        Ok(dir) => dir.join(CRASH_DIR),
+        Err(e) => {
+            warn!("Crash reporting disabled, no app data dir: {}", e);
+            return;
+        }
+    };
+
+    let marker_path = crash_dir.join(CRASH_MARKER_FILE);
+    let last_crash = fs::read_to_string(&marker_path)
+        .ok()
+        .and_then(|c| serde_json::from_str::<CrashMarker>(&c).ok());
+    if let Some(crash) = &last_crash {
+        warn!("Previous session crashed: {} (log: {})", crash.message, crash.log_path);
+        if let Err(e) = fs::remove_file(&marker_path) {
+            warn!("Failed to clear crash marker: {}", e);
+        }
+    }
+    let _ = LAST_CRASH.set(last_crash);
+
+    let handle = app_handle.clone();
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        match write_crash_report(&crash_dir, info) {
+            Ok(path) => error!("Panic: {} (crash log: {})", panic_message(info), path.display()),
+            Err(e) => error!("Panic: {} ({})", panic_message(info), e),
+        }
+        log::logger().flush();
+        default_hook(info);
+        if should_exit(&handle) {
+            log::logger().flush();
+            std::process::exit(PANIC_EXIT_CODE);
+        }
+    }));
+    info!("Panic hook installed");
+}
+
+/// The crash recorded by the previous session, if it ended in one
+pub fn last_crash() -> Option<CrashMarker> {
+    LAST_CRASH.get().cloned().flatten()
+}
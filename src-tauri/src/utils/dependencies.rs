@@ -0,0 +1,181 @@
+// src-tauri/src/utils/dependencies.rs
+// Declared dependencies between mods, used to keep libraries from being disabled out from
+// under the mods that need them.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+use crate::utils::modregistry::{
+    disable_skin_mod_files, set_reframework_mod_enabled, Mod, ModRegistry,
+};
+
+/// modinfo.ini keys that list required mods
+const DEPENDENCY_KEYS: &[&str] = &["requires", "dependencies", "dependency"];
+
+/// An enabled mod that (directly or through another mod) depends on the one being disabled
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DependentMod {
+    pub name: String,
+    pub identifier: String, // Directory name for REFramework mods, path for skin mods
+    pub is_skin: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct DependentsLeftEnabled {
+    mod_name: String,
+    dependents: Vec<DependentMod>,
+}
+
+/// Read the dependency list from a mod's modinfo.ini (comma or semicolon separated)
+pub fn read_declared_dependencies(mod_dir: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(mod_dir.join("modinfo.ini")) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with(';') && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .filter(|(key, _)| DEPENDENCY_KEYS.contains(&key.trim().to_lowercase().as_str()))
+        .flat_map(|(_, value)| value.split([',', ';']))
+        .map(|dep| dep.trim().to_string())
+        .filter(|dep| !dep.is_empty())
+        .collect()
+}
+
+/// Whether a dependency entry refers to `target`
+fn satisfies(dependency: &str, target: &Mod) -> bool {
+    let dependency = dependency.trim();
+    if let Some(id) = dependency.strip_prefix("nexus:") {
+        return id.trim().parse::<i64>().ok().is_some_and(|id| target.nexus_mod_id == Some(id));
+    }
+    dependency.eq_ignore_ascii_case(&target.name)
+        || dependency.eq_ignore_ascii_case(&target.directory_name)
+}
+
+/// Every mod in the registry with its identifier and kind
+fn all_mods(registry: &ModRegistry) -> impl Iterator<Item = (&Mod, String, bool)> {
+    registry
+        .mods
+        .iter()
+        .map(|m| (m, m.directory_name.clone(), false))
+        .chain(registry.skin_mods.iter().map(|s| (&s.base, s.base.path.clone(), true)))
+}
+
+/// Enabled mods that depend on `target`, including dependents of dependents
+pub fn find_enabled_dependents(registry: &ModRegistry, target: &Mod) -> Vec<DependentMod> {
+    let mut dependents = Vec::new();
+    let mut seen: HashSet<String> = HashSet::from([target.directory_name.clone()]);
+    let mut queue: VecDeque<&Mod> = VecDeque::from([target]);
+
+    while let Some(required) = queue.pop_front() {
+        for (candidate, identifier, is_skin) in all_mods(registry) {
+            if !candidate.enabled || seen.contains(&candidate.directory_name) {
+                continue;
+            }
+            if candidate.dependencies.iter().any(|d| satisfies(d, required)) {
+                seen.insert(candidate.directory_name.clone());
+                dependents.push(DependentMod {
+                    name: candidate.name.clone(),
+                    identifier,
+                    is_skin,
+                });
+                queue.push_back(candidate);
+            }
+        }
+    }
+    dependents
+}
+
+/// Deal with the mods depending on `target`, which has just been disabled: disable the whole
+/// chain when asked to, otherwise leave them enabled and warn the frontend through a
+/// "dependents-left-enabled" event. Returns the dependents found.
+pub fn handle_dependents_on_disable(
+    app_handle: &AppHandle,
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    target: &Mod,
+    disable_dependents: bool,
+) -> Result<Vec<DependentMod>, String> {
+    let dependents = find_enabled_dependents(registry, target);
+    if dependents.is_empty() {
+        return Ok(dependents);
+    }
+
+    if disable_dependents {
+        for dependent in &dependents {
+            if dependent.is_skin {
+                let errors = disable_skin_mod_files(registry, game_root, &dependent.identifier)?;
+                if !errors.is_empty() {
+                    warn!(
+                        "Errors removing files of '{}': {}",
+                        dependent.name,
+                        errors.join("; ")
+                    );
+                }
+            } else {
+                set_reframework_mod_enabled(registry, game_root, &dependent.identifier, false)?;
+            }
+        }
+        info!(
+            "Disabled {} mod(s) depending on '{}'",
+            dependents.len(),
+            target.name
+        );
+    } else {
+        warn!(
+            "'{}' was disabled but {} enabled mod(s) depend on it",
+            target.name,
+            dependents.len()
+        );
+        let payload = DependentsLeftEnabled {
+            mod_name: target.name.clone(),
+            dependents: dependents.clone(),
+        };
+        if let Err(e) = app_handle.emit("dependents-left-enabled", &payload) {
+            warn!("Failed to emit dependents warning: {}", e);
+        }
+    }
+    Ok(dependents)
+}
+
+/// Enabled mods that would break if this one were disabled
+#[tauri::command]
+pub async fn get_mod_dependents(
+    app_handle: AppHandle,
+    mod_identifier: String,
+) -> Result<Vec<DependentMod>, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    let target = registry
+        .find_any_mod(&mod_identifier)
+        .ok_or_else(|| format!("Mod '{}' not found in registry", mod_identifier))?;
+    Ok(find_enabled_dependents(&registry, target))
+}
+
+/// Replace a mod's dependency list, for mods that don't declare one in modinfo.ini
+#[tauri::command]
+pub async fn set_mod_dependencies(
+    app_handle: AppHandle,
+    mod_identifier: String,
+    dependencies: Vec<String>,
+) -> Result<(), String> {
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let dependencies: Vec<String> = dependencies
+        .into_iter()
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty())
+        .collect();
+    let mod_entry = registry
+        .find_any_mod_mut(&mod_identifier)
+        .ok_or_else(|| format!("Mod '{}' not found in registry", mod_identifier))?;
+    info!(
+        "Setting dependencies of '{}' to {:?}",
+        mod_entry.name, dependencies
+    );
+    mod_entry.dependencies = dependencies;
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)
+}
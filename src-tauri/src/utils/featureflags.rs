@@ -0,0 +1,93 @@
+// src-tauri/src/utils/featureflags.rs
+// Per-user switches for experimental subsystems, so they can ship dark and be tried out
+// before becoming defaults. Stored in settings.json by key, so flags a newer version added
+// survive a downgrade.
+use log::info;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::utils::settings::AppSettings;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlag {
+    SqliteRegistry,
+    SymlinkDeployment,
+    PakPacking,
+}
+
+impl FeatureFlag {
+    pub const ALL: &'static [FeatureFlag] = &[
+        FeatureFlag::SqliteRegistry,
+        FeatureFlag::SymlinkDeployment,
+        FeatureFlag::PakPacking,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            FeatureFlag::SqliteRegistry => "sqlite_registry",
+            FeatureFlag::SymlinkDeployment => "symlink_deployment",
+            FeatureFlag::PakPacking => "pak_packing",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            FeatureFlag::SqliteRegistry => "Store the mod registry in SQLite instead of JSON",
+            FeatureFlag::SymlinkDeployment => "Deploy skin mod files as symlinks instead of copies",
+            FeatureFlag::PakPacking => "Pack loose natives into a patch pak when deploying",
+        }
+    }
+
+    /// Pak packing predates the flags and stays on unless switched off
+    fn default_enabled(self) -> bool {
+        matches!(self, FeatureFlag::PakPacking)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FeatureFlagState {
+    pub flag: FeatureFlag,
+    pub description: String,
+    pub enabled: bool,
+    pub default_enabled: bool,
+}
+
+/// Whether a flag is on for this user
+pub fn is_enabled(settings: &AppSettings, flag: FeatureFlag) -> bool {
+    settings
+        .feature_flags
+        .get(flag.key())
+        .copied()
+        .unwrap_or_else(|| flag.default_enabled())
+}
+
+/// Every known flag with its current value
+pub fn flag_states(settings: &AppSettings) -> Vec<FeatureFlagState> {
+    FeatureFlag::ALL
+        .iter()
+        .map(|&flag| FeatureFlagState {
+            flag,
+            description: flag.description().to_string(),
+            enabled: is_enabled(settings, flag),
+            default_enabled: flag.default_enabled(),
+        })
+        .collect()
+}
+
+/// Turn a flag on or off; None goes back to the default
+#[tauri::command]
+pub async fn set_feature_flag(
+    app_handle: AppHandle,
+    flag: FeatureFlag,
+    enabled: Option<bool>,
+) -> Result<Vec<FeatureFlagState>, String> {
+    let mut settings = AppSettings::load(&app_handle)?;
+    match enabled {
+        Some(value) => settings.feature_flags.insert(flag.key().to_string(), value),
+        None => settings.feature_flags.remove(flag.key()),
+    };
+    settings.save(&app_handle)?;
+    info!("Feature flag '{}' set to {:?}", flag.key(), enabled);
+    Ok(flag_states(&settings))
+}
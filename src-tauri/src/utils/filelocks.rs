@@ -0,0 +1,183 @@
+// src-tauri/src/utils/filelocks.rs
+// Files the game has open can't be replaced, renamed or removed on Windows, and under Proton
+// Wine applies the same sharing rules to the game folder. A locked file usually frees up
+// within a moment (the game closing, an antivirus scan), so operations are retried with
+// backoff first; whatever stays locked makes the batch roll back instead of leaving a mod
+// half deployed.
+use log::{info, warn};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::utils::fsops::long_path;
+
+/// Prefix for errors caused by files in use, so the frontend can tell the user to close the game
+pub const FILES_LOCKED_ERROR_CODE: &str = "E_FILES_LOCKED";
+
+/// Waits between attempts on a locked file; about four seconds in all
+const LOCK_RETRY_DELAYS: &[Duration] = &[
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_millis(1000),
+    Duration::from_millis(2000),
+];
+
+/// Suffix of files set aside by `remove_files_or_roll_back` until the whole batch is clear
+const REMOVING_SUFFIX: &str = ".fmm-removing";
+
+/// Whether an IO error means another process holds the file
+#[cfg(windows)]
+pub fn is_lock_error(e: &io::Error) -> bool {
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+    const ERROR_LOCK_VIOLATION: i32 = 33;
+    const ERROR_USER_MAPPED_FILE: i32 = 1224;
+    matches!(
+        e.raw_os_error(),
+        Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION | ERROR_USER_MAPPED_FILE)
+    )
+}
+
+#[cfg(not(windows))]
+pub fn is_lock_error(e: &io::Error) -> bool {
+    const EBUSY: i32 = 16;
+    const ETXTBSY: i32 = 26;
+    matches!(e.raw_os_error(), Some(EBUSY | ETXTBSY))
+}
+
+/// Run `op`, retrying with backoff while it fails because the file is locked
+pub fn retry_if_locked<T>(path: &Path, mut op: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    let mut delays = LOCK_RETRY_DELAYS.iter();
+    loop {
+        match op() {
+            Err(e) if is_lock_error(&e) => match delays.next() {
+                Some(delay) => {
+                    info!(
+                        "{} is locked, retrying in {} ms",
+                        path.display(),
+                        delay.as_millis()
+                    );
+                    std::thread::sleep(*delay);
+                }
+                None => return Err(e),
+            },
+            result => return result,
+        }
+    }
+}
+
+/// Error for a single file that stayed locked through the retries
+pub fn locked_file_error(path: &Path, e: &io::Error) -> String {
+    format!(
+        "[{}] {} is in use, most likely by the running game: {}",
+        FILES_LOCKED_ERROR_CODE,
+        path.display(),
+        e
+    )
+}
+
+/// Error for a batch that was rolled back because of locked files
+pub fn locked_files_error(paths: &[PathBuf]) -> String {
+    let listed: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    format!(
+        "[{}] {} file(s) are in use, most likely by the running game, so nothing was changed. \
+         Close the game and try again: {}",
+        FILES_LOCKED_ERROR_CODE,
+        listed.len(),
+        listed.join(", ")
+    )
+}
+
+pub fn is_locked_files_error(message: &str) -> bool {
+    message.contains(&format!("[{}]", FILES_LOCKED_ERROR_CODE))
+}
+
+fn set_aside_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(REMOVING_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Remove `files` as one batch. Each file is first renamed aside, which is what fails while
+/// the game holds it open; if any file can't be, the ones already renamed are put back and
+/// nothing is removed. Missing files are skipped. Returns the files that were removed.
+pub fn remove_files_or_roll_back(files: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut set_aside: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let mut locked = Vec::new();
+    let mut other_error = None;
+    for path in files {
+        if !long_path(path).exists() {
+            continue;
+        }
+        let aside = set_aside_path(path);
+        match retry_if_locked(path, || fs::rename(long_path(path), long_path(&aside))) {
+            Ok(()) => set_aside.push((path.clone(), aside)),
+            Err(e) if is_lock_error(&e) => locked.push(path.clone()),
+            Err(e) => {
+                other_error = Some(format!("Failed to remove {}: {}", path.display(), e));
+                break;
+            }
+        }
+    }
+
+    if !locked.is_empty() || other_error.is_some() {
+        for (path, aside) in set_aside.iter().rev() {
+            if let Err(e) = fs::rename(long_path(aside), long_path(path)) {
+                warn!(
+                    "Failed to put back {} after a cancelled removal: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+        return Err(match other_error {
+            Some(e) => format!("{}; nothing was removed", e),
+            None => locked_files_error(&locked),
+        });
+    }
+
+    let mut removed = Vec::new();
+    for (path, aside) in set_aside {
+        match fs::remove_file(long_path(&aside)) {
+            Ok(()) => removed.push(path),
+            // Already out of the game's way; only the leftover needs cleaning up
+            Err(e) => {
+                warn!("Failed to delete {}: {}", aside.display(), e);
+                removed.push(path);
+            }
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn batch_removal_puts_files_back_when_one_cannot_go() {
+        let game = FakeGame::new();
+        let first = game.deployed("STM/Batch/first.txt");
+        let second = game.deployed("STM/Batch/second.txt");
+        fs::create_dir_all(first.parent().unwrap()).unwrap();
+        fs::write(&first, b"first").unwrap();
+        fs::write(&second, b"second").unwrap();
+        assert!(is_lock_error(&std::io::Error::from_raw_os_error(if cfg!(windows) { 32 } else { 16 })));
+
+        // Something in the way of setting the second file aside fails the batch
+        let blocker = game.deployed("STM/Batch/second.txt.fmm-removing/inside");
+        fs::create_dir_all(blocker.parent().unwrap()).unwrap();
+        fs::write(&blocker, b"").unwrap();
+        let error = remove_files_or_roll_back(&[first.clone(), second.clone()]).unwrap_err();
+        assert!(error.contains("nothing was removed"), "{}", error);
+        assert_eq!(fs::read(&first).unwrap(), b"first");
+        assert_eq!(fs::read(&second).unwrap(), b"second");
+
+        fs::remove_dir_all(blocker.parent().unwrap()).unwrap();
+        let removed = remove_files_or_roll_back(&[first.clone(), second.clone()]).expect("remove batch");
+        assert_eq!(removed.len(), 2);
+        assert!(!first.exists() && !second.exists());
+    }
+}
@@ -0,0 +1,337 @@
+// src-tauri/src/utils/fsops.rs
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+
+use crate::utils::cloudsync::{is_sync_affected, sync_interference_error};
+use crate::utils::filelocks::{is_lock_error, is_locked_files_error, locked_file_error, retry_if_locked};
+use crate::utils::hashing::{hash_file, StreamHasher};
+use crate::utils::walk::Walk;
+
+/// Extra attempts for copies in cloud-synced folders, where files are briefly locked
+const CLOUD_SYNC_RETRIES: u32 = 2;
+const CLOUD_SYNC_RETRY_DELAY: Duration = Duration::from_millis(750);
+
+/// Extended-length form of `path` (`\\?\C:\...`) so Windows APIs accept paths past MAX_PATH,
+/// which deeply nested natives folders regularly exceed. Use it for the OS call only and
+/// keep showing the original path in messages.
+#[cfg(windows)]
+pub fn long_path(path: &Path) -> PathBuf {
+    use std::path::{Component, Prefix};
+    // Verbatim paths skip normalization, so resolve separators and ".." first
+    let Ok(absolute) = std::path::absolute(path) else {
+        return path.to_path_buf();
+    };
+    let mut components = absolute.components();
+    let Some(Component::Prefix(prefix)) = components.next() else {
+        return absolute;
+    };
+    let mut extended = match prefix.kind() {
+        Prefix::Disk(_) => {
+            let mut verbatim = std::ffi::OsString::from(r"\\?\");
+            verbatim.push(prefix.as_os_str());
+            PathBuf::from(verbatim)
+        }
+        Prefix::UNC(server, share) => {
+            let mut verbatim = std::ffi::OsString::from(r"\\?\UNC\");
+            verbatim.push(server);
+            verbatim.push(r"\");
+            verbatim.push(share);
+            PathBuf::from(verbatim)
+        }
+        // Already verbatim, or a device path
+        _ => return absolute,
+    };
+    extended.push(r"\");
+    for component in components {
+        if let Component::Normal(part) = component {
+            extended.push(part);
+        }
+    }
+    extended
+}
+
+/// Paths are only length-limited on Windows
+#[cfg(not(windows))]
+pub fn long_path(path: &Path) -> PathBuf {
+    path.to_path_buf()
+}
+
+/// Recursively copy a directory tree. Fails if the destination already exists.
+pub fn copy_dir_all(from: &Path, to: &Path) -> Result<u64, String> {
+    if long_path(to).exists() {
+        return Err(format!("Destination already exists: {}", to.display()));
+    }
+    let mut copied_files = 0;
+    let long_from = long_path(from);
+    for entry in Walk::new(&long_from).include_hidden() {
+        let rel = entry
+            .path()
+            .strip_prefix(&long_from)
+            .map_err(|e| format!("Failed to resolve path in {}: {}", from.display(), e))?;
+        let target = to.join(rel);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(long_path(&target))
+                .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(long_path(parent))
+                    .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+            }
+            fs::copy(entry.path(), long_path(&target)).map_err(|e| {
+                format!("Failed to copy {} to {}: {}", entry.path().display(), target.display(), e)
+            })?;
+            copied_files += 1;
+        }
+    }
+    Ok(copied_files)
+}
+
+/// Move a file or directory, falling back to copy + delete across filesystems
+pub fn move_path(from: &Path, to: &Path) -> Result<(), String> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(long_path(parent))
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let (long_from, long_to) = (long_path(from), long_path(to));
+    if fs::rename(&long_from, &long_to).is_ok() {
+        return Ok(());
+    }
+
+    // Rename fails across devices, copy instead
+    if long_from.is_dir() {
+        copy_dir_all(from, to)?;
+        fs::remove_dir_all(&long_from)
+            .map_err(|e| format!("Failed to remove {} after copy: {}", from.display(), e))
+    } else {
+        fs::copy(&long_from, &long_to).map_err(|e| {
+            format!("Failed to copy {} to {}: {}", from.display(), to.display(), e)
+        })?;
+        fs::remove_file(&long_from)
+            .map_err(|e| format!("Failed to remove {} after copy: {}", from.display(), e))
+    }
+}
+
+/// Write `content` to a temp file next to `path`, flush it to disk and rename it into place,
+/// so a crash mid-write leaves either the old file or the new one, never half of each
+pub fn write_file_atomic(path: &Path, content: &[u8]) -> Result<(), String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("Failed to write {}: not a file path", path.display()))?;
+    let tmp_path = path.with_file_name(format!("{}.tmp", file_name.to_string_lossy()));
+    let write_tmp = || -> std::io::Result<()> {
+        let mut file = fs::File::create(long_path(&tmp_path))?;
+        file.write_all(content)?;
+        file.sync_all()
+    };
+    if let Err(e) = write_tmp() {
+        let _ = fs::remove_file(long_path(&tmp_path));
+        return Err(format!("Failed to write {}: {}", tmp_path.display(), e));
+    }
+    fs::rename(long_path(&tmp_path), long_path(path)).map_err(|e| {
+        let _ = fs::remove_file(long_path(&tmp_path));
+        format!("Failed to replace {}: {}", path.display(), e)
+    })
+}
+
+/// Run `f` over `items` on up to `workers` threads, returning results in input order
+pub fn parallel_map<T, R, F>(items: &[T], workers: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = workers.clamp(1, items.len().max(1));
+    if workers == 1 {
+        return items.iter().map(&f).collect();
+    }
+    let chunk_size = items.len().div_ceil(workers);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let f = &f;
+                scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("filesystem worker panicked"))
+            .collect()
+    })
+}
+
+/// Like `parallel_map`, but workers take one item at a time and `on_result` runs on the
+/// calling thread as each one finishes, so a slow item doesn't hold back the rest of a chunk.
+/// Results still come back in input order.
+pub fn parallel_map_streaming<T, R, F, G>(items: &[T], workers: usize, f: F, mut on_result: G) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    G: FnMut(usize, &R),
+{
+    let workers = workers.clamp(1, items.len().max(1));
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..workers {
+            let (tx, f, next) = (tx.clone(), &f, &next);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if tx.send((index, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        // Ends once every worker is done and has dropped its sender
+        drop(tx);
+        for (index, result) in rx {
+            on_result(index, &result);
+            results[index] = Some(result);
+        }
+    });
+    results.into_iter().map(|r| r.expect("filesystem worker panicked")).collect()
+}
+
+/// Copy a file in 1 MiB chunks, reporting bytes written through `on_progress` and
+/// fsyncing before returning. With `verify`, the destination is re-read and compared
+/// by SHA-256 so silent write failures (flaky external drives) surface as errors.
+/// Copies touching a cloud-synced folder are retried, then fail with `CLOUD_SYNC_ERROR_CODE`.
+pub fn copy_file_with_progress(
+    from: &Path,
+    to: &Path,
+    verify: bool,
+    on_progress: &(dyn Fn(u64) + Sync),
+) -> Result<u64, String> {
+    let synced = is_sync_affected(from) || is_sync_affected(to);
+    // (bytes reported so far, bytes copied in this attempt): retries only report new bytes
+    let progress = Mutex::new((0u64, 0u64));
+    let report = |n: u64| {
+        let Ok(mut p) = progress.lock() else {
+            return;
+        };
+        p.1 += n;
+        if p.1 > p.0 {
+            on_progress(p.1 - p.0);
+            p.0 = p.1;
+        }
+    };
+
+    let mut attempt = 0;
+    loop {
+        if let Ok(mut p) = progress.lock() {
+            p.1 = 0;
+        }
+        match copy_file_once(from, to, verify, &report) {
+            Ok(copied) => return Ok(copied),
+            // Already retried; a sync client isn't the likely culprit
+            Err(e) if is_locked_files_error(&e) => return Err(e),
+            Err(e) if synced && attempt < CLOUD_SYNC_RETRIES => {
+                attempt += 1;
+                log::warn!("Copy in cloud-synced folder failed (attempt {}), retrying: {}", attempt, e);
+                std::thread::sleep(CLOUD_SYNC_RETRY_DELAY * attempt);
+            }
+            Err(e) if synced => {
+                let affected = if is_sync_affected(to) { to } else { from };
+                return Err(sync_interference_error(affected, &e));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn copy_file_once(
+    from: &Path,
+    to: &Path,
+    verify: bool,
+    on_progress: &(dyn Fn(u64) + Sync),
+) -> Result<u64, String> {
+    let mut source = fs::File::open(long_path(from))
+        .map_err(|e| format!("Failed to open {}: {}", from.display(), e))?;
+    let mut dest = retry_if_locked(to, || fs::File::create(long_path(to))).map_err(|e| {
+        if is_lock_error(&e) {
+            return locked_file_error(to, &e);
+        }
+        format!("Failed to create {}: {}", to.display(), e)
+    })?;
+
+    let mut hasher = verify.then(StreamHasher::new);
+    let mut buffer = vec![0u8; 1024 * 1024];
+    let mut copied = 0u64;
+    loop {
+        let read = source
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read {}: {}", from.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        dest.write_all(&buffer[..read])
+            .map_err(|e| format!("Failed to write {}: {}", to.display(), e))?;
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(&buffer[..read]);
+        }
+        copied += read as u64;
+        on_progress(read as u64);
+    }
+    dest.sync_all()
+        .map_err(|e| format!("Failed to flush {} to disk: {}", to.display(), e))?;
+    drop(dest);
+
+    if let Some(hasher) = hasher {
+        let expected = hasher.finish();
+        let actual = hash_file(to)?;
+        if actual != expected {
+            let _ = fs::remove_file(long_path(to));
+            return Err(format!(
+                "Verification failed for {}: copied data doesn't match {}",
+                to.display(),
+                from.display()
+            ));
+        }
+    }
+    Ok(copied)
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_paths_get_the_extended_length_prefix() {
+        assert_eq!(
+            long_path(Path::new(r"C:\Games\MonsterHunterWilds\natives\STM\armor.tex")),
+            Path::new(r"\\?\C:\Games\MonsterHunterWilds\natives\STM\armor.tex")
+        );
+        // Separators and ".." are resolved first; verbatim paths skip that normalization
+        assert_eq!(
+            long_path(Path::new(r"C:\Games\Old\..\MonsterHunterWilds/natives")),
+            Path::new(r"\\?\C:\Games\MonsterHunterWilds\natives")
+        );
+        // Already prefixed paths are left alone
+        assert_eq!(
+            long_path(Path::new(r"\\?\C:\Games\MonsterHunterWilds")),
+            Path::new(r"\\?\C:\Games\MonsterHunterWilds")
+        );
+        // Network shares use the UNC form
+        assert_eq!(
+            long_path(Path::new(r"\\nas\games\MonsterHunterWilds\natives")),
+            Path::new(r"\\?\UNC\nas\games\MonsterHunterWilds\natives")
+        );
+        // Relative paths are resolved against the working directory
+        let relative = long_path(Path::new(r"natives\STM"));
+        assert!(relative.to_string_lossy().starts_with(r"\\?\"), "{}", relative.display());
+        assert!(relative.ends_with(r"natives\STM"));
+        assert_eq!(
+            relative,
+            long_path(&std::env::current_dir().unwrap().join(r"natives\STM"))
+        );
+    }
+}
@@ -0,0 +1,225 @@
+// src-tauri/src/utils/gamelaunch.rs
+// Starting the game from the manager. Launching directly runs the executable and reports its
+// exit to the crash tracker in safemode.rs; launching through Steam hands off to the Steam
+// client (the only way to get Proton on Linux), which gives no exit code to report.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
+
+use crate::utils::config::{find_steam_app_id, load_game_config};
+use crate::utils::prelaunch::is_process_running;
+use crate::utils::reframework::{check_reframework, ReframeworkStatus};
+use crate::utils::safemode::record_game_exit;
+use crate::utils::tempermission::mod_operations_in_flight;
+
+pub const GAME_EXECUTABLE: &str = "MonsterHunterWilds.exe";
+pub const STEAM_APP_ID: &str = "2246340";
+
+/// Flatpak id of the Steam client, for installs under ~/.var/app
+#[cfg(target_os = "linux")]
+const STEAM_FLATPAK_ID: &str = "com.valvesoftware.Steam";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchMethod {
+    Auto,   // Steam for Steam installs and always on Linux, otherwise the executable
+    Direct, // Run the executable; Windows only
+    Steam,  // steam://run/<appid> through the Steam client
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchResult {
+    pub method: LaunchMethod, // Direct or Steam, whichever was used
+    pub pid: Option<u32>,     // Game process for direct launches
+    pub command: String,      // What was run, for the log view
+}
+
+fn steam_url() -> String {
+    format!("steam://run/{}", STEAM_APP_ID)
+}
+
+/// Resolve Auto to the concrete method for this install
+fn pick_method(requested: LaunchMethod, game_root: &Path) -> LaunchMethod {
+    match requested {
+        LaunchMethod::Auto if cfg!(target_os = "linux") => LaunchMethod::Steam,
+        LaunchMethod::Auto if find_steam_app_id(game_root).is_some() => LaunchMethod::Steam,
+        LaunchMethod::Auto => LaunchMethod::Direct,
+        other => other,
+    }
+}
+
+/// The Steam client install that owns the library holding the game, falling back to the
+/// usual install locations. The library's steamapps folder is only the client's own when
+/// the game is in the default library.
+#[cfg(target_os = "linux")]
+pub fn find_steam_install(game_root: &Path) -> Option<PathBuf> {
+    let is_client = |dir: &Path| dir.join("steam.sh").is_file() || dir.join("ubuntu12_32").is_dir();
+    let library = game_root.parent()?.parent()?.parent()?;
+    if is_client(library) {
+        return Some(library.to_path_buf());
+    }
+    let home = PathBuf::from(std::env::var_os("HOME")?);
+    [
+        home.join(".steam/steam"),
+        home.join(".local/share/Steam"),
+        home.join(".var/app").join(STEAM_FLATPAK_ID).join(".local/share/Steam"),
+    ]
+    .into_iter()
+    .find(|dir| is_client(dir))
+}
+
+/// Command that opens a steam:// URL with the client found for this install
+#[cfg(target_os = "linux")]
+fn steam_command(game_root: &Path) -> Option<Command> {
+    let url = steam_url();
+    let install = find_steam_install(game_root)?;
+    if install.components().any(|c| c.as_os_str() == STEAM_FLATPAK_ID) {
+        let mut cmd = Command::new("flatpak");
+        cmd.args(["run", STEAM_FLATPAK_ID, &url]);
+        return Some(cmd);
+    }
+    let script = install.join("steam.sh");
+    let mut cmd = if script.is_file() {
+        Command::new(script)
+    } else {
+        Command::new("steam")
+    };
+    cmd.arg(url);
+    Some(cmd)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn steam_command(_game_root: &Path) -> Option<Command> {
+    None
+}
+
+fn describe(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|a| a.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn launch_through_steam(app_handle: &AppHandle, game_root: &Path) -> Result<LaunchResult, String> {
+    if let Some(mut cmd) = steam_command(game_root) {
+        let command = describe(&cmd);
+        match cmd.spawn() {
+            Ok(_) => {
+                return Ok(LaunchResult {
+                    method: LaunchMethod::Steam,
+                    pid: None,
+                    command,
+                })
+            }
+            Err(e) => warn!("Failed to run '{}': {}; handing the URL to the system instead", command, e),
+        }
+    }
+    // Whatever handles steam:// links on this system
+    let url = steam_url();
+    app_handle
+        .opener()
+        .open_url(&url, None::<&str>)
+        .map_err(|e| format!("[E_STEAM_NOT_FOUND] Failed to open {}: {}. Is Steam installed?", url, e))?;
+    Ok(LaunchResult {
+        method: LaunchMethod::Steam,
+        pid: None,
+        command: url,
+    })
+}
+
+fn launch_directly(app_handle: &AppHandle, game_root: &Path, executable: &Path) -> Result<LaunchResult, String> {
+    if cfg!(not(windows)) {
+        return Err("[E_DIRECT_LAUNCH_UNSUPPORTED] The game can only be started through Steam (Proton) on this system".to_string());
+    }
+    if !executable.is_file() {
+        return Err(format!("[E_GAME_EXE_MISSING] {} not found", executable.display()));
+    }
+    let mut child = Command::new(executable)
+        .current_dir(game_root)
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", executable.display(), e))?;
+    let pid = child.id();
+
+    // Report the exit so crash counting and safe mode work for manager launches
+    let handle = app_handle.clone();
+    let root = game_root.to_string_lossy().to_string();
+    std::thread::spawn(move || {
+        let exit_code = match child.wait() {
+            Ok(status) => status.code(),
+            Err(e) => {
+                warn!("Lost track of the game process: {}", e);
+                return;
+            }
+        };
+        info!("Game exited with code {:?}", exit_code);
+        match tauri::async_runtime::block_on(record_game_exit(handle.clone(), root, exit_code)) {
+            Ok(result) => {
+                if let Err(e) = handle.emit("game-exited", &result) {
+                    warn!("Failed to emit game exit: {}", e);
+                }
+            }
+            Err(e) => warn!("Failed to record game exit: {}", e),
+        }
+    });
+
+    Ok(LaunchResult {
+        method: LaunchMethod::Direct,
+        pid: Some(pid),
+        command: executable.display().to_string(),
+    })
+}
+
+/// Start the game. Refuses while a mod operation is still writing to the game folder or the
+/// game is already running; with `require_reframework` also when REFramework isn't usable.
+#[tauri::command]
+pub async fn launch_game(
+    app_handle: AppHandle,
+    game_root_path: String,
+    method: Option<LaunchMethod>,
+    require_reframework: Option<bool>,
+) -> Result<LaunchResult, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let executable = load_game_config(app_handle.clone())
+        .await
+        .unwrap_or(None)
+        .map(|g| PathBuf::from(g.game_executable_path))
+        .unwrap_or_else(|| game_root.join(GAME_EXECUTABLE));
+
+    let result = (|| {
+        let in_flight = mod_operations_in_flight(&app_handle);
+        if in_flight > 0 {
+            return Err(format!(
+                "[E_OPERATION_IN_FLIGHT] {} mod operation(s) are still running; launch once they finish",
+                in_flight
+            ));
+        }
+        let exe_name = executable
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| GAME_EXECUTABLE.to_string());
+        if is_process_running(&exe_name) {
+            return Err(format!("[E_GAME_RUNNING] {} is already running", exe_name));
+        }
+        if require_reframework.unwrap_or(false) {
+            let report = check_reframework(&app_handle, &game_root);
+            if matches!(
+                report.status,
+                ReframeworkStatus::NotInstalled | ReframeworkStatus::Dinput8Missing | ReframeworkStatus::IncompleteInstall
+            ) {
+                return Err(format!("[E_REFRAMEWORK_MISSING] {}", report.message));
+            }
+        }
+
+        let result = match pick_method(method.unwrap_or(LaunchMethod::Auto), &game_root) {
+            LaunchMethod::Direct => launch_directly(&app_handle, &game_root, &executable),
+            _ => launch_through_steam(&app_handle, &game_root),
+        }?;
+        info!("Launched the game ({:?}): {}", result.method, result.command);
+        Ok(result)
+    })();
+    crate::utils::telemetry::track_result(&app_handle, "launch_game", result)
+}
@@ -0,0 +1,303 @@
+// src-tauri/src/utils/gameupdate.rs
+// Game patches replace files under natives/ and add pak patches of their own, which leaves
+// enabled skin mods overwritten or loading in the wrong order. The executable's size and
+// timestamp, plus Steam's build id when there is an appmanifest, are remembered so an update
+// can be spotted and every enabled skin mod deployed again.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::config::find_steam_build_id;
+use crate::utils::filelocks::remove_files_or_roll_back;
+use crate::utils::gamelaunch::GAME_EXECUTABLE;
+use crate::utils::integrity::check_skin_mod_integrity;
+use crate::utils::modregistry::{enable_skin_mod_files, ConflictResolution, ModRegistry, ModType};
+use crate::utils::overwrites::refresh_original_backups;
+use crate::utils::pakregistry::pak_load_order;
+use crate::utils::settings::AppSettings;
+use crate::utils::telemetry::track_result;
+use crate::utils::tempermission::ModOperationGuard;
+
+const GAME_BUILD_FILE: &str = "game_build.json";
+
+/// What identifies the installed game version
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GameBuild {
+    pub exe_size: u64,
+    pub exe_modified_timestamp: i64,
+    pub steam_build_id: Option<String>, // None for non-Steam installs
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameUpdateStatus {
+    pub updated: bool,
+    pub reason: Option<String>,      // What changed, when `updated`
+    pub previous: Option<GameBuild>, // None the first time the game is checked
+    pub current: GameBuild,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RedeployFailure {
+    pub mod_name: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RedeployReport {
+    pub redeployed: Vec<String>, // Source paths of the skin mods deployed again
+    pub failed: Vec<RedeployFailure>, // Mods left disabled (or untouched) because of an error
+    pub refreshed_originals: usize, // Files the update replaced, now kept as the originals
+    pub pak_order: Vec<String>,  // Mod names by pak patch number after the redeploy
+}
+
+fn get_game_build_path(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join(GAME_BUILD_FILE)
+}
+
+/// Read the installed game's build from its executable and Steam manifest
+pub fn read_game_build(game_root: &Path) -> Result<GameBuild, String> {
+    let exe = game_root.join(GAME_EXECUTABLE);
+    let meta =
+        fs::metadata(&exe).map_err(|e| format!("Failed to read {}: {}", exe.display(), e))?;
+    let exe_modified_timestamp = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    Ok(GameBuild {
+        exe_size: meta.len(),
+        exe_modified_timestamp,
+        steam_build_id: find_steam_build_id(game_root),
+    })
+}
+
+/// The build recorded after the last deploy, if any
+pub fn load_recorded_game_build(game_root: &Path) -> Option<GameBuild> {
+    let content = fs::read_to_string(get_game_build_path(game_root)).ok()?;
+    serde_json::from_str(&content)
+        .map_err(|e| warn!("Ignoring unreadable {}: {}", GAME_BUILD_FILE, e))
+        .ok()
+}
+
+pub fn record_game_build(game_root: &Path, build: &GameBuild) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(build)
+        .map_err(|e| format!("Failed to serialize game build: {}", e))?;
+    fs::write(get_game_build_path(game_root), content)
+        .map_err(|e| format!("Failed to write {}: {}", GAME_BUILD_FILE, e))
+}
+
+/// Why `current` looks like a different game version than `previous`, if it does. The Steam
+/// build id is trusted when both have one; otherwise the executable has to have changed.
+pub fn game_update_reason(previous: &GameBuild, current: &GameBuild) -> Option<String> {
+    if let (Some(before), Some(after)) = (&previous.steam_build_id, &current.steam_build_id) {
+        return (before != after)
+            .then(|| format!("Steam build changed from {} to {}", before, after));
+    }
+    if previous.exe_size != current.exe_size {
+        return Some(format!(
+            "{} changed size ({} -> {} bytes)",
+            GAME_EXECUTABLE, previous.exe_size, current.exe_size
+        ));
+    }
+    (previous.exe_modified_timestamp != current.exe_modified_timestamp)
+        .then(|| format!("{} was modified", GAME_EXECUTABLE))
+}
+
+/// Compare the installed game with the recorded build. The first check only records it.
+pub fn check_for_game_update(game_root: &Path) -> Result<GameUpdateStatus, String> {
+    let current = read_game_build(game_root)?;
+    let previous = load_recorded_game_build(game_root);
+    if previous.is_none() {
+        record_game_build(game_root, &current)?;
+    }
+    let reason = previous
+        .as_ref()
+        .and_then(|p| game_update_reason(p, &current));
+    if let Some(reason) = &reason {
+        info!("Game update detected: {}", reason);
+    }
+    Ok(GameUpdateStatus {
+        updated: reason.is_some(),
+        reason,
+        previous,
+        current,
+    })
+}
+
+/// Deploy every enabled skin mod again, in registry order, so their paks are numbered in that
+/// order after whatever the game added. Files the game replaced under a mod become the
+/// originals to restore on disable, and which mod wins a shared file stays as it was.
+/// Only updates the in-memory registry.
+pub(crate) fn redeploy_enabled_skin_mods(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+) -> RedeployReport {
+    let mut report = RedeployReport::default();
+    let workers = settings.worker_count(game_root);
+    let game_natives_dir = settings
+        .install_targets
+        .resolve(game_root, &ModType::NativesMod);
+    let enabled: Vec<String> = registry
+        .skin_mods
+        .iter()
+        .filter(|m| m.base.enabled)
+        .map(|m| m.base.path.clone())
+        .collect();
+    let index_of = |registry: &ModRegistry, mod_path: &str| {
+        registry
+            .skin_mods
+            .iter()
+            .position(|m| m.base.path == mod_path)
+    };
+    // Deployed file -> the mod whose copy is there now
+    let winners: HashMap<String, String> = registry
+        .skin_mods
+        .iter()
+        .filter(|m| m.base.enabled)
+        .flat_map(|m| {
+            m.installed_files
+                .iter()
+                .map(move |f| (f.path.clone(), m.base.path.clone()))
+        })
+        .collect();
+
+    // Take every mod's copies out first, so the paks are numbered from scratch
+    let ledger = LedgerOperation::begin(game_root, "redeploy_mods");
+    let mut to_deploy = Vec::new();
+    for mod_path in &enabled {
+        let Some(index) = index_of(registry, mod_path) else {
+            continue;
+        };
+        let skin_mod = &registry.skin_mods[index];
+        let modified = check_skin_mod_integrity(skin_mod, workers).modified;
+        // A patch pak that changed under the mod is one the game shipped at that number
+        let pak_taken = skin_mod
+            .installed_pak_path
+            .as_ref()
+            .filter(|pak| modified.contains(*pak));
+        let replaced: Vec<PathBuf> = modified
+            .iter()
+            .filter(|p| skin_mod.installed_pak_path.as_ref() != Some(*p))
+            .map(PathBuf::from)
+            .collect();
+        if !replaced.is_empty() {
+            match refresh_original_backups(registry, game_root, mod_path, &replaced) {
+                Ok(count) => report.refreshed_originals += count,
+                Err(e) => {
+                    report.failed.push(RedeployFailure {
+                        mod_name: skin_mod.base.name.clone(),
+                        error: e,
+                    });
+                    continue;
+                }
+            }
+        }
+
+        let files: Vec<PathBuf> = skin_mod
+            .installed_files
+            .iter()
+            .filter(|f| pak_taken != Some(&f.path))
+            .map(|f| PathBuf::from(&f.path))
+            .collect();
+        match remove_files_or_roll_back(&files) {
+            Ok(removed) => {
+                for file in removed {
+                    ledger.deleted(&file, false);
+                }
+                // Not a toggle the user made, so the usage stats stay as they are
+                let skin_mod = &mut registry.skin_mods[index];
+                skin_mod.base.enabled = false;
+                skin_mod.installed_files.clear();
+                skin_mod.installed_pak_path = None;
+                to_deploy.push(mod_path.clone());
+            }
+            Err(e) => report.failed.push(RedeployFailure {
+                mod_name: skin_mod.base.name.clone(),
+                error: e,
+            }),
+        }
+    }
+
+    for mod_path in to_deploy {
+        let Some(index) = index_of(registry, &mod_path) else {
+            continue;
+        };
+        // Files another mod had won are kept; the rest go to whoever deploys them
+        let resolutions: HashMap<String, ConflictResolution> = winners
+            .iter()
+            .filter(|(_, owner)| **owner != mod_path)
+            .filter_map(|(file, _)| {
+                let rel = Path::new(file).strip_prefix(&game_natives_dir).ok()?;
+                Some((
+                    rel.to_string_lossy().replace('\\', "/"),
+                    ConflictResolution::Keep,
+                ))
+            })
+            .collect();
+        let usage = registry.skin_mods[index].base.usage.clone();
+        match enable_skin_mod_files(
+            registry,
+            game_root,
+            &mod_path,
+            settings,
+            &resolutions,
+            &|_, _| {},
+        ) {
+            Ok(()) => {
+                registry.skin_mods[index].base.usage = usage;
+                report.redeployed.push(mod_path);
+            }
+            Err(e) => {
+                warn!("Failed to redeploy '{}': {}", mod_path, e);
+                report.failed.push(RedeployFailure {
+                    mod_name: registry.skin_mods[index].base.name.clone(),
+                    error: e,
+                });
+            }
+        }
+    }
+
+    report.pak_order = pak_load_order(registry)
+        .into_iter()
+        .map(|e| e.mod_name)
+        .collect();
+    report
+}
+
+/// Whether the game was patched since mods were last deployed
+#[tauri::command]
+pub async fn detect_game_update(game_root_path: String) -> Result<GameUpdateStatus, String> {
+    check_for_game_update(&PathBuf::from(&game_root_path))
+}
+
+/// Deploy every enabled skin mod again after a game update and remember the new build
+#[tauri::command]
+pub async fn redeploy_all_enabled_mods(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<RedeployReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    let result = (|| {
+        let settings = AppSettings::load(&app_handle)?;
+        let mut registry = ModRegistry::load(&app_handle)?;
+        let report = redeploy_enabled_skin_mods(&mut registry, &game_root, &settings);
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+        record_game_build(&game_root, &read_game_build(&game_root)?)?;
+        info!(
+            "Redeployed {} skin mod(s), {} failed, {} original file(s) refreshed",
+            report.redeployed.len(),
+            report.failed.len(),
+            report.refreshed_originals
+        );
+        Ok(report)
+    })();
+    track_result(&app_handle, "redeploy_all_enabled_mods", result)
+}
@@ -0,0 +1,60 @@
+// src-tauri/src/utils/hashing.rs
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::utils::fsops::{long_path, parallel_map};
+
+/// Files are hashed in chunks of this size so multi-GB paks never land in memory
+const HASH_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Incremental SHA-256 for data that is already being streamed somewhere else (e.g. a copy)
+pub struct StreamHasher(Sha256);
+
+impl StreamHasher {
+    pub fn new() -> Self {
+        Self(Sha256::new())
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Lowercase hex digest, same format as `hash_file`
+    pub fn finish(self) -> String {
+        format!("{:x}", self.0.finalize())
+    }
+}
+
+impl Default for StreamHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SHA-256 of everything a reader yields, read in fixed-size chunks
+pub fn hash_reader(mut reader: impl Read) -> std::io::Result<String> {
+    let mut hasher = StreamHasher::new();
+    let mut buffer = vec![0u8; HASH_CHUNK_SIZE];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finish())
+}
+
+/// SHA-256 of a file, streamed from disk
+pub fn hash_file(path: &Path) -> Result<String, String> {
+    let file = fs::File::open(long_path(path))
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    hash_reader(file).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Hash many files on up to `workers` threads. Results keep the input order.
+pub fn hash_files(paths: &[PathBuf], workers: usize) -> Vec<Result<String, String>> {
+    parallel_map(paths, workers, |path| hash_file(path))
+}
@@ -0,0 +1,181 @@
+// src-tauri/src/utils/healthcheck.rs
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+use crate::utils::cloudsync::{
+    cloud_provider_for_path, find_cloud_placeholders, find_sync_conflict_copies, steam_cloud_files,
+};
+use crate::utils::restorepoints::get_restore_points_dir;
+use crate::utils::startuptiming::{startup_timing, StartupTiming, SLOW_STARTUP_MS};
+use crate::utils::walk::Walk;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum HealthSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// One problem found by the health check
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthIssue {
+    pub code: String, // Stable identifier the frontend can key help text on
+    pub severity: HealthSeverity,
+    pub message: String,
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealthReport {
+    pub checked_timestamp: i64,
+    pub issues: Vec<HealthIssue>,
+    pub startup_timing: StartupTiming,
+}
+
+/// Game or manager data inside a cloud-synced folder, or placeholder files in it
+fn check_cloud_sync(label: &str, dir: &Path, issues: &mut Vec<HealthIssue>) {
+    if let Some(provider) = cloud_provider_for_path(dir) {
+        issues.push(HealthIssue {
+            code: "cloud_synced_folder".to_string(),
+            severity: HealthSeverity::Warning,
+            message: format!(
+                "The {} is inside a {} folder. Syncing can lock or offload files mid-deployment; \
+                 exclude it from sync or move it elsewhere.",
+                label, provider
+            ),
+            paths: vec![dir.to_string_lossy().to_string()],
+        });
+    }
+    let placeholders = find_cloud_placeholders(dir);
+    if !placeholders.is_empty() {
+        issues.push(HealthIssue {
+            code: "cloud_placeholder_files".to_string(),
+            severity: HealthSeverity::Error,
+            message: format!(
+                "{} file(s) in the {} are cloud placeholders that aren't stored locally. \
+                 Mark the folder \"Always keep on this device\" before deploying mods.",
+                placeholders.len(),
+                label
+            ),
+            paths: placeholders,
+        });
+    }
+}
+
+/// Config files REFramework and its scripts write, relative to the game root
+fn managed_config_files(game_root: &Path) -> Vec<String> {
+    let data_dir = game_root.join("reframework").join("data");
+    let script_configs = Walk::new(&data_dir)
+        .include_hidden()
+        .files()
+        .map(|e| e.into_path());
+    let framework_configs = std::fs::read_dir(game_root)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.to_string_lossy().to_lowercase().ends_with("_fw_config.txt"));
+    script_configs
+        .chain(framework_configs)
+        .filter_map(|p| p.strip_prefix(game_root).ok().map(|rel| rel.to_string_lossy().replace('\\', "/")))
+        .collect()
+}
+
+/// Configs that Steam Cloud also syncs, or that a sync client has duplicated
+fn check_synced_configs(game_root: &Path, issues: &mut Vec<HealthIssue>) {
+    let cloud_files: Vec<String> = steam_cloud_files(game_root).iter().map(|f| f.to_lowercase()).collect();
+    if !cloud_files.is_empty() {
+        let synced: Vec<String> = managed_config_files(game_root)
+            .into_iter()
+            .filter(|rel| cloud_files.contains(&rel.to_lowercase()))
+            .collect();
+        if !synced.is_empty() {
+            issues.push(HealthIssue {
+                code: "steam_cloud_configs".to_string(),
+                severity: HealthSeverity::Warning,
+                message: format!(
+                    "{} REFramework config file(s) are also synced by Steam Cloud. Steam may overwrite \
+                     them with another machine's copy on launch; keep only one PC's settings or turn off \
+                     Steam Cloud for this game.",
+                    synced.len()
+                ),
+                paths: synced
+                    .iter()
+                    .map(|rel| game_root.join(rel).to_string_lossy().to_string())
+                    .collect(),
+            });
+        }
+    }
+
+    let duplicates = find_sync_conflict_copies(&game_root.join("reframework"));
+    if !duplicates.is_empty() {
+        issues.push(HealthIssue {
+            code: "sync_conflict_copies".to_string(),
+            severity: HealthSeverity::Warning,
+            message: format!(
+                "{} REFramework file(s) look like conflict copies left by a sync client. Scripts ignore \
+                 them, so settings saved in them are lost; merge what you need and delete the copies.",
+                duplicates.len()
+            ),
+            paths: duplicates,
+        });
+    }
+}
+
+/// Restore point snapshots kept inside a synced folder
+fn check_restore_points_location(points_dir: &Path, issues: &mut Vec<HealthIssue>) {
+    if let Some(provider) = cloud_provider_for_path(points_dir) {
+        issues.push(HealthIssue {
+            code: "synced_restore_points".to_string(),
+            severity: HealthSeverity::Info,
+            message: format!(
+                "Restore points are stored in a {} folder, so every profile switch uploads a new \
+                 snapshot. Move them to an unsynced folder in settings.",
+                provider
+            ),
+            paths: vec![points_dir.to_string_lossy().to_string()],
+        });
+    }
+}
+
+/// This session took unusually long to start; name the phase that took longest
+fn check_startup_time(timing: &StartupTiming, issues: &mut Vec<HealthIssue>) {
+    let Some(total_ms) = timing.total_ms().filter(|ms| *ms > SLOW_STARTUP_MS) else {
+        return;
+    };
+    let slowest = timing
+        .slowest_phase()
+        .map(|p| format!(" The slowest step was {} ({} ms).", p.name, p.duration_ms))
+        .unwrap_or_default();
+    issues.push(HealthIssue {
+        code: "slow_startup".to_string(),
+        severity: HealthSeverity::Info,
+        message: format!("Startup took {:.1} s.{}", total_ms as f64 / 1000.0, slowest),
+        paths: Vec::new(),
+    });
+}
+
+/// Check the game and manager folders for conditions known to break modding
+#[tauri::command]
+pub async fn run_health_check(app_handle: AppHandle, game_root_path: String) -> Result<HealthReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let mut issues = Vec::new();
+
+    check_cloud_sync("game folder", &game_root, &mut issues);
+    if let Ok(config_dir) = app_handle.path().app_config_dir() {
+        check_cloud_sync("manager config folder", &config_dir, &mut issues);
+    }
+    check_synced_configs(&game_root, &mut issues);
+    check_restore_points_location(&get_restore_points_dir(&app_handle, &game_root), &mut issues);
+    let timing = startup_timing();
+    check_startup_time(&timing, &mut issues);
+
+    info!("Health check found {} issue(s)", issues.len());
+    Ok(HealthReport {
+        checked_timestamp: chrono::Utc::now().timestamp(),
+        issues,
+        startup_timing: timing,
+    })
+}
@@ -0,0 +1,148 @@
+// src-tauri/src/utils/httpidentity.rs
+// How the manager identifies itself to the services it talks to. Every outgoing request goes
+// through `http_client` so they all send the same versioned User-Agent, which settings can
+// override per integration.
+use log::info;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::settings::AppSettings;
+
+pub const APP_NAME: &str = "FossModManager";
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A service the manager makes requests to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Integration {
+    GitHub,      // REFramework release lookups
+    Nexus,       // Nexus Mods API and CDN
+    Downloads,   // Direct file downloads
+    KnownBroken, // Community known-broken list
+    ChecksumDb,  // Community file checksum database
+    Telemetry,   // Opt-in failure report submission
+}
+
+impl Integration {
+    pub const ALL: &'static [Integration] = &[
+        Integration::GitHub,
+        Integration::Nexus,
+        Integration::Downloads,
+        Integration::KnownBroken,
+        Integration::ChecksumDb,
+        Integration::Telemetry,
+    ];
+
+    /// Key used in the `user_agent_overrides` setting
+    pub fn key(self) -> &'static str {
+        match self {
+            Integration::GitHub => "github",
+            Integration::Nexus => "nexus",
+            Integration::Downloads => "downloads",
+            Integration::KnownBroken => "known_broken",
+            Integration::ChecksumDb => "checksum_db",
+            Integration::Telemetry => "telemetry",
+        }
+    }
+}
+
+/// User-Agent overrides from settings, by integration key. The default sends the built-in
+/// User-Agent to every integration.
+#[derive(Debug, Clone, Default)]
+pub struct HttpIdentity {
+    overrides: BTreeMap<String, String>,
+}
+
+impl HttpIdentity {
+    /// Overrides as stored in settings; blank ones are dropped
+    pub fn from_overrides(overrides: &BTreeMap<String, String>) -> Self {
+        HttpIdentity {
+            overrides: overrides
+                .iter()
+                .filter(|(_, ua)| !ua.trim().is_empty())
+                .map(|(key, ua)| (key.clone(), ua.trim().to_string()))
+                .collect(),
+        }
+    }
+}
+
+/// The identity in use, kept in managed state so saved settings apply to later requests
+pub struct HttpIdentityState(RwLock<HttpIdentity>);
+
+/// Manage the configured overrides; call once at startup
+pub fn init_http_identity(app_handle: &AppHandle) {
+    let overrides = AppSettings::load(app_handle)
+        .map(|s| s.user_agent_overrides)
+        .unwrap_or_default();
+    app_handle.manage(HttpIdentityState(RwLock::new(HttpIdentity::default())));
+    apply_user_agent_overrides(app_handle, &overrides);
+}
+
+/// The identity requests should use right now
+pub fn http_identity(app_handle: &AppHandle) -> HttpIdentity {
+    app_handle
+        .state::<HttpIdentityState>()
+        .0
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Reject overrides for unknown integrations or that aren't valid header values
+pub fn validate_user_agent_overrides(overrides: &BTreeMap<String, String>) -> Result<(), String> {
+    for (key, ua) in overrides {
+        if !Integration::ALL.iter().any(|i| i.key() == key) {
+            return Err(format!("Unknown integration '{}' in User-Agent overrides", key));
+        }
+        reqwest::header::HeaderValue::from_str(ua.trim())
+            .map_err(|e| format!("Invalid User-Agent for '{}': {}", key, e))?;
+    }
+    Ok(())
+}
+
+/// Use `overrides` for requests from now on; call whenever settings are saved
+pub(crate) fn apply_user_agent_overrides(app_handle: &AppHandle, overrides: &BTreeMap<String, String>) {
+    let identity = HttpIdentity::from_overrides(overrides);
+    if !identity.overrides.is_empty() {
+        info!(
+            "Using custom User-Agent for: {}",
+            identity.overrides.keys().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
+    *app_handle
+        .state::<HttpIdentityState>()
+        .0
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = identity;
+}
+
+/// "FossModManager/<version> (<os>; <arch>; <integration>)" unless settings override it
+pub fn user_agent(identity: &HttpIdentity, integration: Integration) -> String {
+    if let Some(custom) = identity.overrides.get(integration.key()) {
+        return custom.clone();
+    }
+    format!(
+        "{}/{} ({}; {}; {})",
+        APP_NAME,
+        APP_VERSION,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        integration.key()
+    )
+}
+
+/// The User-Agent each integration currently sends, for display
+pub fn user_agents(identity: &HttpIdentity) -> BTreeMap<String, String> {
+    Integration::ALL
+        .iter()
+        .map(|i| (i.key().to_string(), user_agent(identity, *i)))
+        .collect()
+}
+
+/// HTTP client identifying as the manager to `integration`
+pub fn http_client(identity: &HttpIdentity, integration: Integration) -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .user_agent(user_agent(identity, integration))
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
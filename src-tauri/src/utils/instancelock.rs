@@ -0,0 +1,90 @@
+// src-tauri/src/utils/instancelock.rs
+// Advisory lock guarding the registry/config files. Single-instance normally prevents two
+// copies, but AppImage and portable builds can still run side by side; only the copy
+// holding the lock may write.
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+const LOCK_FILE: &str = "instance.lock";
+const OWNER_FILE: &str = "instance.json";
+
+/// Held open for the life of the process; the OS drops the lock if we crash
+static LOCK: OnceCell<File> = OnceCell::new();
+/// Serializes acquisition attempts within this process
+static ACQUIRING: Mutex<()> = Mutex::new(());
+
+/// Who holds the lock, for the error shown to the other instance
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct LockOwner {
+    pid: u32,
+    started_timestamp: i64,
+}
+
+fn get_config_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get app config dir: {}", e))
+}
+
+/// Try to become the writing instance. Returns false when another live instance holds it.
+pub fn acquire_instance_lock(app_handle: &AppHandle) -> Result<bool, String> {
+    let _guard = ACQUIRING.lock().unwrap_or_else(|e| e.into_inner());
+    if LOCK.get().is_some() {
+        return Ok(true);
+    }
+    let config_dir = get_config_dir(app_handle)?;
+    fs::create_dir_all(&config_dir)
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    let lock_path = config_dir.join(LOCK_FILE);
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|e| format!("Failed to open {}: {}", lock_path.display(), e))?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return Ok(false),
+        Err(TryLockError::Error(e)) => {
+            return Err(format!("Failed to lock {}: {}", lock_path.display(), e))
+        }
+    }
+
+    let owner = LockOwner {
+        pid: std::process::id(),
+        started_timestamp: chrono::Utc::now().timestamp(),
+    };
+    if let Ok(content) = serde_json::to_string_pretty(&owner) {
+        if let Err(e) = fs::write(config_dir.join(OWNER_FILE), content) {
+            warn!("Failed to record lock owner: {}", e);
+        }
+    }
+    let _ = LOCK.set(file);
+    info!("Acquired instance lock (pid {})", owner.pid);
+    Ok(true)
+}
+
+/// Fail with a clear error unless this instance may write the registry and config.
+/// Retries acquisition, so a second instance can write once the first has exited.
+pub fn ensure_write_access(app_handle: &AppHandle) -> Result<(), String> {
+    if acquire_instance_lock(app_handle)? {
+        return Ok(());
+    }
+    let owner = get_config_dir(app_handle)
+        .ok()
+        .and_then(|dir| fs::read_to_string(dir.join(OWNER_FILE)).ok())
+        .and_then(|c| serde_json::from_str::<LockOwner>(&c).ok());
+    let holder = owner
+        .map(|o| format!(" (process {})", o.pid))
+        .unwrap_or_default();
+    Err(format!(
+        "Another copy of the mod manager is running{}; close it before making changes here",
+        holder
+    ))
+}
@@ -0,0 +1,149 @@
+// src-tauri/src/utils/integrity.rs
+// Compare what skin mods deployed with what is in the game folder now. A game update or
+// another tool replacing or deleting those files leaves a mod quietly broken; the hashes
+// recorded at deploy time show which files were touched.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+use crate::utils::fsops::long_path;
+use crate::utils::hashing::hash_files;
+use crate::utils::modregistry::{ModRegistry, SkinMod};
+use crate::utils::settings::AppSettings;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModIntegrityStatus {
+    Intact,     // Every hashed file still matches
+    Damaged,    // Files were modified or deleted outside the manager
+    Unverified, // Nothing missing, but no hashes were recorded to compare against
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModIntegrityReport {
+    pub mod_id: String,
+    pub mod_name: String,
+    pub status: ModIntegrityStatus,
+    pub checked: usize,        // Files compared against their recorded hash
+    pub unverified: usize,     // Files deployed before hashes were kept; only checked for presence
+    pub modified: Vec<String>, // Deployed files whose contents changed
+    pub missing: Vec<String>,  // Deployed files that are gone
+}
+
+/// Check one enabled skin mod's deployed files against the hashes recorded when it was enabled
+pub fn check_skin_mod_integrity(skin_mod: &SkinMod, workers: usize) -> ModIntegrityReport {
+    let mut report = ModIntegrityReport {
+        mod_id: skin_mod.base.id.clone(),
+        mod_name: skin_mod.base.name.clone(),
+        status: ModIntegrityStatus::Intact,
+        checked: 0,
+        unverified: 0,
+        modified: Vec::new(),
+        missing: Vec::new(),
+    };
+
+    let mut to_hash: Vec<(PathBuf, &str)> = Vec::new();
+    for file in &skin_mod.installed_files {
+        let path = PathBuf::from(&file.path);
+        if !long_path(&path).is_file() {
+            report.missing.push(file.path.clone());
+            continue;
+        }
+        match &file.sha256 {
+            Some(expected) => to_hash.push((path, expected.as_str())),
+            None => report.unverified += 1,
+        }
+    }
+
+    let paths: Vec<PathBuf> = to_hash.iter().map(|(path, _)| path.clone()).collect();
+    for ((path, expected), actual) in to_hash.iter().zip(hash_files(&paths, workers)) {
+        report.checked += 1;
+        match actual {
+            Ok(actual) if actual == *expected => {}
+            // Unreadable counts as changed: it can't be what was deployed
+            _ => report.modified.push(path.to_string_lossy().to_string()),
+        }
+    }
+
+    report.status = if !report.modified.is_empty() || !report.missing.is_empty() {
+        ModIntegrityStatus::Damaged
+    } else if report.checked == 0 && report.unverified > 0 {
+        ModIntegrityStatus::Unverified
+    } else {
+        ModIntegrityStatus::Intact
+    };
+    report
+}
+
+/// Verify the deployed files of one mod, or of every enabled skin mod
+#[tauri::command]
+pub async fn verify_mod_integrity(
+    app_handle: AppHandle,
+    game_root_path: String,
+    identifier: Option<String>,
+) -> Result<Vec<ModIntegrityReport>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let workers = AppSettings::load(&app_handle)?.worker_count(&game_root);
+    let registry = ModRegistry::load(&app_handle)?;
+    let skin_mods: Vec<&SkinMod> = match &identifier {
+        Some(identifier) => vec![registry
+            .find_skin_mod(identifier)
+            .ok_or_else(|| format!("Skin mod '{}' not found in registry", identifier))?],
+        None => registry
+            .skin_mods
+            .iter()
+            .filter(|m| m.base.enabled)
+            .collect(),
+    };
+
+    let reports: Vec<ModIntegrityReport> = skin_mods
+        .into_iter()
+        .map(|m| check_skin_mod_integrity(m, workers))
+        .collect();
+    let damaged = reports
+        .iter()
+        .filter(|r| r.status == ModIntegrityStatus::Damaged)
+        .count();
+    info!(
+        "Verified {} skin mod(s): {} damaged",
+        reports.len(),
+        damaged
+    );
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{no_progress, FakeGame, SHARED_TEXTURE};
+    use crate::utils::modregistry::{enable_skin_mod_files, InstalledFile};
+    use std::collections::HashMap;
+    use std::fs;
+
+    #[test]
+    fn integrity_check_spots_files_changed_outside_the_manager() {
+        let game = FakeGame::new();
+        let settings = AppSettings::default();
+        let mut registry = ModRegistry::default();
+        let path = game.install_skin(&mut registry, "HashedSkin", b"hashed");
+        enable_skin_mod_files(&mut registry, game.root(), &path, &settings, &HashMap::new(), &no_progress)
+            .expect("enable skin");
+        let skin = registry.skin_mods.iter().find(|s| s.base.path == path).unwrap().clone();
+        assert!(skin.installed_files.iter().all(|f| f.sha256.is_some()));
+        assert_eq!(check_skin_mod_integrity(&skin, 2).status, ModIntegrityStatus::Intact);
+
+        // A game update replaces one file and removes the other
+        let shared = game.deployed(SHARED_TEXTURE);
+        fs::write(&shared, b"patched by the game").unwrap();
+        fs::remove_file(game.deployed("STM/HashedSkin/only.txt")).unwrap();
+        let report = check_skin_mod_integrity(&skin, 2);
+        assert_eq!(report.status, ModIntegrityStatus::Damaged);
+        assert_eq!(report.modified, vec![shared.to_string_lossy().to_string()]);
+        assert_eq!(report.missing.len(), 1);
+
+        // Entries saved before hashes were kept still load, as plain paths
+        let legacy: InstalledFile = serde_json::from_str(&serde_json::to_string(&shared).unwrap()).unwrap();
+        assert_eq!(legacy.sha256, None);
+    }
+}
@@ -0,0 +1,190 @@
+// src-tauri/src/utils/knownbroken.rs
+// List of mods known to break the current game version, fetched from the project repo and
+// cached locally so it's still enforced offline.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::utils::httpidentity::{http_client, http_identity, Integration};
+use crate::utils::modregistry::Mod;
+use crate::utils::scheduler::ScheduledJob;
+use crate::utils::settings::AppSettings;
+
+const KNOWN_BROKEN_URL: &str =
+    "https://raw.githubusercontent.com/slbillups/fossmodmanager-MHW/main/known_broken.json";
+const KNOWN_BROKEN_CACHE_FILE: &str = "known_broken.json";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Prefix for errors raised when enabling a blocked mod, so the frontend can offer the override
+pub const KNOWN_BROKEN_ERROR_CODE: &str = "E_KNOWN_BROKEN";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum KnownBrokenSeverity {
+    Warn,  // Enable anyway, but tell the user
+    Block, // Refuse unless the user explicitly overrides
+}
+
+/// One known-broken mod. Matched by Nexus mod id when available, otherwise by name.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KnownBrokenEntry {
+    #[serde(default)]
+    pub nexus_mod_id: Option<i64>,
+    #[serde(default)]
+    pub name: Option<String>, // Case-insensitive, compared to the mod's name and folder name
+    #[serde(default)]
+    pub versions: Vec<String>, // Affected mod versions; empty means every version
+    pub reason: String,
+    #[serde(default)]
+    pub alternative: Option<String>,
+    pub severity: KnownBrokenSeverity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct KnownBrokenList {
+    #[serde(default)]
+    pub updated_timestamp: i64,
+    #[serde(default)]
+    pub entries: Vec<KnownBrokenEntry>,
+}
+
+/// Sent to the frontend when a mod on the list was enabled anyway
+#[derive(Debug, Serialize, Clone)]
+pub struct KnownBrokenWarning {
+    pub mod_name: String,
+    pub entry: KnownBrokenEntry,
+}
+
+impl KnownBrokenEntry {
+    fn matches(&self, mod_entry: &Mod) -> bool {
+        let identified = match (self.nexus_mod_id, mod_entry.nexus_mod_id) {
+            (Some(listed), Some(installed)) => listed == installed,
+            _ => self.name.as_ref().is_some_and(|name| {
+                name.eq_ignore_ascii_case(&mod_entry.name)
+                    || name.eq_ignore_ascii_case(&mod_entry.directory_name)
+            }),
+        };
+        identified
+            && (self.versions.is_empty()
+                || mod_entry
+                    .version
+                    .as_ref()
+                    .is_some_and(|v| self.versions.iter().any(|listed| listed == v)))
+    }
+}
+
+/// The list entry covering a mod, if any
+pub fn find_known_broken<'a>(list: &'a KnownBrokenList, mod_entry: &Mod) -> Option<&'a KnownBrokenEntry> {
+    list.entries.iter().find(|e| e.matches(mod_entry))
+}
+
+fn get_cache_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to get app config dir: {}", e))?;
+    Ok(config_dir.join(KNOWN_BROKEN_CACHE_FILE))
+}
+
+/// The cached list; empty if it has never been fetched
+pub fn load_known_broken(app_handle: &AppHandle) -> KnownBrokenList {
+    let Ok(path) = get_cache_path(app_handle) else {
+        return KnownBrokenList::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable known-broken list {}: {}", path.display(), e);
+            KnownBrokenList::default()
+        }),
+        Err(_) => KnownBrokenList::default(),
+    }
+}
+
+/// Download the latest list and replace the cached copy
+async fn fetch_known_broken(app_handle: &AppHandle) -> Result<KnownBrokenList, String> {
+    let response = http_client(&http_identity(app_handle), Integration::KnownBroken)?
+        .get(KNOWN_BROKEN_URL)
+        .timeout(FETCH_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch known-broken list: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("Failed to fetch known-broken list: {}", e))?;
+    let list: KnownBrokenList = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse known-broken list: {}", e))?;
+
+    let path = get_cache_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(&list)
+        .map_err(|e| format!("Failed to serialize known-broken list: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write known-broken list: {}", e))?;
+    info!("Updated known-broken list ({} entries)", list.entries.len());
+    Ok(list)
+}
+
+/// Check a mod against the list before enabling it. Blocked mods fail with a
+/// KNOWN_BROKEN_ERROR_CODE error unless `allow_known_broken` is set; anything else on the
+/// list is allowed and reported through a "known-broken-mod-enabled" event.
+pub fn check_before_enable(
+    app_handle: &AppHandle,
+    mod_entry: &Mod,
+    allow_known_broken: bool,
+) -> Result<(), String> {
+    let list = load_known_broken(app_handle);
+    let Some(entry) = list.entries.into_iter().find(|e| e.matches(mod_entry)) else {
+        return Ok(());
+    };
+
+    if entry.severity == KnownBrokenSeverity::Block && !allow_known_broken {
+        let alternative = entry
+            .alternative
+            .as_ref()
+            .map(|a| format!(" Suggested alternative: {}.", a))
+            .unwrap_or_default();
+        return Err(format!(
+            "[{}] '{}' is known to break the current game version: {}.{}",
+            KNOWN_BROKEN_ERROR_CODE, mod_entry.name, entry.reason, alternative
+        ));
+    }
+
+    warn!(
+        "Enabling '{}', which is on the known-broken list: {}",
+        mod_entry.name, entry.reason
+    );
+    let warning = KnownBrokenWarning {
+        mod_name: mod_entry.name.clone(),
+        entry,
+    };
+    if let Err(e) = app_handle.emit("known-broken-mod-enabled", &warning) {
+        warn!("Failed to emit known-broken warning: {}", e);
+    }
+    Ok(())
+}
+
+/// The scheduler job keeping the cached list fresh
+pub fn scheduled_known_broken_job() -> ScheduledJob {
+    ScheduledJob {
+        name: "known_broken_update",
+        interval: |_settings: &AppSettings| Some(Duration::from_secs(24 * 60 * 60)),
+        run: |app_handle: &AppHandle| {
+            tauri::async_runtime::block_on(fetch_known_broken(app_handle)).map(|_| ())
+        },
+    }
+}
+
+#[tauri::command]
+pub async fn get_known_broken_list(app_handle: AppHandle) -> Result<KnownBrokenList, String> {
+    Ok(load_known_broken(&app_handle))
+}
+
+#[tauri::command]
+pub async fn update_known_broken_list(app_handle: AppHandle) -> Result<KnownBrokenList, String> {
+    fetch_known_broken(&app_handle).await
+}
@@ -0,0 +1,213 @@
+// src-tauri/src/utils/launchargs.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+/// Archive types we can be asked to install from the command line / file association
+const ARCHIVE_EXTENSIONS: &[&str] = &["zip", "001", "z01", "rar", "7z"];
+
+/// Actions from our own command line, held in managed state until the frontend is ready
+/// to take them
+#[derive(Default)]
+pub struct PendingLaunchActions(Mutex<Vec<LaunchAction>>);
+
+impl PendingLaunchActions {
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<LaunchAction>>, String> {
+        self.0
+            .lock()
+            .map_err(|e| format!("Failed to lock pending launch actions: {}", e))
+    }
+}
+
+/// A parsed nxm:// link, e.g. nxm://monsterhunterwilds/mods/123/files/456?key=..&expires=..
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NxmLink {
+    pub url: String,
+    pub game_domain: String,
+    pub mod_id: i64,
+    pub file_id: Option<i64>,
+    pub key: Option<String>,
+    pub expires: Option<i64>,
+    pub user_id: Option<i64>,
+}
+
+/// Something a forwarded argument asks us to do
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum LaunchAction {
+    InstallArchive { path: String },
+    NxmLink { link: NxmLink },
+    Flag { name: String, value: Option<String> },
+    Unrecognized { arg: String, reason: String },
+}
+
+/// Payload of the "forwarded-launch-args" event
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ForwardedArgs {
+    pub cwd: String,
+    pub actions: Vec<LaunchAction>,
+}
+
+/// Parse an nxm:// URL into its parts
+pub fn parse_nxm_url(raw: &str) -> Result<NxmLink, String> {
+    let url = reqwest::Url::parse(raw).map_err(|e| format!("Invalid nxm link '{}': {}", raw, e))?;
+    if url.scheme() != "nxm" {
+        return Err(format!("Not an nxm link: {}", raw));
+    }
+    let game_domain = url
+        .host_str()
+        .filter(|h| !h.is_empty())
+        .ok_or_else(|| format!("nxm link is missing the game: {}", raw))?
+        .to_lowercase();
+    let segments: Vec<&str> = url.path_segments().map(|s| s.collect()).unwrap_or_default();
+    let mod_id = match segments.as_slice() {
+        ["mods", id, ..] => id
+            .parse()
+            .map_err(|_| format!("Invalid mod id '{}' in nxm link", id))?,
+        _ => return Err(format!("Unsupported nxm link (expected /mods/<id>/...): {}", raw)),
+    };
+    let file_id = match segments.as_slice() {
+        [_, _, "files", id, ..] => Some(
+            id.parse()
+                .map_err(|_| format!("Invalid file id '{}' in nxm link", id))?,
+        ),
+        _ => None,
+    };
+
+    let query = |name: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.to_string())
+    };
+    Ok(NxmLink {
+        url: raw.to_string(),
+        game_domain,
+        mod_id,
+        file_id,
+        key: query("key"),
+        expires: query("expires").and_then(|v| v.parse().ok()),
+        user_id: query("user_id").and_then(|v| v.parse().ok()),
+    })
+}
+
+fn is_archive(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| ARCHIVE_EXTENSIONS.iter().any(|a| ext.eq_ignore_ascii_case(a)))
+}
+
+/// Turn raw argv (minus the executable) into actions. Relative paths resolve against `cwd`.
+pub fn parse_launch_args(args: &[String], cwd: &Path) -> Vec<LaunchAction> {
+    let mut actions = Vec::new();
+    for arg in args {
+        if arg.is_empty() {
+            continue;
+        }
+        if arg.to_lowercase().starts_with("nxm://") {
+            actions.push(match parse_nxm_url(arg) {
+                Ok(link) => LaunchAction::NxmLink { link },
+                Err(reason) => LaunchAction::Unrecognized { arg: arg.clone(), reason },
+            });
+            continue;
+        }
+        if let Some(flag) = arg.strip_prefix("--") {
+            let (name, value) = match flag.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (flag.to_string(), None),
+            };
+            actions.push(LaunchAction::Flag { name, value });
+            continue;
+        }
+
+        let path = PathBuf::from(arg);
+        let path = if path.is_absolute() { path } else { cwd.join(path) };
+        if !path.is_file() {
+            actions.push(LaunchAction::Unrecognized {
+                arg: arg.clone(),
+                reason: "File not found".to_string(),
+            });
+        } else if is_archive(&path) {
+            actions.push(LaunchAction::InstallArchive {
+                path: path.to_string_lossy().to_string(),
+            });
+        } else {
+            actions.push(LaunchAction::Unrecognized {
+                arg: arg.clone(),
+                reason: "Not a supported archive".to_string(),
+            });
+        }
+    }
+    actions
+}
+
+/// Handle argv forwarded by a second instance: focus the main window and hand the parsed
+/// actions to the frontend, which owns install progress and the download flow.
+pub fn route_forwarded_args(app_handle: &AppHandle, argv: &[String], cwd: &str) {
+    let actions = parse_launch_args(argv.get(1..).unwrap_or_default(), Path::new(cwd));
+    info!("Second instance forwarded {} action(s): {:?}", actions.len(), actions);
+    emit_actions(app_handle, cwd, actions);
+}
+
+/// Focus the main window and send actions to the frontend as "forwarded-launch-args"
+fn emit_actions(app_handle: &AppHandle, cwd: &str, actions: Vec<LaunchAction>) {
+    if let Some(main_window) = app_handle.get_webview_window("main") {
+        let _ = main_window.unminimize();
+        let _ = main_window.set_focus();
+    }
+    if actions.is_empty() {
+        return;
+    }
+    for action in &actions {
+        if let LaunchAction::Unrecognized { arg, reason } = action {
+            warn!("Ignoring forwarded argument '{}': {}", arg, reason);
+        }
+    }
+    let payload = ForwardedArgs {
+        cwd: cwd.to_string(),
+        actions,
+    };
+    if let Err(e) = app_handle.emit("forwarded-launch-args", &payload) {
+        warn!("Failed to emit forwarded launch args: {}", e);
+    }
+}
+
+/// Make this app the nxm:// handler and pick up anything we were launched with. Links that
+/// arrive while running come through the single-instance callback (Windows/Linux) or the
+/// deep-link plugin (macOS).
+pub fn init_protocol_handler(app_handle: &AppHandle) {
+    #[cfg(any(windows, target_os = "linux"))]
+    if let Err(e) = app_handle.deep_link().register_all() {
+        warn!("Failed to register as the nxm:// handler: {}", e);
+    }
+
+    let handle = app_handle.clone();
+    app_handle.deep_link().on_open_url(move |event| {
+        let urls: Vec<String> = event.urls().iter().map(|u| u.to_string()).collect();
+        info!("Opened with {} link(s)", urls.len());
+        emit_actions(&handle, "", parse_launch_args(&urls, Path::new("")));
+    });
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let actions = parse_launch_args(&args, &cwd);
+    if !actions.is_empty() {
+        info!("Started with {} action(s): {:?}", actions.len(), actions);
+    }
+    app_handle.manage(PendingLaunchActions(Mutex::new(actions)));
+}
+
+/// Whether command-line actions (e.g. nxm downloads) are still waiting for the frontend
+pub fn has_pending_launch_actions(app_handle: &AppHandle) -> Result<bool, String> {
+    Ok(!app_handle.state::<PendingLaunchActions>().lock()?.is_empty())
+}
+
+/// Actions from the command line the app was started with; returned once
+#[tauri::command]
+pub async fn take_pending_launch_actions(
+    state: tauri::State<'_, PendingLaunchActions>,
+) -> Result<Vec<LaunchAction>, String> {
+    Ok(std::mem::take(&mut *state.lock()?))
+}
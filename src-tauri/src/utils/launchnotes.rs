@@ -0,0 +1,171 @@
+// src-tauri/src/utils/launchnotes.rs
+// Per-mod Proton/Wine requirements, combined into the Steam launch options Linux users need.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::modregistry::ModRegistry;
+
+/// REFramework is loaded as dinput8.dll, which Wine only picks up with a native override
+const REFRAMEWORK_DLL_OVERRIDE: &str = "dinput8=n,b";
+const DLL_OVERRIDES_VAR: &str = "WINEDLLOVERRIDES";
+
+/// What a mod needs from the Proton/Wine environment
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LaunchNotes {
+    #[serde(default)]
+    pub launch_options: Vec<String>, // "VAR=value" environment entries or game arguments
+    #[serde(default)]
+    pub winetricks_verbs: Vec<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModLaunchNote {
+    pub mod_name: String,
+    pub note: String,
+}
+
+/// Everything the enabled mods need, ready to paste into Steam
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RequiredLaunchOptions {
+    pub launch_options: String, // e.g. WINEDLLOVERRIDES="dinput8=n,b" %command% -dx11
+    pub winetricks_verbs: Vec<String>,
+    pub notes: Vec<ModLaunchNote>,
+    pub contributing_mods: Vec<String>,
+}
+
+/// Split "VAR=value" environment entries from plain game arguments
+fn as_env_assignment(option: &str) -> Option<(&str, &str)> {
+    let (key, value) = option.split_once('=')?;
+    let is_var = !key.is_empty()
+        && key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    is_var.then_some((key, value.trim_matches('"')))
+}
+
+/// Build the Steam launch options string. DLL overrides from different mods are merged into
+/// one WINEDLLOVERRIDES; for other variables the first mod to set one wins.
+fn build_launch_options(options: &[String]) -> String {
+    let mut env: BTreeMap<&str, String> = BTreeMap::new();
+    let mut dll_overrides: Vec<&str> = Vec::new();
+    let mut args: Vec<&str> = Vec::new();
+
+    for option in options {
+        match as_env_assignment(option) {
+            Some((DLL_OVERRIDES_VAR, value)) => {
+                for entry in value.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+                    if !dll_overrides.contains(&entry) {
+                        dll_overrides.push(entry);
+                    }
+                }
+            }
+            Some((key, value)) => {
+                env.entry(key).or_insert_with(|| value.to_string());
+            }
+            None => {
+                if !args.contains(&option.as_str()) {
+                    args.push(option);
+                }
+            }
+        }
+    }
+    if !dll_overrides.is_empty() {
+        env.insert(DLL_OVERRIDES_VAR, dll_overrides.join(";"));
+    }
+
+    let mut parts: Vec<String> = env
+        .into_iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, value))
+        .collect();
+    parts.push("%command%".to_string());
+    parts.extend(args.into_iter().map(str::to_string));
+    parts.join(" ")
+}
+
+/// Attach (or clear, with None) a mod's launch notes
+#[tauri::command]
+pub async fn set_mod_launch_notes(
+    app_handle: AppHandle,
+    mod_identifier: String,
+    notes: Option<LaunchNotes>,
+) -> Result<(), String> {
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_entry = registry
+        .find_any_mod_mut(&mod_identifier)
+        .ok_or_else(|| format!("Mod '{}' not found in registry", mod_identifier))?;
+    mod_entry.launch_notes = notes;
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)
+}
+
+/// The Steam launch options and winetricks verbs the currently enabled mods need
+#[tauri::command]
+pub async fn get_required_launch_options(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<RequiredLaunchOptions, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    let mut options = Vec::new();
+    if Path::new(&game_root_path).join("dinput8.dll").is_file() {
+        options.push(format!("{}={}", DLL_OVERRIDES_VAR, REFRAMEWORK_DLL_OVERRIDE));
+    }
+
+    let mut winetricks_verbs: Vec<String> = Vec::new();
+    let mut notes = Vec::new();
+    let mut contributing_mods = Vec::new();
+    let enabled_mods = registry
+        .mods
+        .iter()
+        .chain(registry.skin_mods.iter().map(|s| &s.base))
+        .filter(|m| m.enabled);
+    for mod_entry in enabled_mods {
+        let Some(launch_notes) = &mod_entry.launch_notes else {
+            continue;
+        };
+        contributing_mods.push(mod_entry.name.clone());
+        options.extend(launch_notes.launch_options.iter().map(|o| o.trim().to_string()));
+        for verb in &launch_notes.winetricks_verbs {
+            if !winetricks_verbs.contains(verb) {
+                winetricks_verbs.push(verb.clone());
+            }
+        }
+        if let Some(note) = launch_notes.note.as_ref().filter(|n| !n.trim().is_empty()) {
+            notes.push(ModLaunchNote {
+                mod_name: mod_entry.name.clone(),
+                note: note.clone(),
+            });
+        }
+    }
+    options.retain(|o| !o.is_empty());
+
+    let launch_options = build_launch_options(&options);
+    info!(
+        "Required launch options from {} mod(s): {}",
+        contributing_mods.len(),
+        launch_options
+    );
+    Ok(RequiredLaunchOptions {
+        launch_options,
+        winetricks_verbs,
+        notes,
+        contributing_mods,
+    })
+}
+
+/// The Proton prefix the game runs in (steamapps/compatdata/<appid>/pfx), on Linux only
+pub fn proton_prefix(game_root: &Path) -> Option<PathBuf> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let app_id = crate::utils::config::find_steam_app_id(game_root)?;
+    let prefix = game_root
+        .parent()?
+        .parent()?
+        .join("compatdata")
+        .join(app_id)
+        .join("pfx");
+    prefix.is_dir().then_some(prefix)
+}
@@ -0,0 +1,299 @@
+// src-tauri/src/utils/legacyimport.rs
+// Explicit importer for installs made by very early versions, which kept their mod list in a
+// modlist.json next to the config (or in the game folder) instead of mod_registry.json.
+// Unlike the silent migration in ModRegistry::load, every entry is checked against the disk
+// and the outcome reported back to the user.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+use crate::utils::backups::create_backup;
+use crate::utils::hashing::hash_file;
+use crate::utils::modregistry::{new_skin_mod_from_dir, InstalledFile, ModRegistry, ModType, SkinMod};
+use crate::utils::pakregistry::list_patch_paks;
+use crate::utils::settings::AppSettings;
+use crate::utils::walk::Walk;
+
+const LEGACY_MODLIST_FILE: &str = "modlist.json";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum LegacyImportStatus {
+    Imported,
+    AlreadyRegistered, // The registry already has a mod with this identifier
+    MissingOnDisk,     // Listed in the old file but its files are gone; not imported
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LegacyImportItem {
+    pub name: String,
+    pub is_skin: bool,
+    pub status: LegacyImportStatus,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LegacyImportReport {
+    pub sources: Vec<String>, // Legacy files that were read (renamed to *.imported afterwards)
+    pub imported: usize,
+    pub already_registered: usize,
+    pub missing: usize,
+    pub items: Vec<LegacyImportItem>,
+    pub errors: Vec<String>,
+}
+
+/// Every place an early version may have written modlist.json
+fn legacy_modlist_candidates(app_handle: &AppHandle, game_root: &Path) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Ok(dir) = app_handle.path().app_config_dir() {
+        candidates.push(dir.join(LEGACY_MODLIST_FILE));
+    }
+    if let Ok(dir) = app_handle.path().app_data_dir() {
+        candidates.push(dir.join(LEGACY_MODLIST_FILE));
+    }
+    candidates.push(game_root.join("fossmodmanager").join(LEGACY_MODLIST_FILE));
+    candidates.push(game_root.join(LEGACY_MODLIST_FILE));
+    candidates.dedup();
+    candidates.into_iter().filter(|p| p.is_file()).collect()
+}
+
+/// Work out what a legacy skin mod left in the game folder: the natives files that match
+/// its own, and the patch pak with the same content as its pak (if any)
+fn find_deployed_skin_files(skin: &SkinMod, game_root: &Path, settings: &AppSettings) -> (Vec<String>, Option<String>) {
+    let mod_dir = Path::new(&skin.base.path);
+    let natives_prefix = mod_dir.join("natives");
+    let game_natives_dir = settings.install_targets.resolve(game_root, &ModType::NativesMod);
+    let mut installed: Vec<String> = Walk::new(&natives_prefix)
+        .files()
+        .filter_map(|e| e.path().strip_prefix(&natives_prefix).ok().map(|rel| game_natives_dir.join(rel)))
+        .filter(|dest| dest.is_file())
+        .map(|dest| dest.to_string_lossy().to_string())
+        .collect();
+
+    let root_pak = fs::read_dir(mod_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .find(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pak")));
+    let mut installed_pak = None;
+    if let Some(root_pak) = root_pak {
+        let pak_dir = settings.install_targets.resolve(game_root, &ModType::SkinMod);
+        let size = fs::metadata(&root_pak).map(|m| m.len()).unwrap_or(0);
+        let source_hash = hash_file(&root_pak).ok();
+        installed_pak = list_patch_paks(&pak_dir)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| p.enabled && p.size_bytes == size)
+            .find(|p| source_hash.is_some() && hash_file(Path::new(&p.path)).ok() == source_hash)
+            .map(|p| p.path);
+        if let Some(pak) = &installed_pak {
+            installed.push(pak.clone());
+        }
+    }
+    (installed, installed_pak)
+}
+
+/// Merge a parsed legacy list into `registry`, verifying each entry against the disk
+pub(crate) fn import_legacy_registry(
+    legacy: ModRegistry,
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+    report: &mut LegacyImportReport,
+) {
+    for mut legacy_mod in legacy.mods {
+        let mut item = LegacyImportItem {
+            name: legacy_mod.name.clone(),
+            is_skin: false,
+            status: LegacyImportStatus::Imported,
+            detail: String::new(),
+        };
+        let enabled_dir = game_root.join(&legacy_mod.installed_directory);
+        let disabled_dir = game_root.join(format!("{}.disabled", legacy_mod.installed_directory));
+        if registry.find_mod(&legacy_mod.directory_name).is_some() {
+            item.status = LegacyImportStatus::AlreadyRegistered;
+            item.detail = "Already in the mod registry".to_string();
+        } else if enabled_dir.is_dir() || disabled_dir.is_dir() {
+            legacy_mod.enabled = enabled_dir.is_dir();
+            item.detail = format!(
+                "Found at {} ({})",
+                legacy_mod.installed_directory,
+                if legacy_mod.enabled { "enabled" } else { "disabled" }
+            );
+            registry.add_mod(legacy_mod);
+        } else {
+            item.status = LegacyImportStatus::MissingOnDisk;
+            item.detail = format!("{} no longer exists", legacy_mod.installed_directory);
+        }
+        report.items.push(item);
+    }
+
+    for legacy_skin in legacy.skin_mods {
+        let mut item = LegacyImportItem {
+            name: legacy_skin.base.name.clone(),
+            is_skin: true,
+            status: LegacyImportStatus::Imported,
+            detail: String::new(),
+        };
+        let source_dir = PathBuf::from(&legacy_skin.base.path);
+        if registry.find_skin_mod(&legacy_skin.base.directory_name).is_some()
+            || registry.skin_mods.iter().any(|m| m.base.path == legacy_skin.base.path)
+        {
+            item.status = LegacyImportStatus::AlreadyRegistered;
+            item.detail = "Already in the mod registry".to_string();
+        } else if source_dir.is_dir() {
+            // Rebuild the file list from disk, keeping what the old list knew about the mod
+            let mut skin = new_skin_mod_from_dir(&source_dir, "legacy_import");
+            skin.base.name = legacy_skin.base.name.clone();
+            skin.base.author = legacy_skin.base.author.or(skin.base.author);
+            skin.base.version = legacy_skin.base.version.or(skin.base.version);
+            skin.base.description = legacy_skin.base.description.or(skin.base.description);
+            skin.thumbnail_path = legacy_skin.thumbnail_path.or(skin.thumbnail_path);
+            if legacy_skin.base.enabled {
+                let (installed_files, installed_pak_path) = find_deployed_skin_files(&skin, game_root, settings);
+                // Only claim it's enabled if something it ships is actually deployed
+                skin.base.enabled = !installed_files.is_empty();
+                item.detail = if skin.base.enabled {
+                    format!("Enabled, {} deployed file(s) found", installed_files.len())
+                } else {
+                    "Listed as enabled but none of its files are deployed; imported as disabled".to_string()
+                };
+                skin.installed_files = installed_files.iter().map(|f| InstalledFile::record(Path::new(f))).collect();
+                skin.installed_pak_path = installed_pak_path;
+            } else {
+                skin.base.enabled = false;
+                item.detail = "Imported as disabled".to_string();
+            }
+            registry.add_skin_mod(skin);
+        } else {
+            item.status = LegacyImportStatus::MissingOnDisk;
+            item.detail = format!("Source folder {} no longer exists", legacy_skin.base.path);
+        }
+        report.items.push(item);
+    }
+}
+
+/// Find legacy modlist.json files, import the mods that still exist on disk, and report what
+/// happened to each entry. Imported files are renamed to modlist.json.imported.
+#[tauri::command]
+pub async fn import_legacy_modlist(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<LegacyImportReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let mut report = LegacyImportReport::default();
+    let sources = legacy_modlist_candidates(&app_handle, &game_root);
+    if sources.is_empty() {
+        info!("No legacy modlist.json found");
+        return Ok(report);
+    }
+
+    // The import rewrites the registry, so keep a snapshot to roll back to
+    if let Err(e) = create_backup(&app_handle) {
+        warn!("Failed to back up config before the legacy import: {}", e);
+    }
+
+    let settings = AppSettings::load(&app_handle)?;
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mut read_sources = Vec::new();
+    for source in sources {
+        let parsed = fs::read_to_string(&source)
+            .map_err(|e| format!("Failed to read {}: {}", source.display(), e))
+            .and_then(|content| ModRegistry::from_legacy_content(&content));
+        match parsed {
+            Ok(legacy) => {
+                info!(
+                    "Importing {} mods and {} skins from {}",
+                    legacy.mods.len(),
+                    legacy.skin_mods.len(),
+                    source.display()
+                );
+                import_legacy_registry(legacy, &mut registry, &game_root, &settings, &mut report);
+                report.sources.push(source.to_string_lossy().to_string());
+                read_sources.push(source);
+            }
+            Err(e) => {
+                warn!("Skipping legacy mod list {}: {}", source.display(), e);
+                report.errors.push(format!("{}: {}", source.display(), e));
+            }
+        }
+    }
+
+    for item in &report.items {
+        match item.status {
+            LegacyImportStatus::Imported => report.imported += 1,
+            LegacyImportStatus::AlreadyRegistered => report.already_registered += 1,
+            LegacyImportStatus::MissingOnDisk => report.missing += 1,
+        }
+    }
+    if report.imported > 0 {
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+    }
+
+    // Only retire the old files once the registry holds their contents
+    for source in read_sources {
+        let imported_path = source.with_extension("json.imported");
+        if let Err(e) = fs::rename(&source, &imported_path) {
+            warn!("Failed to rename {}: {}", source.display(), e);
+            report.errors.push(format!("Failed to rename {}: {}", source.display(), e));
+        }
+    }
+
+    info!(
+        "Legacy import: {} imported, {} already registered, {} missing on disk",
+        report.imported, report.already_registered, report.missing
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn legacy_modlist_import_verifies_mods_on_disk() {
+        let game = FakeGame::new();
+        let settings = AppSettings::default();
+        fs::create_dir_all(game.root().join("reframework/plugins/OldPlugin.disabled")).unwrap();
+        let legacy = serde_json::json!({
+            "mods": [
+                {
+                    "parsed_name": "OldPlugin",
+                    "original_zip_name": "OldPlugin.zip",
+                    "installed_directory": "reframework/plugins/OldPlugin",
+                    "source": "local_zip",
+                    "version": "1.0"
+                },
+                {
+                    "parsed_name": "GonePlugin",
+                    "original_zip_name": "GonePlugin.zip",
+                    "installed_directory": "reframework/plugins/GonePlugin",
+                    "source": "local_zip",
+                    "version": null
+                }
+            ],
+            "skins": []
+        });
+        let parsed = ModRegistry::from_legacy_content(&legacy.to_string()).expect("parse legacy list");
+
+        let mut registry = ModRegistry::default();
+        let mut report = LegacyImportReport::default();
+        import_legacy_registry(parsed, &mut registry, game.root(), &settings, &mut report);
+
+        let statuses: Vec<_> = report.items.iter().map(|i| (i.name.as_str(), i.status.clone())).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("OldPlugin", LegacyImportStatus::Imported),
+                ("GonePlugin", LegacyImportStatus::MissingOnDisk),
+            ]
+        );
+        // Its folder carries the .disabled suffix, so it comes in disabled
+        assert!(registry.find_mod("OldPlugin").is_some_and(|m| !m.enabled));
+        assert!(registry.find_mod("GonePlugin").is_none());
+    }
+}
@@ -0,0 +1,266 @@
+// src-tauri/src/utils/metadatarefresh.rs
+// Re-pull name, author, latest version, description and picture for every mod whose Nexus or GitHub
+// origin is known, so libraries full of "Unknown author" entries can be fixed in one pass.
+// Nexus lookups go through the API cache and stop at the rate limit like update checks do.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::nexus_api::updates::registry_mod_mut;
+use crate::nexus_api::{cache_nexus_picture, fetch_mod_details_cached, NexusClient};
+use crate::utils::modregistry::{Mod, ModRegistry, ModUpdateCheck};
+use crate::{fetch_github_repo, fetch_latest_release, GitHubClient};
+
+/// Where a mod's metadata can be fetched from
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataSource {
+    Nexus { game_domain: String, mod_id: i64 },
+    GitHub { owner: String, repo: String },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetadataRefreshFailure {
+    pub mod_name: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MetadataRefreshReport {
+    pub checked: usize,                      // Mods with a usable origin
+    pub updated: Vec<String>,                // Registry identifiers whose metadata changed
+    pub failed: Vec<MetadataRefreshFailure>, // Lookups that errored
+    pub rate_limited: bool,                  // Stopped early; the rest can be refreshed later
+}
+
+/// Fetched fields, applied over the registry entry afterwards
+#[derive(Debug, Default)]
+struct FetchedMetadata {
+    name: Option<String>,
+    author: Option<String>,
+    version: Option<String>,
+    description: Option<String>,
+    thumbnail: Option<String>,
+}
+
+/// Nexus or GitHub source from an origin URL: nexusmods.com/<domain>/mods/<id> or
+/// github.com/<owner>/<repo>[/...]
+pub fn parse_origin_url(url: &str) -> Option<MetadataSource> {
+    let rest = url.trim().split("://").last()?;
+    let rest = rest.split(['?', '#']).next()?;
+    let mut parts = rest.split('/').filter(|p| !p.is_empty());
+    let host = parts.next()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    match host {
+        "nexusmods.com" => {
+            let game_domain = parts.next()?.to_string();
+            if parts.next()? != "mods" {
+                return None;
+            }
+            let mod_id = parts.next()?.parse().ok()?;
+            Some(MetadataSource::Nexus {
+                game_domain,
+                mod_id,
+            })
+        }
+        "github.com" => {
+            let owner = parts.next()?.to_string();
+            let repo = parts.next()?.trim_end_matches(".git").to_string();
+            Some(MetadataSource::GitHub { owner, repo })
+        }
+        _ => None,
+    }
+}
+
+/// The linked Nexus id wins over the origin URL
+fn metadata_source(m: &Mod, game_domain_name: &str) -> Option<MetadataSource> {
+    if let Some(mod_id) = m.nexus_mod_id {
+        return Some(MetadataSource::Nexus {
+            game_domain: game_domain_name.to_string(),
+            mod_id,
+        });
+    }
+    m.origin_url.as_deref().and_then(parse_origin_url)
+}
+
+async fn fetch_metadata(
+    app_handle: &tauri::AppHandle,
+    source: &MetadataSource,
+    nexus: &NexusClient,
+) -> Result<FetchedMetadata, String> {
+    match source {
+        MetadataSource::Nexus {
+            game_domain,
+            mod_id,
+        } => {
+            let details = fetch_mod_details_cached(nexus, game_domain, *mod_id).await?;
+            let thumbnail = match details.picture_url.as_deref() {
+                Some(url) => match cache_nexus_picture(app_handle, game_domain, *mod_id, url).await
+                {
+                    Ok(path) => Some(path),
+                    Err(e) => {
+                        warn!("Failed to cache Nexus picture for mod {}: {}", mod_id, e);
+                        None
+                    }
+                },
+                None => None,
+            };
+            Ok(FetchedMetadata {
+                name: Some(details.name),
+                author: details.author,
+                version: details.version,
+                description: details.summary,
+                thumbnail,
+            })
+        }
+        MetadataSource::GitHub { owner, repo } => {
+            let github = GitHubClient::new(app_handle);
+            let repository = fetch_github_repo(&github, owner, repo).await?;
+            // Plenty of script repos never cut a release; the rest is still worth having
+            let version = match fetch_latest_release(&github, owner, repo).await {
+                Ok(release) => Some(release.tag_name),
+                Err(e) => {
+                    info!("No release version for {}/{}: {}", owner, repo, e);
+                    None
+                }
+            };
+            Ok(FetchedMetadata {
+                name: Some(repository.name),
+                author: Some(repository.owner.login),
+                version,
+                description: repository.description,
+                thumbnail: None,
+            })
+        }
+    }
+}
+
+/// Fill in what's missing, or everything with `overwrite`. Names are only replaced with
+/// `overwrite`, since users often rename mods on purpose. Returns whether anything changed.
+fn apply_metadata(m: &mut Mod, fetched: &FetchedMetadata, overwrite: bool) -> bool {
+    fn set(field: &mut Option<String>, value: &Option<String>, overwrite: bool) -> bool {
+        let Some(value) = value.as_ref().filter(|v| !v.trim().is_empty()) else {
+            return false;
+        };
+        let missing = field.as_ref().is_none_or(|f| f.trim().is_empty());
+        if (missing || overwrite) && field.as_ref() != Some(value) {
+            *field = Some(value.clone());
+            return true;
+        }
+        false
+    }
+
+    let mut changed = false;
+    if overwrite {
+        if let Some(name) = fetched
+            .name
+            .as_ref()
+            .filter(|n| !n.trim().is_empty() && **n != m.name)
+        {
+            m.name = name.clone();
+            changed = true;
+        }
+    }
+    changed |= set(&mut m.author, &fetched.author, overwrite);
+    // The fetched version is the latest release, not the installed one, so it only feeds
+    // the update check
+    if fetched.version.is_some() {
+        m.update_check = Some(ModUpdateCheck {
+            checked_timestamp: chrono::Utc::now().timestamp(),
+            latest_version: fetched.version.clone(),
+        });
+    }
+    changed |= set(&mut m.description, &fetched.description, overwrite);
+    changed
+}
+
+/// Refresh metadata for every mod with a Nexus or GitHub origin. Only empty fields are filled
+/// unless `overwrite` is set; hitting the Nexus rate limit stops the run with what was fetched.
+#[tauri::command]
+pub async fn refresh_all_metadata(
+    app_handle: tauri::AppHandle,
+    game_domain_name: String,
+    overwrite: Option<bool>,
+) -> Result<MetadataRefreshReport, String> {
+    let overwrite = overwrite.unwrap_or(false);
+    let nexus = NexusClient::new(&app_handle)?;
+    let registry = ModRegistry::load(&app_handle)?;
+    let sources: Vec<(String, String, MetadataSource)> = registry
+        .mods
+        .iter()
+        .chain(registry.skin_mods.iter().map(|sm| &sm.base))
+        .filter_map(|m| {
+            metadata_source(m, &game_domain_name)
+                .map(|s| (m.directory_name.clone(), m.name.clone(), s))
+        })
+        .collect();
+    info!(
+        "Refreshing metadata for {} mod(s) with a known origin",
+        sources.len()
+    );
+
+    let mut report = MetadataRefreshReport {
+        checked: sources.len(),
+        ..Default::default()
+    };
+    let mut fetched = Vec::new();
+    for (directory_name, name, source) in sources {
+        match fetch_metadata(&app_handle, &source, &nexus).await {
+            Ok(metadata) => fetched.push((directory_name, metadata)),
+            Err(e) => {
+                warn!("Metadata refresh for '{}' failed: {}", name, e);
+                let rate_limited =
+                    e.contains("rate limit") || e.starts_with("[E_NEXUS_RATE_LIMITED]");
+                report.failed.push(MetadataRefreshFailure {
+                    mod_name: directory_name,
+                    error: e,
+                });
+                if rate_limited {
+                    report.rate_limited = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    // Reload so changes made while the requests were in flight aren't overwritten
+    if !fetched.is_empty() {
+        let mut registry = ModRegistry::load(&app_handle)?;
+        for (directory_name, metadata) in fetched {
+            let mut changed = registry_mod_mut(&mut registry, &directory_name)
+                .map(|m| apply_metadata(m, &metadata, overwrite))
+                .unwrap_or(false);
+            if let Some(thumbnail) = metadata.thumbnail {
+                let slot = if registry.find_mod(&directory_name).is_some() {
+                    registry
+                        .find_mod_mut(&directory_name)
+                        .map(|m| &mut m.thumbnail)
+                } else {
+                    registry
+                        .find_skin_mod_mut(&directory_name)
+                        .map(|sm| &mut sm.thumbnail_path)
+                };
+                if let Some(slot) = slot.filter(|slot| overwrite || slot.is_none()) {
+                    changed |= slot.as_ref() != Some(&thumbnail);
+                    *slot = Some(thumbnail);
+                }
+            }
+            if changed {
+                report.updated.push(directory_name);
+            }
+        }
+        if !report.updated.is_empty() {
+            registry.last_updated = chrono::Utc::now().timestamp();
+        }
+        // Saved either way for the refreshed update checks
+        registry.save(&app_handle)?;
+    }
+
+    if report.rate_limited {
+        warn!("Nexus rate limit reached during metadata refresh; run it again later for the rest");
+    }
+    info!(
+        "Metadata refresh done: {} updated, {} failed",
+        report.updated.len(),
+        report.failed.len()
+    );
+    Ok(report)
+}
@@ -1,6 +1,63 @@
+pub mod adoption;
+pub mod archives;
+pub mod auditlog;
+pub mod backendinfo;
+pub mod backups;
 pub mod cachethumbs;
+pub mod checksumdb;
+pub mod cleanup;
+pub mod cloudsync;
 pub mod config;
+pub mod confirmation;
+pub mod conflicts;
+pub mod contentindex;
+pub mod crashreport;
+pub mod dependencies;
+pub mod featureflags;
+pub mod filelocks;
+pub mod fsops;
+pub mod gamelaunch;
+pub mod gameupdate;
+pub mod hashing;
+pub mod healthcheck;
+pub mod httpidentity;
+pub mod instancelock;
+pub mod integrity;
+pub mod knownbroken;
+pub mod launchargs;
+pub mod launchnotes;
+pub mod legacyimport;
+pub mod metadatarefresh;
 pub mod modregistry;
+pub mod modwatcher;
+pub mod overwrites;
 pub mod tempermission;
 pub mod pakregistry;
-pub mod skinregistry;
\ No newline at end of file
+pub mod permissions;
+pub mod placeholders;
+pub mod posthooks;
+pub mod prelaunch;
+pub mod profiles;
+pub mod quarantine;
+pub mod reframework;
+pub mod repak;
+pub mod restorepoints;
+pub mod safemode;
+pub mod samplemod;
+pub mod sandbox;
+pub mod savebackups;
+pub mod scheduler;
+pub mod scriptdata;
+pub mod sessiondiff;
+pub mod settings;
+pub mod setupcode;
+pub mod staging;
+pub mod startuptiming;
+pub mod telemetry;
+pub mod tempdirs;
+pub mod throttle;
+pub mod thumbimport;
+pub mod trash;
+pub mod userconfigs;
+pub mod walk;
+pub mod watchdog;
\ No newline at end of file
@@ -6,11 +6,37 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
-use tauri::{AppHandle, Manager};
-use walkdir::WalkDir;
-use std::collections::{HashMap, HashSet};
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Emitter, Manager};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{BufRead, BufReader};
 
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::cachethumbs::ThumbnailCleanup;
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::dependencies::{handle_dependents_on_disable, read_declared_dependencies};
+use crate::utils::featureflags::{is_enabled, FeatureFlag};
+use crate::utils::filelocks::{
+    is_lock_error, is_locked_files_error, locked_file_error, locked_files_error,
+    remove_files_or_roll_back, retry_if_locked,
+};
+use crate::utils::fsops::{copy_file_with_progress, long_path, parallel_map_streaming, write_file_atomic};
+use crate::utils::hashing::{hash_file, hash_files};
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::knownbroken::check_before_enable;
+use crate::utils::placeholders::with_placeholder;
+use crate::utils::repak::{extract_pak_to_natives, plan_pak_extraction, PlannedExtraction};
+use crate::utils::savebackups::check_save_backup_before_enable;
+use crate::utils::launchnotes::LaunchNotes;
+use crate::utils::overwrites::{
+    back_up_overwritten_files, get_overwrite_backup_dir, overwrite_backup_key, restore_overwritten_files,
+};
+use crate::utils::settings::{AppSettings, DeploymentMode, InstallTargets};
+use crate::utils::telemetry::track_result;
+use crate::utils::tempermission::ModOperationGuard;
+use crate::utils::trash::{move_to_trash, trash_dir_for_deletes, TrashedRegistryEntry};
+use crate::utils::walk::{find_shallowest, Walk, LAYOUT_SEARCH_DEPTH};
+
 /// Core representation of a mod in the registry
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[allow(unused_imports)]
@@ -19,6 +45,8 @@ pub struct Mod {
     pub name: String,           // Display name (user-friendly)
     pub directory_name: String, // Folder name or identifier
     pub path: String,           // Original path in mods directory
+    #[serde(default)]
+    pub id: String, // Stable UUID; directory names can collide and change, this doesn't
 
     // Status
     pub enabled: bool, // Whether this mod is currently enabled
@@ -33,6 +61,122 @@ pub struct Mod {
     // File specific info
     pub installed_directory: String, // Relative path from game root
     pub mod_type: ModType,           // Type categorization
+
+    #[serde(default)]
+    pub thumbnail: Option<String>, // Cached, downscaled preview image (REFramework mods)
+    #[serde(default)]
+    pub rating: Option<u8>, // Personal 1-5 star rating
+    #[serde(default)]
+    pub usage: ModUsageStats, // Toggle history for pruning suggestions
+    #[serde(default)]
+    pub nexus_mod_id: Option<i64>, // Linked Nexus Mods page, once matched or installed from Nexus
+    #[serde(default)]
+    pub nexus_file: Option<NexusFileInfo>, // Exact Nexus file installed, when installed via the API
+    #[serde(default)]
+    pub origin_url: Option<String>, // Page the mod was installed from (Nexus, GitHub, direct URL)
+    #[serde(default)]
+    pub dependencies: Vec<String>, // Mods this one needs: names, folder names or "nexus:<id>"
+    #[serde(default)]
+    pub launch_notes: Option<LaunchNotes>, // What the mod needs from Proton/Wine to run
+    #[serde(default)]
+    pub update_check: Option<ModUpdateCheck>, // Last Nexus update check, to keep repeat checks off the API
+    #[serde(default)]
+    pub custom_thumbnail: Option<String>, // Cached copy of a user-imported preview; wins over detected images
+    #[serde(default)]
+    pub tags: Vec<String>, // User tags; "gameplay" and "cosmetic" override the gameplay guess in savebackups.rs
+}
+
+/// Result of the last update check for a Nexus-linked mod
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModUpdateCheck {
+    pub checked_timestamp: i64,
+    pub latest_version: Option<String>, // Version Nexus reported at that time
+}
+
+/// The specific file of a Nexus mod an install came from (main, optional, update, ...)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NexusFileInfo {
+    pub file_id: i64,
+    pub file_name: String,        // Archive name as uploaded to Nexus
+    pub category: Option<String>, // Nexus file category ("MAIN", "OPTIONAL", ...)
+    pub version: Option<String>,  // Version of this file, which may differ from the mod's
+}
+
+/// How often and how recently a mod has been used
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ModUsageStats {
+    pub toggle_count: u32,                   // Times the mod was enabled or disabled through the manager
+    pub last_enabled_timestamp: Option<i64>, // When it was last enabled (unix timestamp)
+}
+
+/// Fresh identifier for a new registry entry
+pub fn new_mod_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+impl Mod {
+    /// A new, disabled entry with a fresh id, installed now. Callers fill in paths and
+    /// whatever else they know with struct update syntax.
+    pub fn new(name: String, directory_name: String, mod_type: ModType, source: &str) -> Self {
+        Self {
+            name,
+            directory_name,
+            path: String::new(),
+            id: new_mod_id(),
+            enabled: false,
+            author: None,
+            version: None,
+            description: None,
+            source: Some(source.to_string()),
+            installed_timestamp: chrono::Utc::now().timestamp(),
+            installed_directory: String::new(),
+            mod_type,
+            thumbnail: None,
+            rating: None,
+            usage: ModUsageStats::default(),
+            nexus_mod_id: None,
+            nexus_file: None,
+            origin_url: None,
+            dependencies: Vec::new(),
+            launch_notes: None,
+            update_check: None,
+            custom_thumbnail: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Whether `identifier` names this mod, by id or directory name
+    pub fn is_identified_by(&self, identifier: &str) -> bool {
+        (!self.id.is_empty() && self.id == identifier) || self.directory_name == identifier
+    }
+
+    /// Key for records kept outside the registry (profiles, restore points): the id, or the
+    /// directory name for entries saved before ids existed
+    pub fn key(&self) -> &str {
+        if self.id.is_empty() {
+            &self.directory_name
+        } else {
+            &self.id
+        }
+    }
+
+    /// This mod's value in a map keyed by `key`, also matching the directory-name keys of
+    /// maps saved before ids existed
+    pub fn keyed_value<'a, V>(&self, map: &'a BTreeMap<String, V>) -> Option<&'a V> {
+        map.get(self.key()).or_else(|| map.get(&self.directory_name))
+    }
+
+    /// Change the enabled flag, recording usage stats when the state actually changes
+    pub fn set_enabled(&mut self, enable: bool) {
+        if self.enabled == enable {
+            return;
+        }
+        self.enabled = enable;
+        self.usage.toggle_count += 1;
+        if enable {
+            self.usage.last_enabled_timestamp = Some(chrono::Utc::now().timestamp());
+        }
+    }
 }
 
 /// Types of mods that can be installed
@@ -53,8 +197,75 @@ pub struct SkinMod {
     pub thumbnail_path: Option<String>, // Path to preview image
     pub conflicts: Vec<String>,         // List of other mods this conflicts with
     pub files: Vec<ModFile>,            // Individual files included in this skin mod
-    pub installed_files: Vec<String>,   // List of files installed by this mod
+    pub installed_files: Vec<InstalledFile>, // Files this mod deployed, with their hashes
     pub installed_pak_path: Option<String>, // Path to the installed (numbered) .pak file
+    #[serde(default)]
+    pub pack_natives: Option<bool>, // Per-mod override for packing loose natives into a pak on enable
+}
+
+/// A file a skin mod deployed into the game folder
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "InstalledFileEntry")]
+pub struct InstalledFile {
+    pub path: String,           // Absolute path of the deployed file
+    pub sha256: Option<String>, // Contents when deployed; None for entries recorded before hashes were kept
+}
+
+/// Registries written before hashes were kept list plain paths
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InstalledFileEntry {
+    Path(String),
+    File {
+        path: String,
+        #[serde(default)]
+        sha256: Option<String>,
+    },
+}
+
+impl From<InstalledFileEntry> for InstalledFile {
+    fn from(entry: InstalledFileEntry) -> Self {
+        match entry {
+            InstalledFileEntry::Path(path) => InstalledFile { path, sha256: None },
+            InstalledFileEntry::File { path, sha256 } => InstalledFile { path, sha256 },
+        }
+    }
+}
+
+impl InstalledFile {
+    /// Record a deployed file with the hash of what is on disk now
+    pub fn record(path: &Path) -> Self {
+        let sha256 = match hash_file(path) {
+            Ok(hash) => Some(hash),
+            Err(e) => {
+                warn!("Recording {} without a hash: {}", path.display(), e);
+                None
+            }
+        };
+        InstalledFile {
+            path: path.to_string_lossy().to_string(),
+            sha256,
+        }
+    }
+}
+
+/// Record freshly deployed files, hashing them on up to `workers` threads
+pub fn record_installed_files(paths: Vec<String>, workers: usize) -> Vec<InstalledFile> {
+    let hashes = hash_files(&paths.iter().map(PathBuf::from).collect::<Vec<_>>(), workers);
+    paths
+        .into_iter()
+        .zip(hashes)
+        .map(|(path, hash)| {
+            let sha256 = match hash {
+                Ok(hash) => Some(hash),
+                Err(e) => {
+                    warn!("Recording {} without a hash: {}", path, e);
+                    None
+                }
+            };
+            InstalledFile { path, sha256 }
+        })
+        .collect()
 }
 
 /// Structure to track individual files within a mod for conflict resolution
@@ -87,12 +298,17 @@ pub struct ModRegistry {
 /// Frontend-friendly view of a mod (for compatibility with existing frontend code)
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ModInfo {
+    #[serde(default)]
+    pub id: String,                  // Stable identifier; commands take it or the directory name
     pub directory_name: String,      // Identifier for the mod
     pub name: Option<String>,        // Display name
     pub version: Option<String>,     // Version if available
     pub author: Option<String>,      // Author if available
     pub description: Option<String>, // Description if available
     pub enabled: bool,               // Whether enabled or not
+    pub thumbnail_path: Option<String>, // Preview image if available
+    pub rating: Option<u8>,          // Personal 1-5 star rating
+    pub origin_url: Option<String>,  // Where the mod was installed from, if known
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -115,6 +331,26 @@ pub struct SkinMetadata {
     pub description: Option<String>,
 }
 
+/// Skin mod registries written by older releases, before skin mods moved into mod_registry.json
+const LEGACY_SKIN_REGISTRY_FILES: &[&str] = &["skin_registry.json", "skinmods_registry.json"];
+
+/// A skin mod entry from a legacy skin registry. Older files were flat like `SkinMetadata`,
+/// later ones nested the common fields under `base`; both are read field by field.
+#[derive(Debug, Deserialize)]
+struct LegacySkinEntry {
+    path: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    thumbnail_path: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ModListContainer {
     pub mods: Vec<LegacyModMetadata>,
@@ -143,6 +379,12 @@ impl ModRegistry {
             .app_config_dir()
             .map_err(|e| format!("Failed to get app config dir: {}", e))?;
 
+        // The sandbox keeps a registry of its own so its paths never leak into the real one
+        let config_dir = match crate::utils::sandbox::active_sandbox(app_handle) {
+            Some(_) => crate::utils::sandbox::sandbox_config_dir(&config_dir),
+            None => config_dir,
+        };
+
         // Ensure the directory exists
         fs::create_dir_all(&config_dir)
             .map_err(|e| format!("Failed to create config directory: {}", e))?;
@@ -173,6 +415,13 @@ impl ModRegistry {
                         log::info!("Mod registry validation successful.");
                         Ok(())
                     }
+                    Err(e) if Self::read_backup(&registry_path).is_some() => {
+                        log::warn!(
+                            "Mod registry failed to parse ({}), it will be restored from its backup on load",
+                            e
+                        );
+                        Ok(())
+                    }
                     Err(e) => {
                         log::error!("Mod registry validation failed: {}", e);
                         Err(format!("Failed to parse mod_registry.json: {}", e))
@@ -192,6 +441,35 @@ impl ModRegistry {
 
     /// Load the registry from disk
     pub fn load(app_handle: &AppHandle) -> Result<Self, String> {
+        let mut registry = Self::read_from_disk(app_handle)?;
+        // Entries from before ids existed get one now; saved straight away so the ids the
+        // frontend sees stay valid across loads
+        let assigned = registry.assign_missing_ids();
+        if assigned > 0 {
+            info!("Assigned ids to {} registry entries", assigned);
+            if let Err(e) = registry.save(app_handle) {
+                warn!("Failed to save registry after assigning ids: {}", e);
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Give every entry without an id (or with one another entry already has) a fresh one.
+    /// Returns how many were assigned.
+    pub fn assign_missing_ids(&mut self) -> usize {
+        let mut seen = HashSet::new();
+        let mut assigned = 0;
+        for m in self.mods.iter_mut().chain(self.skin_mods.iter_mut().map(|sm| &mut sm.base)) {
+            if m.id.is_empty() || !seen.insert(m.id.clone()) {
+                m.id = new_mod_id();
+                seen.insert(m.id.clone());
+                assigned += 1;
+            }
+        }
+        assigned
+    }
+
+    fn read_from_disk(app_handle: &AppHandle) -> Result<Self, String> {
         let registry_path = Self::get_registry_path(app_handle)?;
 
         // If registry doesn't exist, return a new empty one
@@ -204,6 +482,9 @@ impl ModRegistry {
         match fs::read_to_string(&registry_path) {
             Ok(content) => {
                 if content.is_empty() {
+                    if let Some(registry) = Self::recover_from_backup(&registry_path) {
+                        return Ok(registry);
+                    }
                     info!("Registry file exists but is empty, creating new registry");
                     return Ok(Self::new());
                 }
@@ -221,7 +502,11 @@ impl ModRegistry {
                     Err(e) => {
                         // Handle legacy format
                         warn!("Failed to parse registry file as ModRegistry: {}", e);
-                        Self::migrate_from_legacy(content, app_handle)
+                        match Self::migrate_from_legacy(content, app_handle) {
+                            Ok(registry) => Ok(registry),
+                            Err(migrate_err) => Self::recover_from_backup(&registry_path)
+                                .ok_or(migrate_err),
+                        }
                     }
                 }
             }
@@ -238,29 +523,134 @@ impl ModRegistry {
 
     /// Save the registry to disk
     pub fn save(&self, app_handle: &AppHandle) -> Result<(), String> {
+        ensure_write_access(app_handle)?;
         let registry_path = Self::get_registry_path(app_handle)?;
 
         // Serialize to JSON
         let content = serde_json::to_string_pretty(self)
             .map_err(|e| format!("Failed to serialize mod registry: {}", e))?;
 
-        // Write to file
-        fs::write(&registry_path, content)
-            .map_err(|e| format!("Failed to write mod registry: {}", e))?;
+        let keep_backup = crate::utils::settings::AppSettings::load(app_handle)
+            .map(|s| s.keep_registry_backup)
+            .unwrap_or(true);
+        Self::write_registry_file(&registry_path, &content, keep_backup)?;
 
         info!("Successfully saved mod registry");
         Ok(())
     }
 
+    /// Where the previous registry is kept between saves
+    pub fn get_registry_backup_path(registry_path: &Path) -> PathBuf {
+        registry_path.with_extension("json.bak")
+    }
+
+    /// Replace the registry file without ever leaving a half-written one behind. With
+    /// `keep_backup` the file being replaced is first copied to mod_registry.json.bak.
+    pub(crate) fn write_registry_file(
+        registry_path: &Path,
+        content: &str,
+        keep_backup: bool,
+    ) -> Result<(), String> {
+        // Only a registry that still parses is worth keeping; a damaged one would replace a good backup
+        let current_is_valid = fs::read_to_string(registry_path)
+            .is_ok_and(|c| serde_json::from_str::<Self>(&c).is_ok());
+        if keep_backup && current_is_valid {
+            let backup_path = Self::get_registry_backup_path(registry_path);
+            if let Err(e) = fs::copy(registry_path, &backup_path) {
+                warn!("Failed to back up mod registry to {:?}: {}", backup_path, e);
+            }
+        }
+        write_file_atomic(registry_path, content.as_bytes())
+            .map_err(|e| format!("Failed to write mod registry: {}", e))
+    }
+
+    /// The backup registry, if there is one and it parses
+    fn read_backup(registry_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(Self::get_registry_backup_path(registry_path)).ok()?;
+        serde_json::from_str::<Self>(&content).ok()
+    }
+
+    /// Put the backup back in place of a damaged registry. The damaged file is kept as
+    /// mod_registry.json.corrupt-<timestamp> so nothing is lost if the backup is older than expected.
+    pub(crate) fn recover_from_backup(registry_path: &Path) -> Option<Self> {
+        let registry = Self::read_backup(registry_path)?;
+        let corrupt_path = registry_path.with_extension(format!(
+            "json.corrupt-{}",
+            chrono::Utc::now().format("%Y%m%d%H%M%S")
+        ));
+        if let Err(e) = fs::rename(registry_path, &corrupt_path) {
+            warn!("Failed to set damaged mod registry aside as {:?}: {}", corrupt_path, e);
+        }
+        if let Err(e) = fs::copy(Self::get_registry_backup_path(registry_path), registry_path) {
+            warn!("Failed to restore mod registry from backup: {}", e);
+        }
+        warn!(
+            "Mod registry was damaged, recovered {} mods and {} skin mods from its backup (damaged copy kept as {:?})",
+            registry.mods.len(),
+            registry.skin_mods.len(),
+            corrupt_path
+        );
+        Some(registry)
+    }
+
+    /// Fold the skin registries older releases kept next to mod_registry.json into this one.
+    /// Entries whose folder is gone, or that are already registered, are skipped, as are files
+    /// that don't parse. Imported mods start disabled, since the files the old registry
+    /// deployed weren't tracked; enabling them deploys and records them again. The files are
+    /// left in place, see `archive_legacy_skin_registries`. Returns how many skin mods were added.
+    pub(crate) fn import_legacy_skin_registries(&mut self, legacy_files: &[PathBuf]) -> usize {
+        let mut imported = 0;
+        for legacy_path in legacy_files {
+            let entries = fs::read_to_string(legacy_path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).map_err(|e| e.to_string()));
+            match entries {
+                Ok(value) => {
+                    for entry in legacy_skin_entries(&value) {
+                        let dir = Path::new(&entry.path);
+                        if self.skin_mods.iter().any(|m| m.base.path == entry.path) || !is_skin_mod_dir(dir) {
+                            log::debug!("Not importing legacy skin entry {}", entry.path);
+                            continue;
+                        }
+                        let mut skin_mod = new_skin_mod_from_dir(dir, "skin_registry");
+                        if let Some(name) = entry.name.filter(|n| !n.trim().is_empty()) {
+                            skin_mod.base.name = name;
+                        }
+                        skin_mod.base.author = skin_mod.base.author.or(entry.author);
+                        skin_mod.base.version = skin_mod.base.version.or(entry.version);
+                        skin_mod.base.description = skin_mod.base.description.or(entry.description);
+                        skin_mod.thumbnail_path = skin_mod.thumbnail_path.or(entry.thumbnail_path);
+                        self.skin_mods.push(skin_mod);
+                        imported += 1;
+                    }
+                    info!("Imported skin mods from legacy {}", legacy_path.display());
+                }
+                Err(e) => warn!("Legacy skin registry {} is unreadable, setting it aside: {}", legacy_path.display(), e),
+            }
+        }
+        imported
+    }
+
     /// Migrate from old format to new format
     fn migrate_from_legacy(content: String, app_handle: &AppHandle) -> Result<Self, String> {
         info!("Attempting to migrate from legacy format");
+        let mut registry = Self::from_legacy_content(&content)?;
+
+        // Save the migrated registry
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(app_handle)?;
+
+        info!("Successfully migrated to new registry format");
+        Ok(registry)
+    }
 
+    /// Build a registry from legacy modlist content (ModListContainer or a bare mod list)
+    pub(crate) fn from_legacy_content(content: &str) -> Result<Self, String> {
         // Try to handle various formats
         let mut registry = Self::new();
 
         // First try the intermediate "ModListContainer" format
-        match serde_json::from_str::<ModListContainer>(&content) {
+        match serde_json::from_str::<ModListContainer>(content) {
             Ok(container) => {
                 info!(
                     "Found legacy ModListContainer format with {} mods and {} skins",
@@ -270,46 +660,43 @@ impl ModRegistry {
 
                 // Convert ModMetadata to Mod
                 for legacy_mod in container.mods {
+                    let mod_type = if legacy_mod.installed_directory.contains("/autorun/") {
+                        ModType::REFrameworkAutorun
+                    } else if legacy_mod.installed_directory.contains("/plugins/") {
+                        ModType::REFrameworkPlugin
+                    } else {
+                        ModType::Other
+                    };
                     let new_mod = Mod {
-                        name: legacy_mod.parsed_name.clone(),
-                        directory_name: legacy_mod.parsed_name,
                         path: legacy_mod.original_zip_name,
                         enabled: true, // We'll check actual status later
-                        author: None,
                         version: legacy_mod.version,
-                        description: None,
-                        source: Some(legacy_mod.source),
-                        installed_timestamp: chrono::Utc::now().timestamp(),
-                        installed_directory: legacy_mod.installed_directory.clone(),
-                        mod_type: if legacy_mod.installed_directory.contains("/autorun/") {
-                            ModType::REFrameworkAutorun
-                        } else if legacy_mod.installed_directory.contains("/plugins/") {
-                            ModType::REFrameworkPlugin
-                        } else {
-                            ModType::Other
-                        },
+                        installed_directory: legacy_mod.installed_directory,
+                        ..Mod::new(
+                            legacy_mod.parsed_name.clone(),
+                            legacy_mod.parsed_name,
+                            mod_type,
+                            &legacy_mod.source,
+                        )
                     };
                     registry.mods.push(new_mod);
                 }
 
                 // Convert SkinMetadata to SkinMod
                 for legacy_skin in container.skins {
+                    let directory_name = Path::new(&legacy_skin.path)
+                        .file_name()
+                        .and_then(|os_str| os_str.to_str())
+                        .unwrap_or(&legacy_skin.name)
+                        .to_string();
+                    // installed_directory is left empty and updated on refresh
                     let base_mod = Mod {
-                        name: legacy_skin.name.clone(),
-                        directory_name: Path::new(&legacy_skin.path)
-                            .file_name()
-                            .and_then(|os_str| os_str.to_str())
-                            .unwrap_or(&legacy_skin.name)
-                            .to_string(),
                         path: legacy_skin.path,
                         enabled: legacy_skin.enabled,
                         author: legacy_skin.author,
                         version: legacy_skin.version,
                         description: legacy_skin.description,
-                        source: Some("local".to_string()),
-                        installed_timestamp: chrono::Utc::now().timestamp(),
-                        installed_directory: "".to_string(), // Will be updated on refresh
-                        mod_type: ModType::SkinMod,
+                        ..Mod::new(legacy_skin.name, directory_name, ModType::SkinMod, "local")
                     };
 
                     let skin_mod = SkinMod {
@@ -319,6 +706,7 @@ impl ModRegistry {
                         files: Vec::new(),           // Will be populated on refresh
                         installed_files: Vec::new(), // Will be populated on refresh
                         installed_pak_path: None,
+                        pack_natives: None,
                     };
 
                     registry.skin_mods.push(skin_mod);
@@ -326,30 +714,30 @@ impl ModRegistry {
             }
             Err(_) => {
                 // Fall back to older ModList format (Vec<ModMetadata>)
-                match serde_json::from_str::<Vec<crate::ModMetadata>>(&content) {
+                match serde_json::from_str::<Vec<crate::ModMetadata>>(content) {
                     Ok(mod_list) => {
                         info!("Found legacy ModList format with {} mods", mod_list.len());
 
                         // Convert ModMetadata to Mod
                         for legacy_mod in mod_list {
+                            let mod_type = if legacy_mod.installed_directory.contains("/autorun/") {
+                                ModType::REFrameworkAutorun
+                            } else if legacy_mod.installed_directory.contains("/plugins/") {
+                                ModType::REFrameworkPlugin
+                            } else {
+                                ModType::Other
+                            };
                             let new_mod = Mod {
-                                name: legacy_mod.parsed_name.clone(),
-                                directory_name: legacy_mod.parsed_name,
                                 path: legacy_mod.original_zip_name,
                                 enabled: true, // We'll check actual status later
-                                author: None,
                                 version: legacy_mod.version,
-                                description: None,
-                                source: Some(legacy_mod.source),
-                                installed_timestamp: chrono::Utc::now().timestamp(),
-                                installed_directory: legacy_mod.installed_directory.clone(),
-                                mod_type: if legacy_mod.installed_directory.contains("/autorun/") {
-                                    ModType::REFrameworkAutorun
-                                } else if legacy_mod.installed_directory.contains("/plugins/") {
-                                    ModType::REFrameworkPlugin
-                                } else {
-                                    ModType::Other
-                                },
+                                installed_directory: legacy_mod.installed_directory,
+                                ..Mod::new(
+                                    legacy_mod.parsed_name.clone(),
+                                    legacy_mod.parsed_name,
+                                    mod_type,
+                                    &legacy_mod.source,
+                                )
                             };
                             registry.mods.push(new_mod);
                         }
@@ -362,35 +750,38 @@ impl ModRegistry {
             }
         }
 
-        // Save the migrated registry
-        registry.last_updated = chrono::Utc::now().timestamp();
-        registry.save(app_handle)?;
-
-        info!("Successfully migrated to new registry format");
         Ok(registry)
     }
 
     /// Convert a standard Mod to a frontend-friendly ModInfo
     pub fn to_mod_info(m: &Mod) -> ModInfo {
         ModInfo {
+            id: m.id.clone(),
             directory_name: m.directory_name.clone(),
             name: Some(m.name.clone()),
             version: m.version.clone(),
             author: m.author.clone(),
             description: m.description.clone(),
             enabled: m.enabled,
+            thumbnail_path: m.custom_thumbnail.clone().or_else(|| m.thumbnail.clone()),
+            rating: m.rating,
+            origin_url: m.origin_url.clone(),
         }
     }
 
     /// Convert a SkinMod to a frontend-friendly ModInfo
     pub fn skin_to_mod_info(sm: &SkinMod) -> ModInfo {
         ModInfo {
+            id: sm.base.id.clone(),
             directory_name: sm.base.directory_name.clone(),
             name: Some(sm.base.name.clone()),
             version: sm.base.version.clone(),
             author: sm.base.author.clone(),
             description: sm.base.description.clone(),
             enabled: sm.base.enabled,
+            thumbnail_path: sm.base.custom_thumbnail.clone().or_else(|| sm.thumbnail_path.clone()),
+            rating: sm.base.rating,
+            origin_url: sm.base.origin_url.clone(),
         }
     }
 
@@ -429,31 +820,53 @@ impl ModRegistry {
     }
 
     /// Find a mod by directory name
-    pub fn find_mod(&self, directory_name: &str) -> Option<&Mod> {
-        self.mods
-            .iter()
-            .find(|m| m.directory_name == directory_name)
+    pub fn find_mod(&self, identifier: &str) -> Option<&Mod> {
+        self.mods.iter().find(|m| m.is_identified_by(identifier))
     }
 
     /// Find a mod by directory name (mutable)
-    pub fn find_mod_mut(&mut self, directory_name: &str) -> Option<&mut Mod> {
-        self.mods
-            .iter_mut()
-            .find(|m| m.directory_name == directory_name)
+    pub fn find_mod_mut(&mut self, identifier: &str) -> Option<&mut Mod> {
+        self.mods.iter_mut().find(|m| m.is_identified_by(identifier))
     }
 
     /// Find a skin mod by directory name
-    pub fn find_skin_mod(&self, directory_name: &str) -> Option<&SkinMod> {
+    pub fn find_skin_mod(&self, identifier: &str) -> Option<&SkinMod> {
+        self.skin_mods.iter().find(|m| m.base.is_identified_by(identifier))
+    }
+
+    /// Find a skin mod by directory name (mutable)
+    pub fn find_skin_mod_mut(&mut self, identifier: &str) -> Option<&mut SkinMod> {
+        self.skin_mods.iter_mut().find(|m| m.base.is_identified_by(identifier))
+    }
+
+    /// Source path of a skin mod given its path, id or directory name. Skin mod commands key
+    /// on the source path, so they resolve whatever they were given through this first.
+    pub fn resolve_skin_mod_path(&self, identifier: &str) -> Option<String> {
         self.skin_mods
             .iter()
-            .find(|m| m.base.directory_name == directory_name)
+            .find(|s| s.base.path == identifier || s.base.is_identified_by(identifier))
+            .map(|s| s.base.path.clone())
     }
 
-    /// Find a skin mod by directory name (mutable)
-    pub fn find_skin_mod_mut(&mut self, directory_name: &str) -> Option<&mut SkinMod> {
+    /// Find any mod by REFramework directory name, or skin mod path or directory name
+    pub fn find_any_mod(&self, identifier: &str) -> Option<&Mod> {
+        self.find_mod(identifier).or_else(|| {
+            self.skin_mods
+                .iter()
+                .find(|s| s.base.path == identifier || s.base.is_identified_by(identifier))
+                .map(|s| &s.base)
+        })
+    }
+
+    /// Find any mod by REFramework directory name, or skin mod path or directory name (mutable)
+    pub fn find_any_mod_mut(&mut self, identifier: &str) -> Option<&mut Mod> {
+        if self.find_mod(identifier).is_some() {
+            return self.find_mod_mut(identifier);
+        }
         self.skin_mods
             .iter_mut()
-            .find(|m| m.base.directory_name == directory_name)
+            .find(|s| s.base.path == identifier || s.base.is_identified_by(identifier))
+            .map(|s| &mut s.base)
     }
 
     /// Update the enabled status of a mod based on filesystem state
@@ -488,30 +901,31 @@ impl ModRegistry {
         Ok(())
     }
 
-    /// Add a new mod to the registry
-    pub fn add_mod(&mut self, new_mod: Mod) {
-        // Remove any existing mod with same directory name
-        self.mods
-            .retain(|m| m.directory_name != new_mod.directory_name);
-        // Add the new mod
+    /// Add a new mod to the registry, replacing the entry with the same id
+    pub fn add_mod(&mut self, mut new_mod: Mod) {
+        if new_mod.id.is_empty() {
+            new_mod.id = new_mod_id();
+        }
+        // Only the id says two entries are the same mod; directory names are just for lookups
+        self.mods.retain(|m| m.id != new_mod.id);
         self.mods.push(new_mod);
         self.last_updated = chrono::Utc::now().timestamp();
     }
 
-    /// Add a new skin mod to the registry
-    pub fn add_skin_mod(&mut self, new_skin_mod: SkinMod) {
-        // Remove any existing skin mod with same directory name
-        self.skin_mods
-            .retain(|m| m.base.directory_name != new_skin_mod.base.directory_name);
-        // Add the new skin mod
+    /// Add a new skin mod to the registry, replacing the entry with the same id
+    pub fn add_skin_mod(&mut self, mut new_skin_mod: SkinMod) {
+        if new_skin_mod.base.id.is_empty() {
+            new_skin_mod.base.id = new_mod_id();
+        }
+        self.skin_mods.retain(|m| m.base.id != new_skin_mod.base.id);
         self.skin_mods.push(new_skin_mod);
         self.last_updated = chrono::Utc::now().timestamp();
     }
 
     /// Remove a mod from the registry
-    pub fn remove_mod(&mut self, directory_name: &str) -> bool {
+    pub fn remove_mod(&mut self, identifier: &str) -> bool {
         let initial_count = self.mods.len();
-        self.mods.retain(|m| m.directory_name != directory_name);
+        self.mods.retain(|m| !m.is_identified_by(identifier));
         let removed = self.mods.len() != initial_count;
 
         if removed {
@@ -522,10 +936,9 @@ impl ModRegistry {
     }
 
     /// Remove a skin mod from the registry
-    pub fn remove_skin_mod(&mut self, directory_name: &str) -> bool {
+    pub fn remove_skin_mod(&mut self, identifier: &str) -> bool {
         let initial_count = self.skin_mods.len();
-        self.skin_mods
-            .retain(|m| m.base.directory_name != directory_name);
+        self.skin_mods.retain(|m| !m.base.is_identified_by(identifier));
         let removed = self.skin_mods.len() != initial_count;
 
         if removed {
@@ -539,7 +952,7 @@ impl ModRegistry {
     pub fn toggle_mod_enabled(&mut self, directory_name: &str, enable: bool) -> Result<(), String> {
         // Find the mod
         if let Some(mod_entry) = self.find_mod_mut(directory_name) {
-            mod_entry.enabled = enable;
+            mod_entry.set_enabled(enable);
             self.last_updated = chrono::Utc::now().timestamp();
             Ok(())
         } else {
@@ -555,7 +968,7 @@ impl ModRegistry {
     ) -> Result<(), String> {
         // Find the skin mod
         if let Some(skin_mod) = self.find_skin_mod_mut(directory_name) {
-            skin_mod.base.enabled = enable;
+            skin_mod.base.set_enabled(enable);
             self.last_updated = chrono::Utc::now().timestamp();
             Ok(())
         } else {
@@ -569,31 +982,20 @@ impl ModRegistry {
 
 // Utility functions
 
-/// Toggle a mod's enabled state through the registry and on filesystem
-#[tauri::command]
-pub async fn toggle_mod_enabled_state(
-    app_handle: AppHandle,
-    game_root_path: String,
-    mod_name: String,
+/// Rename a REFramework mod's directory to match `enable` and update its registry entry.
+/// Only updates the in-memory registry; callers are responsible for saving it.
+pub(crate) fn set_reframework_mod_enabled(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_name: &str,
     enable: bool,
 ) -> Result<(), String> {
-    log::info!(
-        "Toggling mod '{}' to enabled={} in game root: {}",
-        mod_name,
-        enable,
-        game_root_path
-    );
-    let game_root = PathBuf::from(&game_root_path);
-
-    // Load the registry
-    let mut registry = ModRegistry::load(&app_handle)?;
-
     // Find the mod
-    let mod_entry = match registry.find_mod(&mod_name) {
+    let mod_entry = match registry.find_mod(mod_name) {
         Some(m) => m.clone(), // Clone to avoid borrow issues
         None => {
             // Try to find it as a skin mod
-            if registry.find_skin_mod(&mod_name).is_some() {
+            if registry.find_skin_mod(mod_name).is_some() {
                 return Err(format!(
                     "Mod '{}' is a skin mod. Please use toggle_skin_mod_enabled instead.",
                     mod_name
@@ -619,12 +1021,17 @@ pub async fn toggle_mod_enabled_state(
                 disabled_dir_abs,
                 installed_dir_abs
             );
-            fs::rename(&disabled_dir_abs, &installed_dir_abs).map_err(|e| {
+            retry_if_locked(&disabled_dir_abs, || fs::rename(&disabled_dir_abs, &installed_dir_abs)).map_err(|e| {
+                if is_lock_error(&e) {
+                    return locked_file_error(&disabled_dir_abs, &e);
+                }
                 format!(
                     "Failed to rename {:?} to {:?}: {}",
                     disabled_dir_abs, installed_dir_abs, e
                 )
             })?;
+            LedgerOperation::begin(game_root, "enable_reframework_mod")
+                .renamed(&disabled_dir_abs, &installed_dir_abs);
         } else if installed_dir_abs.exists() {
             log::info!(
                 "Mod '{}' is already enabled (directory {:?} exists).",
@@ -647,12 +1054,17 @@ pub async fn toggle_mod_enabled_state(
                 installed_dir_abs,
                 disabled_dir_abs
             );
-            fs::rename(&installed_dir_abs, &disabled_dir_abs).map_err(|e| {
+            retry_if_locked(&installed_dir_abs, || fs::rename(&installed_dir_abs, &disabled_dir_abs)).map_err(|e| {
+                if is_lock_error(&e) {
+                    return locked_file_error(&installed_dir_abs, &e);
+                }
                 format!(
                     "Failed to rename {:?} to {:?}: {}",
                     installed_dir_abs, disabled_dir_abs, e
                 )
             })?;
+            LedgerOperation::begin(game_root, "disable_reframework_mod")
+                .renamed(&installed_dir_abs, &disabled_dir_abs);
         } else if disabled_dir_abs.exists() {
             log::info!(
                 "Mod '{}' is already disabled (directory {:?} exists).",
@@ -668,8 +1080,56 @@ pub async fn toggle_mod_enabled_state(
         }
     }
 
+    registry.toggle_mod_enabled(mod_name, enable)
+}
+
+/// Toggle a mod's enabled state through the registry and on filesystem
+#[tauri::command]
+pub async fn toggle_mod_enabled_state(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_name: String,
+    enable: bool,
+    allow_known_broken: Option<bool>, // Override for mods the known-broken list blocks
+    disable_dependents: Option<bool>, // Also disable mods that depend on this one
+    skip_save_backup_warning: Option<bool>, // The user chose to go ahead without a save backup
+) -> Result<(), String> {
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    log::info!(
+        "Toggling mod '{}' to enabled={} in game root: {}",
+        mod_name,
+        enable,
+        game_root_path
+    );
+    let game_root = PathBuf::from(&game_root_path);
+
+    // Load the registry
+    let mut registry = ModRegistry::load(&app_handle)?;
+
+    if enable {
+        if let Some(mod_entry) = registry.find_mod(&mod_name) {
+            check_before_enable(&app_handle, mod_entry, allow_known_broken.unwrap_or(false))?;
+            check_save_backup_before_enable(&app_handle, mod_entry, skip_save_backup_warning.unwrap_or(false))?;
+        }
+    }
+
     // Update registry and save
-    registry.toggle_mod_enabled(&mod_name, enable)?;
+    track_result(
+        &app_handle,
+        "toggle_mod_enabled_state",
+        set_reframework_mod_enabled(&mut registry, &game_root, &mod_name, enable),
+    )?;
+    if !enable {
+        if let Some(target) = registry.find_mod(&mod_name).cloned() {
+            handle_dependents_on_disable(
+                &app_handle,
+                &mut registry,
+                &game_root,
+                &target,
+                disable_dependents.unwrap_or(false),
+            )?;
+        }
+    }
     registry.save(&app_handle)?;
 
     log::info!(
@@ -680,18 +1140,111 @@ pub async fn toggle_mod_enabled_state(
     Ok(())
 }
 
-/// Extract a cleaner mod name from folder name
+/// Characters that end the name part of a mod folder name, full-width forms included
+const NAME_DELIMITERS: &[char] = &[
+    '_', '-', ' ', '!', '#', '$', '.', '(', '[', '＿', '－', '\u{3000}', '！', '＃', '（', '［', '【', '〔', '「',
+    '『', '～',
+];
+
+/// Brackets wrapped around tags ("[Author]", "【MHWs】"); a name in 「」 or 『』 is the name itself
+const NAME_BRACKETS: &[(char, char)] = &[
+    ('[', ']'),
+    ('(', ')'),
+    ('［', '］'),
+    ('（', '）'),
+    ('【', '】'),
+    ('〔', '〕'),
+    ('「', '」'),
+    ('『', '』'),
+];
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'     // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}'   // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK unified ideographs
+        | '\u{AC00}'..='\u{D7AF}'   // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}'   // CJK compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}'   // Half-width Katakana
+    )
+}
+
+/// Drop leading bracketed tags, keeping the inside of the last one if nothing follows it
+fn strip_name_tags(mut name: &str) -> &str {
+    loop {
+        name = name.trim_start_matches(|c: char| c.is_whitespace() || (NAME_DELIMITERS.contains(&c) && !NAME_BRACKETS.iter().any(|(open, _)| *open == c)));
+        let Some(&(open, close)) = NAME_BRACKETS.iter().find(|(open, _)| name.starts_with(*open)) else {
+            return name;
+        };
+        let Some(end) = name.find(close) else {
+            return name;
+        };
+        let inside = name[open.len_utf8()..end].trim();
+        let rest = &name[end + close.len_utf8()..];
+        let quoted = matches!(open, '「' | '『');
+        let rest_is_empty = rest.trim_matches(|c: char| c.is_whitespace() || NAME_DELIMITERS.contains(&c)).is_empty();
+        if quoted || rest_is_empty {
+            return inside;
+        }
+        name = rest;
+    }
+}
+
+/// Drop a leading "MHW"/"MHWs" that is set apart by a delimiter or a change of script
+fn strip_game_prefix(name: &str) -> &str {
+    for prefix in ["mhws", "mhw"] {
+        let Some(head) = name.get(..prefix.len()) else {
+            continue;
+        };
+        if !head.eq_ignore_ascii_case(prefix) {
+            continue;
+        }
+        let rest = &name[prefix.len()..];
+        match rest.chars().next() {
+            Some(c) if NAME_DELIMITERS.contains(&c) || is_cjk(c) => {
+                let stripped = rest.trim_start_matches(|c: char| NAME_DELIMITERS.contains(&c));
+                if !stripped.is_empty() {
+                    return stripped;
+                }
+            }
+            _ => {}
+        }
+    }
+    name
+}
+
+/// A version glued onto a CJK name ("リオレウス装備v1.2") is cut off where the script changes
+fn strip_version_suffix(name: &str) -> &str {
+    let Some((boundary, _)) = name.char_indices().rev().find(|(_, c)| is_cjk(*c)) else {
+        return name;
+    };
+    let cut = boundary + name[boundary..].chars().next().map_or(0, char::len_utf8);
+    let tail = &name[cut..];
+    let digits = ["ver", "Ver", "v", "V"]
+        .iter()
+        .find_map(|marker| tail.strip_prefix(*marker))
+        .unwrap_or(tail);
+    if digits.starts_with(|c: char| c.is_ascii_digit()) && digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return &name[..cut];
+    }
+    name
+}
+
+/// Extract a cleaner mod name from folder name. Works on characters rather than bytes and
+/// knows full-width punctuation, so Japanese, Chinese and Korean names come through intact.
 pub fn extract_mod_name_from_folder(folder_name: &str) -> String {
-    // Common delimiters used in mod folder names
-    let delimiters = &['_', '-', ' ', '!', '#', '$', '.', '(', '['];
+    let name = match strip_game_prefix(strip_name_tags(folder_name.trim())) {
+        "" => folder_name.trim(),
+        name => name,
+    };
 
-    // Check if there's any delimiter in the folder name
-    if let Some(first_delimiter_pos) = folder_name.find(|c| delimiters.contains(&c)) {
-        // If found delimiter, return everything before it
+    // Everything before the first delimiter
+    if let Some(first_delimiter_pos) = name.find(|c| NAME_DELIMITERS.contains(&c)) {
         if first_delimiter_pos > 0 {
-            return folder_name[..first_delimiter_pos].to_string();
+            return strip_version_suffix(&name[..first_delimiter_pos]).to_string();
         }
     }
+    let folder_name = name;
 
     // If no delimiter found or name would be empty, return the original folder name
     // Also check if it looks like a PAK file name
@@ -708,105 +1261,288 @@ pub fn extract_mod_name_from_folder(folder_name: &str) -> String {
         return "Custom Skin".to_string();
     }
 
-    folder_name.to_string()
+    strip_version_suffix(folder_name).to_string()
 }
 
 /// Find screenshot in a mod directory (more robust version)
-fn find_screenshot(mod_dir: &Path) -> Option<String> {
+pub(crate) fn find_screenshot(mod_dir: &Path) -> Option<String> {
     let image_extensions = ["png", "jpg", "jpeg", "webp", "gif", "bmp"]; // Added more extensions
 
-    // 1. Search in the root directory first (quick check)
-    if let Ok(entries) = fs::read_dir(mod_dir) {
-        for entry in entries.filter_map(Result::ok) {
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
-                    if image_extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)) {
-                        log::debug!("Found screenshot in root: {}", path.display());
-                        return Some(path.to_string_lossy().to_string());
-                    }
-                }
-            }
-        }
-    }
-    log::debug!(
-        "No screenshot found in root of {}, searching subdirectories.",
-        mod_dir.display()
-    );
-
-    // 2. If not found in root, search recursively up to 3 levels deep
-    // WalkDir depth is relative to the starting path.
-    // max_depth(1) means only the root.
-    // max_depth(2) means root + 1 level down.
-    // max_depth(4) means root + 3 levels down.
-    for entry in WalkDir::new(mod_dir)
-        .max_depth(4) // Search mod_dir + 3 levels of subdirectories
-        .into_iter()
-        .filter_map(Result::ok)
-        .filter(|e| e.path() != mod_dir && e.file_type().is_file()) // Skip root, only files
-    {
-        let path = entry.path();
-        if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
-            if image_extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)) {
-                log::debug!("Found screenshot recursively: {}", path.display());
-                return Some(path.to_string_lossy().to_string());
-            }
-        }
+    // Shallowest image wins, so a preview next to the mod files beats one in a docs folder
+    let found = find_shallowest(mod_dir, LAYOUT_SEARCH_DEPTH, |entry| {
+        entry.file_type().is_file()
+            && entry
+                .path()
+                .extension()
+                .and_then(|s| s.to_str())
+                .is_some_and(|ext| image_extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)))
+    });
+    if let Some(entry) = found {
+        log::debug!("Found screenshot: {}", entry.path().display());
+        return Some(entry.path().to_string_lossy().to_string());
     }
 
     log::debug!("No screenshot found for: {}", mod_dir.display());
     None
 }
 
-/// Helper function to find the next available patch number in the game root directory
-fn find_next_available_patch_number(game_root: &Path) -> Result<u32, String> {
-    let pak_regex = Regex::new(r"re_chunk_000\.pak\.sub_000\.pak\.patch_(\d{3})\.pak(?:\.disabled)?$").unwrap();
-    let mut max_num: u32 = 0;
-    let mut found_any = false;
+/// Find a banner image referenced from a README.md in the root of a mod directory
+pub(crate) fn find_readme_banner(mod_dir: &Path) -> Option<String> {
+    let image_ref_regex = Regex::new(r"!\[[^\]]*\]\(\s*<?([^)\s>]+)>?").unwrap();
+    let image_extensions = ["png", "jpg", "jpeg", "webp", "gif", "bmp"];
 
-    log::debug!("Scanning {} for existing patch files", game_root.display());
+    let canonical_mod_dir = fs::canonicalize(mod_dir).ok()?;
+    let entries = fs::read_dir(mod_dir).ok()?;
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let is_readme = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.to_lowercase().starts_with("readme") && n.to_lowercase().ends_with(".md"))
+            .unwrap_or(false);
+        if !is_readme || !path.is_file() {
+            continue;
+        }
 
-    match fs::read_dir(game_root) {
-        Ok(entries) => {
-            for entry in entries.filter_map(Result::ok) {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
-                        if let Some(caps) = pak_regex.captures(file_name) {
-                            if let Some(num_str) = caps.get(1) {
-                                if let Ok(num) = num_str.as_str().parse::<u32>() {
-                                    log::trace!("Found patch file: {} with number {}", file_name, num);
-                                    max_num = max_num.max(num);
-                                    found_any = true;
-                                }
-                            }
-                        }
-                    }
+        let content = match fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                log::debug!("Could not read {}: {}", path.display(), e);
+                continue;
+            }
+        };
+
+        // Only local images can be used, remote banners are skipped
+        for caps in image_ref_regex.captures_iter(&content) {
+            let target = caps[1].trim_start_matches("./");
+            if target.contains("://") {
+                continue;
+            }
+            let candidate = mod_dir.join(target);
+            let is_image = candidate
+                .extension()
+                .and_then(|s| s.to_str())
+                .map(|ext| image_extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)))
+                .unwrap_or(false);
+            if !is_image || !candidate.is_file() {
+                continue;
+            }
+            // A README can point anywhere ("../../secret.png", symlinks); only the mod's own files count
+            match fs::canonicalize(&candidate) {
+                Ok(resolved) if resolved.starts_with(&canonical_mod_dir) => {
+                    log::debug!("Found README banner: {}", candidate.display());
+                    return Some(candidate.to_string_lossy().to_string());
                 }
+                _ => log::debug!("Ignoring README image outside the mod folder: {}", candidate.display()),
             }
         }
-        Err(e) => {
-            return Err(format!(
-                "Failed to read game root directory {}: {}",
-                game_root.display(),
-                e
-            ));
-        }
     }
-
-    let next_num = if found_any { max_num + 1 } else { 1 }; // Start from 001 if none found
-    log::debug!("Next available patch number determined: {}", next_num);
-    Ok(next_num)
+    None
+}
+
+/// Resolve the on-disk directory of a REFramework mod, whether it is enabled or disabled
+pub(crate) fn resolve_reframework_mod_dir(game_root: &Path, mod_entry: &Mod) -> Option<PathBuf> {
+    let enabled_dir = game_root.join(&mod_entry.installed_directory);
+    if enabled_dir.is_dir() {
+        return Some(enabled_dir);
+    }
+    let disabled_dir = game_root.join(format!("{}.disabled", mod_entry.installed_directory));
+    if disabled_dir.is_dir() {
+        return Some(disabled_dir);
+    }
+    None
+}
+
+/// Find a README shipped with a mod (README.md / README.txt / readme*.txt)
+/// Checks the mod root first, then each level of subdirectories in turn.
+pub(crate) fn find_readme_file(mod_dir: &Path) -> Option<PathBuf> {
+    let is_readme = |path: &Path| -> bool {
+        path.is_file()
+            && path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| {
+                    let lower = n.to_lowercase();
+                    lower.starts_with("readme") && (lower.ends_with(".md") || lower.ends_with(".txt"))
+                })
+                .unwrap_or(false)
+    };
+
+    find_shallowest(mod_dir, LAYOUT_SEARCH_DEPTH, |entry| is_readme(entry.path())).map(|e| e.into_path())
+}
+
+/// Read a README as normalized text (lossy UTF-8, no BOM, LF line endings)
+pub(crate) fn read_readme_text(readme_path: &Path) -> Result<String, String> {
+    let bytes = fs::read(readme_path)
+        .map_err(|e| format!("Failed to read {}: {}", readme_path.display(), e))?;
+    let text = String::from_utf8_lossy(&bytes)
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+    Ok(text.trim().to_string())
+}
+
+/// Maximum length of the short description pulled from a README
+const README_DESCRIPTION_MAX_CHARS: usize = 300;
+
+/// Extract a short description and an author line from README text
+pub(crate) fn parse_readme_summary(text: &str) -> (Option<String>, Option<String>) {
+    let author_regex =
+        Regex::new(r"(?i)^(?:authors?|created by|made by|credits?)\s*[:\-]?\s*(.+)$").unwrap();
+    let markdown_noise: &[char] = &['#', '*', '_', '>', '`', '=', '-', ' '];
+
+    let mut author = None;
+    let mut description_lines: Vec<String> = Vec::new();
+    let mut description_done = false;
+
+    for raw_line in text.lines() {
+        let line = raw_line.trim().trim_matches(markdown_noise).trim();
+
+        if author.is_none() {
+            if let Some(caps) = author_regex.captures(line) {
+                let value = caps[1].trim().trim_matches(markdown_noise).trim();
+                if !value.is_empty() {
+                    author = Some(value.to_string());
+                }
+                continue;
+            }
+        }
+
+        if description_done {
+            continue;
+        }
+        // Headings, images and badges don't make a useful description
+        let is_heading = raw_line.trim_start().starts_with('#');
+        let is_image = raw_line.trim_start().starts_with("![") || raw_line.trim_start().starts_with("[![");
+        if line.is_empty() || is_heading || is_image {
+            if !description_lines.is_empty() {
+                description_done = true;
+            }
+            continue;
+        }
+        description_lines.push(line.to_string());
+    }
+
+    let description = if description_lines.is_empty() {
+        None
+    } else {
+        let joined = description_lines.join(" ");
+        if joined.chars().count() > README_DESCRIPTION_MAX_CHARS {
+            let truncated: String = joined.chars().take(README_DESCRIPTION_MAX_CHARS).collect();
+            Some(format!("{}...", truncated.trim_end()))
+        } else {
+            Some(joined)
+        }
+    };
+
+    (description, author)
+}
+
+/// Fill in missing description/author fields from a README inside the mod directory
+pub(crate) fn apply_readme_metadata(mod_dir: &Path, mod_entry: &mut Mod) {
+    if mod_entry.description.is_some() && mod_entry.author.is_some() {
+        return;
+    }
+    let Some(readme_path) = find_readme_file(mod_dir) else {
+        return;
+    };
+    match read_readme_text(&readme_path) {
+        Ok(text) => {
+            let (description, author) = parse_readme_summary(&text);
+            if mod_entry.description.is_none() {
+                mod_entry.description = description;
+            }
+            if mod_entry.author.is_none() {
+                mod_entry.author = author;
+            }
+        }
+        Err(e) => log::debug!("Skipping README for '{}': {}", mod_entry.name, e),
+    }
+}
+
+/// Locate a preview image for a REFramework mod and cache a downscaled copy of it.
+/// Looks in the enabled directory first, then in the `.disabled` variant.
+pub(crate) fn cache_reframework_mod_thumbnail(
+    app_handle: &AppHandle,
+    game_root: &Path,
+    mod_entry: &mut Mod,
+) {
+    // Keep the existing thumbnail as long as the cached copy is still around
+    if let Some(existing) = &mod_entry.thumbnail {
+        if Path::new(existing).exists() {
+            return;
+        }
+    }
+
+    let Some(mod_dir) = resolve_reframework_mod_dir(game_root, mod_entry) else {
+        return;
+    };
+
+    let source_image = find_readme_banner(&mod_dir).or_else(|| find_screenshot(&mod_dir));
+    mod_entry.thumbnail = source_image.and_then(|image_path| {
+        match crate::utils::cachethumbs::cache_downscaled_image(app_handle, &image_path) {
+            Ok(cached) => Some(cached.to_string_lossy().to_string()),
+            Err(e) => {
+                warn!("Failed to cache thumbnail for mod '{}': {}", mod_entry.name, e);
+                None
+            }
+        }
+    });
+}
+
+/// Helper function to find the next available patch number in the game root directory
+fn find_next_available_patch_number(game_root: &Path) -> Result<u32, String> {
+    let pak_regex = Regex::new(r"re_chunk_000\.pak\.sub_000\.pak\.patch_(\d{3})\.pak(?:\.disabled)?$").unwrap();
+    let mut max_num: u32 = 0;
+    let mut found_any = false;
+
+    log::debug!("Scanning {} for existing patch files", game_root.display());
+
+    match fs::read_dir(game_root) {
+        Ok(entries) => {
+            for entry in entries.filter_map(Result::ok) {
+                let path = entry.path();
+                if path.is_file() {
+                    if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
+                        if let Some(caps) = pak_regex.captures(file_name) {
+                            if let Some(num_str) = caps.get(1) {
+                                if let Ok(num) = num_str.as_str().parse::<u32>() {
+                                    log::trace!("Found patch file: {} with number {}", file_name, num);
+                                    max_num = max_num.max(num);
+                                    found_any = true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            return Err(format!(
+                "Failed to read game root directory {}: {}",
+                game_root.display(),
+                e
+            ));
+        }
+    }
+
+    let next_num = if found_any { max_num + 1 } else { 1 }; // Start from 001 if none found
+    log::debug!("Next available patch number determined: {}", next_num);
+    Ok(next_num)
 }
 
 /// Scans REFramework directories, compares with registry, and updates registry state.
-fn scan_and_update_reframework_mods(registry: &mut ModRegistry, game_root_path: &Path) -> Result<(), String> {
+pub(crate) fn scan_and_update_reframework_mods(
+    registry: &mut ModRegistry,
+    game_root_path: &Path,
+    install_targets: &InstallTargets,
+) -> Result<(), String> {
     log::debug!("Scanning REFramework directories in {}", game_root_path.display());
     let mut found_on_disk = HashSet::new();
     let mut disk_mod_info = HashMap::new(); // Store details like enabled status and path
 
-    let plugins_dir = game_root_path.join("reframework").join("plugins");
-    let autorun_dir = game_root_path.join("reframework").join("autorun");
+    let plugins_dir = install_targets.resolve(game_root_path, &ModType::REFrameworkPlugin);
+    let autorun_dir = install_targets.resolve(game_root_path, &ModType::REFrameworkAutorun);
 
     // Helper closure to scan a directory - mark as mutable
     let mut scan_dir = |dir: &Path, mod_type: ModType| -> Result<(), String> {
@@ -914,18 +1650,12 @@ fn scan_and_update_reframework_mods(registry: &mut ModRegistry, game_root_path:
     for disk_mod_name in found_on_disk.difference(&registry_mod_names) {
         if let Some((disk_enabled, disk_installed_dir, disk_mod_type)) = disk_mod_info.get(disk_mod_name) {
             log::info!("Found manually added mod '{}' on disk. Adding to registry.", disk_mod_name);
+            // The directory name doubles as the display name initially
             let new_mod = Mod {
-                name: disk_mod_name.clone(), // Use directory name as display name initially
-                directory_name: disk_mod_name.clone(),
                 path: "Manually Detected".to_string(), // Indicate it wasn't installed via manager
                 enabled: *disk_enabled,
-                author: None,
-                version: None,
-                description: None,
-                source: Some("manual_scan".to_string()),
-                installed_timestamp: chrono::Utc::now().timestamp(),
                 installed_directory: disk_installed_dir.clone(),
-                mod_type: disk_mod_type.clone(),
+                ..Mod::new(disk_mod_name.clone(), disk_mod_name.clone(), disk_mod_type.clone(), "manual_scan")
             };
             registry.mods.push(new_mod);
             added_new_mod = true;
@@ -944,6 +1674,7 @@ fn scan_and_update_reframework_mods(registry: &mut ModRegistry, game_root_path:
 pub async fn list_mods(
     app_handle: AppHandle,
     game_root_path: String,
+    sort_by: Option<String>,
 ) -> Result<Vec<ModInfo>, String> {
     log::info!(
         "Listing REFramework mods based on registry for game root: {}",
@@ -954,8 +1685,15 @@ pub async fn list_mods(
     let mut registry = ModRegistry::load(&app_handle)?;
 
     // --- Scan filesystem and update registry FIRST --- 
-    log::debug!("Running scan_and_update_reframework_mods before listing...");
-    if let Err(e) = scan_and_update_reframework_mods(&mut registry, &game_root) {
+    // Skipped while the folder watcher has seen nothing change since the last scan
+    let settings = AppSettings::load(&app_handle)?;
+    let scan_result = if crate::utils::modwatcher::take_reframework_rescan(&app_handle, &game_root) {
+        log::debug!("Running scan_and_update_reframework_mods before listing...");
+        scan_and_update_reframework_mods(&mut registry, &game_root, &settings.install_targets)
+    } else {
+        Ok(())
+    };
+    if let Err(e) = scan_result {
         log::error!("Error during REFramework mod scan: {}. Proceeding with potentially stale registry data.", e);
         // Decide if this should be a hard error. For now, log and continue.
     }
@@ -965,6 +1703,19 @@ pub async fn list_mods(
     // Let's comment it out for now and rely on the scan function's update logic.
     // registry.update_mod_enabled_status(&game_root)?;
 
+    // Pick up thumbnails for REFramework mods that don't have a cached one yet
+    for mod_entry in registry.mods.iter_mut().filter(|m| {
+        m.mod_type == ModType::REFrameworkPlugin || m.mod_type == ModType::REFrameworkAutorun
+    }) {
+        cache_reframework_mod_thumbnail(&app_handle, &game_root, mod_entry);
+        if let Some(mod_dir) = resolve_reframework_mod_dir(&game_root, mod_entry) {
+            apply_readme_metadata(&mod_dir, mod_entry);
+        }
+    }
+
+    // DLLs that vanished since last time are usually antivirus quarantine, not user action
+    crate::utils::quarantine::check_and_notify(&app_handle, &game_root, &registry);
+
     // --- Save registry IF changes were made by the scan --- 
     // Check if the scan modified the registry (e.g., added manual mods, changed status)
     // We need a way to track if scan_and_update_reframework_mods actually changed anything.
@@ -976,7 +1727,15 @@ pub async fn list_mods(
     }
 
     // Now get the mod info from the potentially updated registry
-    let mods_info = registry.get_reframework_mod_info();
+    let mut mods_info = registry.get_reframework_mod_info();
+    if let Some(sort_by) = sort_by.as_deref() {
+        sort_mod_infos(&mut mods_info, sort_by);
+    }
+    // Mods without any image get a generated tile (not stored, so a real image still wins later)
+    for info in &mut mods_info {
+        let name = info.name.clone().unwrap_or_else(|| info.directory_name.clone());
+        info.thumbnail_path = with_placeholder(&app_handle, info.thumbnail_path.take(), &name);
+    }
 
     log::info!(
         "Finished processing mod list. Returning {} REFramework mods to frontend.",
@@ -985,12 +1744,318 @@ pub async fn list_mods(
     Ok(mods_info)
 }
 
+/// Whether a directory looks like a skin mod: a `natives` folder or a .pak within
+/// LAYOUT_SEARCH_DEPTH levels
+pub(crate) fn is_skin_mod_dir(path: &Path) -> bool {
+    let found = find_shallowest(path, LAYOUT_SEARCH_DEPTH, |entry| {
+        let file_type = entry.file_type();
+        (file_type.is_dir() && entry.file_name().to_str() == Some("natives"))
+            || (file_type.is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pak")))
+    });
+    if let Some(entry) = &found {
+        log::debug!("Found skin mod content inside {}: {}", path.display(), entry.path().display());
+    }
+    found.is_some()
+}
+
+/// Display name for a skin mod folder. Spaces are dropped rather than split on, so
+/// "Better Camera" stays one name.
+fn skin_mod_display_name(folder_name: &str) -> String {
+    let cleaned_folder_name: String = folder_name
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\\')
+        .collect();
+    extract_mod_name_from_folder(&cleaned_folder_name)
+}
+
+/// Fold the skin registries older releases wrote into mod_registry.json. Run once at startup
+/// by the instance holding the lock. The legacy files are kept until their entries are
+/// safely on disk, so a failed save retries the import on the next start.
+pub fn migrate_legacy_skin_registries(app_handle: &AppHandle) -> Result<usize, String> {
+    let legacy_files = ModRegistry::get_registry_path(app_handle)?
+        .parent()
+        .map(legacy_skin_registry_files)
+        .unwrap_or_default();
+    if legacy_files.is_empty() {
+        return Ok(0);
+    }
+    let mut registry = ModRegistry::load(app_handle)?;
+    let imported = registry.import_legacy_skin_registries(&legacy_files);
+    if imported > 0 {
+        registry.save(app_handle)?;
+    }
+    archive_legacy_skin_registries(&legacy_files);
+    Ok(imported)
+}
+
+/// Legacy skin registries present in `config_dir`
+pub(crate) fn legacy_skin_registry_files(config_dir: &Path) -> Vec<PathBuf> {
+    LEGACY_SKIN_REGISTRY_FILES
+        .iter()
+        .map(|file_name| config_dir.join(file_name))
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+/// Rename imported legacy skin registries to <name>.imported-<timestamp>, so they are read once
+pub(crate) fn archive_legacy_skin_registries(legacy_files: &[PathBuf]) {
+    let stamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+    for legacy_path in legacy_files {
+        let Some(file_name) = legacy_path.file_name() else {
+            continue;
+        };
+        let archived = legacy_path.with_file_name(format!("{}.imported-{}", file_name.to_string_lossy(), stamp));
+        if let Err(e) = fs::rename(legacy_path, &archived) {
+            warn!("Failed to set aside legacy skin registry {}: {}", legacy_path.display(), e);
+        }
+    }
+}
+
+/// Entries of a legacy skin registry: a bare list, or a list under "skin_mods" or "skins"
+fn legacy_skin_entries(value: &serde_json::Value) -> Vec<LegacySkinEntry> {
+    let list = match value {
+        serde_json::Value::Array(list) => Some(list),
+        _ => ["skin_mods", "skins"]
+            .iter()
+            .find_map(|key| value.get(key).and_then(|v| v.as_array())),
+    };
+    list.into_iter()
+        .flatten()
+        .filter_map(|item| serde_json::from_value(item.get("base").unwrap_or(item).clone()).ok())
+        .collect()
+}
+
+/// Build a fresh (disabled) registry entry for a skin mod folder
+pub(crate) fn new_skin_mod_from_dir(path: &Path, source: &str) -> SkinMod {
+    let mod_path = path.to_string_lossy().to_string();
+    let folder_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    let display_name = skin_mod_display_name(&folder_name);
+
+    let screenshot_path = find_screenshot(path);
+
+    // Create the base Mod struct using parsed info or defaults
+    // Author, version and description come from a readme below; TODO: parse modinfo.ini
+    let mut base_mod = Mod {
+        path: mod_path.clone(),
+        installed_directory: mod_path,
+        dependencies: read_declared_dependencies(path),
+        ..Mod::new(display_name, folder_name, ModType::SkinMod, source)
+    };
+    apply_readme_metadata(path, &mut base_mod);
+
+    SkinMod {
+        base: base_mod,
+        thumbnail_path: screenshot_path,
+        conflicts: Vec::new(),
+        files: Vec::new(),
+        installed_files: Vec::new(),
+        installed_pak_path: None,
+        pack_natives: None,
+    }
+}
+
 // --------- Skin Mod Management Commands (Consolidated) --------- //
 
+/// Refresh one folder of the mods dir: `existing` is its registry entry, if it has one.
+/// None when the folder holds no skin mod content.
+fn rescan_skin_mod_dir(path: &Path, existing: Option<SkinMod>) -> Option<SkinMod> {
+    log::debug!("Inspecting potential skin mod folder: {:?}", path);
+    if !is_skin_mod_dir(path) {
+        log::debug!("Skipping directory {:?}: No 'natives' subdir or .pak file found within depth 4.", path);
+        return None;
+    }
+    let mod_path = path.to_string_lossy().to_string();
+
+    let Some(mut existing_mod) = existing else {
+        log::debug!("Found new potential skin mod: {}", mod_path);
+        let skin_mod = new_skin_mod_from_dir(path, "local_scan");
+        log::info!(
+            "Adding new skin mod: Name='{}', Path='{}', Author='{:?}', Version='{:?}'",
+            skin_mod.base.name,
+            mod_path,
+            skin_mod.base.author,
+            skin_mod.base.version
+        );
+        return Some(skin_mod);
+    };
+
+    // --- Re-apply name extraction logic for existing mods ---
+    let folder_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&existing_mod.base.directory_name) // Fallback to existing dir name if needed
+        .to_string();
+
+    let display_name = skin_mod_display_name(&folder_name);
+
+    // Update the name in the existing mod struct if it changed
+    if existing_mod.base.name != display_name {
+        log::debug!(
+            "Updating name for existing mod '{}': '{}' -> '{}'",
+            mod_path,
+            existing_mod.base.name,
+            display_name
+        );
+        existing_mod.base.name = display_name;
+    }
+    // --- End re-applying name extraction ---
+
+    // --- Always re-check for screenshot for existing mods ---
+    let current_screenshot_path = find_screenshot(path);
+    if existing_mod.thumbnail_path != current_screenshot_path {
+        log::debug!(
+            "Updating thumbnail path for existing mod '{}': {:?} -> {:?}",
+            mod_path,
+            existing_mod.thumbnail_path,
+            current_screenshot_path
+        );
+        existing_mod.thumbnail_path = current_screenshot_path;
+    }
+    // --- End screenshot re-check ---
+
+    // --- Parse modinfo.ini *again* within this scope ---
+    let mut ini_name_update: Option<String> = None;
+    let mut ini_author_update: Option<String> = None;
+    let mut ini_version_update: Option<String> = None;
+    let mut ini_description_update: Option<String> = None;
+    let mod_info_path_update = path.join("modinfo.ini"); // path should still be valid here
+
+    if mod_info_path_update.is_file() {
+        log::trace!("Re-parsing modinfo.ini for existing mod {}", mod_path);
+        match fs::File::open(&mod_info_path_update) {
+            Ok(file) => {
+                let reader = BufReader::new(file);
+                for line in reader.lines().map_while(Result::ok) {
+                    let trimmed_line = line.trim();
+                    if trimmed_line.is_empty() || trimmed_line.starts_with(';') || trimmed_line.starts_with('#') { continue; }
+                    if let Some((key, value)) = trimmed_line.split_once('=') {
+                        let key_trimmed = key.trim().to_lowercase();
+                        let value_trimmed = value.trim().to_string();
+                        if !value_trimmed.is_empty() {
+                            match key_trimmed.as_str() {
+                                "name" => ini_name_update = Some(value_trimmed), // Capture potential name change too
+                                "author" => ini_author_update = Some(value_trimmed),
+                                "version" => ini_version_update = Some(value_trimmed),
+                                "description" => ini_description_update = Some(value_trimmed),
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => log::warn!("Could not re-open modinfo.ini for {}: {}", folder_name, e),
+        }
+    } // --- End Re-parsing ---
+
+    // --- Update metadata using newly parsed values ---
+    // Update name if INI specified one (otherwise keep existing derived name)
+    if let Some(name_from_ini) = ini_name_update {
+        if existing_mod.base.name != name_from_ini {
+            log::debug!("Updating name for mod '{}' from modinfo.ini: '{}' -> '{}'", mod_path, existing_mod.base.name, name_from_ini);
+            existing_mod.base.name = name_from_ini;
+        }
+    }
+    // Only what the INI supplies is updated, so metadata from Nexus or a README isn't cleared
+    if let Some(author) = ini_author_update.filter(|a| existing_mod.base.author.as_ref() != Some(a)) {
+        log::debug!("Updating author for mod '{}': {:?} -> {:?}", mod_path, existing_mod.base.author, author);
+        existing_mod.base.author = Some(author);
+    }
+    if let Some(version) = ini_version_update.filter(|v| existing_mod.base.version.as_ref() != Some(v)) {
+        log::debug!("Updating version for mod '{}': {:?} -> {:?}", mod_path, existing_mod.base.version, version);
+        existing_mod.base.version = Some(version);
+    }
+    if let Some(description) = ini_description_update.filter(|d| existing_mod.base.description.as_ref() != Some(d)) {
+        log::debug!("Updating description for mod '{}': Changed", mod_path); // Avoid logging potentially long descriptions
+        existing_mod.base.description = Some(description);
+    }
+    let declared_dependencies = read_declared_dependencies(path);
+    if !declared_dependencies.is_empty() {
+        existing_mod.base.dependencies = declared_dependencies;
+    }
+    // Fall back to README.md/txt for anything modinfo.ini didn't provide
+    apply_readme_metadata(path, &mut existing_mod.base);
+    // --- End Metadata Update ---
+
+    // --- Re-check installed files if mod is enabled ---
+    if existing_mod.base.enabled {
+        // If the mod is marked as enabled in registry, but installed files are missing, mark as disabled
+        let all_files_exist = existing_mod.installed_files.iter().all(|f| PathBuf::from(&f.path).exists());
+        if !all_files_exist {
+            log::warn!("Mod '{}' was enabled but installed files are missing. Disabling in registry.", mod_path);
+            existing_mod.base.enabled = false;
+            existing_mod.installed_files.clear();
+            existing_mod.installed_pak_path = None;
+            // We should probably trigger a save here or after the loop
+        }
+    }
+    // --- End re-check installed files ---
+
+    log::debug!("Found existing mod in registry: {}", mod_path);
+    Some(existing_mod)
+}
+
+/// Rescan every folder directly under `mods_dir` on up to `workers` threads, since checking
+/// a folder's layout is slow on network drives. Folders with an entry in `existing` (keyed
+/// by path) get it refreshed. `on_scanned(scanned, total, found)` runs as each folder is
+/// done; the returned mods are in folder name order.
+pub(crate) fn scan_skin_mod_dirs(
+    mods_dir: &Path,
+    existing: &HashMap<String, SkinMod>,
+    workers: usize,
+    mut on_scanned: impl FnMut(usize, usize, Option<&SkinMod>),
+) -> Vec<SkinMod> {
+    let dirs: Vec<PathBuf> = Walk::new(mods_dir)
+        .min_depth(1)
+        .max_depth(1)
+        .sorted()
+        .into_iter()
+        .map(|entry| entry.path().to_path_buf())
+        .filter(|path| path.is_dir())
+        .collect();
+    let total = dirs.len();
+    let mut scanned = 0;
+    parallel_map_streaming(
+        &dirs,
+        workers,
+        |path| rescan_skin_mod_dir(path, existing.get(&*path.to_string_lossy()).cloned()),
+        |_, found| {
+            scanned += 1;
+            on_scanned(scanned, total, found.as_ref());
+        },
+    )
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Streamed by scan_and_update_skin_mods while it works through the mods folder
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event", content = "data")]
+pub enum SkinScanEvent {
+    #[serde(rename_all = "camelCase")]
+    Found {
+        skin_mod: Box<SkinMod>,
+        scanned: usize,
+        total: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    Progress { scanned: usize, total: usize },
+}
+
 #[tauri::command]
 pub async fn scan_and_update_skin_mods(
     app_handle: AppHandle,
     game_root_path: String,
+    on_event: Channel<SkinScanEvent>,
 ) -> Result<Vec<SkinMod>, String> {
     log::info!(
         "Scanning for skin mods in {} and updating registry",
@@ -1002,8 +2067,9 @@ pub async fn scan_and_update_skin_mods(
         return Err(format!("Invalid game root path: {}", game_root_path));
     }
 
-    // Look in <game_root>/fossmodmanager/mods
-    let mods_dir = game_root.join("fossmodmanager").join("mods");
+    // Look in the staging mods folder (<game_root>/fossmodmanager/mods unless moved)
+    let staging_root = crate::utils::staging::get_staging_root(&app_handle, &game_root);
+    let mods_dir = crate::utils::staging::get_mods_dir(&staging_root);
     log::debug!("Looking for mods in {:?}", mods_dir);
 
     if !mods_dir.exists() || !mods_dir.is_dir() {
@@ -1013,6 +2079,33 @@ pub async fn scan_and_update_skin_mods(
         return Ok(registry.skin_mods);
     }
 
+    // Archives dropped straight into the mods folder get extracted first
+    let settings = AppSettings::load(&app_handle)?;
+    let archives = crate::utils::archives::import_archives_in_mods_dir(&staging_root, &mods_dir);
+    let imported = archives.skin_mod_dirs;
+    if !imported.is_empty() {
+        log::info!("Imported {} mod folder(s) from archives in {:?}", imported.len(), mods_dir);
+        for dir in &imported {
+            let mod_name = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            crate::utils::posthooks::run_post_install_hook(&app_handle, &settings, &mod_name, dir);
+        }
+    }
+    // REFramework archives go through the same installer as a dropped zip; nobody is
+    // listening for its progress here
+    for archive in archives.reframework_archives {
+        let result = crate::install_mod_from_zip(
+            app_handle.clone(),
+            game_root_path.clone(),
+            archive.to_string_lossy().to_string(),
+            None,
+            Channel::new(|_| Ok(())),
+        )
+        .await;
+        if let Err(e) = result {
+            log::warn!("Failed to install {} from the mods folder: {}", archive.display(), e);
+        }
+    }
+
     // Load the existing registry
     let mut registry = ModRegistry::load(&app_handle)?;
     let mut existing_mods: HashMap<String, SkinMod> = registry
@@ -1021,277 +2114,36 @@ pub async fn scan_and_update_skin_mods(
         .map(|m| (m.base.path.clone(), m.clone())) // Use base.path here
         .collect();
 
-    let mut updated_or_new_mods = Vec::new();
-    let mut found_mod_paths = std::collections::HashSet::new();
-
-    // Scan the mods directory
-    for entry in WalkDir::new(&mods_dir)
-        .max_depth(1)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-
-        // Skip the root directory itself
-        if path == mods_dir {
-            continue;
-        }
-
-        if path.is_dir() {
-            log::debug!("Inspecting potential skin mod folder: {:?}", path);
-
-            // --- Filter Check (Recursive, limited depth) ---
-            let mut is_valid_skin_mod = false;
-            // Use WalkDir to check recursively up to depth 4 (root + 3 levels)
-            for inner_entry in WalkDir::new(path)
-                .max_depth(4)
-                .into_iter()
-                .filter_map(Result::ok)
-            {
-                let inner_path = inner_entry.path();
-
-                // Check if it's a directory named "natives"
-                if inner_path.is_dir() && inner_entry.file_name().to_str() == Some("natives") {
-                    is_valid_skin_mod = true;
-                    log::debug!("Found 'natives' directory inside: {}", inner_path.display());
-                    break; // Found one condition, no need to check further
-                }
-
-                // Check if it's a file with a .pak extension
-                if inner_path.is_file() {
-                    if let Some(ext) = inner_path.extension().and_then(|s| s.to_str()) {
-                        if ext.eq_ignore_ascii_case("pak") {
-                            is_valid_skin_mod = true;
-                            log::debug!("Found .pak file inside: {}", inner_path.display());
-                            break; // Found one condition, no need to check further
-                        }
-                    }
-                }
-            }
-
-            // Skip if neither condition was met during the recursive check
-            if !is_valid_skin_mod {
-                log::debug!("Skipping directory {:?}: No 'natives' subdir or .pak file found within depth 4.", path);
-                continue;
-            }
-            // --- End Filter Check ---
-
-            // Get mod path as string
-            let mod_path = path.to_string_lossy().to_string();
-            found_mod_paths.insert(mod_path.clone());
-
-            // Check if we already have this mod in the registry
-            if let Some(mut existing_mod) = existing_mods.remove(&mod_path) {
-                // Make existing_mod mutable
-
-                // --- Re-apply name extraction logic for existing mods ---
-                let folder_name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or(&existing_mod.base.directory_name) // Fallback to existing dir name if needed
-                    .to_string();
-
-                let delimiters: &[char] = &['_', '-', ' ', '!', '#', '$', '.', '(', '['];
-                let cleaned_folder_name: String = folder_name
-                    .chars()
-                    .filter(|c| !c.is_whitespace() && *c != '\\')
-                    .collect();
-
-                // --- Refined Name Extraction Logic (Handles MHW/MHWs prefix) ---
-                let display_name = match cleaned_folder_name.find(delimiters) {
-                    Some(first_delim_index) => {
-                        let prefix = &cleaned_folder_name[..first_delim_index];
-                        if prefix.eq_ignore_ascii_case("mhw") || prefix.eq_ignore_ascii_case("mhws")
-                        {
-                            // Found MHW(s) prefix, look at the part *after* the delimiter
-                            let suffix = &cleaned_folder_name[first_delim_index + 1..];
-                            match suffix.find(delimiters) {
-                                Some(second_delim_index) => {
-                                    suffix[..second_delim_index].to_string()
-                                } // Take part before next delimiter
-                                None => suffix.to_string(), // No more delimiters, take the whole suffix
-                            }
-                        } else {
-                            // Prefix is not MHW(s), just use the prefix
-                            prefix.to_string()
-                        }
-                    }
-                    None => cleaned_folder_name, // No delimiters found, use the whole cleaned name
-                };
-                // --- End Refined Name Extraction ---
-
-                // Update the name in the existing mod struct if it changed
-                if existing_mod.base.name != display_name {
-                    log::debug!(
-                        "Updating name for existing mod '{}': '{}' -> '{}'",
-                        mod_path,
-                        existing_mod.base.name,
-                        display_name
-                    );
-                    existing_mod.base.name = display_name;
-                }
-                // --- End re-applying name extraction ---
-
-                // --- Always re-check for screenshot for existing mods --- 
-                let current_screenshot_path = find_screenshot(path);
-                if existing_mod.thumbnail_path != current_screenshot_path {
-                    log::debug!(
-                        "Updating thumbnail path for existing mod '{}': {:?} -> {:?}",
-                        mod_path,
-                        existing_mod.thumbnail_path,
-                        current_screenshot_path
-                    );
-                    existing_mod.thumbnail_path = current_screenshot_path;
-                }
-                // --- End screenshot re-check ---
-
-                // --- Parse modinfo.ini *again* within this scope ---
-                let mut ini_name_update: Option<String> = None;
-                let mut ini_author_update: Option<String> = None;
-                let mut ini_version_update: Option<String> = None;
-                let mut ini_description_update: Option<String> = None;
-                let mod_info_path_update = path.join("modinfo.ini"); // path should still be valid here
-
-                if mod_info_path_update.is_file() {
-                    log::trace!("Re-parsing modinfo.ini for existing mod {}", mod_path);
-                    match fs::File::open(&mod_info_path_update) {
-                        Ok(file) => {
-                            let reader = BufReader::new(file);
-                            for line_result in reader.lines() {
-                                if let Ok(line) = line_result {
-                                    let trimmed_line = line.trim();
-                                    if trimmed_line.is_empty() || trimmed_line.starts_with(';') || trimmed_line.starts_with('#') { continue; }
-                                    if let Some((key, value)) = trimmed_line.split_once('=') {
-                                        let key_trimmed = key.trim().to_lowercase();
-                                        let value_trimmed = value.trim().to_string();
-                                        if !value_trimmed.is_empty() {
-                                            match key_trimmed.as_str() {
-                                                "name" => ini_name_update = Some(value_trimmed), // Capture potential name change too
-                                                "author" => ini_author_update = Some(value_trimmed),
-                                                "version" => ini_version_update = Some(value_trimmed),
-                                                "description" => ini_description_update = Some(value_trimmed),
-                                                _ => {}
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => log::warn!("Could not re-open modinfo.ini for {}: {}", folder_name, e),
-                    }
-                } // --- End Re-parsing ---
-
-
-                // --- Update metadata using newly parsed values --- 
-                // Update name if INI specified one (otherwise keep existing derived name)
-                if let Some(name_from_ini) = ini_name_update {
-                     if existing_mod.base.name != name_from_ini {
-                         log::debug!("Updating name for mod '{}' from modinfo.ini: '{}' -> '{}'", mod_path, existing_mod.base.name, name_from_ini);
-                         existing_mod.base.name = name_from_ini;
-                     }
-                 }
-                // Update other fields using the parsed values (which default to None if not found)
-                if existing_mod.base.author != ini_author_update {
-                    log::debug!("Updating author for mod '{}': {:?} -> {:?}", mod_path, existing_mod.base.author, ini_author_update);
-                    existing_mod.base.author = ini_author_update;
-                }
-                if existing_mod.base.version != ini_version_update {
-                     log::debug!("Updating version for mod '{}': {:?} -> {:?}", mod_path, existing_mod.base.version, ini_version_update);
-                     existing_mod.base.version = ini_version_update;
-                }
-                 if existing_mod.base.description != ini_description_update {
-                     log::debug!("Updating description for mod '{}': Changed", mod_path); // Avoid logging potentially long descriptions
-                     existing_mod.base.description = ini_description_update;
-                 }
-                 // --- End Metadata Update --- 
-
-                // --- Re-check installed files if mod is enabled ---
-                if existing_mod.base.enabled {
-                    // If the mod is marked as enabled in registry, but installed files are missing, mark as disabled
-                    let all_files_exist = existing_mod.installed_files.iter().all(|f| PathBuf::from(f).exists());
-                    if !all_files_exist {
-                        log::warn!("Mod '{}' was enabled but installed files are missing. Disabling in registry.", mod_path);
-                        existing_mod.base.enabled = false;
-                        existing_mod.installed_files.clear();
-                        existing_mod.installed_pak_path = None;
-                        // We should probably trigger a save here or after the loop
-                    }
-                }
-                // --- End re-check installed files ---
-
-                updated_or_new_mods.push(existing_mod); // Push the potentially updated mod
-                log::debug!("Found existing mod in registry: {}", mod_path);
-                continue;
-            }
-
-            // If not in registry, it's a new mod
-            log::debug!("Found new potential skin mod: {}", mod_path);
-            let folder_name = path
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("Unknown")
-                .to_string();
-
-            // --- Refined Name Extraction ---
-            let delimiters: &[char] = &['_', '-', ' ', '!', '#', '$', '.', '(', '['];
-            let cleaned_folder_name: String = folder_name
-                .chars()
-                .filter(|c| !c.is_whitespace() && *c != '\\')
-                .collect();
-
-            let display_name = match cleaned_folder_name.find(delimiters) {
-                Some(first_delim_index) => {
-                    let prefix = &cleaned_folder_name[..first_delim_index];
-                    if prefix.eq_ignore_ascii_case("mhw") || prefix.eq_ignore_ascii_case("mhws") {
-                        // Found MHW(s) prefix, look at the part *after* the delimiter
-                        let suffix = &cleaned_folder_name[first_delim_index + 1..];
-                        match suffix.find(delimiters) {
-                            Some(second_delim_index) => suffix[..second_delim_index].to_string(), // Take part before next delimiter
-                            None => suffix.to_string(), // No more delimiters, take the whole suffix
-                        }
-                    } else {
-                        // Prefix is not MHW(s), just use the prefix
-                        prefix.to_string()
-                    }
+    let throttle = crate::utils::throttle::ProgressThrottle::for_ui();
+    let mut updated_or_new_mods = scan_skin_mod_dirs(
+        &mods_dir,
+        &existing_mods,
+        settings.worker_count(&game_root),
+        |scanned, total, found| {
+            let event = match found {
+                Some(skin_mod) => SkinScanEvent::Found {
+                    skin_mod: Box::new(skin_mod.clone()),
+                    scanned,
+                    total,
+                },
+                None if throttle.should_emit(scanned as u64, total as u64) => {
+                    SkinScanEvent::Progress { scanned, total }
                 }
-                None => cleaned_folder_name, // No delimiters found, use the whole cleaned name
-            };
-            // --- End Refined Name Extraction ---
-
-            let screenshot_path = find_screenshot(path);
-
-            // Create the base Mod struct using parsed info or defaults
-            let base_mod = Mod {
-                name: display_name.clone(),
-                directory_name: folder_name.clone(),
-                path: mod_path.clone(),
-                enabled: false,
-                author: None,      // TODO: Parse from modinfo.ini
-                version: None,     // TODO: Parse from modinfo.ini
-                description: None, // TODO: Parse from modinfo.ini
-                source: Some("local_scan".to_string()),
-                installed_timestamp: chrono::Utc::now().timestamp(),
-                installed_directory: mod_path.clone(),
-                mod_type: ModType::SkinMod,
+                None => return,
             };
+            if let Err(e) = on_event.send(event) {
+                log::warn!("Failed to send skin scan event: {}", e);
+            }
+        },
+    );
+    for skin_mod in &updated_or_new_mods {
+        existing_mods.remove(&skin_mod.base.path);
+    }
 
-            // Create the SkinMod struct
-            let skin_mod = SkinMod {
-                base: base_mod,
-                thumbnail_path: screenshot_path,
-                conflicts: Vec::new(),
-                files: Vec::new(),
-                installed_files: Vec::new(),
-                installed_pak_path: None,
-            };
-            log::info!(
-                "Adding new skin mod: Name='{}', Path='{}', Author='{:?}', Version='{:?}'",
-                display_name,
-                mod_path,
-                skin_mod.base.author,
-                skin_mod.base.version
-            );
-            updated_or_new_mods.push(skin_mod);
+    // Externally registered mods live outside the mods dir, keep them while they still exist
+    for (external_path, external_mod) in existing_mods {
+        if external_mod.base.source.as_deref() == Some("external") && Path::new(&external_path).is_dir() {
+            updated_or_new_mods.push(external_mod);
         }
     }
 
@@ -1307,42 +2159,297 @@ pub async fn scan_and_update_skin_mods(
     Ok(registry.skin_mods)
 }
 
+/// Register a skin mod folder that lives outside fossmodmanager/mods.
+/// Depending on settings it is either referenced in place or copied into staging.
 #[tauri::command]
-pub async fn enable_skin_mod_via_registry(
+pub async fn add_external_skin_mod(
     app_handle: AppHandle,
     game_root_path: String,
-    mod_path: String, // Use the original path as identifier
-) -> Result<(), String> {
-    log::info!("Enabling skin mod via registry: {}", mod_path);
+    path: String,
+) -> Result<SkinMod, String> {
+    log::info!("Adding external skin mod from: {}", path);
 
     let game_root = PathBuf::from(&game_root_path);
-    if !game_root.exists() || !game_root.is_dir() {
-        return Err(format!("Invalid game root path: {}", game_root_path));
-    }
+    let staging_root = crate::utils::staging::get_staging_root(&app_handle, &game_root);
+    let mods_dir = crate::utils::staging::get_mods_dir(&staging_root);
+    let source_dir = PathBuf::from(&path);
 
-    let mod_dir = PathBuf::from(&mod_path);
-    if !mod_dir.exists() || !mod_dir.is_dir() {
-        return Err(format!("Invalid mod path: {}", mod_path));
+    if !source_dir.is_dir() {
+        return Err(format!("Not a directory: {}", path));
+    }
+    if !is_skin_mod_dir(&source_dir) {
+        return Err(format!(
+            "'{}' doesn't look like a skin mod (no 'natives' folder or .pak file found)",
+            path
+        ));
+    }
+    if source_dir.starts_with(&mods_dir) {
+        return Err(format!(
+            "'{}' is already inside the mods folder, rescan instead",
+            path
+        ));
     }
 
-    // Load the registry
     let mut registry = ModRegistry::load(&app_handle)?;
+    if let Some(existing) = registry.skin_mods.iter().find(|m| m.base.path == path) {
+        log::info!("External skin mod '{}' is already registered", path);
+        return Ok(existing.clone());
+    }
 
-    // Find the mod to enable
-    let mod_index = registry
-        .skin_mods
-        .iter()
-        .position(|m| m.base.path == mod_path)
-        .ok_or_else(|| format!("SkinMod with path '{}' not found in registry", mod_path))?;
+    let folder_name = source_dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid folder name: {}", path))?
+        .to_string();
+    if registry.find_skin_mod(&folder_name).is_some() {
+        return Err(format!("A skin mod named '{}' is already registered", folder_name));
+    }
 
-    // Check if already enabled
-    if registry.skin_mods[mod_index].base.enabled {
-        log::info!("SkinMod '{}' is already enabled.", mod_path);
-        // Optionally, verify installed files here and reinstall if needed?
-        // For now, just return Ok.
+    let settings = AppSettings::load(&app_handle)?;
+    let skin_mod = if settings.import_external_skin_mods {
+        let staged_dir = mods_dir.join(&folder_name);
+        log::info!("Importing {} into staging at {}", path, staged_dir.display());
+        crate::utils::fsops::copy_dir_all(&source_dir, &staged_dir)?;
+        new_skin_mod_from_dir(&staged_dir, "local_scan")
+    } else {
+        new_skin_mod_from_dir(&source_dir, "external")
+    };
+
+    registry.add_skin_mod(skin_mod.clone());
+    registry.save(&app_handle)?;
+
+    log::info!(
+        "Registered skin mod '{}' from {}",
+        skin_mod.base.name,
+        skin_mod.base.path
+    );
+    Ok(skin_mod)
+}
+
+/// A skin mod converted from the form it ships in
+enum ConvertedDeployment {
+    Packed(String), // Its natives, packed into this patch pak
+    Unpacked(Vec<(PathBuf, Vec<PlannedExtraction>)>), // Its paks, with where each file goes as loose natives
+}
+
+/// Deploy a skin mod in the other form when the deployment mode calls for it:
+/// natives-only mods are packed into a patch pak (one file instead of thousands), pak-only
+/// mods unpacked into loose natives. Any failure falls back to deploying the files as shipped.
+/// Packing happens here; unpacking is only planned, so the files it writes can be backed up
+/// and checked against other mods first. Returns None to deploy the mod as shipped.
+fn convert_for_deployment(
+    mod_dir: &Path,
+    settings: &AppSettings,
+    pack_override: Option<bool>,
+    pak_target_dir: &Path,
+    game_natives_dir: &Path,
+    report_bytes: &(dyn Fn(u64) + Sync),
+) -> Option<ConvertedDeployment> {
+    let root_paks: Vec<PathBuf> = fs::read_dir(mod_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pak")))
+        .collect();
+    let has_natives = mod_dir.join("natives").is_dir();
+
+    // A per-mod choice wins over the global deployment mode
+    let pack = is_enabled(settings, FeatureFlag::PakPacking)
+        && pack_override.unwrap_or(settings.deployment_mode == DeploymentMode::Pak);
+
+    match settings.deployment_mode {
+        _ if pack && has_natives && root_paks.is_empty() => {
+            if let Err(e) = fs::create_dir_all(pak_target_dir) {
+                log::warn!("Failed to create pak directory {}: {}", pak_target_dir.display(), e);
+                return None;
+            }
+            let dest_path = find_next_available_patch_number(pak_target_dir)
+                .map(|n| pak_target_dir.join(format!("re_chunk_000.pak.sub_000.pak.patch_{:03}.pak", n)))
+                .ok()?;
+            let natives_path = |rel: &Path| settings.install_targets.natives_platform_path(rel).0;
+            match crate::utils::repak::pack_natives_to_pak(mod_dir, &dest_path, &natives_path, report_bytes) {
+                Ok(_) => Some(ConvertedDeployment::Packed(dest_path.to_string_lossy().to_string())),
+                Err(e) => {
+                    log::warn!("Packing {} failed, deploying loose files instead: {}", mod_dir.display(), e);
+                    None
+                }
+            }
+        }
+        DeploymentMode::LooseNatives if pack_override != Some(true) && !root_paks.is_empty() && !has_natives => {
+            let Some(list_path) = settings.pak_file_list.as_deref() else {
+                log::warn!("No pak file list configured; deploying {} as a pak", mod_dir.display());
+                return None;
+            };
+            let file_list = match crate::utils::repak::load_file_list(Path::new(list_path)) {
+                Ok(list) => list,
+                Err(e) => {
+                    log::warn!("{}; deploying {} as a pak", e, mod_dir.display());
+                    return None;
+                }
+            };
+            let mut planned = Vec::new();
+            for pak in root_paks {
+                match plan_pak_extraction(&pak, &file_list, game_natives_dir) {
+                    Ok(files) => planned.push((pak, files)),
+                    Err(e) => {
+                        log::warn!("{}; deploying {} as a pak", e, mod_dir.display());
+                        return None;
+                    }
+                }
+            }
+            Some(ConvertedDeployment::Unpacked(planned))
+        }
+        _ => None,
+    }
+}
+
+/// How to settle a natives file that another enabled skin mod already deployed
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConflictResolution {
+    Keep,    // Leave the other mod's file in place and skip ours
+    Replace, // Overwrite it; the incoming mod takes ownership of the file
+}
+
+/// A natives file the incoming mod would overwrite
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NativesConflict {
+    pub relative_path: String,      // Path under natives/, the key for resolutions
+    pub file: String,               // Deployed path in the game directory
+    pub current_owner: String,      // Source path of the enabled mod that deployed it
+    pub current_owner_name: String,
+    pub incoming_mod: String,       // Source path of the mod being enabled
+}
+
+/// Result of `enable_skin_mod_via_registry`: either enabled, or the conflicts to resolve first
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SkinEnableResult {
+    pub enabled: bool,
+    pub conflicts: Vec<NativesConflict>,
+}
+
+/// Deployed paths compare case-insensitively with either separator
+pub(crate) fn owned_path_key(path: &str) -> String {
+    path.replace('\\', "/").to_lowercase()
+}
+
+/// Deployed file -> index of the enabled skin mod that owns it (excluding `mod_path`)
+pub(crate) fn deployed_file_owners(registry: &ModRegistry, mod_path: &str) -> HashMap<String, usize> {
+    registry
+        .skin_mods
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.base.enabled && m.base.path != mod_path)
+        .flat_map(|(i, m)| m.installed_files.iter().map(move |f| (owned_path_key(&f.path), i)))
+        .collect()
+}
+
+/// Natives files `mod_path` would deploy that another enabled skin mod already owns
+pub(crate) fn find_natives_conflicts(
+    registry: &ModRegistry,
+    game_root: &Path,
+    mod_path: &str,
+    settings: &AppSettings,
+) -> Vec<NativesConflict> {
+    let owners = deployed_file_owners(registry, mod_path);
+    if owners.is_empty() {
+        return Vec::new();
+    }
+    let natives_prefix = Path::new(mod_path).join("natives");
+    let game_natives_dir = settings.install_targets.resolve(game_root, &ModType::NativesMod);
+    Walk::new(&natives_prefix)
+        .files()
+        .filter_map(|e| {
+            let (rel, _) = settings
+                .install_targets
+                .natives_platform_path(e.path().strip_prefix(&natives_prefix).ok()?);
+            let dest = game_natives_dir.join(&rel).to_string_lossy().to_string();
+            let owner = &registry.skin_mods[*owners.get(&owned_path_key(&dest))?];
+            Some(NativesConflict {
+                relative_path: rel.to_string_lossy().replace('\\', "/"),
+                file: dest,
+                current_owner: owner.base.path.clone(),
+                current_owner_name: owner.base.name.clone(),
+                incoming_mod: mod_path.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Progress of a skin mod deployment, emitted as "skin-deploy-progress"
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DeployProgress {
+    pub mod_path: String,
+    pub bytes_copied: u64,
+    pub total_bytes: u64,
+}
+
+/// Undo a skin deployment that failed partway: remove what was copied and put back the files
+/// it overwrote, leaving the game folder as it was before. Returns what couldn't be undone.
+fn roll_back_skin_deployment(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_key: &str,
+    deployed: &[String],
+    ledger: &LedgerOperation,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for file in deployed {
+        let path = PathBuf::from(file);
+        match retry_if_locked(&path, || fs::remove_file(long_path(&path))) {
+            Ok(()) => ledger.deleted(&path, false),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => errors.push(format!("Failed to remove {}: {}", path.display(), e)),
+        }
+    }
+    errors.extend(restore_overwritten_files(registry, game_root, mod_key).errors);
+    log::info!("Rolled back {} deployed file(s) of '{}'", deployed.len(), mod_key);
+    errors
+}
+
+fn deployment_failed(error: String, rollback_errors: Vec<String>) -> String {
+    if rollback_errors.is_empty() {
+        return format!("{} (the files copied so far were removed again)", error);
+    }
+    format!("{} (rolling back also failed: {})", error, rollback_errors.join("; "))
+}
+
+/// Copy a skin mod's .pak and natives files into the game directory and mark it enabled.
+/// Natives files owned by another enabled skin follow `resolutions` (keyed by path under
+/// natives/); unlisted collisions are replaced. `on_progress` receives (bytes copied, total bytes).
+/// Only updates the in-memory registry; callers are responsible for saving it.
+pub(crate) fn enable_skin_mod_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_path: &str,
+    settings: &AppSettings,
+    resolutions: &HashMap<String, ConflictResolution>,
+    on_progress: &(dyn Fn(u64, u64) + Sync),
+) -> Result<(), String> {
+    // Find the mod to enable
+    let mod_index = registry
+        .skin_mods
+        .iter()
+        .position(|m| m.base.path == mod_path)
+        .ok_or_else(|| format!("SkinMod with path '{}' not found in registry", mod_path))?;
+
+    // Check if already enabled
+    if registry.skin_mods[mod_index].base.enabled {
+        log::info!("SkinMod '{}' is already enabled.", mod_path);
+        // Optionally, verify installed files here and reinstall if needed?
+        // For now, just return Ok.
         return Ok(());
     }
 
+    let mod_dir = PathBuf::from(mod_path);
+    let owners = deployed_file_owners(registry, mod_path);
+    let owner_paths: HashMap<String, String> = owners
+        .iter()
+        .map(|(key, i)| (key.clone(), registry.skin_mods[*i].base.path.clone()))
+        .collect();
+    let mod_key = overwrite_backup_key(game_root, &registry.skin_mods[mod_index].base);
+    let mut taken_over: Vec<(usize, String)> = Vec::new();
+
     // Get mutable reference to the mod we are enabling
     // Do this early to ensure we can update it later
     let skin_mod_entry = registry.skin_mods.get_mut(mod_index).unwrap();
@@ -1353,105 +2460,247 @@ pub async fn enable_skin_mod_via_registry(
 
     let mut installed_files_tracker = Vec::new();
     let mut installed_pak_path_tracker: Option<String> = None;
+    let mut natives_copies: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let mut platform_corrected = 0usize;
+    let ledger = LedgerOperation::begin(game_root, "enable_skin_mod");
 
 
     // Walk the mod directory to find .pak and natives/ files
     log::debug!("Scanning mod directory {} for files to install", mod_dir.display());
+    let install_targets = &settings.install_targets;
+    let workers = settings.worker_count(game_root);
+    let verify = settings.verify_deployed_files;
     let natives_prefix = mod_dir.join("natives");
-    let game_natives_dir = game_root.join("natives");
-
-    for entry_res in WalkDir::new(&mod_dir).into_iter() {
-        let entry = match entry_res {
-            Ok(e) => e,
-            Err(err) => {
-                log::warn!("Error walking mod directory {}: {}", mod_dir.display(), err);
-                continue; // Skip problematic entries
-            }
-        };
+    let game_natives_dir = install_targets.resolve(game_root, &ModType::NativesMod);
+    let pak_target_dir = install_targets.resolve(game_root, &ModType::SkinMod);
 
-        let source_path = entry.path();
+    // Size everything up front so progress can be reported in bytes
+    let is_root_pak = |p: &Path| {
+        p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pak")) && p.parent() == Some(mod_dir.as_path())
+    };
+    let total_bytes: u64 = Walk::new(&mod_dir)
+        .files()
+        .filter(|e| is_root_pak(e.path()) || e.path().starts_with(&natives_prefix))
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum();
+    let bytes_copied = std::sync::atomic::AtomicU64::new(0);
+    let report_bytes = |n: u64| {
+        let copied = bytes_copied.fetch_add(n, std::sync::atomic::Ordering::Relaxed) + n;
+        on_progress(copied, total_bytes);
+    };
 
-        // Skip directories
-        if !source_path.is_file() {
-            continue;
+    // Files another enabled skin deployed are kept or taken over as resolved; true to skip ours
+    let keeps_existing_file = |dest_path: &Path, taken_over: &mut Vec<(usize, String)>| {
+        let Some(owner_index) = owners.get(&owned_path_key(&dest_path.to_string_lossy())) else {
+            return false;
+        };
+        let key = dest_path
+            .strip_prefix(&game_natives_dir)
+            .unwrap_or(dest_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if resolutions.get(&key) == Some(&ConflictResolution::Keep) {
+            log::info!("Keeping existing natives file {} (conflict resolved as keep)", dest_path.display());
+            return true;
+        }
+        taken_over.push((*owner_index, dest_path.to_string_lossy().to_string()));
+        false
+    };
+    let mut pak_extractions: Vec<(PathBuf, Vec<PlannedExtraction>)> = Vec::new();
+
+    // Convert between pak and loose natives when the deployment mode asks for the other form
+    let converted = convert_for_deployment(
+        &mod_dir,
+        settings,
+        skin_mod_entry.pack_natives,
+        &pak_target_dir,
+        &game_natives_dir,
+        &report_bytes,
+    );
+    if let Some(ConvertedDeployment::Packed(pak_path)) = converted {
+        ledger.wrote(Path::new(&pak_path), false);
+        installed_files_tracker.push(pak_path.clone());
+        installed_pak_path_tracker = Some(pak_path);
+    } else if let Some(ConvertedDeployment::Unpacked(planned)) = converted {
+        for (pak, mut files) in planned {
+            files.retain(|file| !keeps_existing_file(&file.dest, &mut taken_over));
+            pak_extractions.push((pak, files));
         }
+    } else {
+        for entry in Walk::new(&mod_dir) {
+            let source_path = entry.path();
 
-        // --- Handle .pak files ---
-        if source_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pak")) && source_path.parent() == Some(&mod_dir) {
-            // Only process .pak files directly in the mod root for now
-            // TODO: Decide if we need to handle .pak in subdirs differently
+            // Skip directories
+            if !source_path.is_file() {
+                continue;
+            }
 
-            let next_patch_num = find_next_available_patch_number(&game_root)?;
-            let pak_file_name = format!("re_chunk_000.pak.sub_000.pak.patch_{:03}.pak", next_patch_num);
-            let dest_path = game_root.join(&pak_file_name);
+            // --- Handle .pak files ---
+            if is_root_pak(source_path) {
+                // Only process .pak files directly in the mod root for now
+                // TODO: Decide if we need to handle .pak in subdirs differently
 
-            log::info!(
-                "Installing .pak file: {} -> {} (as {})",
-                source_path.display(),
-                dest_path.display(),
-                pak_file_name
-            );
+                fs::create_dir_all(&pak_target_dir).map_err(|e| {
+                    format!("Failed to create pak directory {}: {}", pak_target_dir.display(), e)
+                })?;
+                let next_patch_num = find_next_available_patch_number(&pak_target_dir)?;
+                let pak_file_name = format!("re_chunk_000.pak.sub_000.pak.patch_{:03}.pak", next_patch_num);
+                let dest_path = pak_target_dir.join(&pak_file_name);
 
-            fs::copy(source_path, &dest_path).map_err(|e| {
-                format!(
-                    "Failed to copy .pak file {} to {}: {}",
+                log::info!(
+                    "Installing .pak file: {} -> {} (as {})",
                     source_path.display(),
                     dest_path.display(),
-                    e
-                )
-            })?;
+                    pak_file_name
+                );
 
-            let dest_path_str = dest_path.to_string_lossy().to_string();
-            installed_files_tracker.push(dest_path_str.clone());
-            // Assume only one pak file per mod for now, overwrite if multiple found
-            installed_pak_path_tracker = Some(dest_path_str);
-
-        // --- Handle natives files ---
-        } else if source_path.starts_with(&natives_prefix) {
-            let rel_path = match source_path.strip_prefix(&natives_prefix) {
-                Ok(p) => p,
-                Err(_) => {
-                    log::warn!("Failed to strip prefix for natives file: {}", source_path.display());
-                    continue; // Skip if path logic fails
+                if let Err(e) = copy_file_with_progress(source_path, &dest_path, verify, &report_bytes) {
+                    let error = if is_locked_files_error(&e) {
+                        locked_files_error(std::slice::from_ref(&dest_path))
+                    } else {
+                        installed_files_tracker.push(dest_path.to_string_lossy().to_string());
+                        format!(
+                            "Failed to copy .pak file {} to {}: {}",
+                            source_path.display(),
+                            dest_path.display(),
+                            e
+                        )
+                    };
+                    let rollback_errors =
+                        roll_back_skin_deployment(registry, game_root, &mod_key, &installed_files_tracker, &ledger);
+                    return Err(deployment_failed(error, rollback_errors));
                 }
-            };
+                ledger.wrote(&dest_path, false);
+
+                let dest_path_str = dest_path.to_string_lossy().to_string();
+                installed_files_tracker.push(dest_path_str.clone());
+                // Assume only one pak file per mod for now, overwrite if multiple found
+                installed_pak_path_tracker = Some(dest_path_str);
+
+            // --- Handle natives files ---
+            } else if source_path.starts_with(&natives_prefix) {
+                let rel_path = match source_path.strip_prefix(&natives_prefix) {
+                    Ok(p) => p,
+                    Err(_) => {
+                        log::warn!("Failed to strip prefix for natives file: {}", source_path.display());
+                        continue; // Skip if path logic fails
+                    }
+                };
 
-            let dest_path = game_natives_dir.join(rel_path);
+                // Files built for another platform folder go where the game will read them
+                let (rel_path, corrected) = install_targets.natives_platform_path(rel_path);
+                if corrected {
+                    platform_corrected += 1;
+                }
+                let dest_path = game_natives_dir.join(&rel_path);
 
-            // Ensure parent directory exists in game natives
-            if let Some(parent) = dest_path.parent() {
-                if !parent.exists() {
-                    fs::create_dir_all(parent).map_err(|e| {
-                        format!("Failed to create natives subdirectory {}: {}", parent.display(), e)
-                    })?;
-                    log::debug!("Created directory: {}", parent.display());
+                if keeps_existing_file(&dest_path, &mut taken_over) {
+                    continue;
                 }
+
+                // Ensure parent directory exists in game natives
+                if let Some(parent) = dest_path.parent() {
+                    if !long_path(parent).exists() {
+                        fs::create_dir_all(long_path(parent)).map_err(|e| {
+                            format!("Failed to create natives subdirectory {}: {}", parent.display(), e)
+                        })?;
+                        log::debug!("Created directory: {}", parent.display());
+                    }
+                }
+
+                natives_copies.push((source_path.to_path_buf(), dest_path));
+            } else {
+                log::trace!("Skipping file during install (not .pak in root or under natives/): {}", source_path.display());
             }
+        }
+    }
 
-            log::info!(
-                "Installing natives file: {} -> {}",
-                source_path.display(),
-                dest_path.display()
-            );
-            fs::copy(source_path, &dest_path).map_err(|e| {
+    if platform_corrected > 0 {
+        log::warn!(
+            "'{}' ships {} natives file(s) outside natives/{}/; deploying them there instead",
+            mod_path,
+            platform_corrected,
+            install_targets.natives_platform
+        );
+    }
+
+    // Keep whatever is about to be overwritten so disabling this mod can put it back
+    let overwrite_targets: Vec<PathBuf> = natives_copies
+        .iter()
+        .map(|(_, dest)| dest.clone())
+        .chain(pak_extractions.iter().flat_map(|(_, files)| files.iter().map(|f| f.dest.clone())))
+        .collect();
+    back_up_overwritten_files(game_root, &mod_key, mod_path, &overwrite_targets, &|dest| {
+        owner_paths.get(&owned_path_key(&dest.to_string_lossy())).cloned()
+    })?;
+
+    for (pak, files) in &pak_extractions {
+        match extract_pak_to_natives(pak, files, &report_bytes) {
+            Ok(written) => {
+                for (dest_path, replaced) in written {
+                    ledger.wrote(&dest_path, replaced);
+                    installed_files_tracker.push(dest_path.to_string_lossy().to_string());
+                }
+            }
+            Err(e) => {
+                let rollback_errors =
+                    roll_back_skin_deployment(registry, game_root, &mod_key, &installed_files_tracker, &ledger);
+                return Err(deployment_failed(e, rollback_errors));
+            }
+        }
+    }
+
+    // Natives mods can be thousands of files, so copy them on the configured workers
+    let copy_results = crate::utils::fsops::parallel_map(&natives_copies, workers, |(source, dest)| {
+        log::info!("Installing natives file: {} -> {}", source.display(), dest.display());
+        let replaced = long_path(dest).exists();
+        copy_file_with_progress(source, dest, verify, &report_bytes)
+            .map(|_| replaced)
+            .map_err(|e| {
                 format!(
                     "Failed to copy natives file {} to {}: {}",
-                    source_path.display(),
-                    dest_path.display(),
+                    source.display(),
+                    dest.display(),
                     e
                 )
-            })?;
-            installed_files_tracker.push(dest_path.to_string_lossy().to_string());
-        } else {
-             log::trace!("Skipping file during install (not .pak in root or under natives/): {}", source_path.display());
+            })
+    });
+    // Record every copy that landed before reporting the first failure
+    let mut first_error = None;
+    let mut locked = Vec::new();
+    let mut partial_copies = Vec::new();
+    for ((_, dest_path), result) in natives_copies.iter().zip(copy_results) {
+        match result {
+            Ok(replaced) => {
+                ledger.wrote(dest_path, replaced);
+                installed_files_tracker.push(dest_path.to_string_lossy().to_string());
+            }
+            Err(e) => {
+                if is_locked_files_error(&e) {
+                    locked.push(dest_path.clone());
+                } else {
+                    partial_copies.push(dest_path.to_string_lossy().to_string());
+                }
+                first_error.get_or_insert(e);
+            }
         }
     }
+    // Don't leave the mod half deployed: take back what was copied
+    if let Some(e) = first_error {
+        let error = if locked.is_empty() { e } else { locked_files_error(&locked) };
+        installed_files_tracker.extend(partial_copies);
+        let rollback_errors =
+            roll_back_skin_deployment(registry, game_root, &mod_key, &installed_files_tracker, &ledger);
+        return Err(deployment_failed(error, rollback_errors));
+    }
 
 
     // --- Update the registry entry ---
     // We already have skin_mod_entry as a mutable reference
-    skin_mod_entry.base.enabled = true;
-    skin_mod_entry.installed_files = installed_files_tracker; // Store the collected list
+    skin_mod_entry.base.set_enabled(true);
+    // Hash what landed so later verification can tell when something outside the manager changed it
+    skin_mod_entry.installed_files = record_installed_files(installed_files_tracker, workers);
     skin_mod_entry.installed_pak_path = installed_pak_path_tracker; // Store the installed pak path
 
     log::info!(
@@ -1462,30 +2711,111 @@ pub async fn enable_skin_mod_via_registry(
         skin_mod_entry.installed_files.len()
     );
 
-    // --- Save the updated registry ---
-    registry.last_updated = chrono::Utc::now().timestamp();
-    if let Err(e) = registry.save(&app_handle) {
-        // Attempt to clean up installed files if save fails? This could be complex.
-        // For now, just return the save error.
-        log::error!("Failed to save registry after enabling mod {}: {}", mod_path, e);
-        return Err(format!("Failed to save registry state after enabling mod: {}", e));
+    // Replaced files now belong to this mod, so disabling the previous owner mustn't delete them
+    for (owner_index, file) in taken_over {
+        let key = owned_path_key(&file);
+        let owner = &mut registry.skin_mods[owner_index];
+        owner.installed_files.retain(|f| owned_path_key(&f.path) != key);
+        log::info!("'{}' took over {} from '{}'", mod_path, file, owner.base.name);
     }
+    registry.last_updated = chrono::Utc::now().timestamp();
 
-    log::info!("Successfully enabled skin mod '{}' via registry.", mod_path);
     Ok(())
 }
 
 #[tauri::command]
-pub async fn disable_skin_mod_via_registry(
+pub async fn enable_skin_mod_via_registry(
     app_handle: AppHandle,
-    _game_root_path: String, // Not strictly needed if paths are absolute, kept for consistency
-    mod_path: String,        // Use the original path as identifier
-) -> Result<(), String> {
-    log::info!("Disabling skin mod via registry: {}", mod_path);
+    game_root_path: String,
+    mod_path: String, // Use the original path as identifier
+    resolutions: Option<HashMap<String, ConflictResolution>>, // Per-file answers to a previous conflict set
+    allow_known_broken: Option<bool>, // Override for mods the known-broken list blocks
+    skip_save_backup_warning: Option<bool>, // The user chose to go ahead without a save backup
+) -> Result<SkinEnableResult, String> {
+    log::info!("Enabling skin mod via registry: {}", mod_path);
+
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.exists() || !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
 
     // Load the registry
     let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_path = registry.resolve_skin_mod_path(&mod_path).unwrap_or(mod_path);
+
+    let mod_dir = PathBuf::from(&mod_path);
+    if !mod_dir.exists() || !mod_dir.is_dir() {
+        return Err(format!("Invalid mod path: {}", mod_path));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+
+    if let Some(skin_mod) = registry.skin_mods.iter().find(|m| m.base.path == mod_path) {
+        check_before_enable(&app_handle, &skin_mod.base, allow_known_broken.unwrap_or(false))?;
+        check_save_backup_before_enable(&app_handle, &skin_mod.base, skip_save_backup_warning.unwrap_or(false))?;
+    }
+
+    let settings = AppSettings::load(&app_handle)?;
+
+    // Hand collisions back to the user instead of silently overwriting another mod's files
+    let resolutions = resolutions.unwrap_or_default();
+    let unresolved: Vec<NativesConflict> = find_natives_conflicts(&registry, &game_root, &mod_path, &settings)
+        .into_iter()
+        .filter(|c| !resolutions.contains_key(&c.relative_path))
+        .collect();
+    if !unresolved.is_empty() {
+        log::info!(
+            "Enabling '{}' would overwrite {} file(s) of other enabled mods; asking for resolution",
+            mod_path,
+            unresolved.len()
+        );
+        return Ok(SkinEnableResult {
+            enabled: false,
+            conflicts: unresolved,
+        });
+    }
+
+    let throttle = crate::utils::throttle::ProgressThrottle::for_ui();
+    let emit_progress = |bytes_copied: u64, total_bytes: u64| {
+        if !throttle.should_emit(bytes_copied, total_bytes) {
+            return;
+        }
+        let progress = DeployProgress {
+            mod_path: mod_path.clone(),
+            bytes_copied,
+            total_bytes,
+        };
+        if let Err(e) = app_handle.emit("skin-deploy-progress", progress) {
+            log::warn!("Failed to emit deploy progress: {}", e);
+        }
+    };
+    track_result(
+        &app_handle,
+        "enable_skin_mod_via_registry",
+        enable_skin_mod_files(&mut registry, &game_root, &mod_path, &settings, &resolutions, &emit_progress),
+    )?;
+
+    // --- Save the updated registry ---
+    if let Err(e) = registry.save(&app_handle) {
+        // Attempt to clean up installed files if save fails? This could be complex.
+        // For now, just return the save error.
+        log::error!("Failed to save registry after enabling mod {}: {}", mod_path, e);
+        return Err(format!("Failed to save registry state after enabling mod: {}", e));
+    }
 
+    log::info!("Successfully enabled skin mod '{}' via registry.", mod_path);
+    Ok(SkinEnableResult {
+        enabled: true,
+        conflicts: Vec::new(),
+    })
+}
+
+/// Remove a skin mod's installed files from the game directory and mark it disabled.
+/// Returns the per-file removal errors (which don't abort the disable).
+pub(crate) fn disable_skin_mod_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_path: &str,
+) -> Result<Vec<String>, String> {
     // Find the mod to disable
     let mod_index = registry
         .skin_mods
@@ -1496,46 +2826,39 @@ pub async fn disable_skin_mod_via_registry(
     // Check if already disabled
     if !registry.skin_mods[mod_index].base.enabled {
         log::info!("SkinMod '{}' is already disabled.", mod_path);
-        return Ok(());
+        return Ok(Vec::new());
     }
 
     // Get the list of installed files TO REMOVE
     // Clone it so we don't borrow registry while modifying filesystem
     let installed_files_to_remove = registry.skin_mods[mod_index].installed_files.clone();
 
-    // Get mutable reference to the mod entry BEFORE removing files
-    let skin_mod_entry = registry.skin_mods.get_mut(mod_index).unwrap();
-
     log::info!(
         "Removing {} installed files for mod: {}",
         installed_files_to_remove.len(),
         mod_path
     );
 
-    // Remove installed files from the filesystem
-    let mut removal_errors = Vec::new();
-    for file_path_str in &installed_files_to_remove {
-        let file_path = PathBuf::from(file_path_str);
-        if file_path.exists() {
-            log::debug!("Removing file: {}", file_path.display());
-            if let Err(e) = fs::remove_file(&file_path) {
-                // Log error but continue trying to remove other files
-                log::warn!("Failed to remove file {}: {}", file_path.display(), e);
-                removal_errors.push(format!("Failed to remove {}: {}", file_path.display(), e));
-            }
-        } else {
-            log::warn!(
-                "File listed in registry for '{}' not found during removal at path: {}",
-                mod_path,
-                file_path.display()
-            );
-            // File might have been manually deleted, which is okay for disabling.
-        }
+    // Remove installed files as one batch; if the game holds any of them open nothing is
+    // removed and the mod stays enabled
+    let ledger = LedgerOperation::begin(game_root, "disable_skin_mod");
+    let targets: Vec<PathBuf> = installed_files_to_remove.iter().map(|f| PathBuf::from(&f.path)).collect();
+    for missing in targets.iter().filter(|p| !long_path(p).exists()) {
+        // File might have been manually deleted, which is okay for disabling.
+        log::warn!(
+            "File listed in registry for '{}' not found during removal at path: {}",
+            mod_path,
+            missing.display()
+        );
+    }
+    for file_path in remove_files_or_roll_back(&targets)? {
+        ledger.deleted(&file_path, false);
     }
+    let mut removal_errors = Vec::new();
 
     // --- Update the registry entry ---
-    // This happens regardless of removal errors to reflect the *desired* state
-    skin_mod_entry.base.enabled = false;
+    let skin_mod_entry = &mut registry.skin_mods[mod_index];
+    skin_mod_entry.base.set_enabled(false);
     skin_mod_entry.installed_files.clear(); // Clear the list
     skin_mod_entry.installed_pak_path = None; // Clear the pak path
 
@@ -1544,10 +2867,51 @@ pub async fn disable_skin_mod_via_registry(
         mod_path,
         skin_mod_entry.base.enabled
     );
+    let mod_key = overwrite_backup_key(game_root, &skin_mod_entry.base);
+
+    // Put back the files this mod overwrote when it was enabled
+    let restore_report = restore_overwritten_files(registry, game_root, &mod_key);
+    removal_errors.extend(restore_report.errors);
+    registry.last_updated = chrono::Utc::now().timestamp();
+
+    Ok(removal_errors)
+}
+
+#[tauri::command]
+pub async fn disable_skin_mod_via_registry(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_path: String,        // Use the original path as identifier
+    disable_dependents: Option<bool>, // Also disable mods that depend on this one
+) -> Result<(), String> {
+    log::info!("Disabling skin mod via registry: {}", mod_path);
+    let _in_flight = ModOperationGuard::begin(&app_handle);
 
+    // Load the registry
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_path = registry.resolve_skin_mod_path(&mod_path).unwrap_or(mod_path);
+
+    let removal_errors = track_result(
+        &app_handle,
+        "disable_skin_mod_via_registry",
+        disable_skin_mod_files(&mut registry, &PathBuf::from(&game_root_path), &mod_path),
+    )?;
+    if let Some(target) = registry
+        .skin_mods
+        .iter()
+        .find(|m| m.base.path == mod_path)
+        .map(|m| m.base.clone())
+    {
+        handle_dependents_on_disable(
+            &app_handle,
+            &mut registry,
+            &PathBuf::from(&game_root_path),
+            &target,
+            disable_dependents.unwrap_or(false),
+        )?;
+    }
 
     // --- Save the updated registry ---
-    registry.last_updated = chrono::Utc::now().timestamp();
     if let Err(e) = registry.save(&app_handle) {
         log::error!("Failed to save registry after disabling mod {}: {}", mod_path, e);
         // Even if save fails, files might have been removed. State is inconsistent.
@@ -1577,20 +2941,18 @@ pub async fn disable_skin_mod_via_registry(
 
 // --------- Delete Mod Commands --------- //
 
-#[tauri::command]
-pub async fn delete_reframework_mod(
-    app_handle: AppHandle,
-    game_root_path: String,
-    mod_name: String,
-) -> Result<(), String> {
-    log::info!("Attempting to delete REFramework mod: {}", mod_name);
-    let game_root = PathBuf::from(&game_root_path);
-
-    // Load the registry
-    let mut registry = ModRegistry::load(&app_handle)?;
-
+/// Delete a REFramework mod's directories and remove it from the registry (without saving).
+/// With a `trash_dir` they're moved there instead. Returns the filesystem errors encountered
+/// along the way.
+pub(crate) fn delete_reframework_mod_entry(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_name: &str,
+    trash_dir: Option<&Path>,
+    thumbnail_cleanup: &ThumbnailCleanup,
+) -> Result<Vec<String>, String> {
     // Find the mod entry
-    let mod_entry = match registry.find_mod(&mod_name) {
+    let mod_entry = match registry.find_mod(mod_name) {
         Some(m) => m.clone(), // Clone needed info
         None => return Err(format!("REFramework mod '{}' not found in registry for deletion.", mod_name)),
     };
@@ -1603,25 +2965,48 @@ pub async fn delete_reframework_mod(
 
     let mut deleted_fs = false;
     let mut fs_errors = Vec::new();
+    let ledger = LedgerOperation::begin(game_root, "delete_reframework_mod");
+
+    // Soft delete: move whichever directories exist into the trash instead
+    if let Some(trash_dir) = trash_dir.filter(|_| enabled_path.exists() || disabled_path.exists()) {
+        match move_to_trash(
+            game_root,
+            trash_dir,
+            &mod_entry.name,
+            TrashedRegistryEntry::Mod(mod_entry.clone()),
+            &[enabled_path.clone(), disabled_path.clone()],
+        ) {
+            Ok(entry) => {
+                log::info!("Moved mod '{}' to trash entry {}", mod_name, entry.id);
+                deleted_fs = true;
+            }
+            Err(e) => {
+                log::error!("Failed to move mod '{}' to trash: {}", mod_name, e);
+                fs_errors.push(format!("Failed to move to trash: {}", e));
+            }
+        }
+    }
 
     // Delete enabled directory if it exists
-    if enabled_path.exists() {
+    if trash_dir.is_none() && long_path(&enabled_path).exists() {
         log::info!("Removing enabled directory: {}", enabled_path.display());
-        if let Err(e) = fs::remove_dir_all(&enabled_path) {
+        if let Err(e) = fs::remove_dir_all(long_path(&enabled_path)) {
             log::error!("Failed to remove directory {}: {}", enabled_path.display(), e);
             fs_errors.push(format!("Failed to remove {}: {}", enabled_path.display(), e));
         } else {
+            ledger.deleted(&enabled_path, true);
             deleted_fs = true;
         }
     }
 
     // Delete disabled directory if it exists
-    if disabled_path.exists() {
+    if trash_dir.is_none() && long_path(&disabled_path).exists() {
         log::info!("Removing disabled directory: {}", disabled_path.display());
-        if let Err(e) = fs::remove_dir_all(&disabled_path) {
+        if let Err(e) = fs::remove_dir_all(long_path(&disabled_path)) {
             log::error!("Failed to remove directory {}: {}", disabled_path.display(), e);
             fs_errors.push(format!("Failed to remove {}: {}", disabled_path.display(), e));
         } else {
+            ledger.deleted(&disabled_path, true);
             deleted_fs = true;
         }
     }
@@ -1635,79 +3020,141 @@ pub async fn delete_reframework_mod(
                    mod_name, enabled_path.display(), disabled_path.display());
     }
 
+    // Its cached thumbnail is regenerated on restore, so it can go either way
+    let thumbnails: Vec<String> = mod_entry
+        .thumbnail
+        .iter()
+        .chain(mod_entry.custom_thumbnail.iter())
+        .cloned()
+        .collect();
+    thumbnail_cleanup.queue(thumbnails);
+
     // Remove from registry regardless of filesystem state (if it exists)
-    if registry.remove_mod(&mod_name) {
+    if registry.remove_mod(mod_name) {
         log::info!("Removed mod '{}' from registry.", mod_name);
-        registry.last_updated = chrono::Utc::now().timestamp();
-        if let Err(e) = registry.save(&app_handle) {
-            log::error!("Failed to save registry after removing mod '{}': {}", mod_name, e);
-            // Combine FS errors with save error
-            fs_errors.push(format!("Failed to save registry: {}", e));
-        }
     } else {
         log::warn!("Mod '{}' was not found in the registry during deletion attempt, maybe already removed?", mod_name);
         // This case should ideally not happen due to the initial find_mod check
     }
 
-    // Return success or failure based on combined errors
-    if fs_errors.is_empty() {
-        log::info!("Successfully deleted REFramework mod '{}'.", mod_name);
-        Ok(())
-    } else {
-        Err(format!("Errors occurred during deletion of mod '{}': {}", mod_name, fs_errors.join("; ")))
-    }
+    Ok(fs_errors)
 }
 
-
 #[tauri::command]
-pub async fn delete_skin_mod(
+pub async fn delete_reframework_mod(
     app_handle: AppHandle,
-    game_root_path: String, // Needed for potential disable call
-    mod_path: String,       // Original source path identifier
-) -> Result<(), String> {
-    log::info!("Attempting to delete skin mod with source path: {}", mod_path);
-
-    let app_handle_clone = app_handle.clone(); // Clone for potential disable call
+    game_root_path: String,
+    mod_name: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "delete_reframework_mod",
+        std::slice::from_ref(&mod_name),
+        &format!("Delete REFramework mod '{}'", mod_name),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    log::info!("Attempting to delete REFramework mod: {}", mod_name);
+    let game_root = PathBuf::from(&game_root_path);
 
     // Load the registry
     let mut registry = ModRegistry::load(&app_handle)?;
 
-    // Find the mod entry by its original source path
-    let mod_info = match registry.skin_mods.iter().find(|m| m.base.path == mod_path) {
-        Some(m) => {
-            // Clone necessary info before potential mutable borrow in disable
-            Some((m.base.directory_name.clone(), m.base.enabled))
-        }
-        None => {
-            return Err(format!("Skin mod with source path '{}' not found in registry.", mod_path));
-        }
-    };
+    let trash_dir = trash_dir_for_deletes(&app_handle, &game_root)?;
+    let thumbnail_cleanup = app_handle.state::<ThumbnailCleanup>();
+    let mut fs_errors =
+        delete_reframework_mod_entry(&mut registry, &game_root, &mod_name, trash_dir.as_deref(), &thumbnail_cleanup)?;
+
+    if let Err(e) = registry.save(&app_handle) {
+        log::error!("Failed to save registry after removing mod '{}': {}", mod_name, e);
+        // Combine FS errors with save error
+        fs_errors.push(format!("Failed to save registry: {}", e));
+    }
 
-    let (directory_name_to_remove, is_enabled) = mod_info.unwrap(); // We know it exists
+    // Return success or failure based on combined errors
+    if fs_errors.is_empty() {
+        log::info!("Successfully deleted REFramework mod '{}'.", mod_name);
+        Ok(Confirmable::Done(()))
+    } else {
+        Err(format!("Errors occurred during deletion of mod '{}': {}", mod_name, fs_errors.join("; ")))
+    }
+}
+
+/// Disable (if needed) and delete a skin mod, then remove it from the registry (without saving).
+/// With a `trash_dir` its source folder is moved there instead. Returns the errors encountered
+/// along the way.
+pub(crate) fn delete_skin_mod_entry(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_path: &str,
+    trash_dir: Option<&Path>,
+    thumbnail_cleanup: &ThumbnailCleanup,
+) -> Result<Vec<String>, String> {
+    // Find the mod entry by its original source path
+    let (directory_name_to_remove, is_enabled) =
+        match registry.skin_mods.iter().find(|m| m.base.path == mod_path) {
+            Some(m) => (m.base.directory_name.clone(), m.base.enabled),
+            None => {
+                return Err(format!("Skin mod with source path '{}' not found in registry.", mod_path));
+            }
+        };
 
     let mut combined_errors = Vec::new();
 
-    // --- Step 1: Disable the mod first if it's enabled --- 
+    // --- Step 1: Disable the mod first if it's enabled ---
     // This handles removing files from the game directory (.pak, natives/)
     if is_enabled {
         log::info!("Skin mod '{}' is enabled, disabling it first...", directory_name_to_remove);
-        if let Err(e) = disable_skin_mod_via_registry(app_handle_clone, game_root_path, mod_path.clone()).await {
-            log::error!("Failed to disable skin mod '{}' before deletion: {}. Proceeding with deletion attempt anyway.", directory_name_to_remove, e);
-            combined_errors.push(format!("Error during pre-delete disable: {}", e));
-            // Reload registry as disable might have failed partially but still saved
-            registry = ModRegistry::load(&app_handle)?;
-        } else {
-            log::info!("Successfully disabled skin mod '{}' before deletion.", directory_name_to_remove);
-            // Reload registry as disable function saves it
-            registry = ModRegistry::load(&app_handle)?;
+        match disable_skin_mod_files(registry, game_root, mod_path) {
+            Ok(removal_errors) => {
+                log::info!("Successfully disabled skin mod '{}' before deletion.", directory_name_to_remove);
+                combined_errors.extend(removal_errors);
+            }
+            // Its deployed files would be left behind with nothing tracking them
+            Err(e) if is_locked_files_error(&e) => return Err(e),
+            Err(e) => {
+                log::error!("Failed to disable skin mod '{}' before deletion: {}. Proceeding with deletion attempt anyway.", directory_name_to_remove, e);
+                combined_errors.push(format!("Error during pre-delete disable: {}", e));
+            }
         }
     }
 
-    // --- Step 2: Remove the original mod source directory --- 
-    let source_mod_dir = PathBuf::from(&mod_path);
-    if source_mod_dir.exists() {
+    // --- Step 2: Remove the original mod source directory ---
+    let source_mod_dir = PathBuf::from(mod_path);
+    if let Some(trash_dir) = trash_dir.filter(|_| long_path(&source_mod_dir).exists()) {
+        let trashed_entry = registry
+            .find_skin_mod(&directory_name_to_remove)
+            .cloned()
+            .map(TrashedRegistryEntry::Skin);
+        match trashed_entry {
+            Some(entry) => {
+                let mod_name = registry
+                    .find_skin_mod(&directory_name_to_remove)
+                    .map(|m| m.base.name.clone())
+                    .unwrap_or_else(|| directory_name_to_remove.clone());
+                match move_to_trash(game_root, trash_dir, &mod_name, entry, std::slice::from_ref(&source_mod_dir)) {
+                    Ok(trash_entry) => log::info!(
+                        "Moved skin mod '{}' to trash entry {}",
+                        directory_name_to_remove,
+                        trash_entry.id
+                    ),
+                    Err(e) => {
+                        log::error!("Failed to move skin mod '{}' to trash: {}", directory_name_to_remove, e);
+                        combined_errors.push(format!("Failed to move to trash: {}", e));
+                    }
+                }
+            }
+            None => combined_errors.push(format!(
+                "Skin mod '{}' disappeared from registry before it could be trashed",
+                directory_name_to_remove
+            )),
+        }
+    } else if long_path(&source_mod_dir).exists() {
         log::info!("Removing original source directory: {}", source_mod_dir.display());
-        if let Err(e) = fs::remove_dir_all(&source_mod_dir) {
+        if let Err(e) = fs::remove_dir_all(long_path(&source_mod_dir)) {
             log::error!("Failed to remove source directory {}: {}", source_mod_dir.display(), e);
             combined_errors.push(format!("Failed to remove source dir {}: {}", source_mod_dir.display(), e));
         }
@@ -1716,34 +3163,704 @@ pub async fn delete_skin_mod(
                    directory_name_to_remove, source_mod_dir.display());
     }
 
-    // --- Step 3: Remove the mod from the registry --- 
+    // --- Step 3: Remove the mod from the registry ---
+    let thumbnails: Vec<String> = registry
+        .find_skin_mod(&directory_name_to_remove)
+        .map(|m| m.thumbnail_path.iter().chain(m.base.custom_thumbnail.iter()).cloned().collect())
+        .unwrap_or_default();
+    thumbnail_cleanup.queue(thumbnails);
     if registry.remove_skin_mod(&directory_name_to_remove) {
         log::info!("Removed skin mod '{}' from registry.", directory_name_to_remove);
-        registry.last_updated = chrono::Utc::now().timestamp();
-        if let Err(e) = registry.save(&app_handle) {
-            log::error!("Failed to save registry after removing skin mod '{}': {}", directory_name_to_remove, e);
-            combined_errors.push(format!("Failed to save registry: {}", e));
-        }
     } else {
-        // This might happen if disable failed and registry was reloaded without the mod?
         log::warn!("Skin mod '{}' was not found in the registry during final removal attempt.", directory_name_to_remove);
     }
 
-    // --- Final Result --- 
+    Ok(combined_errors)
+}
+
+#[tauri::command]
+pub async fn delete_skin_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_path: String, // Original source path identifier
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "delete_skin_mod",
+        std::slice::from_ref(&mod_path),
+        &format!("Delete skin mod at '{}'", mod_path),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    log::info!(
+        "Attempting to delete skin mod with source path: {} (game root: {})",
+        mod_path,
+        game_root_path
+    );
+
+    // Load the registry
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_path = registry.resolve_skin_mod_path(&mod_path).unwrap_or(mod_path);
+
+    let thumbnail_cleanup = app_handle.state::<ThumbnailCleanup>();
+    let game_root = PathBuf::from(&game_root_path);
+    let trash_dir = trash_dir_for_deletes(&app_handle, &game_root)?;
+    let mut combined_errors =
+        delete_skin_mod_entry(&mut registry, &game_root, &mod_path, trash_dir.as_deref(), &thumbnail_cleanup)?;
+
+    if let Err(e) = registry.save(&app_handle) {
+        log::error!("Failed to save registry after removing skin mod '{}': {}", mod_path, e);
+        combined_errors.push(format!("Failed to save registry: {}", e));
+    }
+
+    // --- Final Result ---
     if combined_errors.is_empty() {
         log::info!("Successfully deleted skin mod from '{}'.", mod_path);
-        Ok(())
+        Ok(Confirmable::Done(()))
     } else {
         Err(format!("Errors occurred during deletion of skin mod from '{}': {}", mod_path, combined_errors.join("; ")))
     }
 }
 
+/// Outcome of deleting a single mod as part of a batch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModDeleteResult {
+    pub identifier: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Summary returned by `delete_mods`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BatchDeleteReport {
+    pub results: Vec<ModDeleteResult>,
+    pub deleted_count: usize,
+    pub failed_count: usize,
+}
+
+/// Delete several mods in one pass. Identifiers are REFramework mod directory names,
+/// or skin mod source paths / directory names. The registry is saved once at the end.
+#[tauri::command]
+pub async fn delete_mods(
+    app_handle: AppHandle,
+    game_root_path: String,
+    identifiers: Vec<String>,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<BatchDeleteReport>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "delete_mods",
+        &identifiers,
+        &format!("Delete {} mod(s)", identifiers.len()),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    log::info!("Batch deleting {} mods", identifiers.len());
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let trash_dir = trash_dir_for_deletes(&app_handle, &game_root)?;
+    let thumbnail_cleanup = app_handle.state::<ThumbnailCleanup>();
+
+    let mut results = Vec::new();
+    for identifier in identifiers {
+        let outcome = if registry.find_mod(&identifier).is_some() {
+            delete_reframework_mod_entry(&mut registry, &game_root, &identifier, trash_dir.as_deref(), &thumbnail_cleanup)
+        } else {
+            // Skin mods are keyed by source path, but accept the id or directory name too
+            match registry.resolve_skin_mod_path(&identifier) {
+                Some(path) => delete_skin_mod_entry(&mut registry, &game_root, &path, trash_dir.as_deref(), &thumbnail_cleanup),
+                None => Err(format!("Mod '{}' not found in registry", identifier)),
+            }
+        };
+
+        let result = match outcome {
+            Ok(errors) if errors.is_empty() => ModDeleteResult {
+                identifier,
+                success: true,
+                error: None,
+            },
+            Ok(errors) => ModDeleteResult {
+                identifier,
+                success: false,
+                error: Some(errors.join("; ")),
+            },
+            Err(e) => ModDeleteResult {
+                identifier,
+                success: false,
+                error: Some(e),
+            },
+        };
+        if let Some(e) = &result.error {
+            log::warn!("Batch delete of '{}' reported errors: {}", result.identifier, e);
+        }
+        results.push(result);
+    }
+
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry
+        .save(&app_handle)
+        .map_err(|e| format!("Failed to save registry after batch delete: {}", e))?;
+
+    let deleted_count = results.iter().filter(|r| r.success).count();
+    let failed_count = results.len() - deleted_count;
+    log::info!(
+        "Batch delete complete: {} succeeded, {} failed",
+        deleted_count,
+        failed_count
+    );
+    Ok(Confirmable::Done(BatchDeleteReport {
+        results,
+        deleted_count,
+        failed_count,
+    }))
+}
+
+/// What `uninstall_mod` removes, or with a dry run what it would remove
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UninstallReport {
+    pub mod_id: String,
+    pub mod_name: String, // Directory name
+    pub dry_run: bool,
+    pub to_trash: bool,             // Folders go to the trash rather than being deleted
+    pub paths: Vec<String>,         // Mod folders and the files it deployed into the game
+    pub backup_dir: Option<String>, // Backups of game files it overwrote; restored, then removed
+    pub thumbnails: Vec<String>,    // Cached previews
+    pub errors: Vec<String>,
+}
+
+/// Everything uninstalling `identifier` touches, without touching it. Identifiers are
+/// REFramework mod directory names, or skin mod source paths / directory names.
+pub(crate) fn plan_uninstall(
+    registry: &ModRegistry,
+    game_root: &Path,
+    identifier: &str,
+    soft_delete: bool,
+) -> Result<UninstallReport, String> {
+    let existing = |paths: Vec<PathBuf>| -> Vec<String> {
+        paths
+            .into_iter()
+            .filter(|p| long_path(p).exists())
+            .map(|p| p.to_string_lossy().to_string())
+            .collect()
+    };
+
+    let (base, paths, thumbnail) = if let Some(mod_entry) = registry.find_mod(identifier) {
+        let enabled_path = game_root.join(&mod_entry.installed_directory);
+        let disabled_path = game_root.join(format!("{}.disabled", mod_entry.installed_directory));
+        (mod_entry, existing(vec![enabled_path, disabled_path]), &mod_entry.thumbnail)
+    } else if let Some(skin_mod) = registry
+        .resolve_skin_mod_path(identifier)
+        .and_then(|path| registry.skin_mods.iter().find(|m| m.base.path == path))
+    {
+        let mut targets: Vec<PathBuf> = if skin_mod.base.enabled {
+            skin_mod.installed_files.iter().map(|f| PathBuf::from(&f.path)).collect()
+        } else {
+            Vec::new()
+        };
+        targets.push(PathBuf::from(&skin_mod.base.path));
+        (&skin_mod.base, existing(targets), &skin_mod.thumbnail_path)
+    } else {
+        return Err(format!("Mod '{}' not found in registry", identifier));
+    };
+
+    let backup_dir = get_overwrite_backup_dir(game_root, &overwrite_backup_key(game_root, base));
+    Ok(UninstallReport {
+        mod_id: base.id.clone(),
+        mod_name: base.directory_name.clone(),
+        dry_run: true,
+        to_trash: soft_delete,
+        paths,
+        backup_dir: backup_dir
+            .is_dir()
+            .then(|| backup_dir.to_string_lossy().to_string()),
+        thumbnails: thumbnail.iter().chain(base.custom_thumbnail.iter()).cloned().collect(),
+        errors: Vec::new(),
+    })
+}
+
+/// Remove a mod completely: its files (to the trash when that's on), its registry entry,
+/// the backups of game files it overwrote and its cached thumbnails. With `dry_run` nothing
+/// is changed and the report lists what would go.
+#[tauri::command]
+pub async fn uninstall_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+    identifier: String,
+    dry_run: Option<bool>,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<UninstallReport>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let trash_dir = trash_dir_for_deletes(&app_handle, &game_root)?;
+    let thumbnail_cleanup = app_handle.state::<ThumbnailCleanup>();
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mut report = plan_uninstall(&registry, &game_root, &identifier, trash_dir.is_some())?;
+    if dry_run.unwrap_or(false) {
+        log::info!(
+            "Uninstall dry run for '{}': {} path(s) would be removed",
+            report.mod_name,
+            report.paths.len()
+        );
+        return Ok(Confirmable::Done(report));
+    }
+
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "uninstall_mod",
+        std::slice::from_ref(&identifier),
+        &format!("Uninstall '{}' ({} path(s))", report.mod_name, report.paths.len()),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    log::info!("Uninstalling mod '{}'", report.mod_name);
+    report.dry_run = false;
+    let backup_key = registry
+        .find_any_mod(&report.mod_id)
+        .map(|base| overwrite_backup_key(&game_root, base))
+        .unwrap_or_else(|| report.mod_name.clone());
+
+    let result = (|| {
+        let errors = if registry.find_mod(&report.mod_id).is_some() {
+            delete_reframework_mod_entry(&mut registry, &game_root, &report.mod_id, trash_dir.as_deref(), &thumbnail_cleanup)?
+        } else {
+            let skin_path = registry
+                .find_skin_mod(&report.mod_id)
+                .map(|m| m.base.path.clone())
+                .ok_or_else(|| format!("Mod '{}' not found in registry", report.mod_name))?;
+            delete_skin_mod_entry(&mut registry, &game_root, &skin_path, trash_dir.as_deref(), &thumbnail_cleanup)?
+        };
+        report.errors.extend(errors);
+
+        // Disabling restores and removes the backups; a mod that was already disabled may
+        // still have some left from a restore that failed back then
+        let backup_dir = get_overwrite_backup_dir(&game_root, &backup_key);
+        if backup_dir.is_dir() && report.errors.is_empty() {
+            let restore = restore_overwritten_files(&mut registry, &game_root, &backup_key);
+            report.errors.extend(restore.errors);
+        }
+
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+        if report.errors.is_empty() {
+            log::info!("Uninstalled mod '{}'", report.mod_name);
+        } else {
+            log::warn!(
+                "Uninstalled mod '{}' with errors: {}",
+                report.mod_name,
+                report.errors.join("; ")
+            );
+        }
+        Ok(Confirmable::Done(report))
+    })();
+    track_result(&app_handle, "uninstall_mod", result)
+}
+
+/// Return the full README text of an installed mod, if it ships one
+#[tauri::command]
+pub async fn get_mod_readme(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_name: String,
+) -> Result<Option<String>, String> {
+    log::info!("Fetching README for mod: {}", mod_name);
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+
+    let mod_dir = if let Some(mod_entry) = registry.find_mod(&mod_name) {
+        resolve_reframework_mod_dir(&game_root, mod_entry)
+    } else if let Some(skin_mod) = registry.find_skin_mod(&mod_name) {
+        Some(PathBuf::from(&skin_mod.base.path))
+    } else {
+        return Err(format!("Mod '{}' not found in registry", mod_name));
+    };
+
+    let Some(readme_path) = mod_dir.as_deref().and_then(find_readme_file) else {
+        log::debug!("No README found for mod '{}'", mod_name);
+        return Ok(None);
+    };
+    read_readme_text(&readme_path).map(Some)
+}
+
+/// Full registry record of a mod (REFramework mod by name, skin mod by name or path),
+/// including where it was installed from
+#[tauri::command]
+pub async fn get_mod_details(app_handle: AppHandle, mod_name: String) -> Result<Mod, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    registry
+        .find_any_mod(&mod_name)
+        .cloned()
+        .ok_or_else(|| format!("Mod '{}' not found in registry", mod_name))
+}
+
+/// Sort mod infos in place. "rating" puts the highest rated first (unrated last),
+/// anything else sorts by display name.
+pub fn sort_mod_infos(mods_info: &mut [ModInfo], sort_by: &str) {
+    match sort_by {
+        "rating" => mods_info.sort_by(|a, b| {
+            b.rating
+                .unwrap_or(0)
+                .cmp(&a.rating.unwrap_or(0))
+                .then_with(|| a.name.cmp(&b.name))
+        }),
+        _ => mods_info.sort_by(|a, b| {
+            a.name
+                .as_deref()
+                .unwrap_or(&a.directory_name)
+                .to_lowercase()
+                .cmp(&b.name.as_deref().unwrap_or(&b.directory_name).to_lowercase())
+        }),
+    }
+}
+
+/// Set (or clear with `None`) the personal 1-5 star rating of a mod or skin mod
+#[tauri::command]
+pub async fn set_mod_rating(
+    app_handle: AppHandle,
+    mod_name: String,
+    rating: Option<u8>,
+) -> Result<(), String> {
+    if let Some(value) = rating {
+        if !(1..=5).contains(&value) {
+            return Err(format!("Rating must be between 1 and 5, got {}", value));
+        }
+    }
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    if let Some(mod_entry) = registry.find_mod_mut(&mod_name) {
+        mod_entry.rating = rating;
+    } else if let Some(skin_mod) = registry.find_skin_mod_mut(&mod_name) {
+        skin_mod.base.rating = rating;
+    } else {
+        return Err(format!("Mod '{}' not found in registry", mod_name));
+    }
+
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+    log::info!("Set rating of '{}' to {:?}", mod_name, rating);
+    Ok(())
+}
+
+/// Replace the user tags of a mod or skin mod. Tags are trimmed and deduplicated.
+#[tauri::command]
+pub async fn set_mod_tags(
+    app_handle: AppHandle,
+    mod_identifier: String,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    let mut tags: Vec<String> = tags
+        .into_iter()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+    tags.sort_by_key(|t| t.to_lowercase());
+    tags.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_entry = registry
+        .find_any_mod_mut(&mod_identifier)
+        .ok_or_else(|| format!("Mod '{}' not found in registry", mod_identifier))?;
+    log::info!("Set tags of '{}' to {:?}", mod_entry.name, tags);
+    mod_entry.tags = tags;
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)
+}
+
+/// Choose whether a skin mod's loose natives are packed into a pak when it is enabled.
+/// `None` follows the global deployment mode. Takes effect the next time the mod is enabled.
+#[tauri::command]
+pub async fn set_skin_mod_packing(
+    app_handle: AppHandle,
+    mod_path: String,
+    pack_natives: Option<bool>,
+) -> Result<(), String> {
+    if pack_natives == Some(true)
+        && !is_enabled(&AppSettings::load(&app_handle)?, FeatureFlag::PakPacking)
+    {
+        return Err("Pak packing is switched off in the experimental features".to_string());
+    }
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_path = registry.resolve_skin_mod_path(&mod_path).unwrap_or(mod_path);
+    let skin_mod = registry
+        .skin_mods
+        .iter_mut()
+        .find(|m| m.base.path == mod_path)
+        .ok_or_else(|| format!("SkinMod with path '{}' not found in registry", mod_path))?;
+    skin_mod.pack_natives = pack_natives;
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+    log::info!("Set natives packing for '{}' to {:?}", mod_path, pack_natives);
+    Ok(())
+}
+
+/// Usage stats for a single mod, as returned by get_mod_stats
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModStatsEntry {
+    pub directory_name: String,
+    pub name: String,
+    pub is_skin_mod: bool,
+    pub enabled: bool,
+    pub toggle_count: u32,
+    pub last_enabled_timestamp: Option<i64>,
+    pub installed_timestamp: i64,
+}
+
+/// Usage stats for every registered mod, least recently used first.
+/// With `unused_for_days`, only disabled mods not enabled within that many days are returned.
+#[tauri::command]
+pub async fn get_mod_stats(
+    app_handle: AppHandle,
+    unused_for_days: Option<u32>,
+) -> Result<Vec<ModStatsEntry>, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+
+    let to_entry = |m: &Mod, is_skin_mod: bool| ModStatsEntry {
+        directory_name: m.directory_name.clone(),
+        name: m.name.clone(),
+        is_skin_mod,
+        enabled: m.enabled,
+        toggle_count: m.usage.toggle_count,
+        last_enabled_timestamp: m.usage.last_enabled_timestamp,
+        installed_timestamp: m.installed_timestamp,
+    };
+    let mut stats: Vec<ModStatsEntry> = registry
+        .mods
+        .iter()
+        .map(|m| to_entry(m, false))
+        .chain(registry.skin_mods.iter().map(|sm| to_entry(&sm.base, true)))
+        .collect();
+
+    // Mods never enabled through the manager count from their install time
+    let last_used = |e: &ModStatsEntry| e.last_enabled_timestamp.unwrap_or(e.installed_timestamp);
+    if let Some(days) = unused_for_days {
+        let cutoff = chrono::Utc::now().timestamp() - i64::from(days) * 24 * 60 * 60;
+        stats.retain(|e| !e.enabled && last_used(e) < cutoff);
+    }
+    stats.sort_by_key(last_used);
+
+    Ok(stats)
+}
+
 // +++ Add back the list_skin_mods_from_registry command +++
 #[tauri::command]
-pub async fn list_skin_mods_from_registry(app_handle: AppHandle) -> Result<Vec<SkinMod>, String> {
+pub async fn list_skin_mods_from_registry(
+    app_handle: AppHandle,
+    sort_by: Option<String>,
+) -> Result<Vec<SkinMod>, String> {
     log::info!("Listing installed skin mods from registry");
     // Consider adding a scan here too if needed, similar to list_mods
     // For now, just load and return
-    let registry = ModRegistry::load(&app_handle)?;
+    let mut registry = ModRegistry::load(&app_handle)?;
+    if sort_by.as_deref() == Some("rating") {
+        registry.skin_mods.sort_by(|a, b| {
+            b.base
+                .rating
+                .unwrap_or(0)
+                .cmp(&a.base.rating.unwrap_or(0))
+                .then_with(|| a.base.name.cmp(&b.base.name))
+        });
+    }
+    for skin_mod in &mut registry.skin_mods {
+        let thumbnail = skin_mod.base.custom_thumbnail.clone().or(skin_mod.thumbnail_path.take());
+        skin_mod.thumbnail_path = with_placeholder(&app_handle, thumbnail, &skin_mod.base.name);
+    }
     Ok(registry.skin_mods)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn damaged_registry_is_recovered_from_its_backup() {
+        let game = FakeGame::new();
+        let registry_path = game.root().join("mod_registry.json");
+
+        let mut registry = ModRegistry::new();
+        registry.add_skin_mod(new_skin_mod_from_dir(&game.mods_dir().join("Armor"), "local"));
+        let first = serde_json::to_string_pretty(&registry).unwrap();
+        ModRegistry::write_registry_file(&registry_path, &first, true).unwrap();
+        registry.add_skin_mod(new_skin_mod_from_dir(&game.mods_dir().join("Cape"), "local"));
+        let second = serde_json::to_string_pretty(&registry).unwrap();
+        ModRegistry::write_registry_file(&registry_path, &second, true).unwrap();
+
+        // The rolling backup holds the previous save and no temp file is left behind
+        let backup_path = ModRegistry::get_registry_backup_path(&registry_path);
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), first);
+        assert!(!game.root().join("mod_registry.json.tmp").exists());
+
+        // A save cut off halfway
+        fs::write(&registry_path, &second[..second.len() / 2]).unwrap();
+        let recovered = ModRegistry::recover_from_backup(&registry_path).expect("backup parses");
+        assert_eq!(recovered.skin_mods.len(), 1);
+        assert_eq!(fs::read_to_string(&registry_path).unwrap(), first);
+        let kept_damaged = fs::read_dir(game.root())
+            .unwrap()
+            .filter_map(Result::ok)
+            .any(|e| e.file_name().to_string_lossy().starts_with("mod_registry.json.corrupt-"));
+        assert!(kept_damaged);
+
+        // A damaged file never replaces a good backup
+        fs::write(&registry_path, b"{ not json").unwrap();
+        ModRegistry::write_registry_file(&registry_path, &second, true).unwrap();
+        assert_eq!(fs::read_to_string(&backup_path).unwrap(), first);
+    }
+
+    #[test]
+    fn registry_entries_are_found_by_their_stable_id() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        let path = game.install_skin(&mut registry, "IdSkin", b"id");
+
+        // Entries saved before ids existed get one on load
+        registry.skin_mods[0].base.id.clear();
+        assert_eq!(registry.assign_missing_ids(), 1);
+        assert_eq!(registry.assign_missing_ids(), 0);
+        let id = registry.skin_mods[0].base.id.clone();
+        assert_eq!(registry.resolve_skin_mod_path(&id), Some(path.clone()));
+        assert_eq!(registry.resolve_skin_mod_path("IdSkin"), Some(path.clone()));
+        assert!(registry.find_any_mod(&id).is_some());
+
+        // A copied entry sharing the id is told apart by a new one
+        let mut copy = registry.skin_mods[0].clone();
+        copy.base.directory_name = "IdSkinCopy".to_string();
+        registry.skin_mods.push(copy);
+        assert_eq!(registry.assign_missing_ids(), 1);
+        assert_ne!(registry.skin_mods[0].base.id, registry.skin_mods[1].base.id);
+        assert_eq!(registry.find_skin_mod(&id).map(|m| m.base.path.clone()), Some(path));
+
+        // Adding replaces by id only; a different mod with the same folder name is kept
+        let mut same_name = registry.skin_mods[0].clone();
+        same_name.base.id = new_mod_id();
+        registry.add_skin_mod(same_name.clone());
+        assert_eq!(registry.skin_mods.len(), 3);
+        same_name.base.version = Some("2.0".to_string());
+        registry.add_skin_mod(same_name.clone());
+        assert_eq!(registry.skin_mods.len(), 3);
+        assert_eq!(registry.find_skin_mod(&same_name.base.id).unwrap().base.version.as_deref(), Some("2.0"));
+        assert_eq!(registry.find_skin_mod(&id).unwrap().base.id, id);
+    }
+
+    #[test]
+    fn readme_banners_outside_the_mod_folder_are_ignored() {
+        let game = FakeGame::new();
+        let mod_dir = game.mods_dir().join("BannerMod");
+        fs::create_dir_all(&mod_dir).unwrap();
+        fs::write(game.mods_dir().join("outside.png"), b"png").unwrap();
+        fs::write(mod_dir.join("README.md"), "![banner](../outside.png)\n").unwrap();
+        assert_eq!(find_readme_banner(&mod_dir), None);
+
+        fs::write(mod_dir.join("banner.png"), b"png").unwrap();
+        fs::write(mod_dir.join("README.md"), "![escape](../outside.png)\n![banner](./banner.png)\n").unwrap();
+        let banner = find_readme_banner(&mod_dir).expect("banner inside the mod");
+        assert!(banner.ends_with("banner.png"));
+    }
+
+    #[test]
+    fn cjk_folder_names_keep_their_name() {
+        for (folder, expected) in [
+            ("【MHWs】リオレウス装備_v1.2", "リオレウス装備"),
+            ("[作者名] 黒龍の防具 ver2.0", "黒龍の防具"),
+            ("「ゼノ・ジーヴァ」風アーマー", "ゼノ・ジーヴァ"),
+            ("アルマ衣装v1.3", "アルマ衣装"),
+            ("MHWs-艾露猫皮肤-1-0", "艾露猫皮肤"),
+            ("MHWs雷狼竜（高画質）", "雷狼竜"),
+            ("대검스킨_1.0", "대검스킨"),
+            ("【受付嬢】", "受付嬢"),
+            // ASCII names come out as before
+            ("Better_Camera_v2", "Better"),
+            ("MHWs_FooBar_1", "FooBar"),
+            ("MHWSkinPack", "MHWSkinPack"),
+        ] {
+            assert_eq!(extract_mod_name_from_folder(folder), expected, "{}", folder);
+        }
+
+        // Skin mod folders drop spaces, full-width ones included, before the name is taken
+        let game = FakeGame::new();
+        let dir = game.mods_dir().join("【MHWs】 受付嬢　衣装 v1");
+        fs::create_dir_all(&dir).unwrap();
+        let skin = new_skin_mod_from_dir(&dir, "local");
+        assert_eq!(skin.base.name, "受付嬢衣装");
+        assert_eq!(skin.base.directory_name, "【MHWs】 受付嬢　衣装 v1");
+    }
+
+    #[test]
+    fn skin_mod_scan_reports_each_folder_and_keeps_name_order() {
+        let game = FakeGame::new();
+        let mods_dir = game.mods_dir();
+        let names = ["Alpha", "Bravo", "Charlie", "Delta", "Echo"];
+        for name in names {
+            let dir = mods_dir.join(name).join("natives/STM");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("tex.bin"), name).unwrap();
+        }
+        fs::create_dir_all(mods_dir.join("Notes/docs")).unwrap();
+
+        // A folder already in the registry keeps its entry
+        let known = new_skin_mod_from_dir(&mods_dir.join("Charlie"), "local_zip");
+        let existing = HashMap::from([(known.base.path.clone(), known.clone())]);
+
+        let mut reported = Vec::new();
+        let mods = scan_skin_mod_dirs(&mods_dir, &existing, 4, |scanned, total, found| {
+            reported.push((scanned, total, found.map(|m| m.base.directory_name.clone())));
+        });
+
+        let found: Vec<&str> = mods.iter().map(|m| m.base.directory_name.as_str()).collect();
+        assert_eq!(found, names);
+        assert_eq!(mods[2].base.id, known.base.id);
+        assert_eq!(mods[2].base.source.as_deref(), Some("local_zip"));
+
+        // One report per folder, the one without mod content included
+        assert_eq!(reported.len(), 6);
+        assert!(reported.iter().enumerate().all(|(i, (scanned, total, _))| *scanned == i + 1 && *total == 6));
+        assert_eq!(reported.iter().filter(|(_, _, found)| found.is_some()).count(), 5);
+    }
+
+    #[test]
+    fn legacy_skin_registry_is_imported_once_and_archived() {
+        let game = FakeGame::new();
+        let config_dir = game.root().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+        let kept = game.mods_dir().join("OldSkin");
+        fs::create_dir_all(kept.join("natives/STM")).unwrap();
+        fs::write(kept.join("natives/STM/armor.tex"), b"old").unwrap();
+        let gone = game.mods_dir().join("DeletedSkin");
+        let legacy = serde_json::json!({
+            "skins": [
+                { "name": "Old Skin", "path": kept.to_string_lossy(), "enabled": true, "author": "someone" },
+                { "name": "Deleted Skin", "path": gone.to_string_lossy(), "enabled": false },
+            ]
+        });
+        fs::write(config_dir.join("skin_registry.json"), legacy.to_string()).unwrap();
+        // Unreadable files are set aside too, so the import never runs twice
+        fs::write(config_dir.join("skinmods_registry.json"), b"{ not json").unwrap();
+
+        let legacy_files = legacy_skin_registry_files(&config_dir);
+        assert_eq!(legacy_files.len(), 2);
+        let mut registry = ModRegistry::default();
+        assert_eq!(registry.import_legacy_skin_registries(&legacy_files), 1);
+        let skin = registry.find_skin_mod("OldSkin").expect("imported skin");
+        assert_eq!(skin.base.path, kept.to_string_lossy());
+        assert_eq!(skin.base.name, "Old Skin");
+        assert_eq!(skin.base.author.as_deref(), Some("someone"));
+        assert!(!skin.base.enabled);
+        assert!(!skin.base.id.is_empty());
+
+        // Nothing is set aside until the caller has saved the imported entries
+        assert_eq!(legacy_skin_registry_files(&config_dir), legacy_files);
+        archive_legacy_skin_registries(&legacy_files);
+        let left: Vec<String> = fs::read_dir(&config_dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(left.len(), 2);
+        assert!(left.iter().all(|name| name.contains(".json.imported-")), "{:?}", left);
+        assert!(legacy_skin_registry_files(&config_dir).is_empty());
+        assert_eq!(registry.skin_mods.len(), 1);
+    }
+}
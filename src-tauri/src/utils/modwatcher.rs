@@ -0,0 +1,257 @@
+// src-tauri/src/utils/modwatcher.rs
+// Watches the REFramework plugin/autorun folders and the staged skin mods folder so mods
+// added, removed or renamed outside the manager show up without a manual refresh. Changes are
+// batched and sent as one "mod-folders-changed" event; list_mods only rescans the REFramework
+// folders when something changed there, or when nothing is being watched.
+use log::{info, warn};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::utils::modregistry::ModType;
+use crate::utils::settings::AppSettings;
+use crate::utils::staging::{get_mods_dir, get_staging_root};
+use crate::utils::tempermission::mod_operations_in_flight;
+
+/// Quiet time after the last change before a batch is reported; extracting an archive by
+/// hand produces many events in a burst
+const DEBOUNCE: Duration = Duration::from_millis(750);
+
+/// The running watcher, kept as managed state
+pub struct ModWatcherState {
+    active: Mutex<Option<ActiveWatcher>>,
+    reframework_rescan_needed: AtomicBool, // Set by the watcher, cleared by the list_mods scan
+}
+
+impl Default for ModWatcherState {
+    fn default() -> Self {
+        Self {
+            active: Mutex::new(None),
+            reframework_rescan_needed: AtomicBool::new(true),
+        }
+    }
+}
+
+/// Register the watcher state. Called once during setup.
+pub fn init_mod_watcher(app_handle: &AppHandle) {
+    app_handle.manage(ModWatcherState::default());
+}
+
+struct ActiveWatcher {
+    _watcher: RecommendedWatcher, // Dropping it stops the events and ends the batching thread
+    game_root: PathBuf,
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ModFoldersChanged {
+    pub reframework: bool, // Plugins or autorun changed; list_mods picks it up
+    pub skins: bool,       // The staged skin mods folder changed
+    pub paths: Vec<String>,
+}
+
+/// Folders to watch: (path, whether it holds REFramework mods)
+fn watched_dirs(game_root: &Path, staging_root: &Path, settings: &AppSettings) -> Vec<(PathBuf, bool)> {
+    vec![
+        (
+            settings
+                .install_targets
+                .resolve(game_root, &ModType::REFrameworkPlugin),
+            true,
+        ),
+        (
+            settings
+                .install_targets
+                .resolve(game_root, &ModType::REFrameworkAutorun),
+            true,
+        ),
+        (get_mods_dir(staging_root), false),
+    ]
+}
+
+/// Creates, removals and renames; content edits inside a mod don't change the list
+fn is_list_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(_))
+    )
+}
+
+/// Fold a batch of watcher events into one change report. Watcher errors (e.g. an event
+/// queue overflow) mean changes may have been missed, so they count as both kinds changing.
+pub(crate) fn summarize_changes(
+    events: &[notify::Result<Event>],
+    dirs: &[(PathBuf, bool)],
+) -> Option<ModFoldersChanged> {
+    let mut change = ModFoldersChanged::default();
+    for event in events {
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                warn!("Mod folder watcher error, rescanning everything: {}", e);
+                change.reframework = true;
+                change.skins = true;
+                continue;
+            }
+        };
+        if !is_list_change(&event.kind) {
+            continue;
+        }
+        for path in &event.paths {
+            let Some((_, is_reframework)) = dirs.iter().find(|(dir, _)| path.starts_with(dir))
+            else {
+                continue;
+            };
+            if *is_reframework {
+                change.reframework = true;
+            } else {
+                change.skins = true;
+            }
+            let path = path.to_string_lossy().to_string();
+            if !change.paths.contains(&path) {
+                change.paths.push(path);
+            }
+        }
+    }
+    (change.reframework || change.skins).then_some(change)
+}
+
+fn run_batches(
+    app_handle: AppHandle,
+    rx: mpsc::Receiver<notify::Result<Event>>,
+    dirs: Vec<(PathBuf, bool)>,
+) {
+    // Ends when the watcher is dropped and the sender with it
+    while let Ok(first) = rx.recv() {
+        let mut events = vec![first];
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            events.push(event);
+        }
+        let Some(change) = summarize_changes(&events, &dirs) else {
+            continue;
+        };
+        if change.reframework {
+            app_handle
+                .state::<ModWatcherState>()
+                .reframework_rescan_needed
+                .store(true, Ordering::SeqCst);
+        }
+        // The manager's own installs and toggles refresh the list themselves
+        if mod_operations_in_flight(&app_handle) > 0 {
+            continue;
+        }
+        info!(
+            "Mod folders changed outside the manager ({} path(s))",
+            change.paths.len()
+        );
+        if let Err(e) = app_handle.emit("mod-folders-changed", &change) {
+            warn!("Failed to emit mod-folders-changed: {}", e);
+        }
+    }
+}
+
+/// Start watching the mod folders of `game_root`, replacing any earlier watcher. Folders
+/// that don't exist yet are skipped.
+pub fn start_watching(app_handle: &AppHandle, game_root: &Path) -> Result<(), String> {
+    let settings = AppSettings::load(app_handle)?;
+    let dirs = watched_dirs(game_root, &get_staging_root(app_handle, game_root), &settings);
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = tx.send(event);
+    })
+    .map_err(|e| format!("Failed to create mod folder watcher: {}", e))?;
+    for (dir, _) in &dirs {
+        if !dir.is_dir() {
+            continue;
+        }
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+    }
+
+    let handle = app_handle.clone();
+    std::thread::spawn(move || run_batches(handle, rx, dirs));
+    let state = app_handle.state::<ModWatcherState>();
+    state.reframework_rescan_needed.store(true, Ordering::SeqCst);
+    let mut active = state
+        .active
+        .lock()
+        .map_err(|e| format!("Failed to lock mod folder watcher: {}", e))?;
+    *active = Some(ActiveWatcher {
+        _watcher: watcher,
+        game_root: game_root.to_path_buf(),
+    });
+    info!("Watching mod folders under {}", game_root.display());
+    Ok(())
+}
+
+/// Whether list_mods has to scan the REFramework folders of `game_root`. Always true when
+/// they aren't being watched; otherwise only after a change, and the flag is cleared.
+pub fn take_reframework_rescan(app_handle: &AppHandle, game_root: &Path) -> bool {
+    let state = app_handle.state::<ModWatcherState>();
+    let watching = state
+        .active
+        .lock()
+        .map(|active| active.as_ref().is_some_and(|w| w.game_root == game_root))
+        .unwrap_or(false);
+    !watching || state.reframework_rescan_needed.swap(false, Ordering::SeqCst)
+}
+
+#[tauri::command]
+pub async fn start_mod_watcher(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<(), String> {
+    start_watching(&app_handle, &PathBuf::from(&game_root_path))
+}
+
+#[tauri::command]
+pub async fn stop_mod_watcher(state: tauri::State<'_, ModWatcherState>) -> Result<(), String> {
+    let mut active = state
+        .active
+        .lock()
+        .map_err(|e| format!("Failed to lock mod folder watcher: {}", e))?;
+    if active.take().is_some() {
+        info!("Stopped watching mod folders");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn folder_watcher_batches_report_list_changes_only() {
+        use notify::event::{CreateKind, DataChange, EventKind, ModifyKind, RemoveKind};
+        use notify::Event;
+
+        let game = FakeGame::new();
+        let plugins = game.root().join("reframework/plugins");
+        let mods = game.mods_dir();
+        let dirs = vec![(plugins.clone(), true), (mods.clone(), false)];
+
+        // Editing a file inside a mod doesn't change the list
+        let edit = Ok(Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+            .add_path(plugins.join("Tool/Tool.dll")));
+        assert!(summarize_changes(&[edit], &dirs).is_none());
+
+        let events = vec![
+            Ok(Event::new(EventKind::Create(CreateKind::Folder)).add_path(mods.join("新しいスキン"))),
+            Ok(Event::new(EventKind::Create(CreateKind::Folder)).add_path(mods.join("新しいスキン"))),
+            Ok(Event::new(EventKind::Remove(RemoveKind::Folder)).add_path(game.root().join("elsewhere"))),
+        ];
+        let change = summarize_changes(&events, &dirs).expect("skin folder added");
+        assert!(change.skins && !change.reframework);
+        assert_eq!(change.paths.len(), 1);
+
+        // A watcher error may have hidden anything, so everything counts as changed
+        let change = summarize_changes(&[Err(notify::Error::generic("queue overflow"))], &dirs).unwrap();
+        assert!(change.skins && change.reframework);
+    }
+}
@@ -0,0 +1,280 @@
+// src-tauri/src/utils/overwrites.rs
+// Backups of game files a skin mod overwrites when it deploys. Whatever was in natives/ before
+// (another mod's copy or a file placed by hand) is kept in fossmodmanager/backups/<mod>/ and
+// put back when the mod is disabled or deleted.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::fsops::long_path;
+use crate::utils::hashing::hash_file;
+use crate::utils::modregistry::{owned_path_key, InstalledFile, Mod, ModRegistry};
+
+const BACKUP_MANIFEST: &str = "manifest.json";
+
+/// One game file as it was before a mod replaced it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OverwrittenFile {
+    pub path: String, // Relative to the game root, '/' separators
+    pub sha256: String,
+    pub previous_owner: Option<String>, // Source path of the skin mod that had deployed it, if any
+    pub backed_up_timestamp: i64,
+}
+
+/// fossmodmanager/backups/<mod id>/manifest.json; the copies sit under files/ by relative path
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OverwriteManifest {
+    pub mod_path: String,
+    pub files: Vec<OverwrittenFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OverwriteRestoreReport {
+    pub restored: Vec<String>,
+    pub returned_to_owner: Vec<String>, // Restored files the previous mod owns again
+    pub discarded: Vec<String>, // Belonged to a mod that has since been disabled or removed
+    pub errors: Vec<String>,
+}
+
+/// Backup folder for one mod, named by `overwrite_backup_key`
+pub fn get_overwrite_backup_dir(game_root: &Path, mod_key: &str) -> PathBuf {
+    game_root.join("fossmodmanager").join("backups").join(mod_key)
+}
+
+/// Which backup folder a mod uses: its id, or its directory name for backups made before
+/// mods had ids, which stay where they are until they're restored
+pub fn overwrite_backup_key(game_root: &Path, base: &Mod) -> String {
+    let legacy = get_overwrite_backup_dir(game_root, &base.directory_name);
+    if base.id.is_empty() || (legacy.is_dir() && !get_overwrite_backup_dir(game_root, &base.id).is_dir()) {
+        base.directory_name.clone()
+    } else {
+        base.id.clone()
+    }
+}
+
+fn load_overwrite_manifest(backup_dir: &Path) -> Option<OverwriteManifest> {
+    let content = fs::read_to_string(backup_dir.join(BACKUP_MANIFEST)).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!("Ignoring unreadable backup manifest in {}: {}", backup_dir.display(), e);
+            None
+        }
+    }
+}
+
+/// Copy every existing file in `targets` into the mod's backup folder before it is overwritten.
+/// `owner_of` names the enabled mod that deployed a file, if any. A file already backed up
+/// keeps its first copy, since that's the one from before this mod ever touched it.
+pub(crate) fn back_up_overwritten_files(
+    game_root: &Path,
+    mod_key: &str,
+    mod_path: &str,
+    targets: &[PathBuf],
+    owner_of: &dyn Fn(&Path) -> Option<String>,
+) -> Result<usize, String> {
+    let existing: Vec<&PathBuf> = targets.iter().filter(|p| long_path(p).is_file()).collect();
+    if existing.is_empty() {
+        return Ok(0);
+    }
+    let backup_dir = get_overwrite_backup_dir(game_root, mod_key);
+    let mut manifest = load_overwrite_manifest(&backup_dir).unwrap_or_default();
+    manifest.mod_path = mod_path.to_string();
+
+    let mut backed_up = 0;
+    for path in existing {
+        let Ok(rel) = path.strip_prefix(game_root) else {
+            warn!("Not backing up {}: outside the game folder", path.display());
+            continue;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        if manifest.files.iter().any(|f| owned_path_key(&f.path) == owned_path_key(&rel)) {
+            continue;
+        }
+        manifest.files.push(OverwrittenFile {
+            sha256: copy_into_backup(&backup_dir, path, &rel)?,
+            path: rel,
+            previous_owner: owner_of(path),
+            backed_up_timestamp: chrono::Utc::now().timestamp(),
+        });
+        backed_up += 1;
+    }
+
+    save_overwrite_manifest(&backup_dir, &manifest)?;
+    info!("Backed up {} file(s) '{}' is about to overwrite", backed_up, mod_path);
+    Ok(backed_up)
+}
+
+/// Make the current contents of `paths` the originals that disabling puts back, for files
+/// something else (usually a game update) replaced under `mod_path`'s deployed copies. Each
+/// file's backups are followed through the mods that had it before, and the copy at the end
+/// of that chain is replaced, or added there if none was kept. Returns how many were stored.
+pub(crate) fn refresh_original_backups(
+    registry: &ModRegistry,
+    game_root: &Path,
+    mod_path: &str,
+    paths: &[PathBuf],
+) -> Result<usize, String> {
+    let mut refreshed = 0;
+    for path in paths {
+        let Ok(rel) = path.strip_prefix(game_root) else {
+            warn!("Not backing up {}: outside the game folder", path.display());
+            continue;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        let mut owner = mod_path.to_string();
+        // Bounded by the number of mods, so a damaged manifest can't loop forever
+        for _ in 0..=registry.skin_mods.len() {
+            let Some(skin_mod) = registry.skin_mods.iter().find(|m| m.base.path == owner) else {
+                break;
+            };
+            let backup_dir = get_overwrite_backup_dir(game_root, &overwrite_backup_key(game_root, &skin_mod.base));
+            let mut manifest = load_overwrite_manifest(&backup_dir).unwrap_or_default();
+            let existing = manifest
+                .files
+                .iter()
+                .position(|f| owned_path_key(&f.path) == owned_path_key(&rel));
+            if let Some(previous) = existing.and_then(|i| manifest.files[i].previous_owner.clone()) {
+                if registry.skin_mods.iter().any(|m| m.base.path == previous) {
+                    owner = previous;
+                    continue;
+                }
+            }
+            let file = OverwrittenFile {
+                sha256: copy_into_backup(&backup_dir, path, &rel)?,
+                path: rel.clone(),
+                previous_owner: None,
+                backed_up_timestamp: chrono::Utc::now().timestamp(),
+            };
+            match existing {
+                Some(i) => manifest.files[i] = file,
+                None => manifest.files.push(file),
+            }
+            manifest.mod_path = owner.clone();
+            save_overwrite_manifest(&backup_dir, &manifest)?;
+            info!("Kept the current {} as the original '{}' puts back", rel, skin_mod.base.name);
+            refreshed += 1;
+            break;
+        }
+    }
+    Ok(refreshed)
+}
+
+/// Copy a game file into a backup folder under its relative path. Returns the copy's hash.
+fn copy_into_backup(backup_dir: &Path, path: &Path, rel: &str) -> Result<String, String> {
+    let backup_path = backup_dir.join("files").join(rel);
+    if let Some(parent) = backup_path.parent() {
+        fs::create_dir_all(long_path(parent))
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    fs::copy(long_path(path), long_path(&backup_path))
+        .map_err(|e| format!("Failed to back up {}: {}", path.display(), e))?;
+    hash_file(&backup_path)
+}
+
+fn save_overwrite_manifest(backup_dir: &Path, manifest: &OverwriteManifest) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize backup manifest: {}", e))?;
+    fs::write(backup_dir.join(BACKUP_MANIFEST), content)
+        .map_err(|e| format!("Failed to write backup manifest: {}", e))
+}
+
+/// Put back the files a mod overwrote, once its own copies are gone. Files whose previous
+/// owner is still enabled go back on that mod's installed list; those of an owner that has
+/// since been disabled or removed aren't restored, as that mod would have removed them too.
+/// The backup folder is removed once everything is settled. Only updates the in-memory registry.
+pub(crate) fn restore_overwritten_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    mod_key: &str,
+) -> OverwriteRestoreReport {
+    let mut report = OverwriteRestoreReport::default();
+    let backup_dir = get_overwrite_backup_dir(game_root, mod_key);
+    let Some(manifest) = load_overwrite_manifest(&backup_dir) else {
+        return report;
+    };
+    let ledger = LedgerOperation::begin(game_root, "restore_original_files");
+
+    for file in &manifest.files {
+        let owner_index = file.previous_owner.as_deref().map(|owner| {
+            registry
+                .skin_mods
+                .iter()
+                .position(|m| m.base.enabled && m.base.path == owner)
+        });
+        if owner_index == Some(None) {
+            report.discarded.push(file.path.clone());
+            continue;
+        }
+        let backup_path = backup_dir.join("files").join(&file.path);
+        let dest = game_root.join(&file.path);
+        let restored = fs::create_dir_all(long_path(dest.parent().unwrap_or(game_root)))
+            .and_then(|_| fs::copy(long_path(&backup_path), long_path(&dest)));
+        if let Err(e) = restored {
+            report.errors.push(format!("Failed to restore {}: {}", dest.display(), e));
+            continue;
+        }
+        ledger.wrote_hashed(&dest, file.sha256.clone());
+        report.restored.push(file.path.clone());
+
+        if let Some(Some(index)) = owner_index {
+            let owner = &mut registry.skin_mods[index];
+            let dest_str = dest.to_string_lossy().to_string();
+            if !owner.installed_files.iter().any(|f| owned_path_key(&f.path) == owned_path_key(&dest_str)) {
+                owner.installed_files.push(InstalledFile {
+                    path: dest_str,
+                    sha256: Some(file.sha256.clone()),
+                });
+            }
+            report.returned_to_owner.push(file.path.clone());
+        }
+    }
+
+    if report.errors.is_empty() {
+        if let Err(e) = fs::remove_dir_all(&backup_dir) {
+            warn!("Failed to remove backup folder {}: {}", backup_dir.display(), e);
+        }
+    }
+    info!(
+        "Restored {} overwritten file(s) for '{}' ({} discarded, {} error(s))",
+        report.restored.len(),
+        manifest.mod_path,
+        report.discarded.len(),
+        report.errors.len()
+    );
+    report
+}
+
+/// Restore the game files a disabled skin mod overwrote. Disable and delete do this on
+/// their own; this is for retrying after one of them reported restore errors.
+#[tauri::command]
+pub async fn restore_original_files(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_path: String,
+) -> Result<OverwriteRestoreReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_path = registry.resolve_skin_mod_path(&mod_path).unwrap_or(mod_path);
+    let skin_mod = registry
+        .skin_mods
+        .iter()
+        .find(|m| m.base.path == mod_path)
+        .ok_or_else(|| format!("SkinMod with path '{}' not found in registry", mod_path))?;
+    if skin_mod.base.enabled {
+        return Err(format!(
+            "'{}' is enabled; disable it to restore the files it replaced",
+            skin_mod.base.name
+        ));
+    }
+    let mod_key = skin_mod.base.directory_name.clone();
+    let report = restore_overwritten_files(&mut registry, &game_root, &mod_key);
+    if !report.returned_to_owner.is_empty() {
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+    }
+    Ok(report)
+}
@@ -0,0 +1,549 @@
+// src-tauri/src/utils/pakregistry.rs
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::hashing::hash_files;
+use crate::utils::modregistry::{ModRegistry, ModType};
+use crate::utils::settings::AppSettings;
+use crate::utils::tempermission::ModOperationGuard;
+
+/// Matches numbered patch paks, enabled or disabled
+pub static PATCH_PAK_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^re_chunk_000\.pak\.sub_000\.pak\.patch_(\d{3})\.pak(\.disabled)?$").unwrap()
+});
+
+/// A numbered patch pak found in the game directory
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PatchPak {
+    pub path: String,
+    pub number: u32,
+    pub enabled: bool,
+    pub size_bytes: u64,
+}
+
+/// Build the file name for a patch pak number
+pub fn patch_pak_file_name(number: u32, enabled: bool) -> String {
+    let name = format!("re_chunk_000.pak.sub_000.pak.patch_{:03}.pak", number);
+    if enabled {
+        name
+    } else {
+        format!("{}.disabled", name)
+    }
+}
+
+/// List every patch pak in a directory, sorted by patch number
+pub fn list_patch_paks(pak_dir: &Path) -> Result<Vec<PatchPak>, String> {
+    let mut paks = Vec::new();
+    let entries = fs::read_dir(pak_dir)
+        .map_err(|e| format!("Failed to read directory {}: {}", pak_dir.display(), e))?;
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if let Some(caps) = PATCH_PAK_REGEX.captures(file_name) {
+            let Ok(number) = caps[1].parse::<u32>() else {
+                continue;
+            };
+            let size_bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            paks.push(PatchPak {
+                path: path.to_string_lossy().to_string(),
+                number,
+                enabled: caps.get(2).is_none(),
+                size_bytes,
+            });
+        }
+    }
+
+    paks.sort_by_key(|p| p.number);
+    Ok(paks)
+}
+
+/// A set of byte-identical patch paks
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DuplicatePakGroup {
+    pub hash: String,
+    pub kept: String,                 // The copy that stays in place
+    pub duplicates: Vec<String>,      // Extra copies that can be removed
+    pub owners: Vec<String>,          // Registry skin mods owning any copy
+    pub skipped_reason: Option<String>, // Set when the group is left untouched
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PakDedupeReport {
+    pub groups: Vec<DuplicatePakGroup>,
+    pub removed: Vec<String>,
+    pub renamed: Vec<(String, String)>, // (from, to)
+    pub dry_run: bool,
+}
+
+/// Find byte-identical patch paks and decide which copy to keep
+fn find_duplicate_groups(
+    paks: &[PatchPak],
+    registry: &ModRegistry,
+    workers: usize,
+) -> Result<Vec<DuplicatePakGroup>, String> {
+    // Only paks sharing a size can be identical, so avoid hashing the rest
+    let mut by_size: HashMap<u64, Vec<&PatchPak>> = HashMap::new();
+    for pak in paks {
+        by_size.entry(pak.size_bytes).or_default().push(pak);
+    }
+
+    let candidates: Vec<&PatchPak> = by_size
+        .values()
+        .filter(|c| c.len() > 1)
+        .flatten()
+        .copied()
+        .collect();
+    let candidate_paths: Vec<PathBuf> = candidates.iter().map(|p| PathBuf::from(&p.path)).collect();
+    let hashes = hash_files(&candidate_paths, workers);
+    let mut by_hash: HashMap<String, Vec<&PatchPak>> = HashMap::new();
+    for (pak, hash) in candidates.into_iter().zip(hashes) {
+        by_hash.entry(hash?).or_default().push(pak);
+    }
+
+    let owner_of = |pak_path: &str| -> Option<String> {
+        registry
+            .skin_mods
+            .iter()
+            .find(|sm| {
+                sm.installed_pak_path.as_deref() == Some(pak_path)
+                    || sm.installed_files.iter().any(|f| f.path == pak_path)
+            })
+            .map(|sm| sm.base.directory_name.clone())
+    };
+
+    let mut groups = Vec::new();
+    for (hash, mut copies) in by_hash.into_iter().filter(|(_, c)| c.len() > 1) {
+        copies.sort_by_key(|p| p.number);
+
+        let owned: Vec<(&PatchPak, String)> = copies
+            .iter()
+            .filter_map(|p| owner_of(&p.path).map(|owner| (*p, owner)))
+            .collect();
+        let mut owners: Vec<String> = owned.iter().map(|(_, o)| o.clone()).collect();
+        owners.sort();
+        owners.dedup();
+
+        // Keep the registry-owned copy, then an enabled one, then the lowest number
+        let kept = owned
+            .first()
+            .map(|(p, _)| *p)
+            .or_else(|| copies.iter().find(|p| p.enabled).copied())
+            .unwrap_or(copies[0]);
+
+        let skipped_reason = if owners.len() > 1 {
+            Some(format!(
+                "Copies are owned by different mods ({}); resolve manually",
+                owners.join(", ")
+            ))
+        } else if owned.len() > 1 {
+            Some("Several copies are tracked by the same mod; resolve manually".to_string())
+        } else {
+            None
+        };
+
+        groups.push(DuplicatePakGroup {
+            hash,
+            kept: kept.path.clone(),
+            duplicates: copies
+                .iter()
+                .filter(|p| p.path != kept.path)
+                .map(|p| p.path.clone())
+                .collect(),
+            owners,
+            skipped_reason,
+        });
+    }
+
+    groups.sort_by(|a, b| a.kept.cmp(&b.kept));
+    Ok(groups)
+}
+
+/// Renumber the patch paks this tool installed so they fill the lowest free numbers from
+/// 001, keeping their relative order. Paks the registry doesn't own belong to other tools
+/// and keep their numbers. Renames are all undone if any fails. Returns the (from, to)
+/// renames and updates registry paths to match.
+pub fn compact_patch_numbers(
+    pak_dir: &Path,
+    registry: &mut ModRegistry,
+) -> Result<Vec<(String, String)>, String> {
+    let mut paks = list_patch_paks(pak_dir)?;
+    // An enabled and a disabled pak can share a number; settle which goes first
+    paks.sort_by_key(|p| (p.number, !p.enabled));
+    let managed: HashSet<&str> = registry
+        .skin_mods
+        .iter()
+        .filter_map(|m| m.installed_pak_path.as_deref())
+        .collect();
+    let (owned, unowned): (Vec<&PatchPak>, Vec<&PatchPak>) =
+        paks.iter().partition(|p| managed.contains(p.path.as_str()));
+    let taken: HashSet<u32> = unowned.iter().map(|p| p.number).collect();
+
+    let mut next_number = 1;
+    let mut moves: Vec<(PathBuf, PathBuf)> = Vec::new();
+    for pak in owned {
+        while taken.contains(&next_number) {
+            next_number += 1;
+        }
+        if pak.number != next_number {
+            let from = PathBuf::from(&pak.path);
+            let to = pak_dir.join(patch_pak_file_name(next_number, pak.enabled));
+            moves.push((from, to));
+        }
+        next_number += 1;
+    }
+
+    rename_paks(&moves)?;
+    apply_pak_renames(registry, &moves);
+    Ok(moves
+        .into_iter()
+        .map(|(from, to)| (from.to_string_lossy().to_string(), to.to_string_lossy().to_string()))
+        .collect())
+}
+
+/// Detect byte-identical patch paks, remove the extras and renumber the rest. Only reports
+/// unless `dry_run` is explicitly false, since the extras are deleted outright.
+#[tauri::command]
+pub async fn dedupe_paks(
+    app_handle: AppHandle,
+    game_root_path: String,
+    dry_run: Option<bool>,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<PakDedupeReport>, String> {
+    let dry_run = dry_run.unwrap_or(true);
+    info!("Checking for duplicate patch paks (dry run: {})", dry_run);
+
+    let game_root = PathBuf::from(&game_root_path);
+    let settings = AppSettings::load(&app_handle)?;
+    let pak_dir = settings.install_targets.resolve(&game_root, &ModType::SkinMod);
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let paks = list_patch_paks(&pak_dir)?;
+    let groups = find_duplicate_groups(&paks, &registry, settings.worker_count(&game_root))?;
+
+    let mut report = PakDedupeReport {
+        groups,
+        dry_run,
+        ..Default::default()
+    };
+
+    if dry_run {
+        info!("Found {} duplicate pak groups", report.groups.len());
+        return Ok(Confirmable::Done(report));
+    }
+
+    let duplicates: Vec<String> = report
+        .groups
+        .iter()
+        .filter(|g| g.skipped_reason.is_none())
+        .flat_map(|g| g.duplicates.iter().cloned())
+        .collect();
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "dedupe_paks",
+        &duplicates,
+        &format!("Delete {} duplicate patch pak(s)", duplicates.len()),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+
+    for group in report.groups.iter().filter(|g| g.skipped_reason.is_none()) {
+        for duplicate in &group.duplicates {
+            info!("Removing duplicate pak {} (same as {})", duplicate, group.kept);
+            fs::remove_file(duplicate)
+                .map_err(|e| format!("Failed to remove duplicate pak {}: {}", duplicate, e))?;
+            report.removed.push(duplicate.clone());
+        }
+    }
+
+    if !report.removed.is_empty() {
+        report.renamed = compact_patch_numbers(&pak_dir, &mut registry)?;
+        registry.last_updated = chrono::Utc::now().timestamp();
+        if let Err(e) = registry.save(&app_handle) {
+            warn!("Failed to save registry after deduplicating paks: {}", e);
+            return Err(format!("Removed duplicates but failed to save registry: {}", e));
+        }
+    }
+
+    info!(
+        "Pak dedupe complete: removed {}, renamed {}",
+        report.removed.len(),
+        report.renamed.len()
+    );
+    Ok(Confirmable::Done(report))
+}
+
+/// One enabled skin mod's pak in the order the game loads them; later entries win
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PakLoadOrderEntry {
+    pub mod_path: String, // Source path, the identifier used by set_pak_load_order
+    pub mod_name: String,
+    pub pak_path: String,
+    pub patch_number: u32,
+}
+
+/// Patch number of a patch pak path, enabled or disabled
+pub(crate) fn patch_number_of(pak_path: &str) -> Option<u32> {
+    Path::new(pak_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| PATCH_PAK_REGEX.captures(n))
+        .and_then(|caps| caps[1].parse().ok())
+}
+
+/// Enabled skin mods with a deployed pak, lowest patch number (loaded first) first
+pub fn pak_load_order(registry: &ModRegistry) -> Vec<PakLoadOrderEntry> {
+    let mut order: Vec<PakLoadOrderEntry> = registry
+        .skin_mods
+        .iter()
+        .filter(|sm| sm.base.enabled)
+        .filter_map(|sm| {
+            let pak_path = sm.installed_pak_path.clone()?;
+            Some(PakLoadOrderEntry {
+                mod_path: sm.base.path.clone(),
+                mod_name: sm.base.name.clone(),
+                patch_number: patch_number_of(&pak_path)?,
+                pak_path,
+            })
+        })
+        .collect();
+    order.sort_by_key(|e| e.patch_number);
+    order
+}
+
+/// Undo completed renames, newest first
+fn roll_back_renames(done: &[(PathBuf, PathBuf)]) {
+    for (from, to) in done.iter().rev() {
+        if let Err(e) = fs::rename(to, from) {
+            warn!("Failed to rename {} back to {}: {}", to.display(), from.display(), e);
+        }
+    }
+}
+
+/// Rename each (from, to) pair through a temporary name first, so swapped or chained numbers
+/// never collide. A target that already exists is never overwritten: it, or any failed
+/// rename, undoes everything done so far.
+fn rename_paks(moves: &[(PathBuf, PathBuf)]) -> Result<(), String> {
+    let temp_of = |from: &Path| {
+        let name = from.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        from.with_file_name(format!("{}.reorder", name))
+    };
+    let mut done: Vec<(PathBuf, PathBuf)> = Vec::new();
+    for (from, _) in moves {
+        let temp = temp_of(from);
+        if let Err(e) = fs::rename(from, &temp) {
+            roll_back_renames(&done);
+            return Err(format!("Failed to rename {}: {}", from.display(), e));
+        }
+        done.push((from.clone(), temp));
+    }
+    for (from, to) in moves {
+        let temp = temp_of(from);
+        if to.exists() {
+            roll_back_renames(&done);
+            return Err(format!("{} is in the way", to.display()));
+        }
+        if let Err(e) = fs::rename(&temp, to) {
+            roll_back_renames(&done);
+            return Err(format!("Failed to rename {} to {}: {}", from.display(), to.display(), e));
+        }
+        done.push((temp, to.clone()));
+    }
+    Ok(())
+}
+
+/// Point registry references at renamed paks. All changes apply at once so a swap doesn't
+/// rewrite the same entry twice.
+fn apply_pak_renames(registry: &mut ModRegistry, moves: &[(PathBuf, PathBuf)]) {
+    let renamed: HashMap<String, String> = moves
+        .iter()
+        .map(|(from, to)| (from.to_string_lossy().to_string(), to.to_string_lossy().to_string()))
+        .collect();
+    for skin_mod in registry.skin_mods.iter_mut() {
+        if let Some(to) = skin_mod.installed_pak_path.as_ref().and_then(|p| renamed.get(p)) {
+            skin_mod.installed_pak_path = Some(to.clone());
+        }
+        for installed in skin_mod.installed_files.iter_mut() {
+            if let Some(to) = renamed.get(installed.path.as_str()) {
+                installed.path = to.clone();
+            }
+        }
+    }
+}
+
+/// Give the managed paks the patch numbers they already occupy, reassigned so `new_order`
+/// (mod source paths, first loaded first) holds. Unmanaged paks keep their numbers. Renames
+/// go through temporary names and are all undone if any fails. Returns the (from, to) renames
+/// and updates registry paths to match.
+pub fn reorder_patch_paks(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    new_order: &[String],
+) -> Result<Vec<(String, String)>, String> {
+    let current = pak_load_order(registry);
+    let mut requested: Vec<&String> = new_order.iter().collect();
+    requested.sort();
+    requested.dedup();
+    let mut expected: Vec<&String> = current.iter().map(|e| &e.mod_path).collect();
+    expected.sort();
+    if requested.len() != new_order.len() || requested != expected {
+        return Err(
+            "The new load order must list every enabled skin mod with a pak exactly once".to_string(),
+        );
+    }
+
+    // (current path, target path) for every pak that changes number
+    let moves: Vec<(PathBuf, PathBuf)> = new_order
+        .iter()
+        .zip(current.iter().map(|e| e.patch_number))
+        .filter_map(|(mod_path, slot)| {
+            let entry = current.iter().find(|e| &e.mod_path == mod_path)?;
+            if entry.patch_number == slot {
+                return None;
+            }
+            let from = PathBuf::from(&entry.pak_path);
+            let to = from.with_file_name(patch_pak_file_name(slot, true));
+            Some((from, to))
+        })
+        .collect();
+    if moves.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    rename_paks(&moves).map_err(|e| format!("{}; the load order was not changed", e))?;
+    apply_pak_renames(registry, &moves);
+
+    let ledger = LedgerOperation::begin(game_root, "set_pak_load_order");
+    for (from, to) in &moves {
+        ledger.renamed(from, to);
+    }
+    Ok(moves
+        .into_iter()
+        .map(|(from, to)| (from.to_string_lossy().to_string(), to.to_string_lossy().to_string()))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_pak_load_order(app_handle: AppHandle) -> Result<Vec<PakLoadOrderEntry>, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    Ok(pak_load_order(&registry))
+}
+
+/// Reorder enabled skin mods' paks. `mod_paths` lists every enabled skin mod with a pak,
+/// first loaded first (the last one wins conflicts).
+#[tauri::command]
+pub async fn set_pak_load_order(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_paths: Vec<String>,
+) -> Result<Vec<PakLoadOrderEntry>, String> {
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mod_paths: Vec<String> = mod_paths
+        .into_iter()
+        .map(|p| registry.resolve_skin_mod_path(&p).unwrap_or(p))
+        .collect();
+    let previous_order: Vec<String> = pak_load_order(&registry).into_iter().map(|e| e.mod_path).collect();
+    let renamed = reorder_patch_paks(&mut registry, &game_root, &mod_paths)?;
+    if renamed.is_empty() {
+        return Ok(pak_load_order(&registry));
+    }
+
+    registry.last_updated = chrono::Utc::now().timestamp();
+    if let Err(e) = registry.save(&app_handle) {
+        // Put the files back so they match the registry that is still on disk
+        if let Err(undo_err) = reorder_patch_paks(&mut registry, &game_root, &previous_order) {
+            warn!("Failed to restore the previous pak order: {}", undo_err);
+        }
+        return Err(format!("Failed to save registry after reordering paks: {}", e));
+    }
+    info!("Reordered {} patch pak(s)", renamed.len());
+    Ok(pak_load_order(&registry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use crate::utils::modregistry::InstalledFile;
+
+    #[test]
+    fn reordering_paks_swaps_patch_numbers_and_registry_paths() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        let mods: Vec<String> = ["FirstSkin", "SecondSkin", "ThirdSkin"]
+            .iter()
+            .map(|name| game.install_skin(&mut registry, name, name.as_bytes()))
+            .collect();
+        // Deployed as patch 001-003, plus an unmanaged pak at 004 that must stay put
+        for (number, skin) in (1..).zip(registry.skin_mods.iter_mut()) {
+            let pak = game.root().join(patch_pak_file_name(number, true));
+            fs::write(&pak, skin.base.name.as_bytes()).unwrap();
+            skin.base.enabled = true;
+            skin.installed_pak_path = Some(pak.to_string_lossy().to_string());
+            skin.installed_files = vec![InstalledFile::record(&pak)];
+        }
+        fs::write(game.root().join(patch_pak_file_name(4, true)), b"unmanaged").unwrap();
+
+        let new_order = vec![mods[2].clone(), mods[0].clone(), mods[1].clone()];
+        let renamed = reorder_patch_paks(&mut registry, game.root(), &new_order).expect("reorder paks");
+        assert_eq!(renamed.len(), 3);
+        let order = pak_load_order(&registry);
+        assert_eq!(order.iter().map(|e| e.mod_path.clone()).collect::<Vec<_>>(), new_order);
+        for entry in &order {
+            assert_eq!(fs::read(&entry.pak_path).unwrap(), entry.mod_name.as_bytes());
+            let skin = registry.skin_mods.iter().find(|m| m.base.path == entry.mod_path).unwrap();
+            assert_eq!(skin.installed_files.iter().map(|f| f.path.clone()).collect::<Vec<_>>(), vec![entry.pak_path.clone()]);
+        }
+        assert_eq!(fs::read(game.root().join(patch_pak_file_name(4, true))).unwrap(), b"unmanaged");
+
+        // Anything but a permutation of the enabled pak mods is refused
+        assert!(reorder_patch_paks(&mut registry, game.root(), &mods[..2]).is_err());
+    }
+
+    #[test]
+    fn compacting_paks_skips_unmanaged_ones_and_never_overwrites() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        for name in ["FirstSkin", "SecondSkin", "ThirdSkin"] {
+            game.install_skin(&mut registry, name, name.as_bytes());
+        }
+        // 002 and 002.disabled share a number; 001 and 003 belong to another tool
+        for ((number, enabled), skin) in [(2, true), (2, false), (4, true)].into_iter().zip(registry.skin_mods.iter_mut()) {
+            let pak = game.root().join(patch_pak_file_name(number, enabled));
+            fs::write(&pak, skin.base.name.as_bytes()).unwrap();
+            skin.base.enabled = enabled;
+            skin.installed_pak_path = Some(pak.to_string_lossy().to_string());
+            skin.installed_files = vec![InstalledFile::record(&pak)];
+        }
+        fs::write(game.root().join(patch_pak_file_name(1, true)), b"other tool 1").unwrap();
+        fs::write(game.root().join(patch_pak_file_name(3, true)), b"other tool 3").unwrap();
+
+        let renamed = compact_patch_numbers(game.root(), &mut registry).expect("compact paks");
+        assert_eq!(renamed.len(), 2);
+        assert_eq!(fs::read(game.root().join(patch_pak_file_name(1, true))).unwrap(), b"other tool 1");
+        assert_eq!(fs::read(game.root().join(patch_pak_file_name(3, true))).unwrap(), b"other tool 3");
+        for (skin, (number, enabled)) in registry.skin_mods.iter().zip([(2, true), (4, false), (5, true)]) {
+            let pak = game.root().join(patch_pak_file_name(number, enabled));
+            assert_eq!(fs::read(&pak).unwrap(), skin.base.name.as_bytes());
+            assert_eq!(skin.installed_pak_path.as_deref(), Some(&*pak.to_string_lossy()));
+            assert_eq!(skin.installed_files[0].path, pak.to_string_lossy());
+        }
+    }
+}
@@ -0,0 +1,290 @@
+// src-tauri/src/utils/permissions.rs
+// Capability tiers for the commands the frontend can invoke. Every command belongs to one
+// tier and the policy in settings.json can switch whole tiers off; the check wraps the
+// invoke handler, so it runs before any command body. The `kiosk` build feature locks everything but
+// reads for read-only demo installs.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Manager, Wry};
+
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::settings::AppSettings;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandTier {
+    Read,          // Inspect state; writes at most the manager's own caches and exports
+    ModifyMods,    // Registry, metadata, staging and app data, but not the game directory
+    ModifyGameDir, // Installs, toggles and anything else that writes into the game directory
+    Destructive,   // Deletes mods or data, or overwrites state with an older copy
+}
+
+impl CommandTier {
+    pub const ALL: &'static [CommandTier] = &[
+        CommandTier::Read,
+        CommandTier::ModifyMods,
+        CommandTier::ModifyGameDir,
+        CommandTier::Destructive,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CommandTier::Read => "read",
+            CommandTier::ModifyMods => "modify-mods",
+            CommandTier::ModifyGameDir => "modify-game-dir",
+            CommandTier::Destructive => "destructive",
+        }
+    }
+}
+
+/// Commands that change the policy itself. They stay reachable whatever tiers are off, so a
+/// user can always lift their own restrictions; kiosk builds refuse them outright.
+pub(crate) const POLICY_COMMANDS: &[&str] = &["save_app_settings"];
+
+/// Tier of each registered command. Anything missing is treated as destructive, so a new
+/// command can't slip past the policy just because nobody classified it.
+pub(crate) const COMMAND_TIERS: &[(&str, CommandTier)] = &[
+    ("load_game_config", CommandTier::Read),
+    ("validate_game_installation", CommandTier::Read),
+    ("check_reframework_installed", CommandTier::Read),
+    ("verify_reframework", CommandTier::Read),
+    ("check_sample_mod", CommandTier::Read),
+    ("check_antivirus_quarantine", CommandTier::Read),
+    ("get_session_changes", CommandTier::Read),
+    ("run_health_check", CommandTier::Read),
+    ("pre_launch_check", CommandTier::Read),
+    ("get_safe_mode_status", CommandTier::Read),
+    ("take_pending_launch_actions", CommandTier::Read),
+    ("preload_mod_assets", CommandTier::Read),
+    ("get_startup_state", CommandTier::Read),
+    ("get_backend_info", CommandTier::Read),
+    ("fetch_trending_mods", CommandTier::Read),
+    ("fetch_latest_added", CommandTier::Read),
+    ("fetch_latest_updated", CommandTier::Read),
+    ("search_mods", CommandTier::Read),
+    ("get_nexus_rate_limit_status", CommandTier::Read),
+    ("get_nexus_api_key_status", CommandTier::Read),
+    ("validate_nexus_api_key", CommandTier::Read),
+    ("match_mod_on_nexus", CommandTier::Read),
+    ("check_for_mod_updates", CommandTier::Read),
+    ("list_mods", CommandTier::Read),
+    ("search_installed_mods", CommandTier::Read),
+    ("get_mod_readme", CommandTier::Read),
+    ("get_mod_details", CommandTier::Read),
+    ("get_mod_stats", CommandTier::Read),
+    ("read_mod_image", CommandTier::Read),
+    ("get_cached_mod_images", CommandTier::Read),
+    ("list_skin_mods_from_registry", CommandTier::Read),
+    ("check_skin_conflicts", CommandTier::Read),
+    ("generate_conflict_report", CommandTier::Read),
+    ("get_pak_load_order", CommandTier::Read),
+    ("scan_unmanaged_natives", CommandTier::Read),
+    ("get_mod_dependents", CommandTier::Read),
+    ("get_required_launch_options", CommandTier::Read),
+    ("get_known_broken_list", CommandTier::Read),
+    ("identify_unknown_mods", CommandTier::Read),
+    ("list_deleted_mods", CommandTier::Read),
+    ("list_restore_points", CommandTier::Read),
+    ("suggest_cleanup", CommandTier::Read),
+    ("verify_mod_integrity", CommandTier::Read),
+    ("detect_game_update", CommandTier::Read),
+    ("list_profiles", CommandTier::Read),
+    ("list_config_backups", CommandTier::Read),
+    ("export_mod", CommandTier::Read),
+    ("export_setup_code", CommandTier::Read),
+    ("export_audit_ledger", CommandTier::Read),
+    ("get_app_settings", CommandTier::Read),
+    ("get_command_permissions", CommandTier::Read),
+    ("get_telemetry_summary", CommandTier::Read),
+    ("get_sandbox_status", CommandTier::Read),
+    ("save_game_config", CommandTier::ModifyMods),
+    ("apply_nexus_match", CommandTier::ModifyMods),
+    ("refresh_all_metadata", CommandTier::ModifyMods),
+    ("start_mod_watcher", CommandTier::ModifyMods),
+    ("stop_mod_watcher", CommandTier::ModifyMods),
+    ("set_nexus_api_key", CommandTier::ModifyMods),
+    ("begin_sso_login", CommandTier::ModifyMods),
+    ("cancel_sso_login", CommandTier::ModifyMods),
+    ("set_mod_rating", CommandTier::ModifyMods),
+    ("set_mod_tags", CommandTier::ModifyMods),
+    ("create_save_backup", CommandTier::ModifyMods),
+    ("list_save_backups", CommandTier::Read),
+    ("enable_with_save_backup", CommandTier::ModifyGameDir),
+    ("set_skin_mod_packing", CommandTier::ModifyMods),
+    ("cache_mod_image", CommandTier::ModifyMods),
+    ("import_thumbnails", CommandTier::ModifyMods),
+    ("rerun_post_install_hook", CommandTier::ModifyGameDir),
+    ("scan_and_update_skin_mods", CommandTier::ModifyMods),
+    ("add_external_skin_mod", CommandTier::ModifyMods),
+    ("adopt_natives_group", CommandTier::ModifyMods),
+    ("set_mod_dependencies", CommandTier::ModifyMods),
+    ("set_mod_launch_notes", CommandTier::ModifyMods),
+    ("update_known_broken_list", CommandTier::ModifyMods),
+    ("update_checksum_db", CommandTier::ModifyMods),
+    ("create_restore_point", CommandTier::ModifyMods),
+    ("set_restore_points_root", CommandTier::ModifyMods),
+    ("create_profile", CommandTier::ModifyMods),
+    ("create_config_backup", CommandTier::ModifyMods),
+    ("import_setup_code", CommandTier::ModifyMods),
+    ("import_legacy_modlist", CommandTier::ModifyMods),
+    ("set_feature_flag", CommandTier::ModifyMods),
+    ("submit_telemetry", CommandTier::ModifyMods),
+    ("clear_telemetry", CommandTier::ModifyMods),
+    ("open_mods_folder", CommandTier::ModifyGameDir),
+    ("ensure_reframework", CommandTier::ModifyGameDir),
+    ("install_mod_from_zip", CommandTier::ModifyGameDir),
+    ("install_mod_from_archive", CommandTier::ModifyGameDir),
+    ("resolve_preserved_config", CommandTier::ModifyGameDir),
+    ("install_from_nxm_link", CommandTier::ModifyGameDir),
+    ("toggle_mod_enabled_state", CommandTier::ModifyGameDir),
+    ("enable_skin_mod_via_registry", CommandTier::ModifyGameDir),
+    ("disable_skin_mod_via_registry", CommandTier::ModifyGameDir),
+    ("restore_deleted_mod", CommandTier::ModifyGameDir),
+    ("restore_original_files", CommandTier::ModifyGameDir),
+    ("switch_profile", CommandTier::ModifyGameDir),
+    ("install_sample_mod", CommandTier::ModifyGameDir),
+    ("remove_sample_mod", CommandTier::ModifyGameDir),
+    ("apply_desired_state", CommandTier::ModifyGameDir),
+    ("redeploy_all_enabled_mods", CommandTier::ModifyGameDir),
+    ("set_pak_load_order", CommandTier::ModifyGameDir),
+    ("record_game_exit", CommandTier::ModifyGameDir),
+    ("launch_game", CommandTier::ModifyGameDir),
+    ("enter_safe_mode", CommandTier::ModifyGameDir),
+    ("exit_safe_mode", CommandTier::ModifyGameDir),
+    ("relocate_game", CommandTier::ModifyGameDir),
+    ("set_staging_root", CommandTier::ModifyGameDir),
+    ("enable_sandbox", CommandTier::ModifyGameDir),
+    ("disable_sandbox", CommandTier::ModifyGameDir),
+    ("nuke_settings_and_relaunch", CommandTier::Destructive),
+    ("delete_reframework_mod", CommandTier::Destructive),
+    ("remove_reframework", CommandTier::Destructive),
+    ("delete_skin_mod", CommandTier::Destructive),
+    ("delete_mods", CommandTier::Destructive),
+    ("uninstall_mod", CommandTier::Destructive),
+    ("delete_restore_point", CommandTier::Destructive),
+    ("run_cleanup", CommandTier::Destructive),
+    ("dedupe_paks", CommandTier::Destructive),
+    ("delete_profile", CommandTier::Destructive),
+    ("restore_config_backup", CommandTier::Destructive),
+    ("restore_to_point", CommandTier::Destructive),
+];
+
+pub fn command_tier(command: &str) -> CommandTier {
+    COMMAND_TIERS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, tier)| *tier)
+        .unwrap_or(CommandTier::Destructive)
+}
+
+/// Whether this is a kiosk build, where the policy is fixed to read-only
+pub fn is_locked() -> bool {
+    cfg!(feature = "kiosk")
+}
+
+/// Tiers currently switched off
+pub fn disabled_tiers(settings: &AppSettings) -> Vec<CommandTier> {
+    if is_locked() {
+        return CommandTier::ALL
+            .iter()
+            .copied()
+            .filter(|t| *t != CommandTier::Read)
+            .collect();
+    }
+    let mut tiers = settings.disabled_command_tiers.clone();
+    tiers.sort();
+    tiers.dedup();
+    tiers
+}
+
+/// Check a command against the policy. Called from the invoke handler for every command.
+pub fn check_command_allowed(app_handle: &AppHandle, command: &str) -> Result<(), String> {
+    if POLICY_COMMANDS.contains(&command) {
+        return check_command_against(&AppSettings::default(), command);
+    }
+    // Settings that can't be read fall back to defaults inside load; only a missing config
+    // dir gets here, and refusing everything over it would leave the app unusable
+    let settings = match AppSettings::load(app_handle) {
+        Ok(settings) => settings,
+        Err(e) => {
+            warn!("Failed to load settings for permission check, using defaults: {}", e);
+            AppSettings::default()
+        }
+    };
+    check_command_against(&settings, command)
+}
+
+/// `check_command_allowed` with the settings already loaded
+pub(crate) fn check_command_against(settings: &AppSettings, command: &str) -> Result<(), String> {
+    if POLICY_COMMANDS.contains(&command) {
+        return if is_locked() {
+            Err(format!("[E_COMMAND_DISABLED] '{}' is not available in this build", command))
+        } else {
+            Ok(())
+        };
+    }
+    let tier = command_tier(command);
+    if disabled_tiers(settings).contains(&tier) {
+        info!("Refused '{}': {} commands are disabled", command, tier.label());
+        return Err(format!(
+            "[E_COMMAND_DISABLED] '{}' is blocked: {} operations are disabled{}",
+            command,
+            tier.label(),
+            if is_locked() { " in this build" } else { " in settings" }
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `command` may change anything on disk, and so has to hold the instance lock
+pub fn needs_write_access(command: &str) -> bool {
+    POLICY_COMMANDS.contains(&command) || command_tier(command) != CommandTier::Read
+}
+
+/// Wrap the generated command handler so every invoke is checked against the policy first.
+/// Commands that write are also refused up front in a second instance, before they touch
+/// the game directory or staging, rather than failing once the registry is saved.
+pub fn with_permission_check<F>(handler: F) -> impl Fn(Invoke<Wry>) -> bool + Send + Sync + 'static
+where
+    F: Fn(Invoke<Wry>) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        let command = invoke.message.command().to_string();
+        let app_handle = invoke.message.webview().app_handle().clone();
+        if let Err(e) = check_command_allowed(&app_handle, &command) {
+            invoke.resolver.reject(e);
+            return true;
+        }
+        if needs_write_access(&command) {
+            if let Err(e) = ensure_write_access(&app_handle) {
+                info!("Refused '{}': {}", command, e);
+                invoke.resolver.reject(e);
+                return true;
+            }
+        }
+        handler(invoke)
+    }
+}
+
+/// The permission policy as the frontend needs it to grey out unavailable actions
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommandPermissions {
+    pub locked: bool,
+    pub disabled_tiers: Vec<CommandTier>,
+    pub commands: BTreeMap<String, CommandTier>,
+}
+
+#[tauri::command]
+pub async fn get_command_permissions(app_handle: AppHandle) -> Result<CommandPermissions, String> {
+    let settings = AppSettings::load(&app_handle)?;
+    Ok(CommandPermissions {
+        locked: is_locked(),
+        disabled_tiers: disabled_tiers(&settings),
+        commands: COMMAND_TIERS
+            .iter()
+            .map(|(name, tier)| (name.to_string(), *tier))
+            .collect(),
+    })
+}
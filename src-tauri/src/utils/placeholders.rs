@@ -0,0 +1,155 @@
+// src-tauri/src/utils/placeholders.rs
+// Generated thumbnails for mods without any image: the mod's initials on a colour derived
+// from its name, so every view shows the same tile for the same mod.
+use image::{Rgb, RgbImage};
+use log::{debug, warn};
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+use crate::utils::cachethumbs::{get_image_cache_dir, get_image_cache_key};
+use crate::utils::hashing::StreamHasher;
+
+const PLACEHOLDER_SIZE: u32 = 256;
+/// Bump when the look changes so old tiles aren't reused
+const PLACEHOLDER_STYLE: u32 = 1;
+/// Pixel size of one font cell when drawing initials
+const GLYPH_SCALE: u32 = 14;
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+
+/// 5x7 bitmap glyphs, one byte per row with the low 5 bits used
+fn glyph(c: char) -> [u8; 7] {
+    match c {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04], // '?'
+    }
+}
+
+/// Up to two initials from the words of a name; names without ASCII letters or digits get "?"
+fn initials(name: &str) -> Vec<char> {
+    let mut letters: Vec<char> = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|word| word.chars().next())
+        .map(|c| c.to_ascii_uppercase())
+        .take(2)
+        .collect();
+    if letters.is_empty() {
+        letters.push('?');
+    }
+    letters
+}
+
+/// Background colour from the name's hash: varied hue, fixed saturation/lightness so white
+/// initials stay readable
+fn background_colour(name: &str) -> Rgb<u8> {
+    let mut hasher = StreamHasher::new();
+    hasher.update(name.as_bytes());
+    let digest = hasher.finish();
+    let hue = u32::from_str_radix(&digest[..4], 16).unwrap_or(0) % 360;
+
+    let (saturation, lightness) = (0.45_f32, 0.42_f32);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue as f32 / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |v: f32| ((v + m) * 255.0).round() as u8;
+    Rgb([channel(r), channel(g), channel(b)])
+}
+
+fn render_placeholder(name: &str) -> RgbImage {
+    let mut img = RgbImage::from_pixel(PLACEHOLDER_SIZE, PLACEHOLDER_SIZE, background_colour(name));
+    let letters = initials(name);
+    let spacing = GLYPH_SCALE;
+    let text_width =
+        letters.len() as u32 * GLYPH_WIDTH * GLYPH_SCALE + (letters.len() as u32 - 1) * spacing;
+    let origin_x = (PLACEHOLDER_SIZE - text_width) / 2;
+    let origin_y = (PLACEHOLDER_SIZE - GLYPH_HEIGHT * GLYPH_SCALE) / 2;
+    let white = Rgb([255, 255, 255]);
+
+    for (index, letter) in letters.iter().enumerate() {
+        let glyph_x = origin_x + index as u32 * (GLYPH_WIDTH * GLYPH_SCALE + spacing);
+        for (row, bits) in glyph(*letter).iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - col)) == 0 {
+                    continue;
+                }
+                let x0 = glyph_x + col * GLYPH_SCALE;
+                let y0 = origin_y + row as u32 * GLYPH_SCALE;
+                for y in y0..y0 + GLYPH_SCALE {
+                    for x in x0..x0 + GLYPH_SCALE {
+                        img.put_pixel(x, y, white);
+                    }
+                }
+            }
+        }
+    }
+    img
+}
+
+/// Cached placeholder thumbnail for a mod name, generated on first use
+pub fn placeholder_thumbnail(app_handle: &AppHandle, name: &str) -> Result<PathBuf, String> {
+    let cache_key = get_image_cache_key(&format!("placeholder:{}:{}", PLACEHOLDER_STYLE, name));
+    let path = get_image_cache_dir(app_handle)?.join(format!("{}.thumb.png", cache_key));
+    if path.exists() {
+        return Ok(path);
+    }
+    render_placeholder(name)
+        .save_with_format(&path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to write placeholder thumbnail {:?}: {}", path, e))?;
+    debug!("Generated placeholder thumbnail for '{}'", name);
+    Ok(path)
+}
+
+/// Fill in a placeholder for a missing thumbnail, leaving it empty if generation fails
+pub fn with_placeholder(app_handle: &AppHandle, thumbnail: Option<String>, name: &str) -> Option<String> {
+    thumbnail.or_else(|| match placeholder_thumbnail(app_handle, name) {
+        Ok(path) => Some(path.to_string_lossy().to_string()),
+        Err(e) => {
+            warn!("{}", e);
+            None
+        }
+    })
+}
@@ -0,0 +1,248 @@
+// src-tauri/src/utils/posthooks.rs
+// Post-install hooks for power users: a program or script run with the installed mod's folder
+// as its argument, e.g. to convert textures. Off unless `post_install_hooks_enabled` is set.
+// A hook that fails or times out is reported but never undoes the install.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+use crate::utils::modregistry::{resolve_reframework_mod_dir, ModRegistry};
+use crate::utils::settings::AppSettings;
+
+/// Output kept from a hook run; the tail is what explains a failure
+const MAX_HOOK_OUTPUT: usize = 4096;
+
+/// Result of one hook run, emitted as "post-install-hook"
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HookRun {
+    pub mod_name: String,
+    pub mod_dir: String,
+    pub hook: String,
+    pub success: bool,
+    pub exit_code: Option<i32>, // None when it was killed or couldn't start
+    pub timed_out: bool,
+    pub output: String, // Last few KB of stdout and stderr
+    pub duration_ms: u64,
+}
+
+/// The hook that applies to a mod: its own if configured, else the global one
+pub fn hook_for<'a>(settings: &'a AppSettings, mod_name: &str) -> Option<&'a str> {
+    if !settings.post_install_hooks_enabled {
+        return None;
+    }
+    settings
+        .mod_post_install_hooks
+        .get(mod_name)
+        .or(settings.post_install_hook.as_ref())
+        .map(|h| h.trim())
+        .filter(|h| !h.is_empty())
+}
+
+/// Whether saving `settings` over `current` lets installs run something they didn't before:
+/// hooks switched on, or a hook added or pointed elsewhere while they are on
+pub fn hooks_widened(settings: &AppSettings, current: &AppSettings) -> bool {
+    settings.post_install_hooks_enabled
+        && (!current.post_install_hooks_enabled
+            || settings.post_install_hook != current.post_install_hook
+            || settings
+                .mod_post_install_hooks
+                .iter()
+                .any(|(m, h)| current.mod_post_install_hooks.get(m) != Some(h)))
+}
+
+/// Hooks must be absolute paths to existing files; checked when settings are saved
+pub fn validate_hooks(settings: &AppSettings) -> Result<(), String> {
+    let global = settings.post_install_hook.iter().map(|h| ("all mods".to_string(), h));
+    let per_mod = settings.mod_post_install_hooks.iter().map(|(m, h)| (format!("'{}'", m), h));
+    for (label, hook) in global.chain(per_mod) {
+        let hook = hook.trim();
+        if hook.is_empty() {
+            continue;
+        }
+        let path = Path::new(hook);
+        if !path.is_absolute() || !path.is_file() {
+            return Err(format!(
+                "Post-install hook for {} must be the full path to an existing program or script: {}",
+                label, hook
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The command that runs `hook`; PowerShell scripts can't be started directly
+fn hook_command(hook: &Path) -> Command {
+    let is_ps1 = hook.extension().is_some_and(|e| e.eq_ignore_ascii_case("ps1"));
+    if is_ps1 {
+        let mut command = Command::new("powershell");
+        command.args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-File"]).arg(hook);
+        command
+    } else {
+        Command::new(hook)
+    }
+}
+
+fn read_tail(mut pipe: impl Read + Send + 'static) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        let start = buffer.len().saturating_sub(MAX_HOOK_OUTPUT);
+        buffer.split_off(start)
+    })
+}
+
+/// Run `hook` on `mod_dir`, killing it after `timeout`. The mod folder is passed as the only
+/// argument and as FMM_MOD_PATH, the mod's name as FMM_MOD_NAME.
+pub fn run_hook(hook: &str, mod_name: &str, mod_dir: &Path, timeout: Duration) -> HookRun {
+    let started = Instant::now();
+    let mut run = HookRun {
+        mod_name: mod_name.to_string(),
+        mod_dir: mod_dir.to_string_lossy().to_string(),
+        hook: hook.to_string(),
+        success: false,
+        exit_code: None,
+        timed_out: false,
+        output: String::new(),
+        duration_ms: 0,
+    };
+    let spawned = hook_command(Path::new(hook))
+        .arg(mod_dir)
+        .current_dir(mod_dir)
+        .env("FMM_MOD_NAME", mod_name)
+        .env("FMM_MOD_PATH", mod_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            run.output = format!("Failed to start post-install hook: {}", e);
+            return run;
+        }
+    };
+    // Drain both pipes so a chatty hook can't block on a full buffer
+    let stdout = child.stdout.take().map(read_tail);
+    let stderr = child.stderr.take().map(read_tail);
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                run.timed_out = true;
+                break None;
+            }
+            Ok(None) => std::thread::sleep(Duration::from_millis(100)),
+            Err(e) => {
+                warn!("Failed to wait for post-install hook {}: {}", hook, e);
+                break None;
+            }
+        }
+    };
+
+    if run.timed_out {
+        // Anything the hook started may still hold the pipes open; leave the readers be
+        run.output = format!("Killed after {} s", timeout.as_secs_f32());
+    } else {
+        let mut output = Vec::new();
+        for reader in [stdout, stderr].into_iter().flatten() {
+            output.extend(reader.join().unwrap_or_default());
+        }
+        run.output = String::from_utf8_lossy(&output).trim().to_string();
+    }
+    run.exit_code = status.and_then(|s| s.code());
+    run.success = status.is_some_and(|s| s.success());
+    run.duration_ms = started.elapsed().as_millis() as u64;
+    run
+}
+
+/// Run the hook configured for a freshly installed mod, if any, and report it to the UI.
+/// Blocking; callers on the async runtime should use spawn_blocking.
+pub fn run_post_install_hook(app_handle: &AppHandle, settings: &AppSettings, mod_name: &str, mod_dir: &Path) {
+    let Some(hook) = hook_for(settings, mod_name) else {
+        return;
+    };
+    info!("Running post-install hook {} for '{}'", hook, mod_name);
+    let timeout = Duration::from_secs(u64::from(settings.post_install_hook_timeout_secs.max(1)));
+    let run = run_hook(hook, mod_name, mod_dir, timeout);
+    if run.success {
+        info!("Post-install hook for '{}' finished in {} ms", mod_name, run.duration_ms);
+    } else if run.timed_out {
+        warn!("Post-install hook for '{}' timed out after {:?}", mod_name, timeout);
+    } else {
+        warn!(
+            "Post-install hook for '{}' failed (exit code {:?}): {}",
+            mod_name, run.exit_code, run.output
+        );
+    }
+    if let Err(e) = app_handle.emit("post-install-hook", &run) {
+        warn!("Failed to emit post-install hook result: {}", e);
+    }
+}
+
+/// Run a mod's post-install hook again by hand, e.g. after changing the script
+#[tauri::command]
+pub async fn rerun_post_install_hook(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_name: String,
+) -> Result<HookRun, String> {
+    let settings = AppSettings::load(&app_handle)?;
+    if !settings.post_install_hooks_enabled {
+        return Err("Post-install hooks are disabled in settings".to_string());
+    }
+    let hook = hook_for(&settings, &mod_name)
+        .ok_or_else(|| format!("No post-install hook is configured for '{}'", mod_name))?
+        .to_string();
+    let registry = ModRegistry::load(&app_handle)?;
+    let game_root = PathBuf::from(&game_root_path);
+    let mod_dir = if let Some(m) = registry.find_mod(&mod_name) {
+        resolve_reframework_mod_dir(&game_root, m)
+            .ok_or_else(|| format!("Folder of mod '{}' not found", mod_name))?
+    } else if let Some(sm) = registry.find_skin_mod(&mod_name) {
+        PathBuf::from(&sm.base.path)
+    } else {
+        return Err(format!("Mod '{}' not found in registry", mod_name));
+    };
+    let timeout = Duration::from_secs(u64::from(settings.post_install_hook_timeout_secs.max(1)));
+    tauri::async_runtime::spawn_blocking(move || run_hook(&hook, &mod_name, &mod_dir, timeout))
+        .await
+        .map_err(|e| format!("Post-install hook task failed: {}", e))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use std::fs;
+
+    #[test]
+    fn post_install_hook_gets_the_mod_folder_and_is_killed_on_timeout() {
+        use std::os::unix::fs::PermissionsExt;
+        let game = FakeGame::new();
+        let mod_dir = game.mods_dir().join("HookedSkin");
+        fs::create_dir_all(&mod_dir).unwrap();
+        let write_script = |name: &str, body: &str| {
+            let script = game.root().join(name);
+            fs::write(&script, format!("#!/bin/sh\n{}\n", body)).unwrap();
+            fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+            script.to_string_lossy().to_string()
+        };
+
+        let convert = write_script("convert.sh", "echo \"$FMM_MOD_NAME\"; touch \"$1/converted\"");
+        let run = run_hook(&convert, "HookedSkin", &mod_dir, std::time::Duration::from_secs(10));
+        assert!(run.success, "{:?}", run);
+        assert_eq!(run.output, "HookedSkin");
+        assert!(mod_dir.join("converted").is_file());
+
+        let stuck = write_script("stuck.sh", "sleep 30");
+        let run = run_hook(&stuck, "HookedSkin", &mod_dir, std::time::Duration::from_millis(300));
+        assert!(run.timed_out && !run.success);
+    }
+}
@@ -0,0 +1,298 @@
+// src-tauri/src/utils/prelaunch.rs
+// Checklist the UI runs before launching the game. Each check passes, warns (launch is fine
+// but something looks off) or blocks (launching now would crash or lose the mod setup).
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::config::load_game_config;
+use crate::utils::conflicts::find_skin_conflicts;
+use crate::utils::knownbroken::{find_known_broken, load_known_broken, KnownBrokenSeverity};
+use crate::utils::launchargs::has_pending_launch_actions;
+use crate::utils::modregistry::{ModRegistry, ModType};
+use crate::utils::reframework::{check_reframework, ReframeworkStatus};
+use crate::utils::settings::AppSettings;
+
+/// Ordered so the overall result is simply the worst check
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PreLaunchStatus {
+    Pass,
+    Warn,
+    Block,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PreLaunchItem {
+    pub check: String, // Stable identifier the frontend can key help text on
+    pub status: PreLaunchStatus,
+    pub message: String,
+    pub details: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PreLaunchReport {
+    pub status: PreLaunchStatus,
+    pub items: Vec<PreLaunchItem>,
+}
+
+fn item(check: &str, status: PreLaunchStatus, message: String, details: Vec<String>) -> PreLaunchItem {
+    PreLaunchItem {
+        check: check.to_string(),
+        status,
+        message,
+        details,
+    }
+}
+
+/// REFramework installed and intact, and (when `latest_tag` is known) on the newest build
+fn check_framework(
+    app_handle: &AppHandle,
+    game_root: &Path,
+    registry: &ModRegistry,
+    latest_tag: Option<&str>,
+) -> PreLaunchItem {
+    let report = check_reframework(app_handle, game_root);
+    let needs_framework = registry.mods.iter().any(|m| {
+        m.enabled && matches!(m.mod_type, ModType::REFrameworkPlugin | ModType::REFrameworkAutorun)
+    });
+    let mut details = report.missing_files.clone();
+    details.extend(report.modified_files.iter().cloned());
+    match report.status {
+        ReframeworkStatus::NotInstalled if needs_framework => item(
+            "reframework",
+            PreLaunchStatus::Block,
+            "REFramework isn't installed, so the enabled REFramework mods won't load.".to_string(),
+            details,
+        ),
+        ReframeworkStatus::NotInstalled => item(
+            "reframework",
+            PreLaunchStatus::Pass,
+            "REFramework isn't installed and no enabled mod needs it.".to_string(),
+            details,
+        ),
+        ReframeworkStatus::Dinput8Missing | ReframeworkStatus::IncompleteInstall => {
+            item("reframework", PreLaunchStatus::Block, report.message, details)
+        }
+        ReframeworkStatus::Modified => item("reframework", PreLaunchStatus::Warn, report.message, details),
+        ReframeworkStatus::Unverified | ReframeworkStatus::Ok => match (latest_tag, &report.release_tag) {
+            (Some(latest), Some(installed)) if latest != installed => item(
+                "reframework",
+                PreLaunchStatus::Warn,
+                format!(
+                    "REFramework {} is installed but {} is available; after a game update an older build can crash on start.",
+                    installed, latest
+                ),
+                details,
+            ),
+            _ => item("reframework", PreLaunchStatus::Pass, report.message, details),
+        },
+    }
+}
+
+/// Enable states in the registry that the game folder doesn't reflect yet
+fn check_pending_deploys(game_root: &Path, registry: &ModRegistry, launch_actions_pending: bool) -> PreLaunchItem {
+    let mut details = Vec::new();
+    for m in &registry.mods {
+        let enabled_dir = game_root.join(&m.installed_directory);
+        let disabled_dir = game_root.join(format!("{}.disabled", m.installed_directory));
+        if m.enabled && !enabled_dir.is_dir() && disabled_dir.is_dir() {
+            details.push(format!("{}: enabled but its folder is still disabled", m.name));
+        } else if !m.enabled && enabled_dir.is_dir() {
+            details.push(format!("{}: disabled but its folder is still active", m.name));
+        }
+    }
+    for sm in registry.skin_mods.iter().filter(|sm| sm.base.enabled) {
+        let missing = sm
+            .installed_files
+            .iter()
+            .filter(|f| !Path::new(&f.path).is_file())
+            .count();
+        if sm.installed_files.is_empty() || missing > 0 {
+            details.push(format!(
+                "{}: enabled but {} of its files aren't deployed",
+                sm.base.name,
+                if sm.installed_files.is_empty() { "all".to_string() } else { missing.to_string() }
+            ));
+        }
+    }
+    if launch_actions_pending {
+        details.push("Downloads or installs passed to the manager haven't been processed yet".to_string());
+    }
+
+    if details.is_empty() {
+        item("pending_deploys", PreLaunchStatus::Pass, "Every enabled mod is deployed.".to_string(), details)
+    } else {
+        item(
+            "pending_deploys",
+            PreLaunchStatus::Warn,
+            format!("{} mod change(s) haven't reached the game folder.", details.len()),
+            details,
+        )
+    }
+}
+
+fn check_conflicts(game_root: &Path, registry: &ModRegistry, settings: &AppSettings) -> PreLaunchItem {
+    match find_skin_conflicts(registry, game_root, None, settings) {
+        Ok(conflicts) if conflicts.is_empty() => item(
+            "conflicts",
+            PreLaunchStatus::Pass,
+            "No enabled skin mods overlap.".to_string(),
+            Vec::new(),
+        ),
+        Ok(conflicts) => item(
+            "conflicts",
+            PreLaunchStatus::Warn,
+            format!("{} file(s) are provided by more than one enabled skin mod.", conflicts.len()),
+            conflicts
+                .iter()
+                .map(|c| format!("{}: {} overrides {}", c.file, c.winning_mod_name, c.losing_mod_name))
+                .collect(),
+        ),
+        Err(e) => item(
+            "conflicts",
+            PreLaunchStatus::Warn,
+            format!("Couldn't check for conflicts: {}", e),
+            Vec::new(),
+        ),
+    }
+}
+
+fn check_known_broken(app_handle: &AppHandle, registry: &ModRegistry) -> PreLaunchItem {
+    let list = load_known_broken(app_handle);
+    let mut status = PreLaunchStatus::Pass;
+    let mut details = Vec::new();
+    let enabled = registry
+        .mods
+        .iter()
+        .filter(|m| m.enabled)
+        .chain(registry.skin_mods.iter().map(|sm| &sm.base).filter(|m| m.enabled));
+    for m in enabled {
+        if let Some(entry) = find_known_broken(&list, m) {
+            status = status.max(match entry.severity {
+                KnownBrokenSeverity::Block => PreLaunchStatus::Block,
+                KnownBrokenSeverity::Warn => PreLaunchStatus::Warn,
+            });
+            details.push(format!("{}: {}", m.name, entry.reason));
+        }
+    }
+    let message = if details.is_empty() {
+        "No enabled mod is on the known-broken list.".to_string()
+    } else {
+        format!("{} enabled mod(s) are on the known-broken list.", details.len())
+    };
+    item("known_broken", status, message, details)
+}
+
+/// Whether a process with this executable name is running. On Linux this also catches the
+/// game under Wine/Proton, whose command line carries the Windows executable name.
+#[cfg(target_os = "linux")]
+pub(crate) fn is_process_running(exe_name: &str) -> bool {
+    let needle = exe_name.to_lowercase();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return false;
+    };
+    entries.filter_map(Result::ok).any(|entry| {
+        if !entry.file_name().to_string_lossy().chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+        std::fs::read(entry.path().join("cmdline"))
+            .map(|cmdline| {
+                String::from_utf8_lossy(&cmdline)
+                    .split('\0')
+                    .take(2)
+                    .any(|arg| arg.replace('\\', "/").to_lowercase().ends_with(&needle))
+            })
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(windows)]
+pub(crate) fn is_process_running(exe_name: &str) -> bool {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("IMAGENAME eq {}", exe_name), "/NH"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).to_lowercase().contains(&exe_name.to_lowercase()))
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+pub(crate) fn is_process_running(_exe_name: &str) -> bool {
+    false
+}
+
+fn check_game_running(executable: Option<&Path>) -> PreLaunchItem {
+    let Some(exe_name) = executable.and_then(|e| e.file_name()).map(|n| n.to_string_lossy().to_string()) else {
+        return item(
+            "game_running",
+            PreLaunchStatus::Warn,
+            "No game executable is configured, so a running game can't be detected.".to_string(),
+            Vec::new(),
+        );
+    };
+    if is_process_running(&exe_name) {
+        item(
+            "game_running",
+            PreLaunchStatus::Block,
+            format!("{} is already running.", exe_name),
+            Vec::new(),
+        )
+    } else {
+        item("game_running", PreLaunchStatus::Pass, "The game isn't running.".to_string(), Vec::new())
+    }
+}
+
+/// Everything to verify before launching the game. `check_for_updates` (default true) also
+/// asks GitHub whether a newer REFramework build exists; without network that part is skipped.
+#[tauri::command]
+pub async fn pre_launch_check(
+    app_handle: AppHandle,
+    game_root_path: String,
+    check_for_updates: Option<bool>,
+) -> Result<PreLaunchReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let settings = AppSettings::load(&app_handle)?;
+    let executable = load_game_config(app_handle.clone())
+        .await
+        .unwrap_or(None)
+        .map(|g| PathBuf::from(g.game_executable_path));
+
+    let latest_tag = if check_for_updates.unwrap_or(true) {
+        let (owner, repo) = crate::REFRAMEWORK_REPO;
+        match crate::fetch_latest_release(&crate::GitHubClient::new(&app_handle), owner, repo).await {
+            Ok(release) => Some(release.tag_name),
+            Err(e) => {
+                warn!("Skipping REFramework update check: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let launch_actions_pending = has_pending_launch_actions(&app_handle)?;
+    let items = vec![
+        check_game_running(executable.as_deref()),
+        check_framework(&app_handle, &game_root, &registry, latest_tag.as_deref()),
+        check_pending_deploys(&game_root, &registry, launch_actions_pending),
+        check_conflicts(&game_root, &registry, &settings),
+        check_known_broken(&app_handle, &registry),
+    ];
+    let status = items.iter().map(|i| i.status).max().unwrap_or(PreLaunchStatus::Pass);
+    info!(
+        "Pre-launch check: {:?} ({})",
+        status,
+        items
+            .iter()
+            .filter(|i| i.status != PreLaunchStatus::Pass)
+            .map(|i| i.check.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok(PreLaunchReport { status, items })
+}
@@ -0,0 +1,560 @@
+// src-tauri/src/utils/profiles.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::modregistry::{
+    disable_skin_mod_files, enable_skin_mod_files, set_reframework_mod_enabled, ModRegistry,
+};
+use crate::utils::restorepoints::create_restore_point_for;
+use crate::utils::settings::AppSettings;
+use crate::utils::telemetry::track_result;
+use crate::utils::tempermission::ModOperationGuard;
+
+const PROFILES_FILE: &str = "profiles.json";
+
+/// A named set of enable states, e.g. "vanilla+QoL" or "full overhaul"
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub created_timestamp: i64,
+    pub mods: BTreeMap<String, bool>,  // REFramework mod `Mod::key` -> enabled
+    pub skins: BTreeMap<String, bool>, // Skin mod `Mod::key` -> enabled
+}
+
+impl Profile {
+    /// Key entries by `Mod::key`. Profiles from before ids used directory names, and skins
+    /// before that their source path, which stops matching once the game or staging folder
+    /// moves; its last component is the name. Entries matching no mod are kept as they are.
+    /// Returns how many keys changed.
+    pub(crate) fn rekey_by_mod_id(&mut self, registry: &ModRegistry) -> usize {
+        let mut changed = 0;
+        let mut rekey = |map: &mut BTreeMap<String, bool>, find: &dyn Fn(&str) -> Option<String>| {
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(key, enabled)| match find(&key) {
+                    Some(new_key) if new_key != key => {
+                        changed += 1;
+                        (new_key, enabled)
+                    }
+                    _ => (key, enabled),
+                })
+                .collect();
+        };
+        rekey(&mut self.mods, &|key| registry.find_mod(key).map(|m| m.key().to_string()));
+        rekey(&mut self.skins, &|key| {
+            let name = key.trim_end_matches(['/', '\\']).rsplit(['/', '\\']).next().unwrap_or_default();
+            registry
+                .skin_mods
+                .iter()
+                .find(|sm| sm.base.path == key)
+                .or_else(|| registry.find_skin_mod(key))
+                .or_else(|| registry.find_skin_mod(name))
+                .map(|sm| sm.base.key().to_string())
+        });
+        changed
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub created_timestamp: i64,
+    pub enabled_count: usize,
+    pub mod_count: usize,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileSwitchReport {
+    pub profile: String,
+    pub enabled: Vec<String>,
+    pub disabled: Vec<String>,
+    pub unlisted: Vec<String>, // Installed after the profile was saved; left as they were
+    pub missing: Vec<String>,  // In the profile but no longer installed
+}
+
+/// Outcome of `apply_desired_state`
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DesiredStateReport {
+    pub dry_run: bool,
+    pub enabled: Vec<String>,
+    pub disabled: Vec<String>,
+    pub unchanged: usize,      // Listed mods that were already in the wanted state
+    pub unlisted: Vec<String>, // Installed mods the mapping doesn't mention; left as they were
+    pub missing: Vec<String>,  // Named in the mapping but not installed
+}
+
+/// Saved profiles, stored next to the mod registry (so the sandbox gets its own)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ProfileManager {
+    pub profiles: Vec<Profile>,
+    pub active_profile: Option<String>,
+    #[serde(default)]
+    pub keyed_by_id: bool, // False for files saved before profiles were keyed by mod id
+}
+
+impl ProfileManager {
+    fn get_profiles_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+        Ok(ModRegistry::get_registry_path(app_handle)?.with_file_name(PROFILES_FILE))
+    }
+
+    pub fn load(app_handle: &AppHandle) -> Result<Self, String> {
+        let path = Self::get_profiles_path(app_handle)?;
+        if !path.exists() {
+            return Ok(Self {
+                keyed_by_id: true,
+                ..Self::default()
+            });
+        }
+        let content =
+            fs::read_to_string(&path).map_err(|e| format!("Failed to read profiles: {}", e))?;
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse profiles: {}", e))
+    }
+
+    pub fn save(&self, app_handle: &AppHandle) -> Result<(), String> {
+        ensure_write_access(app_handle)?;
+        let path = Self::get_profiles_path(app_handle)?;
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize profiles: {}", e))?;
+        fs::write(&path, content).map_err(|e| format!("Failed to write profiles: {}", e))
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Re-key profiles saved before they used mod ids. Run once at startup by the instance
+/// holding the lock; returns how many entries changed.
+pub fn migrate_profile_keys(app_handle: &AppHandle) -> Result<usize, String> {
+    let mut manager = ProfileManager::load(app_handle)?;
+    if manager.keyed_by_id {
+        return Ok(0);
+    }
+    let registry = ModRegistry::load(app_handle)?;
+    let changed = manager
+        .profiles
+        .iter_mut()
+        .map(|profile| profile.rekey_by_mod_id(&registry))
+        .sum();
+    manager.keyed_by_id = true;
+    manager.save(app_handle)?;
+    Ok(changed)
+}
+
+fn capture_profile(name: &str, registry: &ModRegistry) -> Profile {
+    Profile {
+        name: name.to_string(),
+        created_timestamp: chrono::Utc::now().timestamp(),
+        mods: registry
+            .mods
+            .iter()
+            .map(|m| (m.key().to_string(), m.enabled))
+            .collect(),
+        skins: registry
+            .skin_mods
+            .iter()
+            .map(|sm| (sm.base.key().to_string(), sm.base.enabled))
+            .collect(),
+    }
+}
+
+fn summarize(profile: &Profile, active: Option<&str>) -> ProfileSummary {
+    ProfileSummary {
+        name: profile.name.clone(),
+        created_timestamp: profile.created_timestamp,
+        enabled_count: profile.mods.values().chain(profile.skins.values()).filter(|e| **e).count(),
+        mod_count: profile.mods.len() + profile.skins.len(),
+        active: active.is_some_and(|a| a.eq_ignore_ascii_case(&profile.name)),
+    }
+}
+
+/// One enable-state change made while switching, kept so it can be undone
+enum AppliedChange {
+    Mod(String, bool),
+    Skin(String, bool),
+}
+
+fn apply_change(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+    change: &AppliedChange,
+) -> Result<(), String> {
+    match change {
+        AppliedChange::Mod(name, enable) => set_reframework_mod_enabled(registry, game_root, name, *enable),
+        AppliedChange::Skin(path, true) => {
+            enable_skin_mod_files(registry, game_root, path, settings, &HashMap::new(), &|_, _| {})
+        }
+        AppliedChange::Skin(path, false) => {
+            let errors = disable_skin_mod_files(registry, game_root, path)?;
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors.join("; "))
+            }
+        }
+    }
+}
+
+/// The changes that bring the registry to a profile's states, and the report describing them
+fn plan_profile(registry: &ModRegistry, profile: &Profile) -> (Vec<AppliedChange>, ProfileSwitchReport) {
+    let mut report = ProfileSwitchReport {
+        profile: profile.name.clone(),
+        ..Default::default()
+    };
+
+    // Skins are disabled before any are enabled so freed patch numbers and natives get reused
+    let mut changes = Vec::new();
+    for m in &registry.mods {
+        match m.keyed_value(&profile.mods) {
+            Some(&wanted) if wanted != m.enabled => {
+                changes.push(AppliedChange::Mod(m.directory_name.clone(), wanted))
+            }
+            Some(_) => {}
+            None => report.unlisted.push(m.name.clone()),
+        }
+    }
+    for wanted_state in [false, true] {
+        for sm in &registry.skin_mods {
+            if sm.base.keyed_value(&profile.skins) == Some(&wanted_state) && sm.base.enabled != wanted_state {
+                changes.push(AppliedChange::Skin(sm.base.path.clone(), wanted_state));
+            }
+        }
+    }
+    report.unlisted.extend(
+        registry
+            .skin_mods
+            .iter()
+            .filter(|sm| sm.base.keyed_value(&profile.skins).is_none())
+            .map(|sm| sm.base.name.clone()),
+    );
+    report.missing = profile
+        .mods
+        .keys()
+        .filter(|name| registry.find_mod(name).is_none())
+        .chain(
+            profile
+                .skins
+                .keys()
+                .filter(|name| registry.find_skin_mod(name).is_none()),
+        )
+        .cloned()
+        .collect();
+
+    for change in &changes {
+        let (id, enable) = match change {
+            AppliedChange::Mod(name, enable) => (name.clone(), *enable),
+            AppliedChange::Skin(path, enable) => (
+                registry
+                    .skin_mods
+                    .iter()
+                    .find(|sm| &sm.base.path == path)
+                    .map(|sm| sm.base.name.clone())
+                    .unwrap_or_else(|| path.clone()),
+                *enable,
+            ),
+        };
+        if enable {
+            report.enabled.push(id);
+        } else {
+            report.disabled.push(id);
+        }
+    }
+    (changes, report)
+}
+
+/// Bring the registry and game folder to a profile's states. Any failure undoes the changes
+/// already made, so the game is never left half-switched.
+fn apply_profile(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+    profile: &Profile,
+) -> Result<ProfileSwitchReport, String> {
+    let (changes, report) = plan_profile(registry, profile);
+    let mut applied: Vec<&AppliedChange> = Vec::new();
+    for change in &changes {
+        if let Err(e) = apply_change(registry, game_root, settings, change) {
+            warn!("Profile switch to '{}' failed, rolling back {} change(s): {}", profile.name, applied.len(), e);
+            for done in applied.iter().rev() {
+                let undo = match done {
+                    AppliedChange::Mod(name, enable) => AppliedChange::Mod(name.clone(), !enable),
+                    AppliedChange::Skin(path, enable) => AppliedChange::Skin(path.clone(), !enable),
+                };
+                if let Err(undo_err) = apply_change(registry, game_root, settings, &undo) {
+                    warn!("Failed to roll back profile change: {}", undo_err);
+                }
+            }
+            return Err(format!("Failed to switch to profile '{}': {}", profile.name, e));
+        }
+        applied.push(change);
+    }
+    Ok(report)
+}
+
+/// A desired state as a profile. Mods may be named by id, directory name or skin path;
+/// names that match nothing are kept so they show up as missing.
+pub(crate) fn desired_state_profile(registry: &ModRegistry, desired: &BTreeMap<String, bool>) -> Profile {
+    let mut profile = Profile {
+        name: "desired state".to_string(),
+        created_timestamp: chrono::Utc::now().timestamp(),
+        mods: BTreeMap::new(),
+        skins: BTreeMap::new(),
+    };
+    for (identifier, &enabled) in desired {
+        if let Some(m) = registry.find_mod(identifier) {
+            profile.mods.insert(m.key().to_string(), enabled);
+        } else if let Some(skin) = registry.find_any_mod(identifier) {
+            profile.skins.insert(skin.key().to_string(), enabled);
+        } else {
+            profile.mods.insert(identifier.clone(), enabled);
+        }
+    }
+    profile
+}
+
+/// Bring mods to the given enabled states, touching only the ones that differ. Returns what
+/// changed (or would, with `dry_run`); applying the same state again changes nothing.
+pub(crate) fn apply_desired_state_to(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+    desired: &BTreeMap<String, bool>,
+    dry_run: bool,
+) -> Result<DesiredStateReport, String> {
+    let profile = desired_state_profile(registry, desired);
+    let changes = if dry_run {
+        plan_profile(registry, &profile).1
+    } else {
+        apply_profile(registry, game_root, settings, &profile)?
+    };
+    Ok(DesiredStateReport {
+        dry_run,
+        unchanged: desired
+            .len()
+            .saturating_sub(changes.enabled.len() + changes.disabled.len() + changes.missing.len()),
+        enabled: changes.enabled,
+        disabled: changes.disabled,
+        unlisted: changes.unlisted,
+        missing: changes.missing,
+    })
+}
+
+#[tauri::command]
+pub async fn list_profiles(app_handle: AppHandle) -> Result<Vec<ProfileSummary>, String> {
+    let manager = ProfileManager::load(&app_handle)?;
+    Ok(manager
+        .profiles
+        .iter()
+        .map(|p| summarize(p, manager.active_profile.as_deref()))
+        .collect())
+}
+
+/// Save the current enable states as a profile. `overwrite` replaces an existing one.
+#[tauri::command]
+pub async fn create_profile(
+    app_handle: AppHandle,
+    name: String,
+    overwrite: Option<bool>,
+) -> Result<ProfileSummary, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Profile name can't be empty".to_string());
+    }
+    let mut manager = ProfileManager::load(&app_handle)?;
+    if manager.find(&name).is_some() {
+        if !overwrite.unwrap_or(false) {
+            return Err(format!("A profile named '{}' already exists", name));
+        }
+        manager.profiles.retain(|p| !p.name.eq_ignore_ascii_case(&name));
+    }
+
+    let registry = ModRegistry::load(&app_handle)?;
+    let profile = capture_profile(&name, &registry);
+    manager.profiles.push(profile.clone());
+    manager.active_profile = Some(name.clone());
+    manager.save(&app_handle)?;
+    info!("Saved profile '{}' ({} mods)", name, profile.mods.len() + profile.skins.len());
+    Ok(summarize(&profile, manager.active_profile.as_deref()))
+}
+
+/// Enable and disable mods to match a profile. A restore point is taken first.
+#[tauri::command]
+pub async fn switch_profile(
+    app_handle: AppHandle,
+    game_root_path: String,
+    name: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<ProfileSwitchReport>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let mut manager = ProfileManager::load(&app_handle)?;
+    let profile = manager
+        .find(&name)
+        .cloned()
+        .ok_or_else(|| format!("Profile '{}' not found", name))?;
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let disabled = plan_profile(&registry, &profile).1.disabled;
+    if !disabled.is_empty() {
+        if let Some(challenge) = check_confirmation(
+            &app_handle,
+            "switch_profile",
+            &disabled,
+            &format!("Switch to profile '{}', disabling {} mod(s)", profile.name, disabled.len()),
+            confirmation_token.as_deref(),
+        )? {
+            return Ok(Confirmable::ConfirmationRequired(challenge));
+        }
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+
+    if let Err(e) = create_restore_point_for(
+        &app_handle,
+        &game_root,
+        &format!("Before switching to profile '{}'", profile.name),
+    ) {
+        warn!("Failed to create restore point before profile switch: {}", e);
+    }
+
+    let settings = AppSettings::load(&app_handle)?;
+    let result = apply_profile(&mut registry, &game_root, &settings, &profile);
+    // Save either way: a rollback may itself have left something different
+    registry.save(&app_handle)?;
+    let report = result?;
+
+    manager.active_profile = Some(profile.name.clone());
+    manager.save(&app_handle)?;
+    if let Err(e) = app_handle.emit("profile-switched", &report) {
+        warn!("Failed to emit profile-switched: {}", e);
+    }
+    info!(
+        "Switched to profile '{}': {} enabled, {} disabled, {} unlisted, {} missing",
+        profile.name,
+        report.enabled.len(),
+        report.disabled.len(),
+        report.unlisted.len(),
+        report.missing.len()
+    );
+    Ok(Confirmable::Done(report))
+}
+
+#[tauri::command]
+pub async fn delete_profile(
+    app_handle: AppHandle,
+    name: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "delete_profile",
+        std::slice::from_ref(&name),
+        &format!("Delete profile '{}'", name),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let mut manager = ProfileManager::load(&app_handle)?;
+    let before = manager.profiles.len();
+    manager.profiles.retain(|p| !p.name.eq_ignore_ascii_case(&name));
+    if manager.profiles.len() == before {
+        return Err(format!("Profile '{}' not found", name));
+    }
+    if manager
+        .active_profile
+        .as_deref()
+        .is_some_and(|a| a.eq_ignore_ascii_case(&name))
+    {
+        manager.active_profile = None;
+    }
+    manager.save(&app_handle)?;
+    info!("Deleted profile '{}'", name);
+    Ok(Confirmable::Done(()))
+}
+
+/// Set mods to a full mapping of identifier -> enabled in one call. Only mods whose state
+/// differs are touched, and a failure rolls back what was already changed.
+#[tauri::command]
+pub async fn apply_desired_state(
+    app_handle: AppHandle,
+    game_root_path: String,
+    desired: BTreeMap<String, bool>,
+    dry_run: Option<bool>,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<DesiredStateReport>, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let dry_run = dry_run.unwrap_or(false);
+    let settings = AppSettings::load(&app_handle)?;
+    let mut registry = ModRegistry::load(&app_handle)?;
+    if !dry_run {
+        ensure_write_access(&app_handle)?;
+        let profile = desired_state_profile(&registry, &desired);
+        let disabled = plan_profile(&registry, &profile).1.disabled;
+        if !disabled.is_empty() {
+            if let Some(challenge) = check_confirmation(
+                &app_handle,
+                "apply_desired_state",
+                &disabled,
+                &format!("Disable {} mod(s)", disabled.len()),
+                confirmation_token.as_deref(),
+            )? {
+                return Ok(Confirmable::ConfirmationRequired(challenge));
+            }
+        }
+    }
+    let _in_flight = (!dry_run).then(|| ModOperationGuard::begin(&app_handle));
+    let result = apply_desired_state_to(&mut registry, &game_root, &settings, &desired, dry_run);
+    if !dry_run {
+        // Save either way: a rollback may itself have left something different
+        registry.save(&app_handle)?;
+    }
+    let report = track_result(&app_handle, "apply_desired_state", result)?;
+    info!(
+        "{} desired state: {} enabled, {} disabled, {} unchanged, {} missing",
+        if dry_run { "Planned" } else { "Applied" },
+        report.enabled.len(),
+        report.disabled.len(),
+        report.unchanged,
+        report.missing.len()
+    );
+    Ok(Confirmable::Done(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn profiles_keep_matching_skins_after_the_game_moves() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        game.install_skin(&mut registry, "FirstSkin", b"first");
+        game.install_skin(&mut registry, "SecondSkin", b"second");
+        let mut profile = Profile {
+            name: "old".to_string(),
+            created_timestamp: 0,
+            mods: Default::default(),
+            skins: [
+                ("/old/library/MonsterHunterWilds/fossmodmanager/mods/FirstSkin".to_string(), true),
+                ("D:\\Games\\MHWilds\\fossmodmanager\\mods\\SecondSkin\\".to_string(), false),
+                ("ThirdSkin".to_string(), true),
+            ]
+            .into(),
+        };
+        assert_eq!(profile.rekey_by_mod_id(&registry), 2);
+        assert_eq!(profile.rekey_by_mod_id(&registry), 0);
+        let first_id = &registry.find_skin_mod("FirstSkin").unwrap().base.id;
+        let second_id = &registry.find_skin_mod("SecondSkin").unwrap().base.id;
+        assert_eq!(profile.skins.get(first_id), Some(&true));
+        assert_eq!(profile.skins.get(second_id), Some(&false));
+        // Not installed, so kept to show up as missing
+        assert_eq!(profile.skins.get("ThirdSkin"), Some(&true));
+    }
+}
@@ -0,0 +1,221 @@
+// src-tauri/src/utils/quarantine.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+use crate::utils::modregistry::{resolve_reframework_mod_dir, ModRegistry};
+use crate::utils::hashing::hash_file;
+use crate::utils::walk::Walk;
+
+const DLL_MANIFEST_FILE: &str = "dll_manifest.json";
+
+/// A DLL we expect to still be there next session
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrackedDll {
+    pub path: String,          // Relative to the game root
+    pub sha256: String,
+    pub owner: Option<String>, // Registry mod owning it (None for dinput8.dll)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DllManifest {
+    pub recorded_timestamp: i64,
+    pub files: Vec<TrackedDll>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum QuarantineStatus {
+    Clear,
+    PossibleQuarantine,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuarantineReport {
+    pub status: QuarantineStatus,
+    pub affected_files: Vec<String>,
+    pub affected_mods: Vec<String>,
+    pub message: String,
+}
+
+fn get_manifest_path(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join(DLL_MANIFEST_FILE)
+}
+
+fn load_manifest(game_root: &Path) -> DllManifest {
+    fs::read_to_string(get_manifest_path(game_root))
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn is_dll(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("dll"))
+}
+
+/// dinput8.dll plus every DLL inside a registered REFramework mod, as currently on disk
+fn collect_present_dlls(game_root: &Path, registry: &ModRegistry) -> Vec<TrackedDll> {
+    let mut files = Vec::new();
+    let mut track = |path: &Path, owner: Option<String>| {
+        let Ok(rel) = path.strip_prefix(game_root) else {
+            return;
+        };
+        match hash_file(path) {
+            Ok(sha256) => files.push(TrackedDll {
+                path: rel.to_string_lossy().replace('\\', "/"),
+                sha256,
+                owner,
+            }),
+            Err(e) => warn!("Failed to hash {}: {}", path.display(), e),
+        }
+    };
+
+    let dinput_path = game_root.join("dinput8.dll");
+    if dinput_path.is_file() {
+        track(&dinput_path, None);
+    }
+    for mod_entry in &registry.mods {
+        let Some(mod_dir) = resolve_reframework_mod_dir(game_root, mod_entry) else {
+            continue;
+        };
+        for entry in Walk::new(&mod_dir).files() {
+            if is_dll(entry.path()) {
+                track(entry.path(), Some(mod_entry.directory_name.clone()));
+            }
+        }
+    }
+    files
+}
+
+/// Tracked DLLs that no longer exist. A disabled mod's folder is renamed, so its DLLs are
+/// looked up under both names before being reported.
+fn find_vanished(game_root: &Path, registry: &ModRegistry, manifest: &DllManifest) -> Vec<TrackedDll> {
+    manifest
+        .files
+        .iter()
+        .filter(|tracked| {
+            // Mods deleted through the manager aren't a quarantine
+            if let Some(owner) = &tracked.owner {
+                if registry.find_mod(owner).is_none() {
+                    return false;
+                }
+            }
+            let path = game_root.join(&tracked.path);
+            if path.is_file() {
+                return false;
+            }
+            let toggled = registry
+                .find_mod(tracked.owner.as_deref().unwrap_or_default())
+                .and_then(|m| {
+                    let normalized = normalized_path(registry, tracked);
+                    let rel = normalized
+                        .strip_prefix(&m.installed_directory.replace('\\', "/"))?
+                        .trim_start_matches('/')
+                        .to_string();
+                    let dir = resolve_reframework_mod_dir(game_root, m)?;
+                    Some(dir.join(rel))
+                });
+            !toggled.is_some_and(|p| p.is_file())
+        })
+        .cloned()
+        .collect()
+}
+
+/// Normalize a tracked path so enabled/disabled copies of a mod compare equal
+fn normalized_path(registry: &ModRegistry, tracked: &TrackedDll) -> String {
+    let Some(mod_entry) = tracked.owner.as_deref().and_then(|o| registry.find_mod(o)) else {
+        return tracked.path.clone();
+    };
+    let disabled_prefix = format!("{}.disabled", mod_entry.installed_directory.replace('\\', "/"));
+    match tracked.path.strip_prefix(&disabled_prefix) {
+        Some(rest) => format!("{}{}", mod_entry.installed_directory.replace('\\', "/"), rest),
+        None => tracked.path.clone(),
+    }
+}
+
+/// Compare DLLs against the previous session and record the current state.
+/// Vanished DLLs stay tracked until they reappear or their mod is removed, so the warning persists.
+pub fn check_and_record(game_root: &Path, registry: &ModRegistry) -> QuarantineReport {
+    let previous = load_manifest(game_root);
+    let vanished = find_vanished(game_root, registry, &previous);
+
+    let mut files = collect_present_dlls(game_root, registry);
+    let present: HashSet<String> = files.iter().map(|f| normalized_path(registry, f)).collect();
+    files.extend(
+        vanished
+            .iter()
+            .filter(|v| !present.contains(&normalized_path(registry, v)))
+            .cloned(),
+    );
+    let manifest = DllManifest {
+        recorded_timestamp: chrono::Utc::now().timestamp(),
+        files,
+    };
+    let manifest_path = get_manifest_path(game_root);
+    let write_result = manifest_path
+        .parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string()))
+        .and_then(|content| fs::write(&manifest_path, content).map_err(|e| e.to_string()));
+    if let Err(e) = write_result {
+        warn!("Failed to write DLL manifest {}: {}", manifest_path.display(), e);
+    }
+
+    if vanished.is_empty() {
+        return QuarantineReport {
+            status: QuarantineStatus::Clear,
+            affected_files: Vec::new(),
+            affected_mods: Vec::new(),
+            message: "No tracked DLLs have gone missing.".to_string(),
+        };
+    }
+
+    let affected_files: Vec<String> = vanished.iter().map(|v| v.path.clone()).collect();
+    let mut affected_mods: Vec<String> = vanished.iter().filter_map(|v| v.owner.clone()).collect();
+    affected_mods.sort();
+    affected_mods.dedup();
+    warn!(
+        "Possible antivirus quarantine: {} DLL(s) vanished since last session: {:?}",
+        affected_files.len(),
+        affected_files
+    );
+    QuarantineReport {
+        status: QuarantineStatus::PossibleQuarantine,
+        message: format!(
+            "{} DLL file(s) disappeared since the last session without being removed by the manager. \
+             This is usually antivirus software quarantining them; restore them from quarantine and \
+             add an exception for the game folder.",
+            affected_files.len()
+        ),
+        affected_files,
+        affected_mods,
+    }
+}
+
+/// Check for DLLs removed behind the manager's back, notifying the frontend when found
+pub fn check_and_notify(app_handle: &AppHandle, game_root: &Path, registry: &ModRegistry) -> QuarantineReport {
+    let report = check_and_record(game_root, registry);
+    if report.status == QuarantineStatus::PossibleQuarantine {
+        if let Err(e) = app_handle.emit("possible-antivirus-quarantine", &report) {
+            warn!("Failed to emit quarantine warning: {}", e);
+        }
+    }
+    report
+}
+
+#[tauri::command]
+pub async fn check_antivirus_quarantine(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<QuarantineReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let report = check_and_record(&game_root, &registry);
+    info!("Quarantine check: {:?} ({} files)", report.status, report.affected_files.len());
+    Ok(report)
+}
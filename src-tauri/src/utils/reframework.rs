@@ -0,0 +1,363 @@
+// src-tauri/src/utils/reframework.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::hashing::hash_file;
+use crate::utils::modregistry::{resolve_reframework_mod_dir, ModRegistry};
+use crate::utils::walk::Walk;
+
+const MANIFEST_FILE: &str = "reframework_manifest.json";
+
+/// A file placed by the REFramework installer
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FrameworkFile {
+    pub path: String, // Relative to the game root
+    pub sha256: String,
+}
+
+/// What we installed, written to reframework_manifest.json next to the mod registry
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReframeworkManifest {
+    pub release_tag: String,
+    pub installed_timestamp: i64,
+    pub files: Vec<FrameworkFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ReframeworkStatus {
+    Ok,
+    NotInstalled,
+    Dinput8Missing,     // reframework/ is there but the DLL isn't; typically antivirus quarantine
+    Modified,           // Files differ from what was installed
+    IncompleteInstall,  // Expected directories or files are missing
+    Unverified,         // Installed outside the manager, no recorded hashes to compare against
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReframeworkVerifyReport {
+    pub status: ReframeworkStatus,
+    pub release_tag: Option<String>,
+    pub missing_files: Vec<String>,
+    pub modified_files: Vec<String>,
+    pub message: String,
+}
+
+/// Folder REFramework scripts save their settings in; kept on uninstall when asked to
+const USER_CONFIG_DIR: &str = "reframework/data";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ReframeworkRemovalReport {
+    pub removed_files: Vec<String>,  // Relative to the game root
+    pub preserved_files: Vec<String>, // User configs left in place
+    pub unregistered_mods: Vec<String>, // REFramework mods that lived in the removed folder
+    pub errors: Vec<String>,
+}
+
+/// Stored with the app config (next to the registry, so a sandbox gets its own)
+fn get_manifest_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    Ok(ModRegistry::get_registry_path(app_handle)?.with_file_name(MANIFEST_FILE))
+}
+
+/// Where earlier versions kept the manifest
+fn get_legacy_manifest_path(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join(MANIFEST_FILE)
+}
+
+pub fn load_manifest(app_handle: &AppHandle, game_root: &Path) -> Option<ReframeworkManifest> {
+    let path = get_manifest_path(app_handle)
+        .ok()
+        .filter(|p| p.is_file())
+        .unwrap_or_else(|| get_legacy_manifest_path(game_root));
+    let content = fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!("Ignoring unreadable REFramework manifest: {}", e);
+            None
+        }
+    }
+}
+
+/// Hash the files a fresh install extracted (paths relative to the game root)
+pub fn record_reframework_install(
+    app_handle: &AppHandle,
+    game_root: &Path,
+    release_tag: &str,
+    extracted: &[PathBuf],
+) -> Result<(), String> {
+    let mut files = Vec::new();
+    let ledger = LedgerOperation::begin(game_root, "install_reframework");
+    for rel in extracted {
+        files.push(FrameworkFile {
+            path: rel.to_string_lossy().replace('\\', "/"),
+            sha256: hash_file(&game_root.join(rel))?,
+        });
+    }
+
+    for file in &files {
+        ledger.wrote_hashed(&game_root.join(&file.path), file.sha256.clone());
+    }
+
+    let manifest = ReframeworkManifest {
+        release_tag: release_tag.to_string(),
+        installed_timestamp: chrono::Utc::now().timestamp(),
+        files,
+    };
+    let manifest_path = get_manifest_path(app_handle)?;
+    if let Some(parent) = manifest_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize REFramework manifest: {}", e))?;
+    fs::write(&manifest_path, content)
+        .map_err(|e| format!("Failed to write REFramework manifest: {}", e))?;
+    info!(
+        "Recorded REFramework {} install ({} files)",
+        manifest.release_tag,
+        manifest.files.len()
+    );
+    let legacy_path = get_legacy_manifest_path(game_root);
+    if legacy_path.is_file() {
+        if let Err(e) = fs::remove_file(&legacy_path) {
+            warn!("Failed to remove old REFramework manifest {}: {}", legacy_path.display(), e);
+        }
+    }
+    Ok(())
+}
+
+/// Compare the REFramework install against what was recorded when it was installed
+pub fn check_reframework(app_handle: &AppHandle, game_root: &Path) -> ReframeworkVerifyReport {
+    let dinput_present = game_root.join("dinput8.dll").is_file();
+    let framework_dir_present = game_root.join("reframework").is_dir();
+    let manifest = load_manifest(app_handle, game_root);
+
+    let mut report = ReframeworkVerifyReport {
+        status: ReframeworkStatus::Ok,
+        release_tag: manifest.as_ref().map(|m| m.release_tag.clone()),
+        missing_files: Vec::new(),
+        modified_files: Vec::new(),
+        message: String::new(),
+    };
+
+    if !dinput_present && !framework_dir_present {
+        report.status = ReframeworkStatus::NotInstalled;
+        report.message = "REFramework is not installed.".to_string();
+        return report;
+    }
+    if !dinput_present {
+        report.status = ReframeworkStatus::Dinput8Missing;
+        report.missing_files.push("dinput8.dll".to_string());
+        report.message = "dinput8.dll is missing but the reframework folder is present. \
+            Antivirus software commonly quarantines this file; check its quarantine and add an exception."
+            .to_string();
+        return report;
+    }
+    if !framework_dir_present {
+        report.status = ReframeworkStatus::IncompleteInstall;
+        report.missing_files.push("reframework/".to_string());
+        report.message = "The reframework folder is missing; reinstall REFramework.".to_string();
+        return report;
+    }
+
+    let Some(manifest) = manifest else {
+        report.status = ReframeworkStatus::Unverified;
+        report.message =
+            "REFramework looks installed but wasn't installed by the manager, so it can't be verified."
+                .to_string();
+        return report;
+    };
+
+    for file in &manifest.files {
+        let path = game_root.join(&file.path);
+        if !path.is_file() {
+            report.missing_files.push(file.path.clone());
+            continue;
+        }
+        match hash_file(&path) {
+            Ok(hash) if hash == file.sha256 => {}
+            Ok(_) => report.modified_files.push(file.path.clone()),
+            Err(e) => {
+                warn!("Failed to hash {}: {}", path.display(), e);
+                report.modified_files.push(file.path.clone());
+            }
+        }
+    }
+
+    if !report.missing_files.is_empty() {
+        report.status = ReframeworkStatus::IncompleteInstall;
+        report.message = format!(
+            "{} REFramework file(s) are missing; reinstall REFramework.",
+            report.missing_files.len()
+        );
+    } else if !report.modified_files.is_empty() {
+        report.status = ReframeworkStatus::Modified;
+        report.message = format!(
+            "{} REFramework file(s) differ from the installed {} release.",
+            report.modified_files.len(),
+            manifest.release_tag
+        );
+    } else {
+        report.message = format!("REFramework {} is intact.", manifest.release_tag);
+    }
+    report
+}
+
+#[tauri::command]
+pub async fn verify_reframework(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<ReframeworkVerifyReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
+    let report = check_reframework(&app_handle, &game_root);
+    info!("REFramework verification: {:?} - {}", report.status, report.message);
+    Ok(report)
+}
+
+/// Delete dinput8.dll, the manager-installed files and the reframework folder. With
+/// `preserve_user_configs`, everything under reframework/data (script settings) is kept.
+/// Registry entries for REFramework mods that lived in the folder are dropped (without saving).
+pub(crate) fn remove_reframework_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    manifest: Option<&ReframeworkManifest>,
+    preserve_user_configs: bool,
+) -> ReframeworkRemovalReport {
+    let mut report = ReframeworkRemovalReport::default();
+    let ledger = LedgerOperation::begin(game_root, "remove_reframework");
+    let user_config_dir = game_root.join(USER_CONFIG_DIR);
+    let framework_dir = game_root.join("reframework");
+
+    let mut targets: Vec<PathBuf> = manifest
+        .map(|m| m.files.iter().map(|f| game_root.join(&f.path)).collect())
+        .unwrap_or_default();
+    targets.push(game_root.join("dinput8.dll"));
+    targets.extend(
+        Walk::new(&framework_dir)
+            .include_hidden()
+            .no_follow()
+            .into_iter()
+            .filter(|e| !e.file_type().is_dir())
+            .map(|e| e.into_path()),
+    );
+    targets.sort();
+    targets.dedup();
+
+    for path in targets {
+        let rel = path
+            .strip_prefix(game_root)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| path.to_string_lossy().to_string());
+        if !path.is_file() {
+            continue;
+        }
+        if preserve_user_configs && path.starts_with(&user_config_dir) {
+            report.preserved_files.push(rel);
+            continue;
+        }
+        match fs::remove_file(&path) {
+            Ok(_) => {
+                ledger.deleted(&path, false);
+                report.removed_files.push(rel);
+            }
+            Err(e) => report.errors.push(format!("Failed to delete {}: {}", path.display(), e)),
+        }
+    }
+
+    // Clear out the now-empty folders, deepest first
+    let mut dirs: Vec<PathBuf> = Walk::new(&framework_dir)
+        .include_hidden()
+        .no_follow()
+        .into_iter()
+        .filter(|e| e.file_type().is_dir())
+        .map(|e| e.into_path())
+        .collect();
+    dirs.sort_by_key(|d| std::cmp::Reverse(d.components().count()));
+    for dir in dirs {
+        let is_empty = fs::read_dir(&dir).map(|mut d| d.next().is_none()).unwrap_or(false);
+        if is_empty {
+            match fs::remove_dir(&dir) {
+                Ok(_) => ledger.deleted(&dir, true),
+                Err(e) => report.errors.push(format!("Failed to delete {}: {}", dir.display(), e)),
+            }
+        }
+    }
+
+    let orphaned: Vec<String> = registry
+        .mods
+        .iter()
+        .filter(|m| resolve_reframework_mod_dir(game_root, m).is_none())
+        .filter(|m| game_root.join(&m.installed_directory).starts_with(&framework_dir))
+        .map(|m| m.directory_name.clone())
+        .collect();
+    for name in orphaned {
+        registry.remove_mod(&name);
+        report.unregistered_mods.push(name);
+    }
+    report
+}
+
+/// Uninstall REFramework. User script configs are kept unless `preserve_user_configs` is false.
+#[tauri::command]
+pub async fn remove_reframework(
+    app_handle: AppHandle,
+    game_root_path: String,
+    preserve_user_configs: Option<bool>,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<ReframeworkRemovalReport>, String> {
+    let preserve_user_configs = preserve_user_configs.unwrap_or(true);
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "remove_reframework",
+        &[game_root_path.clone(), preserve_user_configs.to_string()],
+        if preserve_user_configs {
+            "Uninstall REFramework and the REFramework mods installed into it"
+        } else {
+            "Uninstall REFramework, its mods and all script settings"
+        },
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
+    info!("Removing REFramework from {}", game_root.display());
+
+    let manifest = load_manifest(&app_handle, &game_root);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mut report = remove_reframework_files(&mut registry, &game_root, manifest.as_ref(), preserve_user_configs);
+    if !report.unregistered_mods.is_empty() {
+        registry.last_updated = chrono::Utc::now().timestamp();
+        if let Err(e) = registry.save(&app_handle) {
+            report.errors.push(format!("Failed to save registry: {}", e));
+        }
+    }
+    for manifest_path in get_manifest_path(&app_handle)
+        .into_iter()
+        .chain([get_legacy_manifest_path(&game_root)])
+        .filter(|p| p.is_file())
+    {
+        if let Err(e) = fs::remove_file(&manifest_path) {
+            report.errors.push(format!("Failed to delete {}: {}", manifest_path.display(), e));
+        }
+    }
+
+    info!(
+        "Removed REFramework: {} file(s) deleted, {} config file(s) kept, {} mod(s) unregistered, {} error(s)",
+        report.removed_files.len(),
+        report.preserved_files.len(),
+        report.unregistered_mods.len(),
+        report.errors.len()
+    );
+    Ok(Confirmable::Done(report))
+}
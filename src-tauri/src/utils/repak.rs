@@ -0,0 +1,353 @@
+// src-tauri/src/utils/repak.rs
+// Minimal reader/writer for RE Engine patch paks (KPKA v4, uncompressed, unencrypted),
+// following the community-documented layout used by REtool and friends.
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::utils::fsops::long_path;
+use crate::utils::walk::Walk;
+
+const PAK_MAGIC: u32 = 0x414B_504B; // "KPKA"
+const PAK_MAJOR_VERSION: u8 = 4;
+const HEADER_SIZE: u64 = 16;
+const ENTRY_SIZE: u64 = 48;
+const PATH_HASH_SEED: u32 = 0xFFFF_FFFF;
+const COMPRESSION_MASK: u64 = 0xF;
+
+/// One file in a pak's entry table
+#[derive(Debug, Clone)]
+pub struct PakEntry {
+    pub hash_lower: u32,
+    pub hash_upper: u32,
+    pub offset: u64,
+    pub compressed_size: u64,
+    pub size: u64,
+    pub attributes: u64,
+}
+
+/// MurmurHash3 x86_32, which RE Engine uses for pak path hashes
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    let mut h = seed;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h ^= k;
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k = 0u32;
+        for (i, byte) in tail.iter().enumerate() {
+            k |= u32::from(*byte) << (8 * i);
+        }
+        h ^= k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+    }
+    h ^= data.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// Hash of a pak path in the given case, over its UTF-16LE encoding
+fn path_hash(path: &str, upper: bool) -> u32 {
+    let cased = if upper {
+        path.to_uppercase()
+    } else {
+        path.to_lowercase()
+    };
+    let bytes: Vec<u8> = cased.encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+    murmur3_32(&bytes, PATH_HASH_SEED)
+}
+
+/// Lower/upper-case hash pair identifying a path in a pak's entry table
+pub fn pak_entry_key(path: &str) -> (u32, u32) {
+    (path_hash(path, false), path_hash(path, true))
+}
+
+/// Normalize a path to the form stored in paks ("natives/STM/...")
+fn pak_path(rel: &Path) -> String {
+    rel.to_string_lossy().replace('\\', "/")
+}
+
+/// Pack every file under `mod_dir/natives` into a single patch pak at `dest`.
+/// `natives_path` maps each file's path below natives/ to the one stored in the pak.
+/// `on_progress` receives bytes written as they are copied.
+pub fn pack_natives_to_pak(
+    mod_dir: &Path,
+    dest: &Path,
+    natives_path: &dyn Fn(&Path) -> PathBuf,
+    on_progress: &(dyn Fn(u64) + Sync),
+) -> Result<usize, String> {
+    let natives_dir = mod_dir.join("natives");
+    let mut files: Vec<(String, PathBuf)> = Vec::new();
+    for entry in Walk::new(&natives_dir).files() {
+        let rel = entry
+            .path()
+            .strip_prefix(&natives_dir)
+            .map_err(|e| format!("Failed to resolve {}: {}", entry.path().display(), e))?;
+        files.push((pak_path(&Path::new("natives").join(natives_path(rel))), entry.path().to_path_buf()));
+    }
+    if files.is_empty() {
+        return Err(format!("No natives files to pack in {}", mod_dir.display()));
+    }
+
+    let write = || -> io::Result<()> {
+        let mut out = io::BufWriter::new(fs::File::create(dest)?);
+        out.write_all(&PAK_MAGIC.to_le_bytes())?;
+        out.write_all(&[PAK_MAJOR_VERSION, 0])?;
+        out.write_all(&0u16.to_le_bytes())?; // feature flags: none (unencrypted)
+        out.write_all(&(files.len() as u32).to_le_bytes())?;
+        out.write_all(&0u32.to_le_bytes())?;
+
+        // Entry table is rewritten once offsets are known
+        let table_start = HEADER_SIZE;
+        out.write_all(&vec![0u8; (ENTRY_SIZE as usize) * files.len()])?;
+
+        let mut entries = Vec::with_capacity(files.len());
+        let mut offset = table_start + ENTRY_SIZE * files.len() as u64;
+        let mut buffer = vec![0u8; 1024 * 1024];
+        for (name, source) in &files {
+            let mut input = fs::File::open(source)?;
+            let mut size = 0u64;
+            loop {
+                let read = input.read(&mut buffer)?;
+                if read == 0 {
+                    break;
+                }
+                out.write_all(&buffer[..read])?;
+                size += read as u64;
+                on_progress(read as u64);
+            }
+            entries.push(PakEntry {
+                hash_lower: path_hash(name, false),
+                hash_upper: path_hash(name, true),
+                offset,
+                compressed_size: size,
+                size,
+                attributes: 0,
+            });
+            offset += size;
+        }
+
+        out.seek(SeekFrom::Start(table_start))?;
+        for entry in &entries {
+            out.write_all(&entry.hash_lower.to_le_bytes())?;
+            out.write_all(&entry.hash_upper.to_le_bytes())?;
+            out.write_all(&entry.offset.to_le_bytes())?;
+            out.write_all(&entry.compressed_size.to_le_bytes())?;
+            out.write_all(&entry.size.to_le_bytes())?;
+            out.write_all(&entry.attributes.to_le_bytes())?;
+            out.write_all(&0u64.to_le_bytes())?; // checksum, unused by the game for patches
+        }
+        out.flush()?;
+        out.get_ref().sync_all()
+    };
+    if let Err(e) = write() {
+        let _ = fs::remove_file(dest);
+        return Err(format!("Failed to write pak {}: {}", dest.display(), e));
+    }
+
+    info!("Packed {} natives files into {}", files.len(), dest.display());
+    Ok(files.len())
+}
+
+/// Read the entry table of an unencrypted v4 pak
+pub fn read_pak_entries(pak: &Path) -> Result<Vec<PakEntry>, String> {
+    let read = || -> io::Result<Result<Vec<PakEntry>, String>> {
+        let mut file = io::BufReader::new(fs::File::open(pak)?);
+        let mut header = [0u8; HEADER_SIZE as usize];
+        file.read_exact(&mut header)?;
+        let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let major = header[4];
+        let feature = u16::from_le_bytes([header[6], header[7]]);
+        let count = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+        if magic != PAK_MAGIC {
+            return Ok(Err("not an RE Engine pak".to_string()));
+        }
+        if major != PAK_MAJOR_VERSION || feature != 0 {
+            return Ok(Err(format!(
+                "unsupported pak version {} / feature flags {:#x}",
+                major, feature
+            )));
+        }
+
+        let mut entries = Vec::with_capacity(count as usize);
+        let mut raw = [0u8; ENTRY_SIZE as usize];
+        let u64_at = |b: &[u8], i: usize| u64::from_le_bytes(b[i..i + 8].try_into().unwrap());
+        for _ in 0..count {
+            file.read_exact(&mut raw)?;
+            entries.push(PakEntry {
+                hash_lower: u32::from_le_bytes(raw[0..4].try_into().unwrap()),
+                hash_upper: u32::from_le_bytes(raw[4..8].try_into().unwrap()),
+                offset: u64_at(&raw, 8),
+                compressed_size: u64_at(&raw, 16),
+                size: u64_at(&raw, 24),
+                attributes: u64_at(&raw, 32),
+            });
+        }
+        Ok(Ok(entries))
+    };
+    read()
+        .map_err(|e| format!("Failed to read pak {}: {}", pak.display(), e))?
+        .map_err(|e| format!("Can't read {}: {}", pak.display(), e))
+}
+
+/// Load a community file list (one pak path per line) used to name pak entries
+pub fn load_file_list(list_path: &Path) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(list_path)
+        .map_err(|e| format!("Failed to read file list {}: {}", list_path.display(), e))?;
+    Ok(content
+        .lines()
+        .map(|l| l.trim().replace('\\', "/"))
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect())
+}
+
+/// One pak entry and the loose file it becomes
+#[derive(Debug, Clone)]
+pub struct PlannedExtraction {
+    entry: PakEntry,
+    pub dest: PathBuf,
+}
+
+/// Work out where a pak's files go as loose natives. `natives_root` receives the part of each
+/// path after "natives/". Fails if any entry can't be named or is compressed, so callers can
+/// fall back to deploying the pak itself before anything is written.
+pub fn plan_pak_extraction(
+    pak: &Path,
+    file_list: &[String],
+    natives_root: &Path,
+) -> Result<Vec<PlannedExtraction>, String> {
+    let entries = read_pak_entries(pak)?;
+    let names: HashMap<(u32, u32), &str> = file_list
+        .iter()
+        .map(|name| (pak_entry_key(name), name.as_str()))
+        .collect();
+
+    let mut planned = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let Some(name) = names.get(&(entry.hash_lower, entry.hash_upper)) else {
+            return Err(format!(
+                "{} contains files missing from the file list; keeping it as a pak",
+                pak.display()
+            ));
+        };
+        if entry.attributes & COMPRESSION_MASK != 0 || entry.compressed_size != entry.size {
+            return Err(format!(
+                "{} uses compressed entries, which can't be unpacked here",
+                pak.display()
+            ));
+        }
+        let lower = name.to_lowercase();
+        let Some(rel) = lower.strip_prefix("natives/").map(|_| &name["natives/".len()..]) else {
+            return Err(format!("Unexpected path '{}' in {}", name, pak.display()));
+        };
+        planned.push(PlannedExtraction {
+            dest: natives_root.join(rel),
+            entry,
+        });
+    }
+    Ok(planned)
+}
+
+/// Write planned files out of a pak. Returns each written file and whether it replaced one;
+/// a failure removes what this call wrote.
+pub fn extract_pak_to_natives(
+    pak: &Path,
+    planned: &[PlannedExtraction],
+    on_progress: &(dyn Fn(u64) + Sync),
+) -> Result<Vec<(PathBuf, bool)>, String> {
+    let mut file = fs::File::open(pak)
+        .map_err(|e| format!("Failed to open {}: {}", pak.display(), e))?;
+    let mut written: Vec<(PathBuf, bool)> = Vec::with_capacity(planned.len());
+    for PlannedExtraction { entry, dest } in planned {
+        let replaced = long_path(dest).exists();
+        let result = (|| -> io::Result<()> {
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(long_path(parent))?;
+            }
+            file.seek(SeekFrom::Start(entry.offset))?;
+            let mut out = fs::File::create(long_path(dest))?;
+            io::copy(&mut (&mut file).take(entry.size), &mut out)?;
+            out.sync_all()
+        })();
+        if let Err(e) = result {
+            for (path, _) in &written {
+                let _ = fs::remove_file(long_path(path));
+            }
+            return Err(format!("Failed to extract {}: {}", dest.display(), e));
+        }
+        on_progress(entry.size);
+        debug!("Extracted {}", dest.display());
+        written.push((dest.clone(), replaced));
+    }
+
+    if written.is_empty() {
+        warn!("{} contained no files", pak.display());
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn murmur3_matches_the_reference_vectors() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514E_28B7);
+        assert_eq!(murmur3_32(b"hello", 0), 0x248B_FA47);
+        // Paths hash the same whatever their case
+        assert_eq!(
+            pak_entry_key("natives/STM/Art/tex.tex.241106027"),
+            pak_entry_key("NATIVES/stm/ART/TEX.TEX.241106027")
+        );
+        assert_ne!(pak_entry_key("natives/STM/a.txt"), pak_entry_key("natives/STM/b.txt"));
+    }
+
+    #[test]
+    fn packed_natives_unpack_into_the_game() {
+        let game = FakeGame::new();
+        let mod_dir = game.mods_dir().join("PakMod");
+        for (rel, contents) in [("STM/a.tex", &b"first"[..]), ("STM/sub/b.txt", &b"second"[..])] {
+            let path = mod_dir.join("natives").join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        let pak = game.root().join("re_chunk_000.pak.patch_001.pak");
+        let packed = pack_natives_to_pak(&mod_dir, &pak, &|rel| rel.to_path_buf(), &|_| {}).expect("pack");
+        assert_eq!(packed, 2);
+
+        let entries = read_pak_entries(&pak).expect("read entries");
+        let mut keys: Vec<_> = entries.iter().map(|e| (e.hash_lower, e.hash_upper)).collect();
+        keys.sort();
+        let mut expected = vec![pak_entry_key("natives/STM/a.tex"), pak_entry_key("natives/STM/sub/b.txt")];
+        expected.sort();
+        assert_eq!(keys, expected);
+
+        // Entries the file list can't name keep the pak packed
+        let partial = vec!["natives/STM/a.tex".to_string()];
+        assert!(plan_pak_extraction(&pak, &partial, &game.root().join("natives")).is_err());
+
+        fs::write(game.deployed("STM/a.tex"), b"original").unwrap();
+        let file_list = vec!["natives/STM/a.tex".to_string(), "natives/STM/sub/b.txt".to_string()];
+        let planned = plan_pak_extraction(&pak, &file_list, &game.root().join("natives")).expect("plan");
+        let mut written = extract_pak_to_natives(&pak, &planned, &|_| {}).expect("extract");
+        written.sort();
+        assert_eq!(
+            written,
+            vec![(game.deployed("STM/a.tex"), true), (game.deployed("STM/sub/b.txt"), false)]
+        );
+        assert_eq!(fs::read(game.deployed("STM/a.tex")).unwrap(), b"first");
+        assert_eq!(fs::read(game.deployed("STM/sub/b.txt")).unwrap(), b"second");
+    }
+}
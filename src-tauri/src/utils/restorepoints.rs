@@ -0,0 +1,536 @@
+// src-tauri/src/utils/restorepoints.rs
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::cloudsync::cloud_provider_for_path;
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::fsops::{move_path, parallel_map};
+use crate::utils::modregistry::{
+    disable_skin_mod_files, enable_skin_mod_files, resolve_reframework_mod_dir,
+    set_reframework_mod_enabled, ModRegistry,
+};
+use crate::utils::hashing::hash_file;
+use crate::utils::settings::AppSettings;
+use crate::utils::tempermission::ModOperationGuard;
+use crate::utils::walk::Walk;
+
+const RESTORE_POINT_MANIFEST: &str = "manifest.json";
+const MAX_RESTORE_POINTS: usize = 10; // Oldest points are pruned past this
+
+/// A file deployed into the game directory by a managed mod
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ManagedFileState {
+    pub owner: String, // directory_name of the owning mod
+    pub path: String,  // Relative to the mod dir (REFramework) or game root (skin mods)
+    pub sha256: String,
+    pub size_bytes: u64,
+}
+
+/// Snapshot of the managed mod state in fossmodmanager/restore_points/<id>/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RestorePoint {
+    pub id: String,
+    pub created_timestamp: i64,
+    pub label: String,
+    pub registry: ModRegistry,
+    pub files: Vec<ManagedFileState>,
+}
+
+/// Lightweight listing entry so the UI doesn't receive every file hash
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RestorePointSummary {
+    pub id: String,
+    pub created_timestamp: i64,
+    pub label: String,
+    pub mod_count: usize,
+    pub enabled_count: usize,
+    pub file_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RestoreReport {
+    pub enabled: Vec<String>,
+    pub disabled: Vec<String>,
+    pub missing_mods: Vec<String>,     // In the restore point but no longer installed
+    pub mismatched_files: Vec<String>, // "<mod>: <path>" whose contents differ from the snapshot
+    pub errors: Vec<String>,
+}
+
+/// Custom restore point folder from settings, kept in managed state; None means inside the
+/// game folder
+pub struct RestorePointsRoot(RwLock<Option<PathBuf>>);
+
+impl RestorePointsRoot {
+    fn get(&self) -> Option<PathBuf> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set(&self, root: Option<PathBuf>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = root;
+    }
+}
+
+/// Manage the configured restore point folder; call once settings are available
+pub fn init_restore_points_root(app_handle: &AppHandle) {
+    let configured = AppSettings::load(app_handle)
+        .ok()
+        .and_then(|s| s.restore_points_root)
+        .map(PathBuf::from);
+    if let Some(root) = &configured {
+        info!("Using restore point folder {}", root.display());
+    }
+    app_handle.manage(RestorePointsRoot(RwLock::new(configured)));
+}
+
+/// Restore point directory used when none is configured
+pub fn default_restore_points_dir(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join("restore_points")
+}
+
+/// Get the restore point directory: the configured folder, or the game's fossmodmanager
+/// folder. A sandbox always keeps its own.
+pub fn get_restore_points_dir(app_handle: &AppHandle, game_root: &Path) -> PathBuf {
+    let custom = match crate::utils::sandbox::active_sandbox(app_handle) {
+        Some(_) => None,
+        None => app_handle.state::<RestorePointsRoot>().get(),
+    };
+    custom.unwrap_or_else(|| default_restore_points_dir(game_root))
+}
+
+/// Hash every file a mod currently has deployed in the game directory
+fn collect_mod_files(
+    game_root: &Path,
+    registry: &ModRegistry,
+    owner: &str,
+    workers: usize,
+) -> Result<Vec<ManagedFileState>, String> {
+    // (absolute path, path recorded in the snapshot)
+    let mut targets: Vec<(PathBuf, String)> = Vec::new();
+
+    if let Some(mod_entry) = registry.find_mod(owner) {
+        // Relative to the mod dir so enabled/disabled copies compare equal
+        if let Some(mod_dir) = resolve_reframework_mod_dir(game_root, mod_entry) {
+            for entry in Walk::new(&mod_dir).include_hidden().files() {
+                let rel = entry
+                    .path()
+                    .strip_prefix(&mod_dir)
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or_default();
+                targets.push((entry.path().to_path_buf(), rel));
+            }
+        }
+    } else if let Some(skin_mod) = registry.find_skin_mod(owner) {
+        for installed in &skin_mod.installed_files {
+            let path = Path::new(&installed.path);
+            if !path.is_file() {
+                continue;
+            }
+            let rel = path
+                .strip_prefix(game_root)
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_else(|_| installed.path.clone());
+            targets.push((path.to_path_buf(), rel));
+        }
+    }
+
+    parallel_map(&targets, workers, |(path, rel)| {
+        Ok(ManagedFileState {
+            owner: owner.to_string(),
+            path: rel.clone(),
+            sha256: hash_file(path)?,
+            size_bytes: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        })
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Record the current registry and managed file hashes as a new restore point.
+/// Called by the UI directly and before bulk operations that change many mods at once.
+pub fn create_restore_point_for(
+    app_handle: &AppHandle,
+    game_root: &Path,
+    label: &str,
+) -> Result<RestorePoint, String> {
+    let registry = ModRegistry::load(app_handle)?;
+    let workers = AppSettings::load(app_handle)?.worker_count(game_root);
+
+    let mut files = Vec::new();
+    let owners = registry
+        .mods
+        .iter()
+        .map(|m| m.directory_name.clone())
+        .chain(registry.skin_mods.iter().map(|sm| sm.base.directory_name.clone()))
+        .collect::<Vec<_>>();
+    for owner in &owners {
+        files.extend(collect_mod_files(game_root, &registry, owner, workers)?);
+    }
+
+    let points_dir = get_restore_points_dir(app_handle, game_root);
+    let base_id = chrono::Utc::now().format("%Y%m%d-%H%M%S%3f").to_string();
+    let mut id = base_id.clone();
+    let mut suffix = 1;
+    while points_dir.join(&id).exists() {
+        id = format!("{}-{}", base_id, suffix);
+        suffix += 1;
+    }
+    let point_dir = points_dir.join(&id);
+    fs::create_dir_all(&point_dir)
+        .map_err(|e| format!("Failed to create restore point {}: {}", point_dir.display(), e))?;
+
+    let point = RestorePoint {
+        id,
+        created_timestamp: chrono::Utc::now().timestamp(),
+        label: label.to_string(),
+        registry,
+        files,
+    };
+    let manifest = serde_json::to_string_pretty(&point)
+        .map_err(|e| format!("Failed to serialize restore point: {}", e))?;
+    fs::write(point_dir.join(RESTORE_POINT_MANIFEST), manifest)
+        .map_err(|e| format!("Failed to write restore point manifest: {}", e))?;
+
+    info!(
+        "Created restore point {} ('{}') covering {} files",
+        point.id,
+        point.label,
+        point.files.len()
+    );
+    prune_restore_points(&points_dir);
+    Ok(point)
+}
+
+/// Read every restore point manifest in `points_dir`, newest first
+pub fn read_restore_points(points_dir: &Path) -> Vec<RestorePoint> {
+    let mut points = Vec::new();
+    let Ok(dir) = fs::read_dir(points_dir) else {
+        return points;
+    };
+    for dir_entry in dir.filter_map(Result::ok) {
+        let manifest_path = dir_entry.path().join(RESTORE_POINT_MANIFEST);
+        match fs::read_to_string(&manifest_path)
+            .map_err(|e| e.to_string())
+            .and_then(|c| serde_json::from_str::<RestorePoint>(&c).map_err(|e| e.to_string()))
+        {
+            Ok(point) => points.push(point),
+            Err(e) => warn!("Skipping unreadable restore point {}: {}", manifest_path.display(), e),
+        }
+    }
+    points.sort_by_key(|b| std::cmp::Reverse(b.created_timestamp));
+    points
+}
+
+/// Folder of the restore point `id`. Ids come from the frontend and from manifests, so
+/// anything but a single plain folder name is refused rather than joined onto the path.
+pub(crate) fn restore_point_dir(points_dir: &Path, id: &str) -> Result<PathBuf, String> {
+    let mut components = Path::new(id).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(points_dir.join(id)),
+        _ => Err(format!("Invalid restore point id: '{}'", id)),
+    }
+}
+
+/// Remove the oldest restore points beyond MAX_RESTORE_POINTS
+fn prune_restore_points(points_dir: &Path) {
+    for point in read_restore_points(points_dir).into_iter().skip(MAX_RESTORE_POINTS) {
+        let point_dir = match restore_point_dir(points_dir, &point.id) {
+            Ok(dir) => dir,
+            Err(e) => {
+                warn!("Not pruning restore point: {}", e);
+                continue;
+            }
+        };
+        match fs::remove_dir_all(&point_dir) {
+            Ok(_) => info!("Pruned old restore point {} ('{}')", point.id, point.label),
+            Err(e) => warn!("Failed to prune restore point {}: {}", point_dir.display(), e),
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn create_restore_point(
+    app_handle: AppHandle,
+    game_root_path: String,
+    label: String,
+) -> Result<RestorePointSummary, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let point = create_restore_point_for(&app_handle, &game_root, &label)?;
+    Ok(summarize(&point))
+}
+
+fn summarize(point: &RestorePoint) -> RestorePointSummary {
+    RestorePointSummary {
+        id: point.id.clone(),
+        created_timestamp: point.created_timestamp,
+        label: point.label.clone(),
+        mod_count: point.registry.mods.len() + point.registry.skin_mods.len(),
+        enabled_count: point.registry.mods.iter().filter(|m| m.enabled).count()
+            + point.registry.skin_mods.iter().filter(|sm| sm.base.enabled).count(),
+        file_count: point.files.len(),
+    }
+}
+
+#[tauri::command]
+pub async fn list_restore_points(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<Vec<RestorePointSummary>, String> {
+    let points_dir = get_restore_points_dir(&app_handle, Path::new(&game_root_path));
+    Ok(read_restore_points(&points_dir).iter().map(summarize).collect())
+}
+
+#[tauri::command]
+pub async fn delete_restore_point(
+    app_handle: AppHandle,
+    game_root_path: String,
+    restore_point_id: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "delete_restore_point",
+        std::slice::from_ref(&restore_point_id),
+        &format!("Delete restore point '{}'", restore_point_id),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let points_dir = get_restore_points_dir(&app_handle, Path::new(&game_root_path));
+    let point_dir = restore_point_dir(&points_dir, &restore_point_id)?;
+    if !point_dir.join(RESTORE_POINT_MANIFEST).is_file() {
+        return Err(format!("Restore point '{}' not found", restore_point_id));
+    }
+    fs::remove_dir_all(&point_dir)
+        .map_err(|e| format!("Failed to delete restore point {}: {}", point_dir.display(), e))?;
+    info!("Deleted restore point {}", restore_point_id);
+    Ok(Confirmable::Done(()))
+}
+
+/// Bring mod enabled states back to a restore point, then verify file hashes.
+/// Mods installed after the point are disabled rather than removed; mods deleted since are reported.
+#[tauri::command]
+pub async fn restore_to_point(
+    app_handle: AppHandle,
+    game_root_path: String,
+    restore_point_id: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<RestoreReport>, String> {
+    if let Some(challenge) = check_confirmation(
+        &app_handle,
+        "restore_to_point",
+        std::slice::from_ref(&restore_point_id),
+        &format!(
+            "Set every managed mod back to how it was at restore point '{}'",
+            restore_point_id
+        ),
+        confirmation_token.as_deref(),
+    )? {
+        return Ok(Confirmable::ConfirmationRequired(challenge));
+    }
+    let _in_flight = ModOperationGuard::begin(&app_handle);
+    info!("Restoring managed mods to restore point {}", restore_point_id);
+    let game_root = PathBuf::from(&game_root_path);
+    let point = read_restore_points(&get_restore_points_dir(&app_handle, &game_root))
+        .into_iter()
+        .find(|p| p.id == restore_point_id)
+        .ok_or_else(|| format!("Restore point '{}' not found", restore_point_id))?;
+
+    let settings = AppSettings::load(&app_handle)?;
+    let workers = settings.worker_count(&game_root);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mut report = RestoreReport::default();
+
+    let wanted: BTreeMap<String, bool> = point
+        .registry
+        .mods
+        .iter()
+        .chain(point.registry.skin_mods.iter().map(|sm| &sm.base))
+        .map(|m| (m.key().to_string(), m.enabled))
+        .collect();
+    let mut missing: Vec<String> = point
+        .registry
+        .mods
+        .iter()
+        .chain(point.registry.skin_mods.iter().map(|sm| &sm.base))
+        .filter(|m| registry.find_mod(m.key()).is_none() && registry.find_skin_mod(m.key()).is_none())
+        .map(|m| m.directory_name.clone())
+        .collect();
+    missing.sort();
+    report.missing_mods = missing;
+
+    // REFramework mods just need their directory renamed
+    let ref_mods: Vec<(String, bool, bool)> = registry
+        .mods
+        .iter()
+        .map(|m| (m.directory_name.clone(), m.enabled, m.keyed_value(&wanted).copied().unwrap_or(false)))
+        .collect();
+    for (name, currently_enabled, target) in ref_mods {
+        if target == currently_enabled {
+            continue;
+        }
+        match set_reframework_mod_enabled(&mut registry, &game_root, &name, target) {
+            Ok(_) if target => report.enabled.push(name),
+            Ok(_) => report.disabled.push(name),
+            Err(e) => report.errors.push(format!("{}: {}", name, e)),
+        }
+    }
+
+    // Disable skins first so their patch numbers are free before re-enabling others
+    let skin_mods: Vec<(String, String, bool, bool)> = registry
+        .skin_mods
+        .iter()
+        .map(|sm| {
+            (
+                sm.base.directory_name.clone(),
+                sm.base.path.clone(),
+                sm.base.enabled,
+                sm.base.keyed_value(&wanted).copied().unwrap_or(false),
+            )
+        })
+        .collect();
+    for (name, path, currently_enabled, target) in &skin_mods {
+        if *currently_enabled && !*target {
+            match disable_skin_mod_files(&mut registry, &game_root, path) {
+                Ok(file_errors) => {
+                    report.errors.extend(file_errors.into_iter().map(|e| format!("{}: {}", name, e)));
+                    report.disabled.push(name.clone());
+                }
+                Err(e) => report.errors.push(format!("{}: {}", name, e)),
+            }
+        }
+    }
+    for (name, path, currently_enabled, target) in &skin_mods {
+        if !*currently_enabled && *target {
+            match enable_skin_mod_files(&mut registry, &game_root, path, &settings, &HashMap::new(), &|_, _| {}) {
+                Ok(_) => report.enabled.push(name.clone()),
+                Err(e) => report.errors.push(format!("{}: {}", name, e)),
+            }
+        }
+    }
+
+    registry.save(&app_handle)?;
+
+    // Skin paks may land on different patch numbers, so compare contents per mod
+    let mut expected: HashMap<&str, Vec<&ManagedFileState>> = HashMap::new();
+    for file in &point.files {
+        expected.entry(file.owner.as_str()).or_default().push(file);
+    }
+    for (owner, files) in expected {
+        if report.missing_mods.iter().any(|m| m == owner) {
+            continue;
+        }
+        let current = match collect_mod_files(&game_root, &registry, owner, workers) {
+            Ok(current) => current,
+            Err(e) => {
+                report.errors.push(format!("{}: {}", owner, e));
+                continue;
+            }
+        };
+        for file in files {
+            if !current.iter().any(|c| c.sha256 == file.sha256) {
+                report.mismatched_files.push(format!("{}: {}", owner, file.path));
+            }
+        }
+    }
+    report.mismatched_files.sort();
+
+    info!(
+        "Restore to {} complete: {} enabled, {} disabled, {} missing, {} mismatched, {} errors",
+        point.id,
+        report.enabled.len(),
+        report.disabled.len(),
+        report.missing_mods.len(),
+        report.mismatched_files.len(),
+        report.errors.len()
+    );
+    Ok(Confirmable::Done(report))
+}
+
+/// Move the restore point snapshots to `new_root` (None for the default inside the game
+/// folder), e.g. out of a cloud-synced game folder. Returns how many points were moved.
+#[tauri::command]
+pub async fn set_restore_points_root(
+    app_handle: AppHandle,
+    game_root_path: String,
+    new_root: Option<String>,
+) -> Result<usize, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let old_dir = get_restore_points_dir(&app_handle, &game_root);
+    let new_dir = new_root
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_restore_points_dir(&game_root));
+    if new_dir == old_dir {
+        return Ok(0);
+    }
+    if let (Some(_), Some(provider)) = (&new_root, cloud_provider_for_path(&new_dir)) {
+        return Err(format!(
+            "{} is inside a {} folder; pick a folder that isn't synced",
+            new_dir.display(),
+            provider
+        ));
+    }
+    if new_dir.starts_with(&old_dir) || old_dir.starts_with(&new_dir) {
+        return Err(format!(
+            "{} can't be inside the current restore point folder (or contain it)",
+            new_dir.display()
+        ));
+    }
+    fs::create_dir_all(&new_dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", new_dir.display(), e))?;
+
+    let mut moves = Vec::new();
+    for point in read_restore_points(&old_dir) {
+        let to = new_dir.join(&point.id);
+        if to.exists() {
+            return Err(format!("{} already exists; move or remove it first", to.display()));
+        }
+        moves.push((old_dir.join(&point.id), to));
+    }
+    let mut moved: Vec<&(PathBuf, PathBuf)> = Vec::new();
+    for pair in &moves {
+        if let Err(e) = move_path(&pair.0, &pair.1) {
+            error!("Restore point move failed, moving {} back: {}", moved.len(), e);
+            for (from, to) in moved.iter().rev() {
+                if let Err(back_err) = move_path(to, from) {
+                    error!("Failed to move {} back: {}", to.display(), back_err);
+                }
+            }
+            return Err(e);
+        }
+        moved.push(pair);
+    }
+
+    let mut settings = AppSettings::load(&app_handle)?;
+    settings.restore_points_root = new_root;
+    settings.save(&app_handle)?;
+    app_handle
+        .state::<RestorePointsRoot>()
+        .set(settings.restore_points_root.map(PathBuf::from));
+
+    info!("Moved {} restore point(s) to {}", moved.len(), new_dir.display());
+    Ok(moved.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn restore_point_ids_stay_inside_the_restore_points_folder() {
+        let game = FakeGame::new();
+        let points_dir = default_restore_points_dir(game.root());
+        let point = restore_point_dir(&points_dir, "20260101-120000").expect("plain id");
+        assert_eq!(point.file_name().unwrap(), "20260101-120000");
+        assert!(point.starts_with(&points_dir));
+        for id in ["", ".", "..", "../..", "a/b", "../natives"] {
+            assert!(restore_point_dir(&points_dir, id).is_err(), "accepted '{}'", id);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+// src-tauri/src/utils/safemode.rs
+// Crash-triggered safe mode. The launcher reports every game exit; after enough crashes in a
+// row the REFramework script mods (plugins and autorun scripts) are disabled for one launch,
+// then put back once that launch exits. Skin mods are left alone -- they don't run code.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::confirmation::{check_confirmation, Confirmable};
+use crate::utils::modregistry::{set_reframework_mod_enabled, ModRegistry, ModType};
+use crate::utils::settings::{AppSettings, CrashSafeMode};
+
+const SAFE_MODE_FILE: &str = "safe_mode.json";
+
+/// Mods disabled for a safe-mode launch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SafeModeSession {
+    pub disabled_mods: Vec<String>, // Directory names, re-enabled when the session ends
+    pub started_timestamp: i64,
+    pub automatic: bool, // Entered by the crash policy rather than the user
+}
+
+/// Stored next to the registry, so a sandbox keeps its own count
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct CrashTracker {
+    consecutive_crashes: u32,
+    last_exit_code: Option<i32>,
+    last_exit_timestamp: Option<i64>,
+    safe_mode: Option<SafeModeSession>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SafeModeStatus {
+    pub policy: CrashSafeMode,
+    pub threshold: u32,
+    pub consecutive_crashes: u32,
+    pub last_exit_code: Option<i32>,
+    pub offer_safe_mode: bool, // Threshold reached and the policy says to ask
+    pub session: Option<SafeModeSession>,
+}
+
+/// What happened to the mods when a game exit was recorded or safe mode toggled
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SafeModeChange {
+    pub disabled: Vec<String>,
+    pub restored: Vec<String>,
+    pub safe_launch_crashed: bool, // The game crashed even without script mods
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GameExitResult {
+    pub status: SafeModeStatus,
+    pub change: SafeModeChange,
+}
+
+fn get_tracker_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    Ok(ModRegistry::get_registry_path(app_handle)?.with_file_name(SAFE_MODE_FILE))
+}
+
+fn load_tracker(app_handle: &AppHandle) -> CrashTracker {
+    get_tracker_path(app_handle)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save_tracker(app_handle: &AppHandle, tracker: &CrashTracker) -> Result<(), String> {
+    let path = get_tracker_path(app_handle)?;
+    let content = serde_json::to_string_pretty(tracker)
+        .map_err(|e| format!("Failed to serialize crash tracker: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write crash tracker: {}", e))
+}
+
+fn status(tracker: &CrashTracker, settings: &AppSettings) -> SafeModeStatus {
+    let threshold = settings.crash_safe_mode_threshold.max(1);
+    SafeModeStatus {
+        policy: settings.crash_safe_mode.clone(),
+        threshold,
+        consecutive_crashes: tracker.consecutive_crashes,
+        last_exit_code: tracker.last_exit_code,
+        offer_safe_mode: settings.crash_safe_mode == CrashSafeMode::Offer
+            && tracker.safe_mode.is_none()
+            && tracker.consecutive_crashes >= threshold,
+        session: tracker.safe_mode.clone(),
+    }
+}
+
+/// Anything but a clean 0 counts as a crash; no code at all means the process was killed
+pub fn is_abnormal_exit(exit_code: Option<i32>) -> bool {
+    exit_code != Some(0)
+}
+
+/// Directory names of the REFramework plugins and scripts that are currently enabled
+fn enabled_script_mods(registry: &ModRegistry) -> Vec<String> {
+    registry
+        .mods
+        .iter()
+        .filter(|m| m.enabled && matches!(m.mod_type, ModType::REFrameworkPlugin | ModType::REFrameworkAutorun))
+        .map(|m| m.directory_name.clone())
+        .collect()
+}
+
+/// Disable every enabled REFramework plugin and script. Returns the directory names that
+/// were disabled plus any errors; mods that fail to disable stay enabled.
+pub(crate) fn disable_script_mods(registry: &mut ModRegistry, game_root: &Path) -> (Vec<String>, Vec<String>) {
+    let targets = enabled_script_mods(registry);
+    let mut disabled = Vec::new();
+    let mut errors = Vec::new();
+    for name in targets {
+        match set_reframework_mod_enabled(registry, game_root, &name, false) {
+            Ok(()) => disabled.push(name),
+            Err(e) => errors.push(format!("{}: {}", name, e)),
+        }
+    }
+    (disabled, errors)
+}
+
+/// Re-enable the mods a safe-mode session disabled. Mods the user removed or re-enabled in the
+/// meantime are skipped.
+pub(crate) fn restore_script_mods(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    disabled_mods: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let mut restored = Vec::new();
+    let mut errors = Vec::new();
+    for name in disabled_mods {
+        match registry.find_mod(name) {
+            Some(m) if !m.enabled => {}
+            Some(_) => continue,
+            None => {
+                info!("Not restoring '{}' after safe mode: no longer installed", name);
+                continue;
+            }
+        }
+        match set_reframework_mod_enabled(registry, game_root, name, true) {
+            Ok(()) => restored.push(name.clone()),
+            Err(e) => errors.push(format!("{}: {}", name, e)),
+        }
+    }
+    (restored, errors)
+}
+
+fn start_session(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    tracker: &mut CrashTracker,
+    automatic: bool,
+    change: &mut SafeModeChange,
+) {
+    let (disabled, errors) = disable_script_mods(registry, game_root);
+    info!("Safe mode: disabled {} script mod(s) for the next launch", disabled.len());
+    tracker.safe_mode = Some(SafeModeSession {
+        disabled_mods: disabled.clone(),
+        started_timestamp: chrono::Utc::now().timestamp(),
+        automatic,
+    });
+    change.disabled = disabled;
+    change.errors.extend(errors);
+}
+
+fn end_session(registry: &mut ModRegistry, game_root: &Path, tracker: &mut CrashTracker, change: &mut SafeModeChange) {
+    let Some(session) = tracker.safe_mode.take() else {
+        return;
+    };
+    let (restored, errors) = restore_script_mods(registry, game_root, &session.disabled_mods);
+    info!("Safe mode ended: restored {} script mod(s)", restored.len());
+    if !errors.is_empty() {
+        warn!("Safe mode: {} mod(s) could not be re-enabled", errors.len());
+    }
+    change.restored = restored;
+    change.errors.extend(errors);
+}
+
+/// Called by the launcher when the game exits. Counts consecutive crashes, ends a safe-mode
+/// session once its launch is over, and enters safe mode when the policy is automatic.
+#[tauri::command]
+pub async fn record_game_exit(
+    app_handle: AppHandle,
+    game_root_path: String,
+    exit_code: Option<i32>,
+) -> Result<GameExitResult, String> {
+    let settings = AppSettings::load(&app_handle)?;
+    let mut tracker = load_tracker(&app_handle);
+    let mut change = SafeModeChange::default();
+    let abnormal = is_abnormal_exit(exit_code);
+    tracker.last_exit_code = exit_code;
+    tracker.last_exit_timestamp = Some(chrono::Utc::now().timestamp());
+
+    if settings.crash_safe_mode == CrashSafeMode::Off && tracker.safe_mode.is_none() {
+        tracker.consecutive_crashes = 0;
+        save_tracker(&app_handle, &tracker)?;
+        return Ok(GameExitResult {
+            status: status(&tracker, &settings),
+            change,
+        });
+    }
+
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    if tracker.safe_mode.is_some() {
+        // That was the safe-mode launch; either way the scripts go back and counting restarts
+        change.safe_launch_crashed = abnormal;
+        end_session(&mut registry, &game_root, &mut tracker, &mut change);
+        tracker.consecutive_crashes = 0;
+    } else if abnormal {
+        tracker.consecutive_crashes += 1;
+        warn!(
+            "Game exited abnormally (code {:?}), {} crash(es) in a row",
+            exit_code, tracker.consecutive_crashes
+        );
+        if settings.crash_safe_mode == CrashSafeMode::Automatic
+            && tracker.consecutive_crashes >= settings.crash_safe_mode_threshold.max(1)
+        {
+            start_session(&mut registry, &game_root, &mut tracker, true, &mut change);
+        }
+    } else {
+        tracker.consecutive_crashes = 0;
+    }
+
+    if !change.disabled.is_empty() || !change.restored.is_empty() {
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+    }
+    save_tracker(&app_handle, &tracker)?;
+    Ok(GameExitResult {
+        status: status(&tracker, &settings),
+        change,
+    })
+}
+
+#[tauri::command]
+pub async fn get_safe_mode_status(app_handle: AppHandle) -> Result<SafeModeStatus, String> {
+    let settings = AppSettings::load(&app_handle)?;
+    Ok(status(&load_tracker(&app_handle), &settings))
+}
+
+/// Disable script mods for the next launch (what the UI calls when the user accepts the offer)
+#[tauri::command]
+pub async fn enter_safe_mode(
+    app_handle: AppHandle,
+    game_root_path: String,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<SafeModeChange>, String> {
+    let mut tracker = load_tracker(&app_handle);
+    if tracker.safe_mode.is_some() {
+        return Err("Safe mode is already active".to_string());
+    }
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let targets = enabled_script_mods(&registry);
+    if !targets.is_empty() {
+        if let Some(challenge) = check_confirmation(
+            &app_handle,
+            "enter_safe_mode",
+            &targets,
+            &format!("Disable {} script mod(s) for safe mode", targets.len()),
+            confirmation_token.as_deref(),
+        )? {
+            return Ok(Confirmable::ConfirmationRequired(challenge));
+        }
+    }
+    let mut change = SafeModeChange::default();
+    start_session(&mut registry, &game_root, &mut tracker, false, &mut change);
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+    save_tracker(&app_handle, &tracker)?;
+    Ok(Confirmable::Done(change))
+}
+
+/// End safe mode early and put the disabled script mods back
+#[tauri::command]
+pub async fn exit_safe_mode(app_handle: AppHandle, game_root_path: String) -> Result<SafeModeChange, String> {
+    let mut tracker = load_tracker(&app_handle);
+    if tracker.safe_mode.is_none() {
+        return Err("Safe mode is not active".to_string());
+    }
+    let game_root = PathBuf::from(&game_root_path);
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let mut change = SafeModeChange::default();
+    end_session(&mut registry, &game_root, &mut tracker, &mut change);
+    tracker.consecutive_crashes = 0;
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+    save_tracker(&app_handle, &tracker)?;
+    Ok(change)
+}
@@ -0,0 +1,200 @@
+// src-tauri/src/utils/samplemod.rs
+// A tiny autorun script bundled with the manager. New users install it before any real mod to
+// check the whole chain: REFramework loads, autorun scripts run, and the manager's installs
+// land where the game looks. The script leaves a marker in reframework/data when it runs, so
+// the backend can tell whether it actually loaded in game.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::cachethumbs::ThumbnailCleanup;
+use crate::utils::fsops::write_file_atomic;
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::modregistry::{delete_reframework_mod_entry, Mod, ModRegistry, ModType};
+use crate::utils::reframework::{check_reframework, ReframeworkStatus};
+use crate::utils::settings::AppSettings;
+use crate::utils::telemetry::track_result;
+use crate::utils::tempermission::ModOperationGuard;
+
+pub const SAMPLE_MOD_NAME: &str = "FossModManagerSample";
+const SAMPLE_SCRIPT_FILE: &str = "fossmodmanager_sample.lua";
+/// Written by the script through REFramework's fs.write, which is rooted at reframework/data
+const SAMPLE_MARKER_FILE: &str = "reframework/data/fossmodmanager_sample_loaded.txt";
+
+const SAMPLE_SCRIPT: &str = r#"-- FOSS Mod Manager sample mod
+-- Shows that REFramework runs autorun scripts installed by the manager.
+-- Remove it from the manager once you've seen the message.
+local message = "Hello from FOSS Mod Manager! REFramework and autorun scripts are working."
+
+log.info("[FossModManagerSample] " .. message)
+fs.write("fossmodmanager_sample_loaded.txt", message)
+
+re.on_draw_ui(function()
+    imgui.text(message)
+end)
+"#;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SampleModStatus {
+    pub installed: bool,
+    pub enabled: bool,
+    pub loaded_in_game: bool, // The script ran since it was installed
+    pub reframework: ReframeworkStatus,
+}
+
+fn sample_mod_dir(game_root: &Path, settings: &AppSettings) -> PathBuf {
+    settings
+        .install_targets
+        .resolve(game_root, &ModType::REFrameworkAutorun)
+        .join(SAMPLE_MOD_NAME)
+}
+
+/// Write the sample script and register it, enabled. A previous run's marker is cleared so
+/// `loaded_in_game` reflects this install.
+pub(crate) fn install_sample_mod_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    settings: &AppSettings,
+) -> Result<Mod, String> {
+    let mod_dir = sample_mod_dir(game_root, settings);
+    let ledger = LedgerOperation::begin(game_root, "install_sample_mod");
+    let marker = game_root.join(SAMPLE_MARKER_FILE);
+    if marker.is_file() {
+        fs::remove_file(&marker)
+            .map_err(|e| format!("Failed to clear {}: {}", marker.display(), e))?;
+        ledger.deleted(&marker, false);
+    }
+    fs::create_dir_all(&mod_dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", mod_dir.display(), e))?;
+    let script = mod_dir.join(SAMPLE_SCRIPT_FILE);
+    let replaced = script.exists();
+    write_file_atomic(&script, SAMPLE_SCRIPT.as_bytes())?;
+    ledger.wrote(&script, replaced);
+
+    let mut sample = Mod {
+        path: "Bundled sample".to_string(),
+        enabled: true,
+        author: Some("FOSS Mod Manager".to_string()),
+        version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        description: Some(
+            "Prints a hello message in REFramework's script log and UI to show autorun scripts load. \
+             Safe to remove."
+                .to_string(),
+        ),
+        installed_directory: mod_dir
+            .strip_prefix(game_root)
+            .unwrap_or(&mod_dir)
+            .to_string_lossy()
+            .replace('\\', "/"),
+        tags: vec!["sample".to_string()],
+        ..Mod::new(
+            "FOSS Mod Manager sample".to_string(),
+            SAMPLE_MOD_NAME.to_string(),
+            ModType::REFrameworkAutorun,
+            "sample",
+        )
+    };
+    if let Some(existing) = registry.mods.iter().find(|m| m.installed_directory == sample.installed_directory) {
+        sample.id = existing.id.clone();
+    }
+    registry.add_mod(sample.clone());
+    Ok(sample)
+}
+
+/// Delete the sample script, its registry entry and the marker it left
+pub(crate) fn remove_sample_mod_files(
+    registry: &mut ModRegistry,
+    game_root: &Path,
+    thumbnail_cleanup: &ThumbnailCleanup,
+) -> Result<(), String> {
+    if registry.find_mod(SAMPLE_MOD_NAME).is_some() {
+        delete_reframework_mod_entry(registry, game_root, SAMPLE_MOD_NAME, None, thumbnail_cleanup)?;
+    }
+    let marker = game_root.join(SAMPLE_MARKER_FILE);
+    if marker.is_file() {
+        fs::remove_file(&marker)
+            .map_err(|e| format!("Failed to remove {}: {}", marker.display(), e))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn sample_mod_status(
+    registry: &ModRegistry,
+    game_root: &Path,
+    reframework: ReframeworkStatus,
+) -> SampleModStatus {
+    let entry = registry.find_mod(SAMPLE_MOD_NAME);
+    SampleModStatus {
+        installed: entry.is_some(),
+        enabled: entry.is_some_and(|m| m.enabled),
+        loaded_in_game: game_root.join(SAMPLE_MARKER_FILE).is_file(),
+        reframework,
+    }
+}
+
+/// Install the bundled sample script. Needs REFramework in place, since that's what it tests.
+#[tauri::command]
+pub async fn install_sample_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<Mod, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let result = (|| {
+        let status = check_reframework(&app_handle, &game_root).status;
+        if matches!(
+            status,
+            ReframeworkStatus::NotInstalled | ReframeworkStatus::Dinput8Missing
+        ) {
+            return Err(
+                "[E_REFRAMEWORK_MISSING] Install REFramework before the sample mod; it's what the sample checks"
+                    .to_string(),
+            );
+        }
+        ensure_write_access(&app_handle)?;
+        let _in_flight = ModOperationGuard::begin(&app_handle);
+        let settings = AppSettings::load(&app_handle)?;
+        let mut registry = ModRegistry::load(&app_handle)?;
+        let sample = install_sample_mod_files(&mut registry, &game_root, &settings)?;
+        registry.save(&app_handle)?;
+        info!(
+            "Installed the sample mod into {}",
+            sample.installed_directory
+        );
+        Ok(sample)
+    })();
+    track_result(&app_handle, "install_sample_mod", result)
+}
+
+/// Whether the sample mod is installed and has run in game yet
+#[tauri::command]
+pub async fn check_sample_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<SampleModStatus, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let registry = ModRegistry::load(&app_handle)?;
+    let reframework = check_reframework(&app_handle, &game_root).status;
+    Ok(sample_mod_status(&registry, &game_root, reframework))
+}
+
+/// Remove the sample mod in one go; it never goes to the trash
+#[tauri::command]
+pub async fn remove_sample_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<(), String> {
+    let game_root = PathBuf::from(&game_root_path);
+    let result = (|| {
+        ensure_write_access(&app_handle)?;
+        let _in_flight = ModOperationGuard::begin(&app_handle);
+        let mut registry = ModRegistry::load(&app_handle)?;
+        remove_sample_mod_files(&mut registry, &game_root, &app_handle.state::<ThumbnailCleanup>())?;
+        registry.save(&app_handle)?;
+        info!("Removed the sample mod");
+        Ok(())
+    })();
+    track_result(&app_handle, "remove_sample_mod", result)
+}
@@ -0,0 +1,194 @@
+// src-tauri/src/utils/sandbox.rs
+// Sandbox mode: every game-directory path is redirected to a scratch copy of the game
+// folder, with its own mod registry, so installs, deletes and restores can be tried out
+// without touching the real installation.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::utils::config::{ensure_directory_layout, read_game_config_file, GameData};
+use crate::utils::fsops::copy_dir_all;
+use crate::utils::settings::AppSettings;
+use crate::utils::staging::default_staging_root;
+
+/// Written into every sandbox so a real game folder is never mistaken for one
+const SANDBOX_MARKER: &str = ".fossmodmanager-sandbox";
+
+/// Sandbox root while sandbox mode is on, kept in managed state
+pub struct ActiveSandbox(RwLock<Option<PathBuf>>);
+
+impl ActiveSandbox {
+    fn get(&self) -> Option<PathBuf> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set(&self, root: Option<PathBuf>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = root;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SandboxStatus {
+    pub active: bool,
+    pub sandbox_root: Option<String>,
+    pub real_game_root: Option<String>,
+}
+
+/// The active sandbox root, if sandbox mode is on. Always None before `init_sandbox` ran.
+pub fn active_sandbox(app_handle: &AppHandle) -> Option<PathBuf> {
+    app_handle.try_state::<ActiveSandbox>().and_then(|s| s.get())
+}
+
+/// Manage sandbox mode as set in settings; call once at startup
+pub fn init_sandbox(app_handle: &AppHandle) {
+    let settings = AppSettings::load(app_handle).unwrap_or_default();
+    let root = settings
+        .sandbox_enabled
+        .then_some(settings.sandbox_root)
+        .flatten()
+        .map(PathBuf::from)
+        .filter(|root| root.join(SANDBOX_MARKER).is_file());
+    if let Some(root) = &root {
+        info!("Sandbox mode is on, game paths point at {}", root.display());
+    }
+    app_handle.manage(ActiveSandbox(RwLock::new(root)));
+}
+
+/// Point the configured game at the sandbox copy when sandbox mode is on
+pub fn redirect_game_data(app_handle: &AppHandle, real: GameData) -> GameData {
+    let Some(sandbox_root) = active_sandbox(app_handle) else {
+        return real;
+    };
+    GameData {
+        game_executable_path: sandbox_exe_path(&real, &sandbox_root).to_string_lossy().to_string(),
+        game_root_path: sandbox_root.to_string_lossy().to_string(),
+    }
+}
+
+/// Where sandbox-only state (the registry) lives inside the app config dir
+pub fn sandbox_config_dir(config_dir: &Path) -> PathBuf {
+    config_dir.join("sandbox")
+}
+
+/// Build a sandbox from the real game folder: a stub executable, the REFramework install,
+/// empty natives/ and the manager folder layout. Game data itself isn't copied.
+fn create_sandbox(real: &GameData, sandbox_root: &Path) -> Result<(), String> {
+    let real_root = Path::new(&real.game_root_path);
+    if sandbox_root.starts_with(real_root) {
+        return Err("The sandbox can't be inside the real game folder".to_string());
+    }
+    fs::create_dir_all(sandbox_root.join("natives"))
+        .map_err(|e| format!("Failed to create sandbox at {}: {}", sandbox_root.display(), e))?;
+
+    let exe_path = sandbox_exe_path(real, sandbox_root);
+    if let Some(parent) = exe_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+    fs::write(&exe_path, b"")
+        .map_err(|e| format!("Failed to create stub executable {}: {}", exe_path.display(), e))?;
+
+    let dinput = real_root.join("dinput8.dll");
+    if dinput.is_file() {
+        fs::copy(&dinput, sandbox_root.join("dinput8.dll"))
+            .map_err(|e| format!("Failed to copy dinput8.dll into sandbox: {}", e))?;
+    }
+    let framework_dir = real_root.join("reframework");
+    if framework_dir.is_dir() {
+        copy_dir_all(&framework_dir, &sandbox_root.join("reframework"))?;
+    }
+    ensure_directory_layout(sandbox_root, &default_staging_root(sandbox_root))?;
+    fs::write(sandbox_root.join(SANDBOX_MARKER), real.game_root_path.as_bytes())
+        .map_err(|e| format!("Failed to mark sandbox: {}", e))?;
+    info!("Created sandbox at {}", sandbox_root.display());
+    Ok(())
+}
+
+/// The stub executable's location inside a sandbox
+fn sandbox_exe_path(real: &GameData, sandbox_root: &Path) -> PathBuf {
+    let real_root = Path::new(&real.game_root_path);
+    let exe = Path::new(&real.game_executable_path);
+    sandbox_root.join(
+        exe.strip_prefix(real_root)
+            .unwrap_or_else(|_| Path::new(exe.file_name().unwrap_or_default())),
+    )
+}
+
+fn status(app_handle: &AppHandle) -> SandboxStatus {
+    let sandbox = active_sandbox(app_handle);
+    SandboxStatus {
+        active: sandbox.is_some(),
+        sandbox_root: sandbox.map(|p| p.to_string_lossy().to_string()),
+        real_game_root: read_game_config_file(app_handle)
+            .ok()
+            .flatten()
+            .map(|g| g.game_root_path),
+    }
+}
+
+#[tauri::command]
+pub async fn get_sandbox_status(app_handle: AppHandle) -> Result<SandboxStatus, String> {
+    Ok(status(&app_handle))
+}
+
+/// Turn sandbox mode on, creating the sandbox first if needed. `sandbox_path` defaults to
+/// a folder in the app data dir. The frontend should reload the game config afterwards.
+#[tauri::command]
+pub async fn enable_sandbox(
+    app_handle: AppHandle,
+    sandbox_path: Option<String>,
+) -> Result<SandboxStatus, String> {
+    let real = read_game_config_file(&app_handle)?
+        .ok_or_else(|| "Set up the game before using sandbox mode".to_string())?;
+    let mut settings = AppSettings::load(&app_handle)?;
+    let sandbox_root = match sandbox_path.or_else(|| settings.sandbox_root.clone()) {
+        Some(path) => PathBuf::from(path),
+        None => app_handle
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to get app data dir: {}", e))?
+            .join("sandbox")
+            .join(
+                Path::new(&real.game_root_path)
+                    .file_name()
+                    .unwrap_or(OsStr::new("game")),
+            ),
+    };
+
+    if !sandbox_root.join(SANDBOX_MARKER).is_file() {
+        if sandbox_root.is_dir() && fs::read_dir(&sandbox_root).is_ok_and(|mut d| d.next().is_some()) {
+            return Err(format!(
+                "{} isn't empty and isn't a sandbox; pick another folder",
+                sandbox_root.display()
+            ));
+        }
+        create_sandbox(&real, &sandbox_root)?;
+    }
+
+    settings.sandbox_enabled = true;
+    settings.sandbox_root = Some(sandbox_root.to_string_lossy().to_string());
+    settings.save(&app_handle)?;
+    app_handle.state::<ActiveSandbox>().set(Some(sandbox_root));
+
+    let status = status(&app_handle);
+    let _ = app_handle.emit("sandbox-changed", &status);
+    Ok(status)
+}
+
+/// Go back to the real game folder. The sandbox is kept for next time.
+#[tauri::command]
+pub async fn disable_sandbox(app_handle: AppHandle) -> Result<SandboxStatus, String> {
+    let mut settings = AppSettings::load(&app_handle)?;
+    settings.sandbox_enabled = false;
+    settings.save(&app_handle)?;
+    app_handle.state::<ActiveSandbox>().set(None);
+    info!("Sandbox mode off");
+
+    let status = status(&app_handle);
+    let _ = app_handle.emit("sandbox-changed", &status);
+    Ok(status)
+}
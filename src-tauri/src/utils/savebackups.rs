@@ -0,0 +1,352 @@
+// src-tauri/src/utils/savebackups.rs
+// Copies of the game's save data (Steam's userdata/<user>/<appid>/remote folders) taken before
+// enabling mods that can change what ends up in a save: cheats, reward and drop changes,
+// unlocks. Mods are judged by keywords in their name and description unless the user has
+// tagged them "gameplay" or "cosmetic".
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::utils::cloudsync::steam_root_candidates;
+use crate::utils::config::find_steam_app_id;
+use crate::utils::gamelaunch::STEAM_APP_ID;
+use crate::utils::modregistry::{
+    enable_skin_mod_via_registry, toggle_mod_enabled_state, Mod, ModRegistry, SkinEnableResult,
+};
+use crate::utils::settings::AppSettings;
+use crate::utils::walk::Walk;
+
+/// Error code for the warning, so the frontend can offer to back up and enable in one go
+pub const SAVE_BACKUP_RECOMMENDED_CODE: &str = "E_SAVE_BACKUP_RECOMMENDED";
+
+pub const GAMEPLAY_TAG: &str = "gameplay";
+pub const COSMETIC_TAG: &str = "cosmetic";
+
+/// A save backup this recent is good enough; no need to ask again
+const RECENT_BACKUP_SECS: i64 = 12 * 60 * 60;
+
+/// Words in a mod's name or description that suggest it changes gameplay
+const GAMEPLAY_KEYWORDS: &[&str] = &[
+    "cheat",
+    "damage",
+    "infinite",
+    "unlimited",
+    "stamina",
+    "god mode",
+    "invincib",
+    "one shot",
+    "one-shot",
+    "drop rate",
+    "reward",
+    "zenny",
+    "currency",
+    "item box",
+    "unlock",
+    "save edit",
+    "difficulty",
+    "multiplier",
+    "spawn",
+];
+
+/// A save backup archive
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SaveBackup {
+    pub id: String, // Archive file stem, e.g. "saves-20250101-120000"
+    pub created_timestamp: i64,
+    pub size_bytes: u64,
+    pub file_count: usize,
+    pub reason: String, // Why it was taken, e.g. the mod about to be enabled
+}
+
+/// What `enable_with_save_backup` did
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackupAndEnableResult {
+    pub backup: SaveBackup,
+    pub skin_result: Option<SkinEnableResult>, // Set for skin mods, which may report conflicts instead
+}
+
+/// Whether enabling this mod could change the user's saves
+pub fn is_gameplay_affecting(mod_entry: &Mod) -> bool {
+    let has_tag = |tag: &str| mod_entry.tags.iter().any(|t| t.eq_ignore_ascii_case(tag));
+    if has_tag(GAMEPLAY_TAG) {
+        return true;
+    }
+    if has_tag(COSMETIC_TAG) {
+        return false;
+    }
+    let text = format!(
+        "{} {} {}",
+        mod_entry.name,
+        mod_entry.directory_name,
+        mod_entry.description.as_deref().unwrap_or_default()
+    )
+    .to_lowercase();
+    GAMEPLAY_KEYWORDS.iter().any(|k| text.contains(k))
+}
+
+/// The game's save folders, one per Steam user that has played it
+pub fn find_save_dirs(game_root: &Path) -> Vec<PathBuf> {
+    let app_id = find_steam_app_id(game_root).unwrap_or_else(|| STEAM_APP_ID.to_string());
+    let mut dirs: Vec<PathBuf> = steam_root_candidates(game_root)
+        .into_iter()
+        .filter_map(|root| fs::read_dir(root.join("userdata")).ok())
+        .flat_map(|users| users.filter_map(Result::ok))
+        .map(|user| user.path().join(&app_id).join("remote"))
+        .filter(|dir| dir.is_dir())
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
+
+/// Save backups live in app data next to the config backups
+pub fn get_save_backups_dir(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?
+        .join("save_backups");
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create save backups directory {}: {}", dir.display(), e))?;
+    Ok(dir)
+}
+
+fn read_save_backup_info(archive_path: &Path) -> Option<SaveBackup> {
+    let id = archive_path.file_stem()?.to_string_lossy().to_string();
+    let meta = fs::metadata(archive_path).ok()?;
+    let archive = ZipArchive::new(fs::File::open(archive_path).ok()?).ok()?;
+    let created_timestamp = chrono::NaiveDateTime::parse_from_str(id.trim_start_matches("saves-"), "%Y%m%d-%H%M%S")
+        .map(|t| t.and_utc().timestamp())
+        .unwrap_or(0);
+    Some(SaveBackup {
+        reason: String::from_utf8_lossy(archive.comment()).to_string(),
+        file_count: archive.len(),
+        id,
+        created_timestamp,
+        size_bytes: meta.len(),
+    })
+}
+
+/// All save backups in `backups_dir`, newest first
+pub fn list_save_backups_in(backups_dir: &Path) -> Vec<SaveBackup> {
+    let mut backups: Vec<SaveBackup> = fs::read_dir(backups_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|ext| ext == "zip"))
+                .filter_map(|p| read_save_backup_info(&p))
+                .collect()
+        })
+        .unwrap_or_default();
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_timestamp));
+    backups
+}
+
+/// Zip every save folder into `backups_dir`, each under its Steam user id, then drop the
+/// oldest backups beyond `retention`
+pub fn write_save_backup(
+    save_dirs: &[PathBuf],
+    backups_dir: &Path,
+    reason: &str,
+    retention: usize,
+) -> Result<SaveBackup, String> {
+    if save_dirs.is_empty() {
+        return Err("[E_NO_SAVES_FOUND] No save data for the game was found in Steam's userdata folder".to_string());
+    }
+    let id = format!("saves-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S"));
+    let archive_path = backups_dir.join(format!("{}.zip", id));
+
+    let write = || -> Result<usize, String> {
+        let file = fs::File::create(&archive_path)
+            .map_err(|e| format!("Failed to create {}: {}", archive_path.display(), e))?;
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let mut count = 0;
+        for save_dir in save_dirs {
+            // userdata/<user>/<appid>/remote
+            let user = save_dir
+                .parent()
+                .and_then(Path::parent)
+                .and_then(Path::file_name)
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "user".to_string());
+            for entry in Walk::new(save_dir).include_hidden().files() {
+                let Ok(rel) = entry.path().strip_prefix(save_dir) else {
+                    continue;
+                };
+                let name = format!("{}/{}", user, rel.to_string_lossy().replace('\\', "/"));
+                let content = fs::read(entry.path())
+                    .map_err(|e| format!("Failed to read {}: {}", entry.path().display(), e))?;
+                zip.start_file(name.as_str(), options)
+                    .map_err(|e| format!("Failed to add {} to save backup: {}", name, e))?;
+                zip.write_all(&content)
+                    .map_err(|e| format!("Failed to write {} to save backup: {}", name, e))?;
+                count += 1;
+            }
+        }
+        zip.set_comment(reason);
+        zip.finish()
+            .map_err(|e| format!("Failed to finish save backup archive: {}", e))?;
+        Ok(count)
+    };
+    match write() {
+        Ok(0) => {
+            let _ = fs::remove_file(&archive_path);
+            return Err("[E_NO_SAVES_FOUND] The game's save folders are empty".to_string());
+        }
+        Ok(count) => info!("Backed up {} save file(s) to {} ({})", count, archive_path.display(), reason),
+        Err(e) => {
+            let _ = fs::remove_file(&archive_path);
+            return Err(e);
+        }
+    }
+
+    for old in list_save_backups_in(backups_dir).into_iter().skip(retention.max(1)) {
+        let path = backups_dir.join(format!("{}.zip", old.id));
+        match fs::remove_file(&path) {
+            Ok(_) => info!("Rotated out old save backup {}", old.id),
+            Err(e) => warn!("Failed to remove old save backup {}: {}", path.display(), e),
+        }
+    }
+
+    read_save_backup_info(&archive_path).ok_or_else(|| format!("Failed to read back {}", archive_path.display()))
+}
+
+pub fn create_save_backup_for(app_handle: &AppHandle, game_root: &Path, reason: &str) -> Result<SaveBackup, String> {
+    let retention = AppSettings::load(app_handle)?.save_backup_retention as usize;
+    write_save_backup(&find_save_dirs(game_root), &get_save_backups_dir(app_handle)?, reason, retention)
+}
+
+/// Check a mod before enabling it. Gameplay-affecting mods fail with a
+/// SAVE_BACKUP_RECOMMENDED_CODE error unless the saves were backed up recently, the user
+/// already answered (`acknowledged`), or the warning is switched off in the settings.
+pub fn check_save_backup_before_enable(
+    app_handle: &AppHandle,
+    mod_entry: &Mod,
+    acknowledged: bool,
+) -> Result<(), String> {
+    if acknowledged || !is_gameplay_affecting(mod_entry) {
+        return Ok(());
+    }
+    if !AppSettings::load(app_handle)?.warn_before_gameplay_mods {
+        return Ok(());
+    }
+    let now = chrono::Utc::now().timestamp();
+    let recent = get_save_backups_dir(app_handle)
+        .map(|dir| list_save_backups_in(&dir))
+        .unwrap_or_default()
+        .first()
+        .is_some_and(|b| now - b.created_timestamp < RECENT_BACKUP_SECS);
+    if recent {
+        return Ok(());
+    }
+    Err(format!(
+        "[{}] '{}' looks like it changes gameplay and may affect your saves. Back up your saves before enabling it.",
+        SAVE_BACKUP_RECOMMENDED_CODE, mod_entry.name
+    ))
+}
+
+#[tauri::command]
+pub async fn create_save_backup(
+    app_handle: AppHandle,
+    game_root_path: String,
+    reason: Option<String>,
+) -> Result<SaveBackup, String> {
+    let reason = reason.unwrap_or_else(|| "Manual backup".to_string());
+    let result = create_save_backup_for(&app_handle, Path::new(&game_root_path), &reason);
+    crate::utils::telemetry::track_result(&app_handle, "create_save_backup", result)
+}
+
+#[tauri::command]
+pub async fn list_save_backups(app_handle: AppHandle) -> Result<Vec<SaveBackup>, String> {
+    Ok(list_save_backups_in(&get_save_backups_dir(&app_handle)?))
+}
+
+/// Back up the saves, then enable the mod: the answer to a SAVE_BACKUP_RECOMMENDED_CODE error.
+/// Nothing is enabled if the backup fails.
+#[tauri::command]
+pub async fn enable_with_save_backup(
+    app_handle: AppHandle,
+    game_root_path: String,
+    mod_identifier: String,
+    allow_known_broken: Option<bool>,
+) -> Result<BackupAndEnableResult, String> {
+    let registry = ModRegistry::load(&app_handle)?;
+    let (name, skin_path) = if let Some(m) = registry.find_mod(&mod_identifier) {
+        (m.name.clone(), None)
+    } else if let Some(sm) = registry.find_skin_mod(&mod_identifier).or_else(|| {
+        registry.skin_mods.iter().find(|sm| sm.base.path == mod_identifier)
+    }) {
+        (sm.base.name.clone(), Some(sm.base.path.clone()))
+    } else {
+        return Err(format!("Mod '{}' not found in registry", mod_identifier));
+    };
+
+    let backup = create_save_backup_for(&app_handle, Path::new(&game_root_path), &format!("Before enabling {}", name))?;
+    let skin_result = match skin_path {
+        Some(mod_path) => Some(
+            enable_skin_mod_via_registry(
+                app_handle,
+                game_root_path,
+                mod_path,
+                None,
+                allow_known_broken,
+                Some(true),
+            )
+            .await?,
+        ),
+        None => {
+            toggle_mod_enabled_state(
+                app_handle,
+                game_root_path,
+                mod_identifier,
+                true,
+                allow_known_broken,
+                None,
+                Some(true),
+            )
+            .await?;
+            None
+        }
+    };
+    Ok(BackupAndEnableResult { backup, skin_result })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use crate::utils::modregistry::new_skin_mod_from_dir;
+
+    #[test]
+    fn gameplay_mods_are_recognized_and_saves_backed_up() {
+        let game = FakeGame::new();
+        let mut cheat = new_skin_mod_from_dir(&game.mods_dir().join("Infinite Stamina"), "local").base;
+        assert!(is_gameplay_affecting(&cheat));
+        cheat.tags = vec!["Cosmetic".to_string()];
+        assert!(!is_gameplay_affecting(&cheat));
+        let mut armor = new_skin_mod_from_dir(&game.mods_dir().join("Gold Armor"), "local").base;
+        assert!(!is_gameplay_affecting(&armor));
+        armor.tags = vec!["gameplay".to_string()];
+        assert!(is_gameplay_affecting(&armor));
+
+        let save_dir = game.root().join("userdata/12345/2246340/remote/win64_save");
+        fs::create_dir_all(&save_dir).unwrap();
+        fs::write(save_dir.join("data00-1.bin"), b"hunter").unwrap();
+        let backups_dir = game.root().join("save_backups");
+        fs::create_dir_all(&backups_dir).unwrap();
+
+        let backup = write_save_backup(&[save_dir.parent().unwrap().to_path_buf()], &backups_dir, "Before enabling Infinite Stamina", 3)
+            .expect("back up saves");
+        assert_eq!(backup.file_count, 1);
+        assert_eq!(backup.reason, "Before enabling Infinite Stamina");
+        assert_eq!(list_save_backups_in(&backups_dir).len(), 1);
+        assert!(write_save_backup(&[], &backups_dir, "nothing", 3).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+// src-tauri/src/utils/scheduler.rs
+// Runs periodic background jobs (backups, maintenance) on a single thread. Last-run times
+// are persisted so daily/weekly jobs keep their cadence across restarts.
+use log::{error, info, warn};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::settings::AppSettings;
+
+const SCHEDULER_STATE_FILE: &str = "scheduler_state.json";
+
+/// How often the scheduler wakes up to see whether anything is due
+const SCHEDULER_TICK: Duration = Duration::from_secs(15 * 60);
+
+/// A job the scheduler runs whenever its interval has elapsed
+pub struct ScheduledJob {
+    pub name: &'static str,
+    /// Current interval from settings; None means the job is switched off
+    pub interval: fn(&AppSettings) -> Option<Duration>,
+    pub run: fn(&AppHandle) -> Result<(), String>,
+}
+
+fn get_state_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    Ok(data_dir.join(SCHEDULER_STATE_FILE))
+}
+
+/// Job name -> unix timestamp of its last successful run
+fn load_last_runs(app_handle: &AppHandle) -> HashMap<String, i64> {
+    get_state_path(app_handle)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save_last_runs(app_handle: &AppHandle, last_runs: &HashMap<String, i64>) {
+    let result = get_state_path(app_handle).and_then(|path| {
+        let content = serde_json::to_string_pretty(last_runs)
+            .map_err(|e| format!("Failed to serialize scheduler state: {}", e))?;
+        fs::write(&path, content).map_err(|e| format!("Failed to write scheduler state: {}", e))
+    });
+    if let Err(e) = result {
+        warn!("{}", e);
+    }
+}
+
+/// Run every job that is due right now
+fn run_due_jobs(app_handle: &AppHandle, jobs: &[ScheduledJob]) {
+    let settings = AppSettings::load(app_handle).unwrap_or_default();
+    let mut last_runs = load_last_runs(app_handle);
+    let now = chrono::Utc::now().timestamp();
+    let mut changed = false;
+
+    for job in jobs {
+        let Some(interval) = (job.interval)(&settings) else {
+            continue;
+        };
+        let last_run = last_runs.get(job.name).copied().unwrap_or(0);
+        if now - last_run < interval.as_secs() as i64 {
+            continue;
+        }
+        info!("Running scheduled job '{}'", job.name);
+        match (job.run)(app_handle) {
+            Ok(()) => {
+                last_runs.insert(job.name.to_string(), now);
+                changed = true;
+            }
+            Err(e) => error!("Scheduled job '{}' failed: {}", job.name, e),
+        }
+    }
+    if changed {
+        save_last_runs(app_handle, &last_runs);
+    }
+}
+
+/// Start the scheduler thread. Jobs are checked immediately and then every tick.
+pub fn start_scheduler(app_handle: AppHandle, jobs: Vec<ScheduledJob>) {
+    info!("Starting scheduler with {} job(s)", jobs.len());
+    std::thread::spawn(move || loop {
+        run_due_jobs(&app_handle, &jobs);
+        std::thread::sleep(SCHEDULER_TICK);
+    });
+}
@@ -0,0 +1,139 @@
+// src-tauri/src/utils/scriptdata.rs
+// REFramework scripts keep settings and saved state in files they never shipped: under
+// reframework/data/ or in a data folder next to the plugin. Reinstalling or updating a mod
+// replaces its folder wholesale, so files matching the configured patterns are copied aside
+// first and put back wherever the new version doesn't ship a file of its own.
+use log::info;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::fsops::long_path;
+use crate::utils::walk::Walk;
+
+/// Used when settings don't list any patterns of their own
+pub const DEFAULT_SCRIPT_DATA_PATTERNS: &[&str] = &[
+    "reframework/data/{mod}",
+    "reframework/data/{mod}.*",
+    "{mod_dir}/**/data",
+];
+
+/// A pattern as a regex over game-relative paths. `{mod}` is the mod's name and `{mod_dir}`
+/// its install folder; `*` matches within one path segment and `**` across segments.
+/// Everything below a matching folder matches too.
+fn pattern_regex(expanded: &str) -> Result<Regex, String> {
+    let mut regex = String::from("(?i)^");
+    let mut rest = expanded;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**/") {
+            regex.push_str("(?:[^/]*/)*");
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("**") {
+            regex.push_str(".*");
+            rest = after;
+        } else if c == '*' {
+            regex.push_str("[^/]*");
+            rest = &rest[1..];
+        } else {
+            regex.push_str(&regex::escape(&c.to_string()));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    regex.push_str("(?:/.*)?$");
+    Regex::new(&regex).map_err(|e| format!("Invalid script data pattern '{}': {}", expanded, e))
+}
+
+fn expand_pattern(pattern: &str, mod_name: &str, mod_dir: &str) -> String {
+    pattern
+        .replace("{mod_dir}", mod_dir)
+        .replace("{mod}", mod_name)
+        .replace('\\', "/")
+        .trim_matches('/')
+        .to_string()
+}
+
+/// The folder to search for a pattern: its segments up to the first wildcard
+fn pattern_base(expanded: &str) -> String {
+    expanded
+        .split('/')
+        .take_while(|segment| !segment.contains('*'))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Game-relative paths of the files of a script mod that match `patterns`. `mod_dir` is the
+/// mod's install folder relative to the game root.
+pub fn find_script_data(
+    game_root: &Path,
+    patterns: &[String],
+    mod_name: &str,
+    mod_dir: &str,
+) -> Result<Vec<String>, String> {
+    let mut files = Vec::new();
+    for pattern in patterns {
+        let expanded = expand_pattern(pattern, mod_name, mod_dir);
+        if expanded.is_empty() {
+            continue;
+        }
+        let regex = pattern_regex(&expanded)?;
+        let base = game_root.join(pattern_base(&expanded));
+        // A base that is itself a file (no wildcard in the pattern) is walked as one entry
+        for entry in Walk::new(&base).include_hidden().no_follow().files() {
+            let Ok(rel) = entry.path().strip_prefix(game_root) else {
+                continue;
+            };
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            if regex.is_match(&rel) {
+                files.push(rel);
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Copy the given game-relative files into `into`, keeping their layout
+pub(crate) fn snapshot_script_data(
+    game_root: &Path,
+    files: &[String],
+    into: &Path,
+) -> Result<(), String> {
+    for rel in files {
+        let target = into.join(rel);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::copy(long_path(&game_root.join(rel)), &target)
+            .map_err(|e| format!("Failed to keep script data {}: {}", rel, e))?;
+    }
+    Ok(())
+}
+
+/// Put back files from a snapshot that are missing from the game folder now. Files the new
+/// version shipped are left alone; edited configs among them are handled by userconfigs.rs.
+pub(crate) fn restore_script_data(
+    game_root: &Path,
+    files: &[String],
+    from: &Path,
+) -> Result<Vec<String>, String> {
+    let mut restored = Vec::new();
+    for rel in files {
+        let dest = game_root.join(rel);
+        if long_path(&dest).exists() {
+            continue;
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(long_path(parent))
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::copy(from.join(rel), long_path(&dest))
+            .map_err(|e| format!("Failed to restore script data {}: {}", rel, e))?;
+        restored.push(rel.clone());
+    }
+    if !restored.is_empty() {
+        info!("Restored {} script data file(s)", restored.len());
+    }
+    Ok(restored)
+}
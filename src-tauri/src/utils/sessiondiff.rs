@@ -0,0 +1,241 @@
+// src-tauri/src/utils/sessiondiff.rs
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::utils::modregistry::{ModRegistry, ModType};
+use crate::utils::settings::AppSettings;
+use crate::utils::walk::Walk;
+
+const SESSION_SNAPSHOT_FILE: &str = "session_snapshot.json";
+
+/// Cheap content fingerprint of a mod or file: enough to notice external edits without hashing
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct Fingerprint {
+    pub file_count: usize,
+    pub total_bytes: u64,
+    pub latest_modified: u64, // Unix seconds of the newest file
+}
+
+/// What the game directory looked like when the manager last ran
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SessionSnapshot {
+    pub recorded_timestamp: i64,
+    pub game_executable: Option<Fingerprint>,
+    pub mods: BTreeMap<String, Fingerprint>, // Keyed by mod directory relative to the game root
+}
+
+/// Changes made behind the manager's back since the previous session
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SessionChangeSummary {
+    pub first_session: bool,
+    pub previous_session_timestamp: Option<i64>,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<String>,
+    pub game_updated: bool,
+}
+
+impl SessionChangeSummary {
+    pub fn has_changes(&self) -> bool {
+        self.game_updated || !self.added.is_empty() || !self.removed.is_empty() || !self.modified.is_empty()
+    }
+}
+
+/// Summary computed at startup, kept for a frontend that subscribes late
+#[derive(Default)]
+pub struct SessionChanges {
+    startup: OnceCell<SessionChangeSummary>,
+}
+
+/// Register the startup summary slot. Called once during setup.
+pub fn init_session_changes(app_handle: &AppHandle) {
+    app_handle.manage(SessionChanges::default());
+}
+
+fn get_snapshot_path(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager").join(SESSION_SNAPSHOT_FILE)
+}
+
+fn fingerprint_paths<I: IntoIterator<Item = PathBuf>>(paths: I) -> Fingerprint {
+    let mut fingerprint = Fingerprint::default();
+    for path in paths {
+        let Ok(meta) = fs::metadata(&path) else {
+            continue;
+        };
+        if !meta.is_file() {
+            continue;
+        }
+        fingerprint.file_count += 1;
+        fingerprint.total_bytes += meta.len();
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        fingerprint.latest_modified = fingerprint.latest_modified.max(modified);
+    }
+    fingerprint
+}
+
+fn fingerprint_dir(dir: &Path) -> Fingerprint {
+    fingerprint_paths(
+        Walk::new(dir).include_hidden().into_iter().map(|e| e.into_path()),
+    )
+}
+
+/// Fingerprint every REFramework mod folder on disk and every deployed skin mod
+fn take_snapshot(game_root: &Path, executable: &Path, registry: &ModRegistry, settings: &AppSettings) -> SessionSnapshot {
+    let mut mods = BTreeMap::new();
+    for mod_type in [ModType::REFrameworkPlugin, ModType::REFrameworkAutorun] {
+        let dir = settings.install_targets.resolve(game_root, &mod_type);
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let Ok(rel) = path.strip_prefix(game_root) else {
+                continue;
+            };
+            // Toggling a mod isn't an external change, so both states share a key
+            let key = rel.to_string_lossy().replace('\\', "/");
+            let key = key.trim_end_matches(".disabled").to_string();
+            mods.insert(key, fingerprint_dir(&path));
+        }
+    }
+    for skin_mod in registry.skin_mods.iter().filter(|m| m.base.enabled) {
+        let mut files: Vec<PathBuf> = skin_mod.installed_files.iter().map(|f| PathBuf::from(&f.path)).collect();
+        files.extend(skin_mod.installed_pak_path.iter().map(PathBuf::from));
+        mods.insert(format!("skin:{}", skin_mod.base.directory_name), fingerprint_paths(files));
+    }
+
+    SessionSnapshot {
+        recorded_timestamp: chrono::Utc::now().timestamp(),
+        game_executable: executable
+            .is_file()
+            .then(|| fingerprint_paths([executable.to_path_buf()])),
+        mods,
+    }
+}
+
+fn diff_snapshots(previous: Option<&SessionSnapshot>, current: &SessionSnapshot) -> SessionChangeSummary {
+    let Some(previous) = previous else {
+        return SessionChangeSummary {
+            first_session: true,
+            ..Default::default()
+        };
+    };
+    let mut summary = SessionChangeSummary {
+        previous_session_timestamp: Some(previous.recorded_timestamp),
+        game_updated: previous.game_executable.is_some()
+            && current.game_executable.is_some()
+            && previous.game_executable != current.game_executable,
+        ..Default::default()
+    };
+    for (key, fingerprint) in &current.mods {
+        match previous.mods.get(key) {
+            None => summary.added.push(key.clone()),
+            Some(old) if old != fingerprint => summary.modified.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    summary.removed = previous
+        .mods
+        .keys()
+        .filter(|key| !current.mods.contains_key(*key))
+        .cloned()
+        .collect();
+    summary
+}
+
+fn write_snapshot(game_root: &Path, snapshot: &SessionSnapshot) {
+    let snapshot_path = get_snapshot_path(game_root);
+    let write_result = snapshot_path
+        .parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string_pretty(snapshot).map_err(|e| e.to_string()))
+        .and_then(|content| fs::write(&snapshot_path, content).map_err(|e| e.to_string()));
+    if let Err(e) = write_result {
+        warn!("Failed to write session snapshot {}: {}", snapshot_path.display(), e);
+    }
+}
+
+/// Record the state at the end of a session, so changes made through the manager
+/// aren't reported as external next time
+pub fn record_session_snapshot(app_handle: &AppHandle, game_root: &Path, executable: &Path) {
+    let registry = match ModRegistry::load(app_handle) {
+        Ok(registry) => registry,
+        Err(e) => {
+            warn!("Skipping session snapshot, registry unavailable: {}", e);
+            return;
+        }
+    };
+    let settings = AppSettings::load(app_handle).unwrap_or_default();
+    write_snapshot(game_root, &take_snapshot(game_root, executable, &registry, &settings));
+    info!("Recorded end-of-session snapshot");
+}
+
+/// Compare the game directory against the previous session and record the current state
+pub fn check_session_changes(
+    game_root: &Path,
+    executable: &Path,
+    registry: &ModRegistry,
+    settings: &AppSettings,
+) -> SessionChangeSummary {
+    let snapshot_path = get_snapshot_path(game_root);
+    let previous: Option<SessionSnapshot> = fs::read_to_string(&snapshot_path)
+        .ok()
+        .and_then(|c| serde_json::from_str(&c).ok());
+    let current = take_snapshot(game_root, executable, registry, settings);
+    let summary = diff_snapshots(previous.as_ref(), &current);
+    write_snapshot(game_root, &current);
+    summary
+}
+
+/// Run the session diff in the background at startup and emit "session-changes" when
+/// something changed outside the manager
+pub fn spawn_startup_check(app_handle: AppHandle, game_root: PathBuf, executable: PathBuf) {
+    std::thread::spawn(move || {
+        let registry = match ModRegistry::load(&app_handle) {
+            Ok(registry) => registry,
+            Err(e) => {
+                warn!("Skipping session diff, registry unavailable: {}", e);
+                return;
+            }
+        };
+        let settings = AppSettings::load(&app_handle).unwrap_or_default();
+        let summary = check_session_changes(&game_root, &executable, &registry, &settings);
+        info!(
+            "Session diff: {} added, {} removed, {} modified, game updated: {}",
+            summary.added.len(),
+            summary.removed.len(),
+            summary.modified.len(),
+            summary.game_updated
+        );
+        let _ = app_handle.state::<SessionChanges>().startup.set(summary.clone());
+        if summary.has_changes() {
+            if let Err(e) = app_handle.emit("session-changes", &summary) {
+                warn!("Failed to emit session changes: {}", e);
+            }
+        }
+    });
+}
+
+/// Changes detected at startup, or None if the check hasn't finished (or ran without a game)
+#[tauri::command]
+pub async fn get_session_changes(
+    changes: State<'_, SessionChanges>,
+) -> Result<Option<SessionChangeSummary>, String> {
+    Ok(changes.startup.get().cloned())
+}
@@ -0,0 +1,431 @@
+// src-tauri/src/utils/settings.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+use crate::utils::confirmation::{gate_confirmation, Confirmable, ConfirmationState};
+use crate::utils::modregistry::ModType;
+use crate::utils::permissions::CommandTier;
+
+/// Where each kind of mod gets deployed, relative to the game root
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct InstallTargets {
+    pub reframework_plugin: String, // REFramework plugin mods (dll)
+    pub reframework_autorun: String, // REFramework autorun mods (lua)
+    pub skin_pak: String,           // Numbered patch paks ("" = game root)
+    pub natives: String,            // Loose natives files
+    pub natives_platform: String,   // Platform folder the game reads below natives/ ("" = none)
+    pub other: String,              // Anything we can't categorize
+}
+
+/// Platform folders RE Engine games use directly below natives/
+pub const NATIVES_PLATFORM_DIRS: &[&str] = &["STM", "MSG", "X64", "NSW", "PS4", "PS5", "XSX"];
+
+impl Default for InstallTargets {
+    fn default() -> Self {
+        Self {
+            reframework_plugin: "reframework/plugins".to_string(),
+            reframework_autorun: "reframework/autorun".to_string(),
+            skin_pak: "".to_string(),
+            natives: "natives".to_string(),
+            natives_platform: "STM".to_string(),
+            other: "".to_string(),
+        }
+    }
+}
+
+impl InstallTargets {
+    /// Target directory (relative to game root) for a mod type
+    pub fn target_for(&self, mod_type: &ModType) -> &str {
+        match mod_type {
+            ModType::REFrameworkPlugin => &self.reframework_plugin,
+            ModType::REFrameworkAutorun => &self.reframework_autorun,
+            ModType::SkinMod => &self.skin_pak,
+            ModType::NativesMod => &self.natives,
+            ModType::Other => &self.other,
+        }
+    }
+
+    /// Absolute target directory for a mod type
+    pub fn resolve(&self, game_root: &Path, mod_type: &ModType) -> PathBuf {
+        let target = self.target_for(mod_type);
+        if target.is_empty() {
+            game_root.to_path_buf()
+        } else {
+            game_root.join(target)
+        }
+    }
+
+    /// Where a mod file at `rel` (relative to the mod's natives/) belongs below the game's
+    /// natives/. Files under another platform's folder, or missing the platform folder
+    /// altogether, are moved into ours since the game would never read them. The flag is set
+    /// when the path was corrected.
+    pub fn natives_platform_path(&self, rel: &Path) -> (PathBuf, bool) {
+        let platform = self.natives_platform.trim();
+        let mut components = rel.components();
+        let Some(first) = components.next().map(|c| c.as_os_str().to_string_lossy().to_string()) else {
+            return (rel.to_path_buf(), false);
+        };
+        let rest = components.as_path();
+        // Files directly in natives/ aren't platform-specific
+        if platform.is_empty() || first.eq_ignore_ascii_case(platform) || rest.as_os_str().is_empty() {
+            return (rel.to_path_buf(), false);
+        }
+        if NATIVES_PLATFORM_DIRS.iter().any(|p| p.eq_ignore_ascii_case(&first)) {
+            (Path::new(platform).join(rest), true)
+        } else {
+            (Path::new(platform).join(rel), true)
+        }
+    }
+
+    /// Targets must stay inside the game root
+    pub fn validate(&self) -> Result<(), String> {
+        for (label, target) in [
+            ("reframework_plugin", &self.reframework_plugin),
+            ("reframework_autorun", &self.reframework_autorun),
+            ("skin_pak", &self.skin_pak),
+            ("natives", &self.natives),
+            ("other", &self.other),
+        ] {
+            let escapes_root = Path::new(target).components().any(|c| {
+                matches!(c, Component::ParentDir | Component::RootDir | Component::Prefix(_))
+            });
+            if escapes_root {
+                return Err(format!(
+                    "Install target '{}' for {} must be a path relative to the game root",
+                    target, label
+                ));
+            }
+        }
+        let platform = Path::new(self.natives_platform.trim());
+        if platform.components().count() > 1 || !platform.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(format!(
+                "Natives platform '{}' must be a single folder name such as STM",
+                self.natives_platform
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// How skin mods get their files into the game
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum DeploymentMode {
+    #[default]
+    AsShipped, // Deploy whatever the mod contains (paks and/or loose natives)
+    Pak,          // Pack natives-only mods into a patch pak
+    LooseNatives, // Unpack pak-only mods into loose natives (needs a file list)
+}
+
+/// How often config and registry snapshots are taken automatically
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum BackupSchedule {
+    Off,
+    #[default]
+    Daily,
+    Weekly,
+}
+
+/// What to do once the game has crashed `crash_safe_mode_threshold` times in a row
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum CrashSafeMode {
+    #[default]
+    Off,       // Don't track game exits
+    Offer,     // Suggest safe mode to the user
+    Automatic, // Disable script mods for the next launch without asking
+}
+
+/// User-adjustable application settings (settings.json in the app config dir)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct AppSettings {
+    pub install_targets: InstallTargets,
+    pub trash_retention_days: u32, // Deleted mods are kept this long (0 = delete immediately)
+    pub import_external_skin_mods: bool, // Copy external skin mods into staging instead of linking
+    pub fs_worker_count: u32, // Parallel scan/copy/hash workers (0 = pick from CPU count and drive type)
+    pub verify_deployed_files: bool, // Re-hash files after copying them into the game directory
+    pub deployment_mode: DeploymentMode,
+    pub pak_file_list: Option<String>, // Community file list used to name pak entries when unpacking
+    pub require_confirmation: bool, // Destructive commands need a second call with a confirmation token
+    pub backup_schedule: BackupSchedule,
+    pub backup_retention: u32, // Scheduled backups to keep before the oldest is rotated out
+    pub staging_root: Option<String>, // Staged mods/archives folder; None keeps it in the game folder
+    pub feature_flags: BTreeMap<String, bool>, // Experimental toggles by key, see featureflags.rs
+    pub sandbox_enabled: bool, // Redirect game paths to the sandbox copy
+    pub sandbox_root: Option<String>,
+    pub telemetry_enabled: bool, // Opt-in: record anonymized command failures locally
+    pub telemetry_endpoint: Option<String>, // Where submitted failure reports are sent
+    pub disabled_command_tiers: Vec<CommandTier>, // Command classes refused by the backend, see permissions.rs
+    pub restore_points_root: Option<String>, // Restore point snapshots folder; None keeps them in the game folder
+    pub user_agent_overrides: BTreeMap<String, String>, // Integration key -> User-Agent, see httpidentity.rs
+    pub crash_safe_mode: CrashSafeMode, // See safemode.rs
+    pub crash_safe_mode_threshold: u32, // Consecutive crashes before safe mode kicks in
+    pub post_install_hooks_enabled: bool, // Run the hooks below after installs, see posthooks.rs
+    pub post_install_hook: Option<String>, // Program or script run for every installed mod
+    pub mod_post_install_hooks: BTreeMap<String, String>, // Mod directory name -> hook, replacing the global one
+    pub post_install_hook_timeout_secs: u32, // Hooks still running after this are killed
+    pub keep_registry_backup: bool, // Keep the previous mod_registry.json as .bak, used if the file is damaged
+    pub warn_before_gameplay_mods: bool, // Ask for a save backup before enabling mods that change gameplay
+    pub save_backup_retention: u32, // Save backups to keep before the oldest is rotated out
+    pub script_data_patterns: Vec<String>, // Script mod files kept across reinstalls, see scriptdata.rs
+    pub checksum_db_sync: bool, // Opt-in: fetch the community checksum database weekly, see checksumdb.rs
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            install_targets: InstallTargets::default(),
+            trash_retention_days: 14,
+            import_external_skin_mods: false,
+            fs_worker_count: 0,
+            verify_deployed_files: false,
+            deployment_mode: DeploymentMode::default(),
+            pak_file_list: None,
+            require_confirmation: false,
+            backup_schedule: BackupSchedule::default(),
+            backup_retention: 7,
+            staging_root: None,
+            feature_flags: BTreeMap::new(),
+            sandbox_enabled: false,
+            sandbox_root: None,
+            telemetry_enabled: false,
+            telemetry_endpoint: None,
+            disabled_command_tiers: Vec::new(),
+            restore_points_root: None,
+            user_agent_overrides: BTreeMap::new(),
+            crash_safe_mode: CrashSafeMode::default(),
+            crash_safe_mode_threshold: 3,
+            post_install_hooks_enabled: false,
+            post_install_hook: None,
+            mod_post_install_hooks: BTreeMap::new(),
+            post_install_hook_timeout_secs: 300,
+            keep_registry_backup: true,
+            warn_before_gameplay_mods: true,
+            save_backup_retention: 10,
+            script_data_patterns: crate::utils::scriptdata::DEFAULT_SCRIPT_DATA_PATTERNS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            checksum_db_sync: false,
+        }
+    }
+}
+
+/// Upper bound for the automatic worker count; more threads only add disk contention
+const MAX_AUTO_WORKERS: usize = 8;
+
+/// Whether `path` lives on a spinning disk, where parallel IO just causes seeking.
+/// Only detectable on Linux (sysfs); elsewhere we assume solid state.
+#[cfg(target_os = "linux")]
+fn is_rotational_drive(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    let Ok(dev) = fs::metadata(path).map(|m| m.dev()) else {
+        return false;
+    };
+    let major = ((dev >> 8) & 0xfff) | ((dev >> 32) & !0xfff);
+    let minor = (dev & 0xff) | ((dev >> 12) & !0xff);
+    let sys_dir = PathBuf::from(format!("/sys/dev/block/{}:{}", major, minor));
+    // Partitions don't have a queue/ of their own, their parent disk does
+    [sys_dir.join("queue/rotational"), sys_dir.join("../queue/rotational")]
+        .iter()
+        .find_map(|p| fs::read_to_string(p).ok())
+        .is_some_and(|v| v.trim() == "1")
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_rotational_drive(_path: &Path) -> bool {
+    false
+}
+
+impl AppSettings {
+    /// Number of filesystem workers to use for operations on the game directory
+    pub fn worker_count(&self, game_root: &Path) -> usize {
+        if self.fs_worker_count > 0 {
+            return self.fs_worker_count as usize;
+        }
+        if is_rotational_drive(game_root) {
+            return 1;
+        }
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .clamp(1, MAX_AUTO_WORKERS)
+    }
+
+    /// Get the path to the settings file
+    pub fn get_settings_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+        let config_dir = app_handle
+            .path()
+            .app_config_dir()
+            .map_err(|e| format!("Failed to get app config dir: {}", e))?;
+
+        fs::create_dir_all(&config_dir)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+
+        Ok(config_dir.join("settings.json"))
+    }
+
+    /// Load settings from disk, falling back to defaults when missing or unreadable
+    pub fn load(app_handle: &AppHandle) -> Result<Self, String> {
+        let settings_path = Self::get_settings_path(app_handle)?;
+        if !settings_path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&settings_path)
+            .map_err(|e| format!("Failed to read settings: {}", e))?;
+        match serde_json::from_str::<Self>(&content) {
+            Ok(settings) => Ok(settings),
+            Err(e) => {
+                warn!("Failed to parse settings.json: {}. Using defaults.", e);
+                Ok(Self::default())
+            }
+        }
+    }
+
+    /// Save settings to disk
+    pub fn save(&self, app_handle: &AppHandle) -> Result<(), String> {
+        crate::utils::instancelock::ensure_write_access(app_handle)?;
+        let settings_path = Self::get_settings_path(app_handle)?;
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        fs::write(&settings_path, content)
+            .map_err(|e| format!("Failed to write settings: {}", e))?;
+        info!("Successfully saved settings");
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn get_app_settings(app_handle: AppHandle) -> Result<AppSettings, String> {
+    AppSettings::load(&app_handle)
+}
+
+/// Changes that switch a safeguard off or let installs run a program. The settings page can
+/// only make them with a confirmation token, whatever `require_confirmation` says, so a
+/// misbehaving frontend can't quietly lift the protection or point a hook at a file it just
+/// deployed.
+pub(crate) fn safeguard_changes(settings: &AppSettings, current: &AppSettings) -> Vec<String> {
+    let mut changes = Vec::new();
+    if current.require_confirmation && !settings.require_confirmation {
+        changes.push("require_confirmation".to_string());
+    }
+    if crate::utils::posthooks::hooks_widened(settings, current) {
+        changes.push("post_install_hooks".to_string());
+    }
+    changes
+}
+
+/// Check settings coming from outside (the settings page or a setup code) before they are
+/// saved, keeping the fields of `current` that only change through their own commands, which
+/// also move files around. Safeguards stay as they are unless `confirmed`, see
+/// `safeguard_changes`. Everything that writes settings.json from such input goes through here.
+pub(crate) fn checked_for_save(
+    mut settings: AppSettings,
+    current: &AppSettings,
+    confirmed: bool,
+) -> Result<AppSettings, String> {
+    if !confirmed {
+        settings.require_confirmation = settings.require_confirmation || current.require_confirmation;
+        if crate::utils::posthooks::hooks_widened(&settings, current) {
+            settings.post_install_hooks_enabled = current.post_install_hooks_enabled;
+            settings.post_install_hook = current.post_install_hook.clone();
+            settings.mod_post_install_hooks = current.mod_post_install_hooks.clone();
+        }
+    }
+    settings.install_targets.validate()?;
+    crate::utils::httpidentity::validate_user_agent_overrides(&settings.user_agent_overrides)?;
+    crate::utils::posthooks::validate_hooks(&settings)?;
+    settings.staging_root = current.staging_root.clone();
+    settings.sandbox_enabled = current.sandbox_enabled;
+    settings.sandbox_root = current.sandbox_root.clone();
+    settings.restore_points_root = current.restore_points_root.clone();
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn save_app_settings(
+    app_handle: AppHandle,
+    settings: AppSettings,
+    confirmation_token: Option<String>,
+) -> Result<Confirmable<()>, String> {
+    let current = AppSettings::load(&app_handle)?;
+    let safeguards = safeguard_changes(&settings, &current);
+    if !safeguards.is_empty() {
+        if let Some(challenge) = gate_confirmation(
+            &app_handle.state::<ConfirmationState>(),
+            true,
+            "save_app_settings",
+            &safeguards,
+            &format!("Turn off safeguards: {}", safeguards.join(", ")),
+            confirmation_token.as_deref(),
+        )? {
+            return Ok(Confirmable::ConfirmationRequired(challenge));
+        }
+    }
+    let settings = checked_for_save(settings, &current, true)?;
+    settings.save(&app_handle)?;
+    crate::utils::httpidentity::apply_user_agent_overrides(&app_handle, &settings.user_agent_overrides);
+    Ok(Confirmable::Done(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn safeguards_stay_on_without_a_confirmation() {
+        let current = AppSettings {
+            require_confirmation: true,
+            ..AppSettings::default()
+        };
+        let lifted = AppSettings {
+            require_confirmation: false,
+            trash_retention_days: 3,
+            ..AppSettings::default()
+        };
+        assert_eq!(safeguard_changes(&lifted, &current), vec!["require_confirmation".to_string()]);
+        // Turning it on, or leaving it alone, needs nothing
+        assert!(safeguard_changes(&current, &AppSettings::default()).is_empty());
+        assert!(safeguard_changes(&current, &current).is_empty());
+
+        let saved = checked_for_save(lifted.clone(), &current, false).expect("save settings");
+        assert!(saved.require_confirmation);
+        assert_eq!(saved.trash_retention_days, 3);
+        assert!(!checked_for_save(lifted, &current, true).expect("save settings").require_confirmation);
+    }
+
+    #[test]
+    fn hooks_need_a_confirmation_to_run_anything_new() {
+        let game = FakeGame::new();
+        let hook = game.root().join("hook.sh");
+        fs::write(&hook, b"#!/bin/sh\n").unwrap();
+        let hook = hook.to_string_lossy().to_string();
+        let current = AppSettings {
+            post_install_hook: Some(hook.clone()),
+            ..AppSettings::default()
+        };
+        let enabled = AppSettings {
+            post_install_hooks_enabled: true,
+            ..current.clone()
+        };
+        assert_eq!(safeguard_changes(&enabled, &current), vec!["post_install_hooks".to_string()]);
+        let saved = checked_for_save(enabled.clone(), &current, false).expect("save settings");
+        assert!(!saved.post_install_hooks_enabled);
+        assert!(checked_for_save(enabled.clone(), &current, true).expect("save settings").post_install_hooks_enabled);
+
+        // Once on, pointing a mod at another program needs one too; switching off never does
+        let repointed = AppSettings {
+            mod_post_install_hooks: [("SomeMod".to_string(), hook.clone())].into(),
+            ..enabled.clone()
+        };
+        assert_eq!(safeguard_changes(&repointed, &enabled), vec!["post_install_hooks".to_string()]);
+        assert!(checked_for_save(repointed, &enabled, false).expect("save settings").mod_post_install_hooks.is_empty());
+        assert!(safeguard_changes(&current, &enabled).is_empty());
+    }
+}
@@ -0,0 +1,317 @@
+// src-tauri/src/utils/setupcode.rs
+// Portable "setup code": settings plus the modlist, packed into a small (optionally
+// AES-encrypted) zip and base64-encoded so it can be pasted or shown as a QR code.
+use base64::{engine::general_purpose, Engine};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::PathBuf;
+use tauri::AppHandle;
+use zip::write::SimpleFileOptions;
+use zip::{AesMode, ZipArchive, ZipWriter};
+
+use crate::utils::modregistry::ModRegistry;
+use crate::utils::settings::{checked_for_save, AppSettings};
+
+const SETUP_CODE_PREFIX: &str = "FMMSETUP1:";
+const SETUP_CODE_ENTRY: &str = "setup.json";
+const SETUP_CODE_FORMAT_VERSION: u32 = 1;
+
+/// A mod as recorded in a setup code
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SetupCodeMod {
+    #[serde(default)]
+    pub id: String, // Empty in codes made before mods had ids
+    pub name: String,
+    pub directory_name: String,
+    pub is_skin: bool,
+    pub enabled: bool,
+    pub version: Option<String>,
+    pub nexus_mod_id: Option<i64>,
+    pub nexus_file_id: Option<i64>,
+    pub origin_url: Option<String>,
+}
+
+/// Everything needed to bootstrap the same setup elsewhere. The Nexus API key itself is
+/// never included, only whether one was configured.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SetupCode {
+    pub format_version: u32,
+    pub created_timestamp: i64,
+    pub nexus_api_key_configured: bool,
+    pub settings: AppSettings,
+    pub mods: Vec<SetupCodeMod>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SetupCodeExport {
+    pub code: String,
+    pub file_path: Option<String>,
+    pub mod_count: usize,
+}
+
+/// What importing a setup code did and what's left for the user
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SetupImportPlan {
+    pub settings_applied: bool,
+    pub already_installed: Vec<String>,
+    pub to_download: Vec<SetupCodeMod>, // Have a Nexus source; install through the download flow
+    pub unavailable: Vec<SetupCodeMod>, // No known source, must be installed by hand
+    pub nexus_api_key_needed: bool,
+}
+
+/// Copy the settings that belong to one machine from `local` into `shared`. Exports take them
+/// from the defaults so paths, hooks and policy never leave the machine; imports keep this
+/// machine's, so a pasted code can't run programs on install, opt this machine into sending
+/// reports or fetching checksums, change where reports go or which commands are allowed.
+pub(crate) fn with_local_settings(mut shared: AppSettings, local: &AppSettings) -> AppSettings {
+    shared.staging_root = local.staging_root.clone();
+    shared.sandbox_enabled = local.sandbox_enabled;
+    shared.sandbox_root = local.sandbox_root.clone();
+    shared.restore_points_root = local.restore_points_root.clone();
+    shared.telemetry_enabled = local.telemetry_enabled;
+    shared.telemetry_endpoint = local.telemetry_endpoint.clone();
+    shared.checksum_db_sync = local.checksum_db_sync;
+    shared.disabled_command_tiers = local.disabled_command_tiers.clone();
+    shared.require_confirmation = local.require_confirmation;
+    shared.post_install_hooks_enabled = local.post_install_hooks_enabled;
+    shared.post_install_hook = local.post_install_hook.clone();
+    shared.mod_post_install_hooks = local.mod_post_install_hooks.clone();
+    shared.post_install_hook_timeout_secs = local.post_install_hook_timeout_secs;
+    shared
+}
+
+/// Settings from a setup code as they would be saved here, or why they can't be
+pub(crate) fn settings_to_import(imported: AppSettings, current: &AppSettings) -> Result<AppSettings, String> {
+    checked_for_save(with_local_settings(imported, current), current, false)
+}
+
+fn build_setup_code(app_handle: &AppHandle) -> Result<SetupCode, String> {
+    let registry = ModRegistry::load(app_handle)?;
+    let mods = registry
+        .mods
+        .iter()
+        .map(|m| (m, false))
+        .chain(registry.skin_mods.iter().map(|s| (&s.base, true)))
+        .map(|(m, is_skin)| SetupCodeMod {
+            id: m.id.clone(),
+            name: m.name.clone(),
+            directory_name: m.directory_name.clone(),
+            is_skin,
+            enabled: m.enabled,
+            version: m.version.clone(),
+            nexus_mod_id: m.nexus_mod_id,
+            nexus_file_id: m.nexus_file.as_ref().map(|f| f.file_id),
+            origin_url: m.origin_url.clone(),
+        })
+        .collect();
+    Ok(SetupCode {
+        format_version: SETUP_CODE_FORMAT_VERSION,
+        created_timestamp: chrono::Utc::now().timestamp(),
+        nexus_api_key_configured: crate::nexus_api::nexus_api_key_configured(app_handle)?,
+        settings: with_local_settings(AppSettings::load(app_handle)?, &AppSettings::default()),
+        mods,
+    })
+}
+
+fn pack(setup: &SetupCode, passphrase: Option<&str>) -> Result<Vec<u8>, String> {
+    let json = serde_json::to_vec(setup).map_err(|e| format!("Failed to serialize setup code: {}", e))?;
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    if let Some(passphrase) = passphrase.filter(|p| !p.is_empty()) {
+        options = options.with_aes_encryption(AesMode::Aes256, passphrase);
+    }
+    zip.start_file(SETUP_CODE_ENTRY, options)
+        .map_err(|e| format!("Failed to build setup code: {}", e))?;
+    zip.write_all(&json)
+        .map_err(|e| format!("Failed to build setup code: {}", e))?;
+    let cursor = zip
+        .finish()
+        .map_err(|e| format!("Failed to build setup code: {}", e))?;
+    Ok(cursor.into_inner())
+}
+
+fn unpack(bytes: Vec<u8>, passphrase: Option<&str>) -> Result<SetupCode, String> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("Not a valid setup code: {}", e))?;
+    let mut json = Vec::new();
+    let read = match passphrase.filter(|p| !p.is_empty()) {
+        Some(passphrase) => archive
+            .by_name_decrypt(SETUP_CODE_ENTRY, passphrase.as_bytes())
+            .map_err(|e| format!("Wrong passphrase or damaged setup code: {}", e))?
+            .read_to_end(&mut json),
+        None => archive
+            .by_name(SETUP_CODE_ENTRY)
+            .map_err(|e| format!("Setup code is encrypted or damaged: {}", e))?
+            .read_to_end(&mut json),
+    };
+    read.map_err(|e| format!("Wrong passphrase or damaged setup code: {}", e))?;
+    let setup: SetupCode =
+        serde_json::from_slice(&json).map_err(|e| format!("Unreadable setup code: {}", e))?;
+    if setup.format_version > SETUP_CODE_FORMAT_VERSION {
+        return Err(format!(
+            "Setup code was made by a newer version of the manager (format {})",
+            setup.format_version
+        ));
+    }
+    Ok(setup)
+}
+
+/// Export settings and the modlist as a setup code, optionally also written to a file
+#[tauri::command]
+pub async fn export_setup_code(
+    app_handle: AppHandle,
+    passphrase: Option<String>,
+    output_path: Option<String>,
+) -> Result<SetupCodeExport, String> {
+    let setup = build_setup_code(&app_handle)?;
+    let bytes = pack(&setup, passphrase.as_deref())?;
+    if let Some(path) = &output_path {
+        fs::write(path, &bytes).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+    let code = format!("{}{}", SETUP_CODE_PREFIX, general_purpose::URL_SAFE_NO_PAD.encode(&bytes));
+    info!(
+        "Exported setup code with {} mods ({} characters)",
+        setup.mods.len(),
+        code.len()
+    );
+    Ok(SetupCodeExport {
+        code,
+        file_path: output_path,
+        mod_count: setup.mods.len(),
+    })
+}
+
+/// Import a setup code (the text code or a path to an exported file): apply its settings and
+/// work out which mods are already here, which can be downloaded and which can't
+#[tauri::command]
+pub async fn import_setup_code(
+    app_handle: AppHandle,
+    code_or_path: String,
+    passphrase: Option<String>,
+    apply_settings: bool,
+) -> Result<SetupImportPlan, String> {
+    let trimmed = code_or_path.trim();
+    let bytes = match trimmed.strip_prefix(SETUP_CODE_PREFIX) {
+        Some(encoded) => general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|e| format!("Setup code is malformed: {}", e))?,
+        None => fs::read(PathBuf::from(trimmed))
+            .map_err(|e| format!("Not a setup code, and couldn't read it as a file: {}", e))?,
+    };
+    let setup = unpack(bytes, passphrase.as_deref())?;
+
+    let mut settings_applied = false;
+    if apply_settings {
+        match settings_to_import(setup.settings, &AppSettings::load(&app_handle)?) {
+            Ok(settings) => {
+                settings.save(&app_handle)?;
+                crate::utils::httpidentity::apply_user_agent_overrides(&app_handle, &settings.user_agent_overrides);
+                settings_applied = true;
+            }
+            Err(e) => warn!("Not applying imported settings: {}", e),
+        }
+    }
+
+    let registry = ModRegistry::load(&app_handle)?;
+    let mut plan = SetupImportPlan {
+        settings_applied,
+        already_installed: Vec::new(),
+        to_download: Vec::new(),
+        unavailable: Vec::new(),
+        nexus_api_key_needed: false,
+    };
+    for entry in setup.mods {
+        // Codes from before ids existed only have the directory name to go on
+        let key = if entry.id.is_empty() { &entry.directory_name } else { &entry.id };
+        let present = if entry.is_skin {
+            registry.find_skin_mod(key).is_some()
+        } else {
+            registry.find_mod(key).is_some()
+        };
+        if present {
+            plan.already_installed.push(entry.name);
+        } else if entry.nexus_mod_id.is_some() {
+            plan.to_download.push(entry);
+        } else {
+            plan.unavailable.push(entry);
+        }
+    }
+    plan.nexus_api_key_needed =
+        !plan.to_download.is_empty() && !crate::nexus_api::nexus_api_key_configured(&app_handle)?;
+    info!(
+        "Imported setup code: {} installed, {} to download, {} unavailable",
+        plan.already_installed.len(),
+        plan.to_download.len(),
+        plan.unavailable.len()
+    );
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::permissions::CommandTier;
+
+    #[test]
+    fn imported_setup_codes_keep_this_machines_paths_and_policy() {
+        let current = AppSettings {
+            staging_root: Some("/here/staging".to_string()),
+            telemetry_endpoint: Some("https://reports.example/here".to_string()),
+            disabled_command_tiers: vec![CommandTier::Destructive],
+            require_confirmation: true,
+            ..AppSettings::default()
+        };
+        let imported = AppSettings {
+            staging_root: Some("/elsewhere/staging".to_string()),
+            sandbox_enabled: true,
+            sandbox_root: Some("/elsewhere/sandbox".to_string()),
+            restore_points_root: Some("/elsewhere/restore".to_string()),
+            telemetry_enabled: true,
+            telemetry_endpoint: Some("https://reports.example/elsewhere".to_string()),
+            checksum_db_sync: true,
+            disabled_command_tiers: Vec::new(),
+            require_confirmation: false,
+            post_install_hooks_enabled: true,
+            post_install_hook: Some("/elsewhere/payload.sh".to_string()),
+            mod_post_install_hooks: [("SomeMod".to_string(), "/elsewhere/other.sh".to_string())].into(),
+            trash_retention_days: 30,
+            ..AppSettings::default()
+        };
+
+        let applied = settings_to_import(imported.clone(), &current).expect("import settings");
+        assert_eq!(applied.staging_root, current.staging_root);
+        assert!(!applied.sandbox_enabled);
+        assert_eq!(applied.sandbox_root, None);
+        assert_eq!(applied.restore_points_root, None);
+        assert!(!applied.telemetry_enabled);
+        assert_eq!(applied.telemetry_endpoint, current.telemetry_endpoint);
+        assert!(!applied.checksum_db_sync);
+        assert_eq!(applied.disabled_command_tiers, vec![CommandTier::Destructive]);
+        assert!(applied.require_confirmation);
+        assert!(!applied.post_install_hooks_enabled);
+        assert_eq!(applied.post_install_hook, None);
+        assert!(applied.mod_post_install_hooks.is_empty());
+        // Everything else comes across
+        assert_eq!(applied.trash_retention_days, 30);
+
+        // Imports go through the same checks as the settings page
+        let mut bad_agent = imported.clone();
+        bad_agent.user_agent_overrides.insert("not_an_integration".to_string(), "x".to_string());
+        assert!(settings_to_import(bad_agent, &current).is_err());
+        // Hooks are checked on every save, not only from the settings page
+        let bad_hook = AppSettings {
+            post_install_hook: Some("relative/hook.sh".to_string()),
+            ..current.clone()
+        };
+        assert!(checked_for_save(bad_hook, &current, true).is_err());
+
+        // Exports leave them out altogether
+        let exported = with_local_settings(current.clone(), &AppSettings::default());
+        assert_eq!(exported.staging_root, None);
+        assert_eq!(exported.telemetry_endpoint, None);
+        assert!(exported.disabled_command_tiers.is_empty());
+        assert!(!exported.require_confirmation);
+    }
+}
@@ -0,0 +1,166 @@
+// src-tauri/src/utils/staging.rs
+// Where staged mods and retained archives live. Defaults to <game_root>/fossmodmanager, but
+// can be moved to another drive so a small game SSD isn't filled with mod sources.
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::config::rebase_registry;
+use crate::utils::fsops::move_path;
+use crate::utils::modregistry::ModRegistry;
+use crate::utils::settings::AppSettings;
+use crate::utils::trash::{get_trash_dir, rebase_trash_entries};
+
+/// Folders that follow the staging root
+const STAGED_DIRS: &[&str] = &["mods", "archives", "downloads", ".trash"];
+
+/// Custom staging root from settings, kept in managed state; None means inside the game folder
+pub struct StagingRoot(RwLock<Option<PathBuf>>);
+
+impl StagingRoot {
+    fn get(&self) -> Option<PathBuf> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set(&self, root: Option<PathBuf>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = root;
+    }
+}
+
+/// Manage the configured staging root; call once settings are available
+pub fn init_staging_root(app_handle: &AppHandle) {
+    let configured = AppSettings::load(app_handle)
+        .ok()
+        .and_then(|s| s.staging_root)
+        .map(PathBuf::from);
+    if let Some(root) = &configured {
+        info!("Using staging root {}", root.display());
+    }
+    app_handle.manage(StagingRoot(RwLock::new(configured)));
+}
+
+/// Staging root used when none is configured
+pub fn default_staging_root(game_root: &Path) -> PathBuf {
+    game_root.join("fossmodmanager")
+}
+
+/// Folder holding the staged mods and archives for this game. A sandbox always keeps its
+/// own inside the sandbox folder.
+pub fn get_staging_root(app_handle: &AppHandle, game_root: &Path) -> PathBuf {
+    let custom = match crate::utils::sandbox::active_sandbox(app_handle) {
+        Some(_) => None,
+        None => app_handle.state::<StagingRoot>().get(),
+    };
+    custom.unwrap_or_else(|| default_staging_root(game_root))
+}
+
+/// Folder mods are staged in before being deployed
+pub fn get_mods_dir(staging_root: &Path) -> PathBuf {
+    staging_root.join("mods")
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StagingMigrationReport {
+    pub old_root: String,
+    pub new_root: String,
+    pub moved_entries: usize,
+    pub rewritten_paths: usize,
+}
+
+/// Move the staged mods, archives and trash to `new_staging_root` (None for the default inside the
+/// game folder), update registry paths and remember the new location. If a move fails,
+/// whatever was already moved is put back.
+#[tauri::command]
+pub async fn set_staging_root(
+    app_handle: AppHandle,
+    game_root_path: String,
+    new_staging_root: Option<String>,
+) -> Result<StagingMigrationReport, String> {
+    let game_root = PathBuf::from(&game_root_path);
+    if !game_root.is_dir() {
+        return Err(format!("Invalid game root path: {}", game_root_path));
+    }
+    let old_root = get_staging_root(&app_handle, &game_root);
+    let new_root = new_staging_root
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_staging_root(&game_root));
+    let mut report = StagingMigrationReport {
+        old_root: old_root.to_string_lossy().to_string(),
+        new_root: new_root.to_string_lossy().to_string(),
+        moved_entries: 0,
+        rewritten_paths: 0,
+    };
+    if new_root == old_root {
+        return Ok(report);
+    }
+    if STAGED_DIRS
+        .iter()
+        .any(|d| new_root.starts_with(old_root.join(d)) || old_root.starts_with(new_root.join(d)))
+    {
+        return Err(format!(
+            "{} can't be inside the current staging folders (or contain them)",
+            report.new_root
+        ));
+    }
+    info!("Moving staging from {:?} to {:?}", old_root, new_root);
+
+    // Refuse up front rather than half-way through when something is in the way
+    let mut moves = Vec::new();
+    for dir in STAGED_DIRS {
+        let (from_dir, to_dir) = (old_root.join(dir), new_root.join(dir));
+        fs::create_dir_all(&to_dir)
+            .map_err(|e| format!("Failed to create directory {}: {}", to_dir.display(), e))?;
+        let Ok(entries) = fs::read_dir(&from_dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let to = to_dir.join(entry.file_name());
+            if to.exists() {
+                return Err(format!("{} already exists; move or remove it first", to.display()));
+            }
+            moves.push((entry.path(), to));
+        }
+    }
+
+    let mut moved: Vec<&(PathBuf, PathBuf)> = Vec::new();
+    for pair in &moves {
+        if let Err(e) = move_path(&pair.0, &pair.1) {
+            error!("Staging move failed, moving {} entries back: {}", moved.len(), e);
+            for (from, to) in moved.iter().rev() {
+                if let Err(back_err) = move_path(to, from) {
+                    error!("Failed to move {} back: {}", to.display(), back_err);
+                }
+            }
+            return Err(e);
+        }
+        moved.push(pair);
+    }
+    report.moved_entries = moved.len();
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    for dir in STAGED_DIRS {
+        report.rewritten_paths += rebase_registry(&mut registry, &old_root.join(dir), &new_root.join(dir));
+        // Trashed mods are restored to where they were staged, so follow them too
+        report.rewritten_paths +=
+            rebase_trash_entries(&get_trash_dir(&new_root), &old_root.join(dir), &new_root.join(dir))?;
+    }
+    registry.last_updated = chrono::Utc::now().timestamp();
+    registry.save(&app_handle)?;
+
+    let mut settings = AppSettings::load(&app_handle)?;
+    settings.staging_root = new_staging_root;
+    settings.save(&app_handle)?;
+    app_handle
+        .state::<StagingRoot>()
+        .set(settings.staging_root.map(PathBuf::from));
+
+    info!(
+        "Moved {} staged entries to {:?}, rewrote {} registry paths",
+        report.moved_entries, new_root, report.rewritten_paths
+    );
+    Ok(report)
+}
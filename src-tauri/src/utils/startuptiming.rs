@@ -0,0 +1,87 @@
+// src-tauri/src/utils/startuptiming.rs
+// How long each startup phase took, kept in memory for get_backend_info and the health check
+// so a slow start can be pinned on a phase instead of guessed at.
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+/// Starts showing up in the health check past this
+pub const SLOW_STARTUP_MS: u64 = 10_000;
+
+static PROCESS_START: OnceLock<Instant> = OnceLock::new();
+static PHASES: Mutex<Vec<StartupPhase>> = Mutex::new(Vec::new());
+static SETUP_DONE_MS: OnceLock<u64> = OnceLock::new();
+static FRONTEND_READY_MS: OnceLock<u64> = OnceLock::new();
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StartupPhase {
+    pub name: String,
+    pub started_ms: u64, // Since the process started
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StartupTiming {
+    pub phases: Vec<StartupPhase>,
+    pub setup_done_ms: Option<u64>,     // Backend setup finished, since the process started
+    pub frontend_ready_ms: Option<u64>, // The window reported in, since the process started
+}
+
+impl StartupTiming {
+    /// Until the frontend reports in, the backend's part
+    pub fn total_ms(&self) -> Option<u64> {
+        self.frontend_ready_ms.or(self.setup_done_ms)
+    }
+
+    pub fn slowest_phase(&self) -> Option<&StartupPhase> {
+        self.phases.iter().max_by_key(|p| p.duration_ms)
+    }
+}
+
+fn since_start(instant: Instant) -> u64 {
+    let start = *PROCESS_START.get_or_init(Instant::now);
+    instant.saturating_duration_since(start).as_millis() as u64
+}
+
+/// Call first thing in `run`; everything else is measured from here
+pub fn mark_process_start() {
+    PROCESS_START.get_or_init(Instant::now);
+}
+
+/// Run one startup phase and record how long it took
+pub fn time_phase<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    let started = Instant::now();
+    let result = f();
+    let phase = StartupPhase {
+        name: name.to_string(),
+        started_ms: since_start(started),
+        duration_ms: started.elapsed().as_millis() as u64,
+    };
+    if let Ok(mut phases) = PHASES.lock() {
+        phases.push(phase);
+    }
+    result
+}
+
+pub fn mark_setup_done() {
+    let ms = since_start(Instant::now());
+    if SETUP_DONE_MS.set(ms).is_ok() {
+        info!("Backend setup finished {} ms after start", ms);
+    }
+}
+
+pub fn mark_frontend_ready() {
+    let ms = since_start(Instant::now());
+    if FRONTEND_READY_MS.set(ms).is_ok() {
+        info!("Frontend ready {} ms after start", ms);
+    }
+}
+
+pub fn startup_timing() -> StartupTiming {
+    StartupTiming {
+        phases: PHASES.lock().map(|p| p.clone()).unwrap_or_default(),
+        setup_done_ms: SETUP_DONE_MS.get().copied(),
+        frontend_ready_ms: FRONTEND_READY_MS.get().copied(),
+    }
+}
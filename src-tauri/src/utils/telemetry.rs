@@ -0,0 +1,191 @@
+// src-tauri/src/utils/telemetry.rs
+// Opt-in failure diagnostics. Only the command name, a coarse error code, the OS and the app
+// version are kept -- error messages (which contain paths and mod names) never are. Records
+// stay local until the user asks to submit them.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::utils::httpidentity::{http_client, http_identity, Integration};
+use crate::utils::settings::AppSettings;
+
+const TELEMETRY_FILE: &str = "telemetry.json";
+/// Oldest records are dropped past this many
+const MAX_RECORDS: usize = 500;
+const SUBMIT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Serializes read-modify-write of the telemetry file
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FailureRecord {
+    pub command: String,
+    pub error_code: String,
+    pub os: String,
+    pub app_version: String,
+    pub day: String, // YYYY-MM-DD; finer timestamps aren't needed to prioritize
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct TelemetryLog {
+    records: Vec<FailureRecord>,
+}
+
+/// What a submission would send: failure counts per command and error code
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TelemetrySummary {
+    pub enabled: bool,
+    pub os: String,
+    pub app_version: String,
+    pub record_count: usize,
+    pub failures: BTreeMap<String, BTreeMap<String, usize>>, // command -> error code -> count
+}
+
+/// Reduce an error message to a code that can't carry user data
+fn classify_error(error: &str) -> String {
+    // Errors that already carry a code, e.g. "[E_CLOUD_SYNC] ..."
+    if let Some(code) = error
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map(|(code, _)| code)
+        .filter(|code| code.starts_with("E_") && code.chars().all(|c| c.is_ascii_uppercase() || c == '_'))
+    {
+        return code.to_string();
+    }
+    let lower = error.to_lowercase();
+    let classes: &[(&[&str], &str)] = &[
+        (&["permission denied", "access is denied", "os error 5)"], "E_PERMISSION"),
+        (&["being used by another process", "os error 32)", "resource busy"], "E_FILE_LOCKED"),
+        (&["no space left", "not enough space", "os error 112)"], "E_DISK_FULL"),
+        (&["no such file", "cannot find the", "not found"], "E_NOT_FOUND"),
+        (&["already exists"], "E_ALREADY_EXISTS"),
+        (&["invalid zip", "unreadable", "corrupt", "failed to parse"], "E_INVALID_DATA"),
+        (&["timed out", "error sending request", "connection"], "E_NETWORK"),
+        (&["another copy of the mod manager"], "E_OTHER_INSTANCE"),
+        (&["invalid game root", "invalid mod path"], "E_INVALID_PATH"),
+    ];
+    classes
+        .iter()
+        .find(|(needles, _)| needles.iter().any(|n| lower.contains(n)))
+        .map(|(_, code)| code.to_string())
+        .unwrap_or_else(|| "E_OTHER".to_string())
+}
+
+fn get_telemetry_path(app_handle: &AppHandle) -> Result<PathBuf, String> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    Ok(data_dir.join(TELEMETRY_FILE))
+}
+
+fn load_log(app_handle: &AppHandle) -> TelemetryLog {
+    get_telemetry_path(app_handle)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save_log(app_handle: &AppHandle, log: &TelemetryLog) -> Result<(), String> {
+    let path = get_telemetry_path(app_handle)?;
+    let content = serde_json::to_string(log)
+        .map_err(|e| format!("Failed to serialize telemetry: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write telemetry: {}", e))
+}
+
+/// Record a failed command when the user opted in. Returns the result unchanged so it can
+/// wrap the fallible step of a command.
+pub fn track_result<T>(app_handle: &AppHandle, command: &str, result: Result<T, String>) -> Result<T, String> {
+    if let Err(error) = &result {
+        let enabled = AppSettings::load(app_handle).is_ok_and(|s| s.telemetry_enabled);
+        if enabled {
+            let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let mut log = load_log(app_handle);
+            log.records.push(FailureRecord {
+                command: command.to_string(),
+                error_code: classify_error(error),
+                os: std::env::consts::OS.to_string(),
+                app_version: env!("CARGO_PKG_VERSION").to_string(),
+                day: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            });
+            let overflow = log.records.len().saturating_sub(MAX_RECORDS);
+            log.records.drain(..overflow);
+            if let Err(e) = save_log(app_handle, &log) {
+                warn!("{}", e);
+            }
+        }
+    }
+    result
+}
+
+fn summarize(app_handle: &AppHandle) -> TelemetrySummary {
+    let log = load_log(app_handle);
+    let mut failures: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    for record in &log.records {
+        *failures
+            .entry(record.command.clone())
+            .or_default()
+            .entry(record.error_code.clone())
+            .or_default() += 1;
+    }
+    TelemetrySummary {
+        enabled: AppSettings::load(app_handle).is_ok_and(|s| s.telemetry_enabled),
+        os: std::env::consts::OS.to_string(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        record_count: log.records.len(),
+        failures,
+    }
+}
+
+/// Everything that would be submitted, for the user to review
+#[tauri::command]
+pub async fn get_telemetry_summary(app_handle: AppHandle) -> Result<TelemetrySummary, String> {
+    Ok(summarize(&app_handle))
+}
+
+/// Send the summary to the configured endpoint and clear the local records
+#[tauri::command]
+pub async fn submit_telemetry(app_handle: AppHandle) -> Result<usize, String> {
+    let settings = AppSettings::load(&app_handle)?;
+    if !settings.telemetry_enabled {
+        return Err("Error reporting is turned off".to_string());
+    }
+    let endpoint = settings
+        .telemetry_endpoint
+        .ok_or_else(|| "No error reporting endpoint is configured".to_string())?;
+    let summary = summarize(&app_handle);
+    if summary.record_count == 0 {
+        return Ok(0);
+    }
+
+    http_client(&http_identity(&app_handle), Integration::Telemetry)?
+        .post(&endpoint)
+        .timeout(SUBMIT_TIMEOUT)
+        .json(&summary)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to submit error reports: {}", e))?;
+
+    clear_records(&app_handle)?;
+    info!("Submitted {} failure record(s)", summary.record_count);
+    Ok(summary.record_count)
+}
+
+fn clear_records(app_handle: &AppHandle) -> Result<(), String> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    save_log(app_handle, &TelemetryLog::default())
+}
+
+#[tauri::command]
+pub async fn clear_telemetry(app_handle: AppHandle) -> Result<(), String> {
+    clear_records(&app_handle)
+}
@@ -0,0 +1,150 @@
+// src-tauri/src/utils/tempdirs.rs
+// Scratch space for extractions, downloads and archive joins. Each operation gets its own
+// folder under fossmodmanager/tmp that is removed when its guard drops, on success, error or
+// panic alike; folders left behind by a crash are swept on the next start. Keeping it inside
+// the staging root means results can be renamed into place instead of copied.
+use log::{info, warn};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::utils::archives::{get_archives_dir, get_downloads_dir};
+use crate::utils::staging::get_mods_dir;
+
+/// Distinguishes folders created by the same process
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Scratch folders used before operations had their own temp dir, relative to the archives dir
+const LEGACY_SCRATCH_DIRS: &[&str] = &[".joined", ".incoming"];
+
+/// Get the folder operation temp dirs are created in
+pub fn get_temp_root(staging_root: &Path) -> PathBuf {
+    staging_root.join("tmp")
+}
+
+/// A temp folder owned by one operation; it and everything in it are deleted on drop
+#[derive(Debug)]
+pub struct OperationTempDir {
+    path: PathBuf,
+}
+
+impl OperationTempDir {
+    /// Create a fresh folder for `operation` (e.g. "extract", "download")
+    pub fn new(staging_root: &Path, operation: &str) -> Result<Self, String> {
+        let name = format!(
+            "{}-{}-{}",
+            operation,
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = get_temp_root(staging_root).join(name);
+        if path.exists() {
+            fs::remove_dir_all(&path)
+                .map_err(|e| format!("Failed to clear temp folder {}: {}", path.display(), e))?;
+        }
+        fs::create_dir_all(&path)
+            .map_err(|e| format!("Failed to create temp folder {}: {}", path.display(), e))?;
+        Ok(OperationTempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for OperationTempDir {
+    fn drop(&mut self) {
+        if !self.path.exists() {
+            return;
+        }
+        if let Err(e) = fs::remove_dir_all(&self.path) {
+            warn!("Failed to remove temp folder {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Temp folders left by earlier runs, along with the partial downloads and interrupted
+/// extractions older versions scattered around the staging folders
+pub fn find_stale_temp_entries(staging_root: &Path) -> Vec<PathBuf> {
+    let own_prefix = format!("-{}-", std::process::id());
+    let is_ours = |name: &str| name.contains(&own_prefix);
+    let is_partial_download = |name: &str| name.ends_with(".part");
+    let is_interrupted_extraction = |name: &str| name.starts_with('.') && name.ends_with(".extracting");
+
+    let mut stale: Vec<PathBuf> = Vec::new();
+    let mut collect = |dir: PathBuf, matches: &dyn Fn(&str) -> bool| {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            return;
+        };
+        stale.extend(
+            entries
+                .filter_map(Result::ok)
+                .filter(|e| matches(&e.file_name().to_string_lossy()))
+                .map(|e| e.path()),
+        );
+    };
+    collect(get_temp_root(staging_root), &|name| !is_ours(name));
+    collect(get_downloads_dir(staging_root), &is_partial_download);
+    collect(get_mods_dir(staging_root), &is_interrupted_extraction);
+    collect(get_archives_dir(staging_root), &|name| LEGACY_SCRATCH_DIRS.contains(&name));
+    stale
+}
+
+/// Remove what `find_stale_temp_entries` turns up. Only call this while holding the instance
+/// lock, so another instance's work in progress is never touched. Returns how many entries
+/// were removed.
+pub fn sweep_stale_temp_dirs(staging_root: &Path) -> usize {
+    let mut removed = 0;
+    for path in find_stale_temp_entries(staging_root) {
+        let result = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        match result {
+            Ok(()) => removed += 1,
+            Err(e) => warn!("Failed to remove stale temp entry {}: {}", path.display(), e),
+        }
+    }
+    if removed > 0 {
+        info!("Removed {} stale temp file(s) and folder(s) under {}", removed, staging_root.display());
+    }
+    removed
+}
+
+/// Sweep stale temp dirs in the background so startup isn't held up by a large leftover
+pub fn spawn_startup_sweep(staging_root: PathBuf) {
+    std::thread::spawn(move || {
+        sweep_stale_temp_dirs(&staging_root);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn operation_temp_dirs_are_removed_on_drop_and_swept_at_startup() {
+        let game = FakeGame::new();
+        let scratch = OperationTempDir::new(&game.staging_root(), "extract").expect("create temp dir");
+        let scratch_path = scratch.path().to_path_buf();
+        fs::write(scratch_path.join("partial.bin"), b"half").unwrap();
+        drop(scratch);
+        assert!(!scratch_path.exists());
+
+        // Leftovers from a crashed run, next to a temp dir this process is still using
+        let crashed = get_temp_root(&game.staging_root()).join("extract-999999-0");
+        fs::create_dir_all(&crashed).unwrap();
+        let partial = get_downloads_dir(&game.staging_root()).join("Armor.zip.part");
+        fs::create_dir_all(partial.parent().unwrap()).unwrap();
+        fs::write(&partial, b"half").unwrap();
+        let extracting = game.mods_dir().join(".Armor.extracting");
+        fs::create_dir_all(&extracting).unwrap();
+        let live = OperationTempDir::new(&game.staging_root(), "download").expect("create temp dir");
+
+        assert_eq!(sweep_stale_temp_dirs(&game.staging_root()), 3);
+        assert!(!crashed.exists() && !partial.exists() && !extracting.exists());
+        assert!(live.path().is_dir());
+    }
+}
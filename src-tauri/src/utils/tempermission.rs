@@ -1,6 +1,41 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tauri::{ipc::Channel, AppHandle};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tauri::{ipc::Channel, AppHandle, Manager};
+
+/// How many mod operations are writing to the game directory right now
+#[derive(Default)]
+pub struct ModOperations {
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// Register the operation counter. Called once during setup.
+pub fn init_mod_operations(app_handle: &AppHandle) {
+    app_handle.manage(ModOperations::default());
+}
+
+/// Held for as long as a mod operation is writing to the game directory
+pub struct ModOperationGuard(Arc<AtomicUsize>);
+
+impl ModOperationGuard {
+    pub fn begin(app_handle: &AppHandle) -> Self {
+        let in_flight = app_handle.state::<ModOperations>().in_flight.clone();
+        in_flight.fetch_add(1, Ordering::SeqCst);
+        ModOperationGuard(in_flight)
+    }
+}
+
+impl Drop for ModOperationGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Installs, toggles and deploys currently running
+pub fn mod_operations_in_flight(app_handle: &AppHandle) -> usize {
+    app_handle.state::<ModOperations>().in_flight.load(Ordering::SeqCst)
+}
 // Event types for file operations
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "event", content = "data")]
@@ -60,7 +95,9 @@ where
         .map_err(|e| format!("Failed to send start event: {}", e))?;
 
     // 3. Execute the action
+    let in_flight = ModOperationGuard::begin(app_handle);
     let result = action(on_event);
+    drop(in_flight);
 
     // 4. Notify completion
     match &result {
@@ -0,0 +1,67 @@
+// src-tauri/src/utils/throttle.rs
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Minimum gap between two progress events sent to the frontend
+pub const UI_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+/// Minimum progress (as a fraction of the total) worth an event on its own
+pub const UI_PROGRESS_STEP: f64 = 0.01;
+
+struct ThrottleState {
+    last_emit: Option<Instant>,
+    last_fraction: f64,
+}
+
+/// Coalesces progress updates so bulk operations don't flood IPC. An update passes when
+/// enough time has elapsed or enough progress was made since the last one; the first and
+/// the final (done == total) updates always pass. Safe to share between worker threads.
+pub struct ProgressThrottle {
+    min_interval: Duration,
+    min_step: f64,
+    state: Mutex<ThrottleState>,
+}
+
+impl ProgressThrottle {
+    pub fn new(min_interval: Duration, min_step: f64) -> Self {
+        Self {
+            min_interval,
+            min_step,
+            state: Mutex::new(ThrottleState {
+                last_emit: None,
+                last_fraction: 0.0,
+            }),
+        }
+    }
+
+    /// Throttle tuned for progress bars in the UI
+    pub fn for_ui() -> Self {
+        Self::new(UI_PROGRESS_INTERVAL, UI_PROGRESS_STEP)
+    }
+
+    /// Whether progress `done` of `total` should be sent now
+    pub fn should_emit(&self, done: u64, total: u64) -> bool {
+        let fraction = if total == 0 { 1.0 } else { done as f64 / total as f64 };
+        let Ok(mut state) = self.state.lock() else {
+            return true;
+        };
+        let due = match state.last_emit {
+            None => true,
+            Some(_) if done >= total => true,
+            Some(last) => {
+                last.elapsed() >= self.min_interval
+                    || fraction - state.last_fraction >= self.min_step
+            }
+        };
+        if due {
+            state.last_emit = Some(Instant::now());
+            state.last_fraction = fraction;
+        }
+        due
+    }
+}
+
+impl Default for ProgressThrottle {
+    fn default() -> Self {
+        Self::for_ui()
+    }
+}
@@ -0,0 +1,347 @@
+// src-tauri/src/utils/thumbimport.rs
+// Import a folder of preview images named after mods ("Better Camera.png", "better_camera.jpg")
+// as their thumbnails. Names are compared loosely, since image names rarely match a mod's
+// folder exactly; anything that can't be matched with confidence is reported, not guessed.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::cachethumbs::cache_custom_thumbnail;
+use crate::utils::modregistry::ModRegistry;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "gif", "bmp"];
+/// Similarity (0-1) a file name needs to be matched to a mod
+const MIN_MATCH_SCORE: f32 = 0.75;
+/// Trailing words image names often carry that aren't part of the mod name
+const NOISE_SUFFIXES: &[&str] = &["thumbnail", "thumb", "preview", "cover", "icon", "screenshot"];
+/// Score after dropping a noise suffix; anything at or above it is an exact name match
+const NOISE_SUFFIX_WEIGHT: f32 = 0.97;
+
+/// A registry mod as the matcher sees it
+#[derive(Debug, Clone)]
+pub struct ThumbnailCandidate {
+    pub mod_id: String, // Directory name (REFramework) or source path (skin)
+    pub mod_name: String,
+    pub is_skin: bool,
+    pub names: Vec<String>, // Display name, folder name, and variants to compare against
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ThumbnailMatch {
+    pub file: String,
+    pub mod_id: String,
+    pub mod_name: String,
+    pub is_skin: bool,
+    pub score: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnmatchedThumbnail {
+    pub file: String,
+    pub reason: String,
+    pub best_guess: Option<String>, // Closest mod name, for the user to assign by hand
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ThumbnailImportReport {
+    pub matched: Vec<ThumbnailMatch>,
+    pub unmatched: Vec<UnmatchedThumbnail>,
+    pub applied: usize, // 0 on a dry run
+    pub errors: Vec<String>,
+}
+
+/// Lowercase letters and digits only: "Better_Camera v1.2" -> "bettercamerav12"
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// 1.0 for identical normalized names; edit distance scaled by length otherwise. One name
+/// containing the other (e.g. a version number tacked on) counts as a near match.
+fn similarity(a: &str, b: &str) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let longest = a_chars.len().max(b_chars.len());
+    let score = 1.0 - levenshtein(&a_chars, &b_chars) as f32 / longest as f32;
+    let shortest = a_chars.len().min(b_chars.len());
+    if shortest >= 4 && (a.contains(b) || b.contains(a)) {
+        return score.max(0.9 * shortest as f32 / longest as f32 + 0.1).min(0.95);
+    }
+    score
+}
+
+/// Normalized forms of an image's file stem with a weight; dropping a noise suffix costs a
+/// little, so "Better Camera.png" beats "better_camera_preview.png" for the same mod
+fn file_name_variants(stem: &str) -> Vec<(String, f32)> {
+    let mut variants = vec![(normalize(stem), 1.0)];
+    let words: Vec<String> = stem
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.len() > 1 && NOISE_SUFFIXES.contains(&words[words.len() - 1].as_str()) {
+        variants.push((words[..words.len() - 1].concat(), NOISE_SUFFIX_WEIGHT));
+    }
+    variants
+}
+
+/// Every mod in the registry, with skin `_tex`/`_model` halves also known by their shared name
+pub(crate) fn thumbnail_candidates(registry: &ModRegistry) -> Vec<ThumbnailCandidate> {
+    let mut candidates: Vec<ThumbnailCandidate> = registry
+        .mods
+        .iter()
+        .map(|m| ThumbnailCandidate {
+            mod_id: m.directory_name.clone(),
+            mod_name: m.name.clone(),
+            is_skin: false,
+            names: vec![m.name.clone(), m.directory_name.clone()],
+        })
+        .collect();
+    for sm in &registry.skin_mods {
+        let mut names = vec![sm.base.name.clone(), sm.base.directory_name.clone()];
+        let lower = sm.base.directory_name.to_lowercase();
+        for suffix in ["_tex", "_model"] {
+            if lower.ends_with(suffix) {
+                names.push(sm.base.directory_name[..sm.base.directory_name.len() - suffix.len()].to_string());
+            }
+        }
+        candidates.push(ThumbnailCandidate {
+            mod_id: sm.base.path.clone(),
+            mod_name: sm.base.name.clone(),
+            is_skin: true,
+            names,
+        });
+    }
+    candidates
+}
+
+/// Pair image files with mods. An exact name shared by several mods (a skin's `_tex` and
+/// `_model` halves) applies to all of them; any other tie is ambiguous. When several images
+/// match one mod, the closest wins.
+pub(crate) fn match_thumbnails(
+    files: &[PathBuf],
+    candidates: &[ThumbnailCandidate],
+) -> (Vec<ThumbnailMatch>, Vec<UnmatchedThumbnail>) {
+    let normalized: Vec<Vec<String>> = candidates
+        .iter()
+        .map(|c| c.names.iter().map(|n| normalize(n)).collect())
+        .collect();
+    let mut matches: Vec<ThumbnailMatch> = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for file in files {
+        let file_str = file.to_string_lossy().to_string();
+        let stem = file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        let variants = file_name_variants(&stem);
+        let mut scored: Vec<(usize, f32)> = normalized
+            .iter()
+            .enumerate()
+            .map(|(i, names)| {
+                let best = variants
+                    .iter()
+                    .flat_map(|(v, weight)| names.iter().map(move |n| similarity(v, n) * weight))
+                    .fold(0.0f32, f32::max);
+                (i, best)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let Some(&(best, best_score)) = scored.first() else {
+            unmatched.push(UnmatchedThumbnail {
+                file: file_str,
+                reason: "No mods in the library".to_string(),
+                best_guess: None,
+            });
+            continue;
+        };
+        if best_score < MIN_MATCH_SCORE {
+            unmatched.push(UnmatchedThumbnail {
+                file: file_str,
+                reason: "No mod with a similar name".to_string(),
+                best_guess: (best_score > 0.0).then(|| candidates[best].mod_name.clone()),
+            });
+            continue;
+        }
+        let tied: Vec<usize> = scored
+            .iter()
+            .take_while(|(_, score)| best_score - score < 0.02)
+            .map(|(i, _)| *i)
+            .collect();
+        if tied.len() > 1 && best_score < NOISE_SUFFIX_WEIGHT {
+            unmatched.push(UnmatchedThumbnail {
+                file: file_str,
+                reason: format!(
+                    "Matches several mods equally well: {}",
+                    tied.iter().map(|i| candidates[*i].mod_name.as_str()).collect::<Vec<_>>().join(", ")
+                ),
+                best_guess: None,
+            });
+            continue;
+        }
+        for i in tied {
+            let candidate = &candidates[i];
+            match matches.iter().position(|m| m.mod_id == candidate.mod_id) {
+                Some(existing) if matches[existing].score >= best_score => {
+                    unmatched.push(UnmatchedThumbnail {
+                        file: file_str.clone(),
+                        reason: format!("{} matches '{}' more closely", matches[existing].file, candidate.mod_name),
+                        best_guess: Some(candidate.mod_name.clone()),
+                    });
+                }
+                existing => {
+                    let new_match = ThumbnailMatch {
+                        file: file_str.clone(),
+                        mod_id: candidate.mod_id.clone(),
+                        mod_name: candidate.mod_name.clone(),
+                        is_skin: candidate.is_skin,
+                        score: best_score,
+                    };
+                    if let Some(existing) = existing {
+                        let replaced = std::mem::replace(&mut matches[existing], new_match);
+                        unmatched.push(UnmatchedThumbnail {
+                            reason: format!("{} matches '{}' more closely", file_str, candidate.mod_name),
+                            file: replaced.file,
+                            best_guess: Some(candidate.mod_name.clone()),
+                        });
+                    } else {
+                        matches.push(new_match);
+                    }
+                }
+            }
+        }
+    }
+    (matches, unmatched)
+}
+
+/// Image files directly inside `folder`
+fn list_images(folder: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut images: Vec<PathBuf> = fs::read_dir(folder)
+        .map_err(|e| format!("Failed to read {}: {}", folder.display(), e))?
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| {
+            p.is_file()
+                && p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        })
+        .collect();
+    images.sort();
+    Ok(images)
+}
+
+/// Match the images in `folder` to mods by file name and use them as thumbnails. A dry run
+/// only reports the matches, so the UI can show them for confirmation first.
+#[tauri::command]
+pub async fn import_thumbnails(
+    app_handle: AppHandle,
+    folder: String,
+    dry_run: Option<bool>,
+) -> Result<ThumbnailImportReport, String> {
+    let folder = PathBuf::from(&folder);
+    let images = list_images(&folder)?;
+    let mut registry = ModRegistry::load(&app_handle)?;
+    let (matched, unmatched) = match_thumbnails(&images, &thumbnail_candidates(&registry));
+    let mut report = ThumbnailImportReport {
+        matched,
+        unmatched,
+        ..Default::default()
+    };
+    if dry_run.unwrap_or(false) {
+        return Ok(report);
+    }
+
+    for m in &report.matched {
+        let mod_key = format!("{}:{}", if m.is_skin { "skin" } else { "reframework" }, m.mod_id);
+        let cached = match cache_custom_thumbnail(&app_handle, Path::new(&m.file), &mod_key) {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(e) => {
+                warn!("Skipping thumbnail {}: {}", m.file, e);
+                report.errors.push(e);
+                continue;
+            }
+        };
+        let target = if m.is_skin {
+            registry.skin_mods.iter_mut().find(|sm| sm.base.path == m.mod_id).map(|sm| &mut sm.base)
+        } else {
+            registry.find_mod_mut(&m.mod_id)
+        };
+        if let Some(mod_entry) = target {
+            mod_entry.custom_thumbnail = Some(cached);
+            report.applied += 1;
+        }
+    }
+    if report.applied > 0 {
+        registry.last_updated = chrono::Utc::now().timestamp();
+        registry.save(&app_handle)?;
+    }
+    info!(
+        "Imported {} thumbnail(s) from {} ({} unmatched, {} error(s))",
+        report.applied,
+        folder.display(),
+        report.unmatched.len(),
+        report.errors.len()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn thumbnail_import_matches_loose_file_names() {
+        let candidate = |id: &str, names: &[&str], is_skin: bool| ThumbnailCandidate {
+            mod_id: id.to_string(),
+            mod_name: names[0].to_string(),
+            is_skin,
+            names: names.iter().map(|n| n.to_string()).collect(),
+        };
+        let candidates = vec![
+            candidate("BetterCamera", &["Better Camera", "BetterCamera"], false),
+            candidate("/skins/Armor_tex", &["Armor_tex", "Armor_tex", "Armor"], true),
+            candidate("/skins/Armor_model", &["Armor_model", "Armor_model", "Armor"], true),
+        ];
+        let files: Vec<std::path::PathBuf> = ["better_camera_preview.png", "armor.jpg", "Better Camera.webp", "sunset.png"]
+            .iter()
+            .map(|f| Path::new("/imports").join(f))
+            .collect();
+        let (matched, unmatched) = match_thumbnails(&files, &candidates);
+
+        let matched_ids: HashMap<&str, &str> = matched
+            .iter()
+            .map(|m| (m.mod_id.as_str(), m.file.rsplit('/').next().unwrap()))
+            .collect();
+        // Both skin halves share the image; the exact name beats the one with a noise suffix
+        assert_eq!(matched_ids.get("/skins/Armor_tex"), Some(&"armor.jpg"));
+        assert_eq!(matched_ids.get("/skins/Armor_model"), Some(&"armor.jpg"));
+        assert_eq!(matched_ids.get("BetterCamera"), Some(&"Better Camera.webp"));
+        let unmatched_files: Vec<&str> = unmatched.iter().map(|u| u.file.rsplit('/').next().unwrap()).collect();
+        assert!(unmatched_files.contains(&"better_camera_preview.png"));
+        assert!(unmatched_files.contains(&"sunset.png"));
+    }
+}
@@ -0,0 +1,303 @@
+// src-tauri/src/utils/trash.rs
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use crate::utils::auditlog::LedgerOperation;
+use crate::utils::config::rebase_registry;
+use crate::utils::fsops::move_path;
+use crate::utils::modregistry::{Mod, ModRegistry, SkinMod};
+use crate::utils::settings::AppSettings;
+use crate::utils::staging::get_staging_root;
+
+const TRASH_ENTRY_FILE: &str = "entry.json";
+
+/// Registry entry saved alongside trashed files so it can be restored
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "kind", content = "entry")]
+pub enum TrashedRegistryEntry {
+    Mod(Mod),
+    Skin(SkinMod),
+}
+
+/// A single file or directory moved into the trash
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrashedItem {
+    pub trashed_name: String,  // Name inside the trash entry folder
+    pub original_path: String, // Absolute path it was moved from
+}
+
+/// Manifest of a deleted mod in <staging root>/.trash/<id>/
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrashEntry {
+    pub id: String,
+    pub deleted_timestamp: i64,
+    pub mod_name: String,
+    pub items: Vec<TrashedItem>,
+    pub registry_entry: TrashedRegistryEntry,
+}
+
+/// Get the trash directory, which sits in the staging root next to the mods it holds
+pub fn get_trash_dir(staging_root: &Path) -> PathBuf {
+    staging_root.join(".trash")
+}
+
+/// Where deletes go: the trash, or None when trash retention is off and deletes are permanent
+pub fn trash_dir_for_deletes(app_handle: &AppHandle, game_root: &Path) -> Result<Option<PathBuf>, String> {
+    let soft_delete = AppSettings::load(app_handle)?.trash_retention_days > 0;
+    Ok(soft_delete.then(|| get_trash_dir(&get_staging_root(app_handle, game_root))))
+}
+
+/// Move the given paths into a new trash entry in `trash_dir` and record how to restore them
+pub fn move_to_trash(
+    game_root: &Path,
+    trash_dir: &Path,
+    mod_name: &str,
+    registry_entry: TrashedRegistryEntry,
+    paths: &[PathBuf],
+) -> Result<TrashEntry, String> {
+    let base_id = chrono::Utc::now().format("%Y%m%d-%H%M%S%3f").to_string();
+    let mut id = base_id.clone();
+    let mut suffix = 1;
+    while trash_dir.join(&id).exists() {
+        id = format!("{}-{}", base_id, suffix);
+        suffix += 1;
+    }
+    let entry_dir = trash_dir.join(&id);
+    fs::create_dir_all(&entry_dir)
+        .map_err(|e| format!("Failed to create trash entry {}: {}", entry_dir.display(), e))?;
+
+    let ledger = LedgerOperation::begin(game_root, "move_to_trash");
+    let mut items = Vec::new();
+    for (index, path) in paths.iter().enumerate() {
+        if !path.exists() {
+            continue;
+        }
+        let trashed_name = format!("{}", index);
+        info!("Moving {} to trash entry {}", path.display(), id);
+        let is_dir = path.is_dir();
+        move_path(path, &entry_dir.join(&trashed_name))?;
+        ledger.deleted(path, is_dir);
+        items.push(TrashedItem {
+            trashed_name,
+            original_path: path.to_string_lossy().to_string(),
+        });
+    }
+
+    let entry = TrashEntry {
+        id,
+        deleted_timestamp: chrono::Utc::now().timestamp(),
+        mod_name: mod_name.to_string(),
+        items,
+        registry_entry,
+    };
+    write_trash_entry(&entry_dir, &entry)?;
+    Ok(entry)
+}
+
+fn write_trash_entry(entry_dir: &Path, entry: &TrashEntry) -> Result<(), String> {
+    let manifest = serde_json::to_string_pretty(entry)
+        .map_err(|e| format!("Failed to serialize trash entry: {}", e))?;
+    fs::write(entry_dir.join(TRASH_ENTRY_FILE), manifest)
+        .map_err(|e| format!("Failed to write trash manifest: {}", e))
+}
+
+/// Read every trash entry manifest, newest first
+pub fn read_trash_entries(trash_dir: &Path) -> Vec<TrashEntry> {
+    let mut entries = Vec::new();
+    let Ok(dir) = fs::read_dir(trash_dir) else {
+        return entries;
+    };
+    for dir_entry in dir.filter_map(Result::ok) {
+        let manifest_path = dir_entry.path().join(TRASH_ENTRY_FILE);
+        match fs::read_to_string(&manifest_path)
+            .map_err(|e| e.to_string())
+            .and_then(|c| serde_json::from_str::<TrashEntry>(&c).map_err(|e| e.to_string()))
+        {
+            Ok(entry) => entries.push(entry),
+            Err(e) => warn!("Skipping unreadable trash entry {}: {}", manifest_path.display(), e),
+        }
+    }
+    entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_timestamp));
+    entries
+}
+
+/// Re-root the original paths of trashed items (and their registry entries) that were under
+/// `old_root`, once the staging folders have moved. Returns how many paths changed.
+pub fn rebase_trash_entries(trash_dir: &Path, old_root: &Path, new_root: &Path) -> Result<usize, String> {
+    let mut count = 0;
+    for mut entry in read_trash_entries(trash_dir) {
+        let mut changed = 0;
+        for item in &mut entry.items {
+            if let Ok(rel) = Path::new(&item.original_path).strip_prefix(old_root) {
+                item.original_path = new_root.join(rel).to_string_lossy().to_string();
+                changed += 1;
+            }
+        }
+        // Reuse the registry rebase on a registry holding just this entry
+        let mut registry = ModRegistry::default();
+        match entry.registry_entry.clone() {
+            TrashedRegistryEntry::Mod(m) => registry.mods.push(m),
+            TrashedRegistryEntry::Skin(sm) => registry.skin_mods.push(sm),
+        }
+        changed += rebase_registry(&mut registry, old_root, new_root);
+        if changed == 0 {
+            continue;
+        }
+        entry.registry_entry = match registry.skin_mods.pop() {
+            Some(sm) => TrashedRegistryEntry::Skin(sm),
+            None => TrashedRegistryEntry::Mod(registry.mods.remove(0)),
+        };
+        write_trash_entry(&trash_dir.join(&entry.id), &entry)?;
+        count += changed;
+    }
+    Ok(count)
+}
+
+/// Permanently remove trash entries older than the retention period
+pub fn purge_expired_trash(trash_dir: &Path, retention_days: u32) -> usize {
+    let cutoff = chrono::Utc::now().timestamp() - i64::from(retention_days) * 24 * 60 * 60;
+    let mut purged = 0;
+    for entry in read_trash_entries(trash_dir) {
+        if entry.deleted_timestamp >= cutoff {
+            continue;
+        }
+        let entry_dir = trash_dir.join(&entry.id);
+        match fs::remove_dir_all(&entry_dir) {
+            Ok(_) => {
+                info!("Purged expired trash entry {} ({})", entry.id, entry.mod_name);
+                purged += 1;
+            }
+            Err(e) => warn!("Failed to purge trash entry {}: {}", entry_dir.display(), e),
+        }
+    }
+    purged
+}
+
+#[tauri::command]
+pub async fn list_deleted_mods(
+    app_handle: AppHandle,
+    game_root_path: String,
+) -> Result<Vec<TrashEntry>, String> {
+    let trash_dir = get_trash_dir(&get_staging_root(&app_handle, Path::new(&game_root_path)));
+    let settings = AppSettings::load(&app_handle)?;
+    purge_expired_trash(&trash_dir, settings.trash_retention_days);
+    Ok(read_trash_entries(&trash_dir))
+}
+
+#[tauri::command]
+pub async fn restore_deleted_mod(
+    app_handle: AppHandle,
+    game_root_path: String,
+    trash_id: String,
+) -> Result<(), String> {
+    info!("Restoring deleted mod from trash entry {}", trash_id);
+    let game_root = PathBuf::from(&game_root_path);
+    let trash_dir = get_trash_dir(&get_staging_root(&app_handle, &game_root));
+    let entry = read_trash_entries(&trash_dir)
+        .into_iter()
+        .find(|e| e.id == trash_id)
+        .ok_or_else(|| format!("Trash entry '{}' not found", trash_id))?;
+    let entry_dir = trash_dir.join(&entry.id);
+
+    // Refuse to clobber anything that has appeared at the original location since
+    if let Some(item) = entry.items.iter().find(|i| Path::new(&i.original_path).exists()) {
+        return Err(format!(
+            "Cannot restore '{}': {} already exists",
+            entry.mod_name, item.original_path
+        ));
+    }
+
+    let mut registry = ModRegistry::load(&app_handle)?;
+    match &entry.registry_entry {
+        TrashedRegistryEntry::Mod(m) => {
+            if registry.find_mod(&m.directory_name).is_some() {
+                return Err(format!("A mod named '{}' is already installed", m.directory_name));
+            }
+        }
+        TrashedRegistryEntry::Skin(sm) => {
+            if registry.find_skin_mod(&sm.base.directory_name).is_some() {
+                return Err(format!(
+                    "A skin mod named '{}' is already installed",
+                    sm.base.directory_name
+                ));
+            }
+        }
+    }
+
+    let ledger = LedgerOperation::begin(&game_root, "restore_deleted_mod");
+    for item in &entry.items {
+        move_path(&entry_dir.join(&item.trashed_name), Path::new(&item.original_path))?;
+        ledger.wrote(Path::new(&item.original_path), false);
+    }
+
+    match entry.registry_entry {
+        TrashedRegistryEntry::Mod(mut m) => {
+            // The enabled state follows whichever directory came back
+            m.enabled = entry
+                .items
+                .iter()
+                .any(|i| !i.original_path.ends_with(".disabled"));
+            registry.add_mod(m);
+        }
+        TrashedRegistryEntry::Skin(mut sm) => {
+            // Installed game files were removed on delete, so it comes back disabled
+            sm.base.enabled = false;
+            sm.installed_files.clear();
+            sm.installed_pak_path = None;
+            registry.add_skin_mod(sm);
+        }
+    }
+    registry.save(&app_handle)?;
+
+    if let Err(e) = fs::remove_dir_all(&entry_dir) {
+        warn!("Failed to clean up trash entry {}: {}", entry_dir.display(), e);
+    }
+    info!("Restored '{}' from trash", entry.mod_name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use crate::utils::modregistry::new_skin_mod_from_dir;
+
+    #[test]
+    fn trashed_mods_follow_the_staging_folder_when_it_moves() {
+        let game = FakeGame::new();
+        let mut registry = ModRegistry::default();
+        let path = game.install_skin(&mut registry, "TrashedSkin", b"trashed");
+        let old_staging = game.staging_root();
+        let trash_dir = get_trash_dir(&old_staging);
+        let skin = new_skin_mod_from_dir(Path::new(&path), "local");
+        let entry = move_to_trash(
+            game.root(),
+            &trash_dir,
+            "TrashedSkin",
+            TrashedRegistryEntry::Skin(skin),
+            &[PathBuf::from(&path)],
+        )
+        .expect("move to trash");
+        assert!(!Path::new(&path).exists());
+
+        // The staging move carries the entry folder over, then the manifest is rebased
+        let new_staging = game.root().join("elsewhere");
+        let new_trash_dir = get_trash_dir(&new_staging);
+        fs::create_dir_all(&new_trash_dir).unwrap();
+        move_path(&trash_dir.join(&entry.id), &new_trash_dir.join(&entry.id)).unwrap();
+        let rebased = rebase_trash_entries(&new_trash_dir, &old_staging.join("mods"), &new_staging.join("mods"))
+            .expect("rebase trash");
+        assert!(rebased >= 2);
+
+        let moved = read_trash_entries(&new_trash_dir).remove(0);
+        let expected = new_staging.join("mods").join("TrashedSkin");
+        assert_eq!(Path::new(&moved.items[0].original_path), expected);
+        match moved.registry_entry {
+            TrashedRegistryEntry::Skin(sm) => assert_eq!(Path::new(&sm.base.path), expected),
+            TrashedRegistryEntry::Mod(_) => panic!("expected a skin entry"),
+        }
+    }
+}
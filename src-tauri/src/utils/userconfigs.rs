@@ -0,0 +1,313 @@
+// src-tauri/src/utils/userconfigs.rs
+// Script mods keep their settings in .json/.lua files next to the script, and users edit them.
+// Reinstalling or updating such a mod used to overwrite those edits. A file counts as edited
+// when its hash no longer matches the one the audit ledger recorded when the manager wrote it;
+// edited files are carried over into the new copy (JSON gets the new keys merged in) and the
+// shipped version is kept beside it as <file>.new until the user picks one.
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+use crate::utils::auditlog::{read_ledger, LedgerAction, LedgerOperation};
+use crate::utils::fsops::{move_path, write_file_atomic};
+use crate::utils::hashing::hash_file;
+use crate::utils::instancelock::ensure_write_access;
+use crate::utils::walk::Walk;
+
+/// Extensions of files users are expected to edit by hand
+const CONFIG_EXTENSIONS: &[&str] = &["json", "lua", "ini", "cfg", "toml", "yaml", "yml"];
+
+/// Suffix of the shipped version kept next to a preserved file
+pub const NEW_VERSION_SUFFIX: &str = ".new";
+
+/// What to do with a file the user edited when the mod ships a different version of it
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ConfigFileChoice {
+    KeepMine, // Keep the edited file, drop the shipped one
+    UseNew,   // Replace the edited file with the shipped one
+    Merge,    // JSON only: the shipped file with the user's values laid over it
+}
+
+/// An edited file that survived a reinstall
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PreservedConfig {
+    pub path: String,                     // Relative to the game root, '/' separators
+    pub applied: ConfigFileChoice,        // KeepMine or Merge, whichever was done on install
+    pub new_version_path: Option<String>, // Shipped version awaiting a choice; None if it had no changes
+    pub can_merge: bool,                  // Both versions are JSON objects
+    #[serde(skip)]
+    pub shipped_sha256: Option<String>, // Recorded in the ledger so the file still counts as edited next time
+}
+
+#[derive(Debug, Serialize, Clone)]
+struct PreservedConfigsEvent {
+    mod_name: String,
+    files: Vec<PreservedConfig>,
+}
+
+pub fn is_config_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| CONFIG_EXTENSIONS.iter().any(|c| c.eq_ignore_ascii_case(e)))
+}
+
+/// The newest hash the ledger holds for each game-relative file path
+pub fn recorded_hashes(game_root: &Path) -> Result<HashMap<String, String>, String> {
+    let mut hashes = HashMap::new();
+    for entry in read_ledger(game_root)? {
+        if matches!(entry.action, LedgerAction::Created | LedgerAction::Modified) {
+            if let Some(sha256) = entry.sha256 {
+                hashes.insert(entry.path, sha256);
+            }
+        }
+    }
+    Ok(hashes)
+}
+
+fn game_relative(game_root: &Path, path: &Path) -> Option<String> {
+    path.strip_prefix(game_root)
+        .ok()
+        .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+}
+
+fn new_version_of(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(NEW_VERSION_SUFFIX);
+    path.with_file_name(name)
+}
+
+fn read_json_object(path: &Path) -> Option<Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str::<Value>(&content).ok().filter(Value::is_object)
+}
+
+/// Lay `user` over `shipped`: keys only the new version has are added, every value the user
+/// has wins, nested objects are merged the same way
+pub fn merge_json(shipped: &Value, user: &Value) -> Value {
+    match (shipped, user) {
+        (Value::Object(shipped), Value::Object(user)) => {
+            let mut merged = shipped.clone();
+            for (key, user_value) in user {
+                let value = match shipped.get(key) {
+                    Some(shipped_value) => merge_json(shipped_value, user_value),
+                    None => user_value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Object(merged)
+        }
+        (_, user) => user.clone(),
+    }
+}
+
+fn write_merged(shipped: &Value, user: &Value, dest: &Path) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(&merge_json(shipped, user))
+        .map_err(|e| format!("Failed to serialize merged {}: {}", dest.display(), e))?;
+    write_file_atomic(dest, content.as_bytes())
+}
+
+/// Before `installed_dir` is replaced by `staged_dir`, copy the user's edited config files
+/// into the staged copy. Files the manager never recorded a hash for are left to be replaced,
+/// as before.
+pub fn carry_over_user_edits(
+    game_root: &Path,
+    installed_dir: &Path,
+    staged_dir: &Path,
+) -> Result<Vec<PreservedConfig>, String> {
+    if !installed_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let recorded = recorded_hashes(game_root)?;
+    let mut preserved = Vec::new();
+
+    for entry in Walk::new(installed_dir).include_hidden().no_follow().files() {
+        let installed = entry.path();
+        if !is_config_file(installed) {
+            continue;
+        }
+        let Some(rel) = game_relative(game_root, installed) else {
+            continue;
+        };
+        let Some(recorded_sha) = recorded.get(&rel) else {
+            continue;
+        };
+        let current_sha = hash_file(installed)?;
+        if &current_sha == recorded_sha {
+            continue;
+        }
+
+        let Ok(rel_in_mod) = installed.strip_prefix(installed_dir) else {
+            continue;
+        };
+        let staged = staged_dir.join(rel_in_mod);
+        let shipped_sha = if staged.is_file() { Some(hash_file(&staged)?) } else { None };
+        if shipped_sha.as_ref() == Some(&current_sha) {
+            continue; // The new version is exactly what the user had
+        }
+        // Only offer a choice when the mod actually changed the file
+        let offers_new_version = shipped_sha.as_ref().is_some_and(|sha| sha != recorded_sha);
+
+        let shipped_json = shipped_sha.as_ref().and_then(|_| read_json_object(&staged));
+        let user_json = read_json_object(installed);
+        let can_merge = shipped_json.is_some() && user_json.is_some();
+
+        let mut new_version_path = None;
+        if offers_new_version {
+            let sidecar = new_version_of(&staged);
+            fs::rename(&staged, &sidecar).map_err(|e| {
+                format!("Failed to set aside new version of {}: {}", staged.display(), e)
+            })?;
+            new_version_path = Some(format!("{}{}", rel, NEW_VERSION_SUFFIX));
+        }
+        let applied = match (&shipped_json, &user_json) {
+            (Some(shipped), Some(user)) if offers_new_version => {
+                write_merged(shipped, user, &staged)?;
+                ConfigFileChoice::Merge
+            }
+            _ => {
+                if let Some(parent) = staged.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+                }
+                fs::copy(installed, &staged).map_err(|e| {
+                    format!("Failed to keep edited {}: {}", installed.display(), e)
+                })?;
+                ConfigFileChoice::KeepMine
+            }
+        };
+        info!("Kept user edits to {} ({:?})", rel, applied);
+        preserved.push(PreservedConfig {
+            path: rel,
+            applied,
+            new_version_path,
+            can_merge,
+            shipped_sha256: shipped_sha,
+        });
+    }
+    Ok(preserved)
+}
+
+/// Let the UI know which files it may want to ask about
+pub fn notify_preserved_configs(app_handle: &AppHandle, mod_name: &str, files: &[PreservedConfig]) {
+    if files.is_empty() {
+        return;
+    }
+    let payload = PreservedConfigsEvent {
+        mod_name: mod_name.to_string(),
+        files: files.to_vec(),
+    };
+    if let Err(e) = app_handle.emit("user-configs-preserved", &payload) {
+        warn!("Failed to emit preserved config notice: {}", e);
+    }
+}
+
+/// Settle a preserved file: keep the user's version, take the shipped one, or merge them.
+/// Removes the <file>.new left by the install either way.
+pub(crate) fn resolve_preserved_file(
+    game_root: &Path,
+    rel_path: &str,
+    choice: ConfigFileChoice,
+) -> Result<(), String> {
+    let rel = Path::new(rel_path);
+    if !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(format!("Invalid config path: {}", rel_path));
+    }
+    let file = game_root.join(rel);
+    let sidecar = new_version_of(&file);
+    if !sidecar.is_file() {
+        return Err(format!("No new version of {} is waiting", rel_path));
+    }
+
+    let ledger = LedgerOperation::begin(game_root, "resolve_preserved_config");
+    match choice {
+        ConfigFileChoice::KeepMine => {
+            fs::remove_file(&sidecar)
+                .map_err(|e| format!("Failed to remove {}: {}", sidecar.display(), e))?;
+            ledger.deleted(&sidecar, false);
+        }
+        ConfigFileChoice::UseNew => {
+            move_path(&sidecar, &file)?;
+            ledger.renamed(&sidecar, &file);
+            ledger.wrote(&file, true);
+        }
+        ConfigFileChoice::Merge => {
+            let (Some(shipped), Some(user)) = (read_json_object(&sidecar), read_json_object(&file)) else {
+                return Err(format!("{} can't be merged: both versions must be JSON objects", rel_path));
+            };
+            // Record the shipped hash so the merged file still counts as edited
+            let shipped_sha = hash_file(&sidecar)?;
+            write_merged(&shipped, &user, &file)?;
+            fs::remove_file(&sidecar)
+                .map_err(|e| format!("Failed to remove {}: {}", sidecar.display(), e))?;
+            ledger.wrote_hashed(&file, shipped_sha);
+            ledger.deleted(&sidecar, false);
+        }
+    }
+    info!("Resolved preserved config {} with {:?}", rel_path, choice);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn resolve_preserved_config(
+    app_handle: AppHandle,
+    game_root_path: String,
+    path: String,
+    choice: ConfigFileChoice,
+) -> Result<(), String> {
+    ensure_write_access(&app_handle)?;
+    let result = resolve_preserved_file(Path::new(&game_root_path), &path, choice);
+    crate::utils::telemetry::track_result(&app_handle, "resolve_preserved_config", result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+
+    #[test]
+    fn edited_script_configs_survive_an_update() {
+        let game = FakeGame::new();
+        let installed = game.root().join("reframework/autorun/Hud");
+        fs::create_dir_all(&installed).unwrap();
+        fs::write(installed.join("Hud.lua"), b"-- v1").unwrap();
+        fs::write(installed.join("config.json"), br#"{"scale": 1.0}"#).unwrap();
+        fs::write(installed.join("colors.lua"), b"return { red = 1 }").unwrap();
+        {
+            let ledger = LedgerOperation::begin(game.root(), "install_reframework_mod");
+            for name in ["Hud.lua", "config.json", "colors.lua"] {
+                ledger.wrote(&installed.join(name), false);
+            }
+        }
+
+        // The user tweaks two files, then installs v2, which changes all three
+        fs::write(installed.join("config.json"), br#"{"scale": 1.5}"#).unwrap();
+        fs::write(installed.join("colors.lua"), b"return { red = 0 }").unwrap();
+        let staged = game.root().join("staged");
+        fs::create_dir_all(&staged).unwrap();
+        fs::write(staged.join("Hud.lua"), b"-- v2").unwrap();
+        fs::write(staged.join("config.json"), br#"{"scale": 1.0, "opacity": 0.8}"#).unwrap();
+        fs::write(staged.join("colors.lua"), b"return { red = 1, blue = 1 }").unwrap();
+
+        let preserved = carry_over_user_edits(game.root(), &installed, &staged).expect("carry over");
+        assert_eq!(preserved.len(), 2);
+        let merged: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(staged.join("config.json")).unwrap()).unwrap();
+        assert_eq!(merged, serde_json::json!({"scale": 1.5, "opacity": 0.8}));
+        assert_eq!(fs::read(staged.join("colors.lua")).unwrap(), b"return { red = 0 }");
+        assert!(staged.join("colors.lua.new").is_file());
+        assert_eq!(fs::read(staged.join("Hud.lua")).unwrap(), b"-- v2");
+
+        // Taking the shipped version replaces the edited one
+        fs::remove_dir_all(&installed).unwrap();
+        fs::rename(&staged, &installed).unwrap();
+        resolve_preserved_file(game.root(), "reframework/autorun/Hud/colors.lua", ConfigFileChoice::UseNew)
+            .expect("resolve");
+        assert_eq!(fs::read(installed.join("colors.lua")).unwrap(), b"return { red = 1, blue = 1 }");
+        assert!(!installed.join("colors.lua.new").exists());
+        assert!(resolve_preserved_file(game.root(), "../outside.json", ConfigFileChoice::KeepMine).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+// src-tauri/src/utils/walk.rs
+// Every recursive scan of mod and game folders goes through here so they agree on how deep to
+// look, what counts as hidden and what to do with symlinks. Links are followed, since mod
+// folders are often linked in from another drive; a link back to one of its own parents is
+// logged and skipped instead of being walked until the path gets too long.
+use log::{debug, warn};
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, FilterEntry, IntoIter, WalkDir};
+
+/// Deepest entry layout detection (natives/, .pak, screenshots, READMEs) looks at: a file
+/// inside three levels of subfolders, e.g. `Mod v1.2/Author/Mod/natives`
+pub const LAYOUT_SEARCH_DEPTH: usize = 4;
+
+/// Dot files and folders, and on Windows anything with the hidden attribute
+pub fn is_hidden(entry: &DirEntry) -> bool {
+    if entry.file_name().to_string_lossy().starts_with('.') {
+        return true;
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        if entry
+            .metadata()
+            .is_ok_and(|m| m.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+        {
+            return true;
+        }
+    }
+    false
+}
+
+fn keep_all(_: &DirEntry) -> bool {
+    true
+}
+
+// The starting folder is always walked, even if it is hidden itself
+fn keep_visible(entry: &DirEntry) -> bool {
+    entry.depth() == 0 || !is_hidden(entry)
+}
+
+/// A directory walk with the shared defaults: the whole tree including the starting folder,
+/// hidden entries skipped, links followed
+#[derive(Debug, Clone)]
+pub struct Walk {
+    root: PathBuf,
+    min_depth: usize,
+    max_depth: usize,
+    include_hidden: bool,
+    follow_links: bool,
+    sorted: bool,
+}
+
+impl Walk {
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Walk {
+            root: root.as_ref().to_path_buf(),
+            min_depth: 0,
+            max_depth: usize::MAX,
+            include_hidden: false,
+            follow_links: true,
+            sorted: false,
+        }
+    }
+
+    /// Only yield entries at least this many levels below the start (0 is the start itself)
+    pub fn min_depth(mut self, depth: usize) -> Self {
+        self.min_depth = depth;
+        self
+    }
+
+    /// Don't go further than this many levels below the start
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Also yield hidden entries, for walks that must see everything (copies, backups, removal)
+    pub fn include_hidden(mut self) -> Self {
+        self.include_hidden = true;
+        self
+    }
+
+    /// Don't descend into linked folders; for walks that delete what they find
+    pub fn no_follow(mut self) -> Self {
+        self.follow_links = false;
+        self
+    }
+
+    /// Visit siblings in file name order
+    pub fn sorted(mut self) -> Self {
+        self.sorted = true;
+        self
+    }
+
+    /// Regular files only (the targets of links, when links are followed)
+    pub fn files(self) -> impl Iterator<Item = DirEntry> {
+        self.into_iter().filter(|e| e.file_type().is_file())
+    }
+}
+
+impl IntoIterator for Walk {
+    type Item = DirEntry;
+    type IntoIter = WalkIter;
+
+    fn into_iter(self) -> WalkIter {
+        let mut walker = WalkDir::new(&self.root)
+            .min_depth(self.min_depth)
+            .max_depth(self.max_depth)
+            .follow_links(self.follow_links);
+        if self.sorted {
+            walker = walker.sort_by_file_name();
+        }
+        let filter: fn(&DirEntry) -> bool = if self.include_hidden { keep_all } else { keep_visible };
+        WalkIter {
+            inner: walker.into_iter().filter_entry(filter),
+            root: self.root,
+        }
+    }
+}
+
+/// Entries of a Walk. Unreadable entries are logged and skipped.
+pub struct WalkIter {
+    inner: FilterEntry<IntoIter, fn(&DirEntry) -> bool>,
+    root: PathBuf,
+}
+
+impl WalkIter {
+    /// Don't descend into the folder just yielded
+    pub fn skip_current_dir(&mut self) {
+        self.inner.skip_current_dir();
+    }
+}
+
+impl Iterator for WalkIter {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<DirEntry> {
+        loop {
+            let err = match self.inner.next()? {
+                Ok(entry) => return Some(entry),
+                Err(err) => err,
+            };
+            let path = err.path().unwrap_or(self.root.as_path()).display().to_string();
+            if let Some(ancestor) = err.loop_ancestor() {
+                warn!("Skipping {}: it links back to {}", path, ancestor.display());
+            } else if err.depth() == 0 && err.io_error().is_some_and(|e| e.kind() == io::ErrorKind::NotFound) {
+                // Scanning a folder that doesn't exist finds nothing
+            } else {
+                debug!("Skipping {} while scanning {}: {}", path, self.root.display(), err);
+            }
+        }
+    }
+}
+
+/// The first entry below `root` matching `predicate`, looking one level deeper at a time so a
+/// match near the top wins over one buried in a subfolder. Gives up after `max_depth` levels
+/// or as soon as a level is empty.
+pub fn find_shallowest(
+    root: &Path,
+    max_depth: usize,
+    mut predicate: impl FnMut(&DirEntry) -> bool,
+) -> Option<DirEntry> {
+    for depth in 1..=max_depth {
+        let mut level_empty = true;
+        for entry in Walk::new(root).min_depth(depth).max_depth(depth).sorted() {
+            level_empty = false;
+            if predicate(&entry) {
+                return Some(entry);
+            }
+        }
+        if level_empty {
+            break;
+        }
+    }
+    None
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::tests::FakeGame;
+    use crate::utils::modregistry::is_skin_mod_dir;
+    use std::fs;
+
+    #[test]
+    fn walks_skip_hidden_entries_and_symlink_loops() {
+        let game = FakeGame::new();
+        let mod_dir = game.mods_dir().join("LoopySkin");
+        fs::create_dir_all(mod_dir.join("natives/STM/Art")).unwrap();
+        fs::write(mod_dir.join("natives/STM/Art/tex.tex"), b"tex").unwrap();
+        fs::create_dir_all(mod_dir.join(".git")).unwrap();
+        fs::write(mod_dir.join(".git/HEAD"), b"ref").unwrap();
+        fs::write(mod_dir.join("natives/STM/.DS_Store"), b"junk").unwrap();
+        std::os::unix::fs::symlink(&mod_dir, mod_dir.join("natives/STM/Art/back")).unwrap();
+
+        let files: Vec<String> = Walk::new(&mod_dir)
+            .files()
+            .map(|e| e.path().strip_prefix(&mod_dir).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(files, vec!["natives/STM/Art/tex.tex"]);
+        assert_eq!(Walk::new(&mod_dir).include_hidden().files().count(), 3);
+
+        // Layout detection looks a few folders down, nearest match first
+        let nested = game.mods_dir().join("Nested");
+        fs::create_dir_all(nested.join("Nested v1.2/Author/Nested/natives/STM")).unwrap();
+        assert!(is_skin_mod_dir(&nested));
+        assert!(!is_skin_mod_dir(&nested.join("Nested v1.2/Author/Nested/natives/STM")));
+    }
+}
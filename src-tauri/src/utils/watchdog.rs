@@ -0,0 +1,81 @@
+// src-tauri/src/utils/watchdog.rs
+use log::{error, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Listener, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// How long the frontend gets to report in before we assume it died during init
+pub const FRONTEND_READY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Watch for the "frontend-ready" event from `window_label`. If it doesn't arrive in time the
+/// window is forced visible and an error dialog is shown, so a crashed frontend never leaves
+/// a hidden process the user can't see or close.
+pub fn arm_frontend_watchdog(app_handle: &AppHandle, window_label: &str) {
+    let ready = Arc::new(AtomicBool::new(false));
+
+    let ready_flag = ready.clone();
+    let watched = window_label.to_string();
+    app_handle.listen_any("frontend-ready", move |event| {
+        // The payload is the label of the window that came up; other windows don't count
+        let sender = serde_json::from_str::<String>(event.payload()).unwrap_or_default();
+        if sender != watched {
+            info!("Ignoring 'frontend-ready' from '{}' while waiting for '{}'", sender, watched);
+            return;
+        }
+        info!("Received 'frontend-ready' from the '{}' window", sender);
+        crate::utils::startuptiming::mark_frontend_ready();
+        ready_flag.store(true, Ordering::SeqCst);
+    });
+
+    let handle = app_handle.clone();
+    let label = window_label.to_string();
+    std::thread::spawn(move || {
+        std::thread::sleep(FRONTEND_READY_TIMEOUT);
+        if ready.load(Ordering::SeqCst) {
+            return;
+        }
+        warn!(
+            "No 'frontend-ready' signal from '{}' window after {}s; forcing it visible",
+            label,
+            FRONTEND_READY_TIMEOUT.as_secs()
+        );
+        match handle.get_webview_window(&label) {
+            Some(window) => {
+                if let Err(e) = window.show() {
+                    error!("Failed to show '{}' window from watchdog: {}", label, e);
+                }
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+            None => error!("Watchdog couldn't find the '{}' window", label),
+        }
+
+        let exit_handle = handle.clone();
+        handle
+            .dialog()
+            .message(format!(
+                "The interface didn't finish loading within {} seconds. It may have crashed during startup.\n\n\
+                 Check the log for errors, then quit and relaunch. If this keeps happening, try resetting the settings.",
+                FRONTEND_READY_TIMEOUT.as_secs()
+            ))
+            .title("FOSS Mod Manager failed to start")
+            .kind(MessageDialogKind::Error)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                "Quit".to_string(),
+                "Keep waiting".to_string(),
+            ))
+            .show(move |quit| {
+                if quit {
+                    info!("User chose to quit after frontend startup timeout");
+                    exit_handle.exit(1);
+                }
+            });
+    });
+    info!(
+        "Frontend watchdog armed for '{}' window ({}s)",
+        window_label,
+        FRONTEND_READY_TIMEOUT.as_secs()
+    );
+}
@@ -1,7 +1,8 @@
 import React, { useState, useEffect, useCallback, useContext, lazy, Suspense } from 'react';
-import { Button, notification, Spin, Typography, List, Card, message, Layout, Popconfirm } from 'antd';
+import { Button, notification, Spin, Typography, List, Card, message, Layout, Popconfirm, Modal } from 'antd';
 import { open } from '@tauri-apps/plugin-dialog';
 import { invoke, Channel } from '@tauri-apps/api/core';
+import { getCurrentWebview } from '@tauri-apps/api/webview';
 import { useGameConfig } from '../contexts/GameConfigContext';
 import { GameConfigContext } from '../contexts/GameConfigContext';
 import SetupOverlay from './SetupOverlay';
@@ -174,6 +175,40 @@ const MainContent = () => {
     }
   };
 
+  // Archives dropped onto the window are classified and routed by the backend
+  useEffect(() => {
+    if (!gameConfig?.game_root_path) return;
+    let unlisten;
+    getCurrentWebview().onDragDropEvent(async (event) => {
+      if (event.payload.type !== 'drop') return;
+      const channel = new Channel();
+      channel.onmessage = (evt) => console.log('Installation event:', evt);
+      setIsInstalling(true);
+      for (const archivePath of event.payload.paths) {
+        try {
+          const kind = await invoke('install_mod_from_archive', {
+            gameRootPath: gameConfig.game_root_path,
+            archivePath,
+            onEvent: channel,
+          });
+          message.success(kind === 'SkinMod'
+            ? `Added ${getFilename(archivePath)}; enable it from the skins tab`
+            : `Installed ${getFilename(archivePath)}`);
+        } catch (error) {
+          const errorMsg = typeof error === 'string' ? error : (error.message || 'Unknown error during installation');
+          notification.error({
+            message: 'Installation Error',
+            description: `Failed to install ${getFilename(archivePath)}: ${errorMsg}`,
+            duration: 5
+          });
+        }
+      }
+      setIsInstalling(false);
+      fetchMods(gameConfig.game_root_path);
+    }).then((fn) => { unlisten = fn; });
+    return () => { if (unlisten) unlisten(); };
+  }, [gameConfig?.game_root_path]);
+
   const handleToggleMod = async (modName, currentStatus) => {
       if (!gameConfig?.game_root_path) {
           message.error('Game config not loaded.');
@@ -196,10 +231,38 @@ const MainContent = () => {
       } catch (err) {
           console.error(`Error toggling mod ${modName}:`, err);
           const errorMsg = typeof err === 'string' ? err : (err.message || 'Unknown error');
+          if (errorMsg.includes('[E_SAVE_BACKUP_RECOMMENDED]')) {
+              message.destroy('toggleMod');
+              confirmSaveBackup(modName, errorMsg.replace('[E_SAVE_BACKUP_RECOMMENDED] ', ''));
+              return;
+          }
           message.error({ content: `Failed to toggle mod '${modName}': ${errorMsg}`, key: 'toggleMod', duration: 4 });
       }
   };
 
+  // Gameplay-affecting mods ask for a save backup first; back up and enable in one call
+  const confirmSaveBackup = (modName, warning) => {
+      Modal.confirm({
+          title: 'Back up your saves?',
+          content: warning,
+          okText: 'Back up and enable',
+          cancelText: 'Cancel',
+          onOk: async () => {
+              try {
+                  const result = await invoke('enable_with_save_backup', {
+                      gameRootPath: gameConfig.game_root_path,
+                      modIdentifier: modName,
+                  });
+                  message.success(`Saves backed up (${result.backup.file_count} files) and '${modName}' enabled.`);
+                  fetchMods(gameConfig.game_root_path);
+              } catch (err) {
+                  const errorMsg = typeof err === 'string' ? err : (err.message || 'Unknown error');
+                  message.error({ content: `Failed to back up saves for '${modName}': ${errorMsg}`, duration: 5 });
+              }
+          },
+      });
+  };
+
   // --- New Delete Handler for REFramework Mods ---
   const handleDeleteReframeworkMod = async (modName) => {
     if (!gameConfig?.game_root_path) {
@@ -10,12 +10,22 @@ const { Search } = Input;
 
 // Sort options for the dropdown
 const sortOptions = [
-  { value: 'default', label: 'Default' },
+  { value: 'default', label: 'Trending' },
+  { value: 'newest', label: 'Latest Added' },
+  { value: 'updated', label: 'Recently Updated' },
   { value: 'popular', label: 'Most Popular' },
-  { value: 'newest', label: 'Newest' },
   { value: 'endorsed', label: 'Most Endorsed' },
 ];
 
+// Short lists served by their own Nexus endpoints; everything else goes through search_mods
+const listCommands = {
+  default: 'fetch_trending_mods',
+  newest: 'fetch_latest_added',
+  updated: 'fetch_latest_updated',
+};
+
+const GAME_DOMAIN = 'monsterhunterwilds';
+
 const sortOrderOptions = [
   { value: 'desc', label: 'Descending' },
   { value: 'asc', label: 'Ascending' },
@@ -28,8 +38,13 @@ function SearchPage() {
   const [sortBy, setSortBy] = useState('default');
   const [sortOrder, setSortOrder] = useState('desc');
   const [searchQuery, setSearchQuery] = useState('');
+  const [submittedQuery, setSubmittedQuery] = useState('');
+  const [total, setTotal] = useState(0);
   const [viewMode, setViewMode] = useState('grid'); // 'grid' or 'list'
   const [page, setPage] = useState(1);
+  const pageSize = viewMode === 'grid' ? 20 : 10;
+  // Searching or sorting by popularity pages through Nexus; the short lists page locally
+  const serverPaged = Boolean(submittedQuery) || !listCommands[sortBy];
 
   // Fetch mods from Nexus API
   const fetchMods = async () => {
@@ -38,14 +53,22 @@ function SearchPage() {
     
     try {
       // Hardcoded for Monster Hunter Wilds
-      const response = await invoke('fetch_trending_mods', { 
-        gameDomainName: "monsterhunterwilds",
-        page,
-        sortBy: sortBy === 'default' ? null : sortBy,
-        sortOrder
-      });
-      
-      setMods(response || []);
+      if (serverPaged) {
+        const response = await invoke('search_mods', {
+          gameDomainName: GAME_DOMAIN,
+          query: submittedQuery || null,
+          page,
+          pageSize,
+          sortBy: sortBy === 'default' ? null : sortBy,
+          sortOrder
+        });
+        setMods(response?.mods || []);
+        setTotal(response?.total_count || 0);
+      } else {
+        const response = await invoke(listCommands[sortBy], { gameDomainName: GAME_DOMAIN });
+        setMods(response || []);
+        setTotal(response?.length || 0);
+      }
     } catch (err) {
       console.error('Error fetching mods:', err);
       setError(typeof err === 'string' ? err : 'Failed to load mods');
@@ -54,29 +77,40 @@ function SearchPage() {
     }
   };
 
-  // Fetch mods when sort options change
+  // Fetch mods when the query, sort options or (server-side) page change
   useEffect(() => {
     fetchMods();
-  }, [page, sortBy, sortOrder]);
+  }, [submittedQuery, sortBy, sortOrder, pageSize, serverPaged ? page : null]);
+
+  const handleSearch = (value) => {
+    setSubmittedQuery(value.trim());
+    setPage(1);
+  };
+
+  const handleSortChange = (value) => {
+    setSortBy(value);
+    setPage(1);
+  };
 
   // Handle opening a mod on Nexus Mods
   const handleOpenMod = async (mod) => {
     try {
       // Construct Nexus Mods URL from mod ID
-      const url = `https://www.nexusmods.com/monsterhunterwilds/mods/${mod.mod_id}`;
+      const url = `https://www.nexusmods.com/${GAME_DOMAIN}/mods/${mod.mod_id}`;
       await open(url);
     } catch (err) {
       console.error('Failed to open URL:', err);
     }
   };
 
-  // Filter mods by search query
-  const filteredMods = mods.filter(mod => 
-    searchQuery ? 
-      mod.name.toLowerCase().includes(searchQuery.toLowerCase()) || 
-      (mod.summary && mod.summary.toLowerCase().includes(searchQuery.toLowerCase()))
-    : true
-  );
+  // Search results arrive one page at a time; the short lists are paged here
+  const pagination = {
+    current: page,
+    onChange: (page) => setPage(page),
+    pageSize,
+    total,
+  };
+  const visibleMods = serverPaged ? mods : mods.slice((page - 1) * pageSize, page * pageSize);
 
   return (
     <div style={{ padding: '0 24px 24px' }}>
@@ -86,11 +120,12 @@ function SearchPage() {
       <Row gutter={[16, 16]} style={{ marginBottom: 16 }}>
         <Col xs={24} md={8}>
           <Search
-            placeholder="Search mods..."
+            placeholder="Search mods by name or file MD5..."
             allowClear
             enterButton={<SearchOutlined />}
             value={searchQuery}
             onChange={(e) => setSearchQuery(e.target.value)}
+            onSearch={handleSearch}
           />
         </Col>
         <Col xs={12} md={6}>
@@ -98,7 +133,7 @@ function SearchPage() {
             style={{ width: '100%' }}
             placeholder="Sort by"
             value={sortBy}
-            onChange={setSortBy}
+            onChange={handleSortChange}
             options={sortOptions}
           />
         </Col>
@@ -145,13 +180,8 @@ function SearchPage() {
         viewMode === 'grid' ? (
           <List
             grid={{ gutter: 16, xs: 1, sm: 2, md: 3, lg: 4, xl: 5, xxl: 6 }}
-            dataSource={filteredMods}
-            pagination={{
-              onChange: (page) => setPage(page),
-              pageSize: 20,
-              defaultCurrent: 1,
-              total: filteredMods.length,
-            }}
+            dataSource={visibleMods}
+            pagination={pagination}
             renderItem={(mod) => (
               <List.Item>
                 <Card
@@ -175,13 +205,8 @@ function SearchPage() {
         ) : (
           <List
             itemLayout="horizontal"
-            dataSource={filteredMods}
-            pagination={{
-              onChange: (page) => setPage(page),
-              pageSize: 10,
-              defaultCurrent: 1,
-              total: filteredMods.length,
-            }}
+            dataSource={visibleMods}
+            pagination={pagination}
             renderItem={(mod) => (
               <List.Item 
                 actions={[
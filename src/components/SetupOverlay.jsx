@@ -25,6 +25,9 @@ const SetupOverlay = ({ onSetupComplete }) => {
 
       if (selectedPath && typeof selectedPath === 'string') {
         const validatedData = await invoke('validate_game_installation', { executablePath: selectedPath });
+        (validatedData.warnings || []).forEach((warning) =>
+          notification.warning({ message: 'Game Path Warning', description: warning, duration: 0 })
+        );
         await onSetupComplete(validatedData);
         notification.success({
           message: 'Game Path Validated',
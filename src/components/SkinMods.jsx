@@ -1,6 +1,6 @@
 import React, { useState, useEffect, useRef } from 'react';
 import { List, Card, Spin, Typography, Tag, notification, Button, Switch, Tooltip, Popconfirm } from 'antd';
-import { invoke } from '@tauri-apps/api/core';
+import { invoke, Channel } from '@tauri-apps/api/core';
 import { ReloadOutlined, CheckCircleOutlined, StopOutlined, DeleteOutlined } from '@ant-design/icons';
 import LoadingOverlay from './LoadingOverlay';
 
@@ -24,10 +24,20 @@ const SkinMods = ({ gameRoot }) => {
     setError(null);
     
     try {
+      // Show mods as the scan finds them; large libraries on network drives take a while
+      const onEvent = new Channel();
+      const scanned = [];
+      onEvent.onmessage = (message) => {
+        if (message.event === 'found') {
+          scanned.push(message.data.skinMod);
+          setSkinMods([...scanned]);
+        }
+      };
+
       // First scan for new mods and update registry
       const mods = await invoke('scan_and_update_skin_mods', { 
         gameRootPath: gameRoot,
-        // No appHandle needed from frontend
+        onEvent,
       });
       
       // Then load all mods from the updated registry
@@ -296,7 +306,7 @@ const SkinMods = ({ gameRoot }) => {
       )}
       
       {/* Available Skins Section */}
-      {loading ? (
+      {loading && skinMods.length === 0 ? (
         <div style={{ textAlign: 'center', padding: 40 }}>
           <Spin size="large" />
         </div>
@@ -1,5 +1,5 @@
 import { useState, useEffect, useCallback, useContext } from 'react';
-import { invoke } from '@tauri-apps/api/core';
+import { invoke, Channel } from '@tauri-apps/api/core';
 import { notification } from 'antd';
 import { GameConfigContext } from '../contexts/GameConfigContext'; // Assuming context provides gameConfig
 
@@ -56,9 +56,20 @@ export const useInstaller = () => {
 
         try {
             console.log('useInstaller: Triggering REFramework installation...');
+            const channel = new Channel();
+            channel.onmessage = (event) => {
+                if (event.event === 'progress') {
+                    notification.info({
+                        key: 'rfInstall',
+                        message: 'Downloading REFramework',
+                        description: `${Math.round(event.data.progress * 100)}% - ${event.data.message}`,
+                        duration: 0,
+                    });
+                }
+            };
             await invoke('ensure_reframework', {
                 gameRootPath: gameConfig.game_root_path,
-                // Pass appHandle if the backend command needs it (currently doesn't)
+                onEvent: channel,
             });
             setIsRfInstalled(true); // Assume success means installed
             console.log('useInstaller: REFramework installation successful.');
@@ -13,6 +13,7 @@ const ErrorPage = lazy(() => import("./ErrorPage"));
 
 import { ConfigProvider, theme, App as AntApp, Spin } from "antd";
 import { GameConfigProvider } from "./contexts/GameConfigContext";
+import { emit } from "@tauri-apps/api/event";
 
 // Loading component for suspense fallback
 const LoadingFallback = () => (
@@ -59,3 +60,6 @@ ReactDOM.createRoot(document.getElementById("root")).render(
     </GameConfigProvider>
   </React.StrictMode>,
 );
+
+// Let the backend watchdog know the UI came up
+emit("frontend-ready", "main");
@@ -47,4 +47,7 @@ ReactDOM.createRoot(document.getElementById('root')).render(
   <React.StrictMode>
     <SetupApp />
   </React.StrictMode>
-); 
\ No newline at end of file
+);
+
+// Let the backend watchdog know the UI came up
+emit('frontend-ready', 'setup');